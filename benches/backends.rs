@@ -0,0 +1,51 @@
+//! Compares the time to parse a large document with the xml-rs backend and with the quick-xml
+//! backend. Run with `cargo bench --bench backends --features quick-xml`.
+
+use opendrive::core::OpenDrive;
+use opendrive::generate::generators::StandardMap;
+use opendrive::units::prelude::*;
+use std::time::{Duration, Instant};
+
+/// The number of times each backend parses the document, of which the fastest counts
+const RUNS: usize = 5;
+
+fn main() {
+    let xml = StandardMap::GridCity {
+        columns: 40,
+        rows: 40,
+        block: Length::new::<meter>(100.0),
+    }
+    .generate()
+    .to_xml_string()
+    .unwrap();
+    println!("document of {:.1} MB", xml.len() as f64 / 1e6);
+
+    let xml_rs = fastest(|| OpenDrive::from_xml_str(&xml).unwrap());
+    let quick_xml =
+        fastest(|| OpenDrive::from_source(quick_xml::NsReader::from_str(&xml)).unwrap());
+    report("xml-rs", xml_rs, xml.len());
+    report("quick-xml", quick_xml, xml.len());
+    println!(
+        "quick-xml is {:.1}x as fast as xml-rs",
+        xml_rs.as_secs_f64() / quick_xml.as_secs_f64()
+    );
+}
+
+fn fastest(parse: impl Fn() -> OpenDrive) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(parse());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn report(backend: &str, duration: Duration, bytes: usize) {
+    println!(
+        "{backend:>9}: {:>8.1} ms, {:>6.1} MB/s",
+        duration.as_secs_f64() * 1e3,
+        bytes as f64 / 1e6 / duration.as_secs_f64()
+    );
+}
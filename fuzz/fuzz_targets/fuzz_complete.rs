@@ -2,10 +2,16 @@
 
 use libfuzzer_sys::fuzz_target;
 use opendrive::core::OpenDrive;
+use opendrive::parser::ParseOptions;
 
 fuzz_target!(|data: OpenDrive| {
     let string = data.to_xml_string().unwrap();
-    let data_2 = OpenDrive::from_xml_str(&string);
+    // enums contain values unknown to the schema as their `Other` variant
+    let options = ParseOptions {
+        tolerant_enums: true,
+        ..ParseOptions::default()
+    };
+    let data_2 = OpenDrive::from_str_with(&string, options);
 
     if let Err(e) = &data_2 {
         eprintln!("{e}");
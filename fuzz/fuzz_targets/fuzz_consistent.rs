@@ -0,0 +1,22 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use opendrive::core::OpenDrive;
+use opendrive::fuzzing::ConsistentOpenDrive;
+use opendrive::parser::ParseOptions;
+
+fuzz_target!(|data: ConsistentOpenDrive| {
+    let ConsistentOpenDrive(data) = data;
+    let string = data.to_xml_string().unwrap();
+    // enums contain values unknown to the schema as their `Other` variant
+    let options = ParseOptions {
+        tolerant_enums: true,
+        ..ParseOptions::default()
+    };
+    let data_2 = OpenDrive::from_str_with(&string, options).unwrap();
+    assert_eq!(data, data_2);
+
+    let mut normalized = data_2.clone();
+    normalized.normalize();
+    assert_eq!(data_2, normalized);
+});
@@ -0,0 +1,60 @@
+/* C interface of the opendrive crate, built with the `capi` feature, see `src/capi.rs`:
+ * cargo rustc --lib --release --features capi --crate-type cdylib */
+#ifndef OPENDRIVE_H
+#define OPENDRIVE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct OdrDocument OdrDocument;
+
+typedef struct OdrPose {
+    double x;
+    double y;
+    double hdg;
+} OdrPose;
+
+typedef struct OdrRoadPosition {
+    double s;
+    double t;
+} OdrRoadPosition;
+
+typedef struct OdrRoadInfo {
+    double length;
+    size_t lane_section_count;
+    bool junction;
+} OdrRoadInfo;
+
+typedef struct OdrExtent {
+    double north;
+    double south;
+    double east;
+    double west;
+} OdrExtent;
+
+const char *odr_last_error(void);
+
+OdrDocument *odr_document_parse(const char *xml, size_t length);
+OdrDocument *odr_document_read_file(const char *path);
+void odr_document_free(OdrDocument *document);
+char *odr_document_write(const OdrDocument *document);
+void odr_string_free(char *string);
+
+size_t odr_document_road_count(const OdrDocument *document);
+ptrdiff_t odr_document_find_road(const OdrDocument *document, const char *id);
+bool odr_document_extent(const OdrDocument *document, OdrExtent *extent);
+
+ptrdiff_t odr_road_id(const OdrDocument *document, size_t index, char *buffer, size_t capacity);
+bool odr_road_info(const OdrDocument *document, size_t index, OdrRoadInfo *info);
+bool odr_road_pose_at(const OdrDocument *document, size_t index, OdrRoadPosition position, OdrPose *pose);
+bool odr_road_project(const OdrDocument *document, size_t index, double x, double y, OdrRoadPosition *position);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,370 @@
+//! The XML backend of the parser and the writer.
+//!
+//! Documents are read from and written as the backend-neutral [`Event`]s of this module, which
+//! any XML library can produce as an [`EventSource`] for [`crate::core::OpenDrive::from_source`]
+//! or consume as an [`EventSink`] of [`crate::core::OpenDrive::write_to_sink`]. [`xml`] (xml-rs)
+//! is the backend of the other read and write functions and is an [`EventSource`] as
+//! [`xml::EventReader`] and an [`EventSink`] as [`xml::EventWriter`]. With the `quick-xml`
+//! feature, `quick_xml::NsReader` is an [`EventSource`] and `quick::QuickXmlWriter` an
+//! [`EventSink`] as well.
+
+use crate::writer::layout::LayoutWriter;
+use std::borrow::Cow;
+
+#[cfg(feature = "quick-xml")]
+pub mod quick;
+
+/// The qualified name of an element or an attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Name<'a> {
+    pub local_name: Cow<'a, str>,
+    pub prefix: Option<Cow<'a, str>>,
+    /// The URI of the namespace the prefix is bound to
+    pub namespace: Option<Cow<'a, str>>,
+}
+
+impl<'a> Name<'a> {
+    /// A name without prefix and namespace
+    pub fn local(local_name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            local_name: local_name.into(),
+            prefix: None,
+            namespace: None,
+        }
+    }
+
+    /// The name as written, like `xsi:noNamespaceSchemaLocation`
+    pub fn qualified(&self) -> Cow<'_, str> {
+        match &self.prefix {
+            Some(prefix) => Cow::Owned(format!("{prefix}:{}", self.local_name)),
+            None => Cow::Borrowed(&self.local_name),
+        }
+    }
+
+    fn as_xml_rs(&self) -> xml::name::Name<'_> {
+        xml::name::Name {
+            local_name: &self.local_name,
+            namespace: self.namespace.as_deref(),
+            prefix: self.prefix.as_deref(),
+        }
+    }
+
+    pub fn into_owned(self) -> Name<'static> {
+        Name {
+            local_name: Cow::Owned(self.local_name.into_owned()),
+            prefix: self.prefix.map(|prefix| Cow::Owned(prefix.into_owned())),
+            namespace: self
+                .namespace
+                .map(|namespace| Cow::Owned(namespace.into_owned())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Attribute<'a> {
+    pub name: Name<'a>,
+    /// The value without escapes
+    pub value: Cow<'a, str>,
+}
+
+/// An XML event of a document, independent of the backend that reads or writes it. Texts are
+/// without escapes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event<'a> {
+    StartDocument {
+        standalone: Option<bool>,
+    },
+    ProcessingInstruction {
+        name: Cow<'a, str>,
+        data: Option<Cow<'a, str>>,
+    },
+    StartElement {
+        name: Name<'a>,
+        attributes: Vec<Attribute<'a>>,
+        /// The namespaces the element declares, or which are in scope of it, as prefix and URI,
+        /// with an empty prefix for the default namespace
+        namespaces: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    },
+    /// The end of the element that has been started last
+    EndElement,
+    Characters(Cow<'a, str>),
+    /// Characters that are whitespace only
+    Whitespace(Cow<'a, str>),
+    CData(Cow<'a, str>),
+    Comment(Cow<'a, str>),
+    EndDocument,
+}
+
+/// A source of the XML events of a document, like an [`xml::EventReader`] or any iterator of
+/// events as [`IterSource`]
+pub trait EventSource {
+    type Events: Iterator<Item = crate::parser::Result<Event<'static>>>;
+
+    fn into_events(self) -> Self::Events;
+}
+
+/// The events of any iterator as an [`EventSource`]
+pub struct IterSource<T>(pub T);
+
+impl<T> EventSource for IterSource<T>
+where
+    T: IntoIterator<Item = crate::parser::Result<Event<'static>>>,
+{
+    type Events = T::IntoIter;
+
+    fn into_events(self) -> Self::Events {
+        self.0.into_iter()
+    }
+}
+
+/// A consumer of the XML events of a document, like an [`xml::EventWriter`]
+pub trait EventSink {
+    fn write_event(&mut self, event: Event<'_>) -> crate::writer::Result<()>;
+}
+
+/// The events of xml-rs, like those of an [`xml::EventReader`], as an [`EventSource`]
+pub struct XmlRsEvents<I>(I);
+
+impl<I> XmlRsEvents<I> {
+    pub fn new(events: I) -> Self {
+        Self(events)
+    }
+}
+
+impl<I> Iterator for XmlRsEvents<I>
+where
+    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+{
+    type Item = crate::parser::Result<Event<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let event = self.0.next()?;
+        Some(
+            event
+                .map(Event::from)
+                .map_err(crate::parser::Error::from)
+                .map_err(Box::new),
+        )
+    }
+}
+
+impl<R: std::io::Read> EventSource for xml::EventReader<R> {
+    type Events = XmlRsEvents<xml::reader::Events<R>>;
+
+    fn into_events(self) -> Self::Events {
+        XmlRsEvents(self.into_iter())
+    }
+}
+
+impl From<xml::reader::XmlEvent> for Event<'static> {
+    fn from(event: xml::reader::XmlEvent) -> Self {
+        use xml::reader::XmlEvent;
+        match event {
+            XmlEvent::StartDocument { standalone, .. } => Event::StartDocument { standalone },
+            XmlEvent::EndDocument => Event::EndDocument,
+            XmlEvent::ProcessingInstruction { name, data } => Event::ProcessingInstruction {
+                name: Cow::Owned(name),
+                data: data.map(Cow::Owned),
+            },
+            XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } => Event::StartElement {
+                name: Name::from(name),
+                attributes: attributes
+                    .into_iter()
+                    .map(|attribute| Attribute {
+                        name: Name::from(attribute.name),
+                        value: Cow::Owned(attribute.value),
+                    })
+                    .collect(),
+                namespaces: namespace
+                    .into_iter()
+                    .filter(|(prefix, _)| {
+                        *prefix != xml::namespace::NS_XML_PREFIX
+                            && *prefix != xml::namespace::NS_XMLNS_PREFIX
+                    })
+                    .map(|(prefix, uri)| {
+                        (Cow::Owned(prefix.to_string()), Cow::Owned(uri.to_string()))
+                    })
+                    .collect(),
+            },
+            XmlEvent::EndElement { .. } => Event::EndElement,
+            XmlEvent::CData(data) => Event::CData(Cow::Owned(data)),
+            XmlEvent::Comment(text) => Event::Comment(Cow::Owned(text)),
+            XmlEvent::Characters(data) => Event::Characters(Cow::Owned(data)),
+            XmlEvent::Whitespace(data) => Event::Whitespace(Cow::Owned(data)),
+        }
+    }
+}
+
+impl From<xml::name::OwnedName> for Name<'static> {
+    fn from(name: xml::name::OwnedName) -> Self {
+        Self {
+            local_name: Cow::Owned(name.local_name),
+            prefix: name.prefix.map(Cow::Owned),
+            namespace: name.namespace.map(Cow::Owned),
+        }
+    }
+}
+
+impl<'a> From<xml::writer::XmlEvent<'a>> for Event<'a> {
+    fn from(event: xml::writer::XmlEvent<'a>) -> Self {
+        use xml::writer::XmlEvent;
+        match event {
+            XmlEvent::StartDocument { standalone, .. } => Event::StartDocument { standalone },
+            XmlEvent::ProcessingInstruction { name, data } => Event::ProcessingInstruction {
+                name: Cow::Borrowed(name),
+                data: data.map(Cow::Borrowed),
+            },
+            XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } => Event::StartElement {
+                name: Name::from(name),
+                attributes: attributes
+                    .iter()
+                    .map(|attribute| Attribute {
+                        name: Name::from(attribute.name),
+                        value: Cow::Borrowed(attribute.value),
+                    })
+                    .collect(),
+                namespaces: namespace
+                    .iter()
+                    .map(|(prefix, uri)| {
+                        (Cow::Owned(prefix.to_string()), Cow::Owned(uri.to_string()))
+                    })
+                    .collect(),
+            },
+            XmlEvent::EndElement { .. } => Event::EndElement,
+            XmlEvent::CData(data) => Event::CData(Cow::Borrowed(data)),
+            XmlEvent::Comment(text) => Event::Comment(Cow::Borrowed(text)),
+            XmlEvent::Characters(data) => Event::Characters(Cow::Borrowed(data)),
+        }
+    }
+}
+
+impl<'a> From<xml::name::Name<'a>> for Name<'a> {
+    fn from(name: xml::name::Name<'a>) -> Self {
+        Self {
+            local_name: Cow::Borrowed(name.local_name),
+            prefix: name.prefix.map(Cow::Borrowed),
+            namespace: name.namespace.map(Cow::Borrowed),
+        }
+    }
+}
+
+/// Writes the event as the event of xml-rs it is
+fn write_xml_rs(
+    event: &Event<'_>,
+    write: impl FnOnce(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+) -> xml::writer::Result<()> {
+    use xml::writer::XmlEvent;
+    match event {
+        Event::StartDocument { standalone } => write(XmlEvent::StartDocument {
+            version: xml::common::XmlVersion::Version10,
+            encoding: None,
+            standalone: *standalone,
+        }),
+        Event::ProcessingInstruction { name, data } => write(XmlEvent::ProcessingInstruction {
+            name,
+            data: data.as_deref(),
+        }),
+        Event::StartElement {
+            name: element,
+            attributes,
+            namespaces,
+        } => {
+            let mut namespace = xml::namespace::Namespace::empty();
+            for (prefix, uri) in namespaces {
+                namespace.put(prefix.as_ref(), uri.as_ref());
+            }
+            write(XmlEvent::StartElement {
+                name: element.as_xml_rs(),
+                attributes: Cow::Owned(
+                    attributes
+                        .iter()
+                        .map(|attribute| {
+                            xml::attribute::Attribute::new(
+                                attribute.name.as_xml_rs(),
+                                &attribute.value,
+                            )
+                        })
+                        .collect(),
+                ),
+                namespace: Cow::Owned(namespace),
+            })
+        }
+        Event::EndElement => write(XmlEvent::EndElement { name: None }),
+        Event::Characters(data) | Event::Whitespace(data) => write(XmlEvent::Characters(data)),
+        Event::CData(data) => write(XmlEvent::CData(data)),
+        Event::Comment(text) => write(XmlEvent::Comment(text)),
+        Event::EndDocument => Ok(()),
+    }
+}
+
+impl<W: std::io::Write> EventSink for xml::EventWriter<W> {
+    fn write_event(&mut self, event: Event<'_>) -> crate::writer::Result<()> {
+        write_xml_rs(&event, |event| self.write(event))
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)
+    }
+}
+
+impl<W: std::io::Write> EventSink for LayoutWriter<'_, W> {
+    fn write_event(&mut self, event: Event<'_>) -> crate::writer::Result<()> {
+        write_xml_rs(&event, |event| self.write(event))
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::{Event, EventSink, EventSource, IterSource};
+    use crate::core::OpenDrive;
+    use crate::fixtures::{document, TestRoad};
+    use crate::writer::WriteOptions;
+
+    #[test]
+    pub fn test_event_source_and_sink() {
+        let xml = document(TestRoad::new("1").sides(&[], &[]));
+        let drive = OpenDrive::from_source(xml::EventReader::from_str(xml.as_str())).unwrap();
+        assert_eq!(drive, OpenDrive::from_xml_str(&xml).unwrap());
+
+        let events = xml::EventReader::from_str(xml.as_str())
+            .into_events()
+            .collect::<Vec<_>>();
+        assert_eq!(drive, OpenDrive::from_source(IterSource(events)).unwrap());
+
+        #[derive(Default)]
+        struct Names(Vec<String>);
+
+        impl EventSink for Names {
+            fn write_event(&mut self, event: Event<'_>) -> crate::writer::Result<()> {
+                if let Event::StartElement { name, .. } = event {
+                    self.0.push(name.local_name.into_owned());
+                }
+                Ok(())
+            }
+        }
+
+        let mut names = Names::default();
+        drive
+            .write_to_sink(&mut names, &WriteOptions::default())
+            .unwrap();
+        assert_eq!(
+            [
+                "OpenDRIVE",
+                "header",
+                "road",
+                "planView",
+                "geometry",
+                "line"
+            ],
+            names.0[..6]
+        );
+    }
+}
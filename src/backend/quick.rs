@@ -0,0 +1,310 @@
+//! The quick-xml backend, which reads the events of a [`quick_xml::NsReader`] and writes them with
+//! a [`quick_xml::Writer`]
+
+use crate::backend::{Attribute, Event, EventSink, EventSource, Name};
+use quick_xml::events::{BytesCData, BytesDecl, BytesEnd, BytesPI, BytesStart, BytesText};
+use quick_xml::name::{PrefixDeclaration, ResolveResult};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+impl<R: std::io::BufRead> EventSource for quick_xml::NsReader<R> {
+    type Events = QuickXmlEvents<R>;
+
+    fn into_events(mut self) -> Self::Events {
+        // like xml-rs, which reports empty elements as start and end
+        self.config_mut().expand_empty_elements = true;
+        QuickXmlEvents {
+            reader: self,
+            buffer: Vec::new(),
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+/// The events of a [`quick_xml::NsReader`] as an [`EventSource`]. Texts split by references,
+/// like `A &amp; B`, are joined into a single event.
+pub struct QuickXmlEvents<R> {
+    reader: quick_xml::NsReader<R>,
+    buffer: Vec<u8>,
+    pending: VecDeque<Event<'static>>,
+    done: bool,
+}
+
+impl<R: std::io::BufRead> QuickXmlEvents<R> {
+    /// Reads events until the next event that is no text, which it returns together with the
+    /// text in front of it
+    fn read(&mut self) -> quick_xml::Result<(Option<String>, Event<'static>)> {
+        use quick_xml::events::Event as QuickXmlEvent;
+        let mut text: Option<String> = None;
+        loop {
+            self.buffer.clear();
+            let event = self.reader.read_event_into(&mut self.buffer)?;
+            let decoder = self.reader.decoder();
+            let event = match event {
+                QuickXmlEvent::Text(t) => {
+                    text.get_or_insert_default().push_str(&t.xml10_content()?);
+                    continue;
+                }
+                QuickXmlEvent::GeneralRef(reference) => {
+                    let text = text.get_or_insert_default();
+                    match reference.resolve_char_ref()? {
+                        Some(c) => text.push(c),
+                        None => {
+                            let name = reference.decode()?;
+                            match quick_xml::escape::resolve_predefined_entity(&name) {
+                                Some(resolved) => text.push_str(resolved),
+                                None => {
+                                    return Err(quick_xml::escape::EscapeError::UnrecognizedEntity(
+                                        0..name.len(),
+                                        name.to_string(),
+                                    )
+                                    .into())
+                                }
+                            }
+                        }
+                    }
+                    continue;
+                }
+                // empty elements are reported as start and end, see `into_events`
+                QuickXmlEvent::Start(start) | QuickXmlEvent::Empty(start) => {
+                    let (namespace, _) = self.reader.resolve_element(start.name());
+                    let name = Name {
+                        local_name: owned(decoder.decode(start.local_name().as_ref())?),
+                        prefix: match start.name().prefix() {
+                            Some(prefix) => Some(owned(decoder.decode(prefix.as_ref())?)),
+                            None => None,
+                        },
+                        namespace: bound(namespace, decoder)?,
+                    };
+                    let mut attributes = Vec::new();
+                    let mut namespaces = Vec::new();
+                    for attribute in start.attributes() {
+                        let attribute = attribute?;
+                        let value = owned(attribute.decode_and_unescape_value(decoder)?);
+                        match attribute.key.as_namespace_binding() {
+                            Some(PrefixDeclaration::Default) => {
+                                namespaces.push((Cow::Borrowed(""), value))
+                            }
+                            Some(PrefixDeclaration::Named(prefix)) => {
+                                namespaces.push((owned(decoder.decode(prefix)?), value))
+                            }
+                            None => {
+                                let (namespace, local_name) =
+                                    self.reader.resolve_attribute(attribute.key);
+                                attributes.push(Attribute {
+                                    name: Name {
+                                        local_name: owned(decoder.decode(local_name.as_ref())?),
+                                        prefix: match attribute.key.prefix() {
+                                            Some(prefix) => {
+                                                Some(owned(decoder.decode(prefix.as_ref())?))
+                                            }
+                                            None => None,
+                                        },
+                                        namespace: bound(namespace, decoder)?,
+                                    },
+                                    value,
+                                })
+                            }
+                        }
+                    }
+                    Event::StartElement {
+                        name,
+                        attributes,
+                        namespaces,
+                    }
+                }
+                QuickXmlEvent::End(_) => Event::EndElement,
+                QuickXmlEvent::CData(data) => Event::CData(owned(data.decode()?)),
+                QuickXmlEvent::Comment(comment) => Event::Comment(owned(comment.decode()?)),
+                QuickXmlEvent::Decl(declaration) => Event::StartDocument {
+                    standalone: match declaration.standalone() {
+                        Some(standalone) => Some(standalone?.as_ref() == b"yes"),
+                        None => None,
+                    },
+                },
+                QuickXmlEvent::PI(instruction) => {
+                    let data = decoder.decode(instruction.content())?;
+                    let data = data.trim();
+                    Event::ProcessingInstruction {
+                        name: owned(decoder.decode(instruction.target())?),
+                        data: (!data.is_empty()).then(|| Cow::Owned(data.to_string())),
+                    }
+                }
+                QuickXmlEvent::DocType(_) => continue,
+                QuickXmlEvent::Eof => {
+                    self.done = true;
+                    Event::EndDocument
+                }
+            };
+            return Ok((text, event));
+        }
+    }
+}
+
+impl<R: std::io::BufRead> Iterator for QuickXmlEvents<R> {
+    type Item = crate::parser::Result<Event<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.pending.pop_front() {
+            return Some(Ok(event));
+        }
+        if self.done {
+            return None;
+        }
+        match self.read() {
+            Ok((Some(text), event)) => {
+                self.pending.push_back(event);
+                Some(Ok(if text.trim().is_empty() {
+                    Event::Whitespace(Cow::Owned(text))
+                } else {
+                    Event::Characters(Cow::Owned(text))
+                }))
+            }
+            Ok((None, event)) => Some(Ok(event)),
+            Err(e) => {
+                self.done = true;
+                Some(Err(Box::new(crate::parser::Error::from(e))))
+            }
+        }
+    }
+}
+
+fn owned(value: Cow<'_, str>) -> Cow<'static, str> {
+    Cow::Owned(value.into_owned())
+}
+
+fn bound(
+    namespace: ResolveResult<'_>,
+    decoder: quick_xml::encoding::Decoder,
+) -> quick_xml::Result<Option<Cow<'static, str>>> {
+    Ok(match namespace {
+        ResolveResult::Bound(namespace) => Some(owned(decoder.decode(namespace.as_ref())?)),
+        ResolveResult::Unbound | ResolveResult::Unknown(_) => None,
+    })
+}
+
+/// Writes the events with a [`quick_xml::Writer`], which is pretty-printed if it is created
+/// with an indent. Elements without content are written as empty elements, like `<line/>`.
+pub struct QuickXmlWriter<W: std::io::Write> {
+    writer: quick_xml::Writer<W>,
+    /// The element that has been started last, until it is known whether it is empty
+    started: Option<BytesStart<'static>>,
+    names: Vec<String>,
+}
+
+impl<W: std::io::Write> QuickXmlWriter<W> {
+    pub fn new(writer: quick_xml::Writer<W>) -> Self {
+        Self {
+            writer,
+            started: None,
+            names: Vec::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> quick_xml::Writer<W> {
+        self.writer
+    }
+
+    fn write(&mut self, event: Event<'_>) -> std::io::Result<()> {
+        use quick_xml::events::Event as QuickXmlEvent;
+        if let Some(started) = self.started.take() {
+            if let Event::EndElement = event {
+                self.names.pop();
+                return self.writer.write_event(QuickXmlEvent::Empty(started));
+            }
+            self.writer.write_event(QuickXmlEvent::Start(started))?;
+        }
+        match event {
+            Event::StartDocument { standalone } => {
+                let standalone = standalone.map(|standalone| if standalone { "yes" } else { "no" });
+                self.writer
+                    .write_event(QuickXmlEvent::Decl(BytesDecl::new("1.0", None, standalone)))
+            }
+            Event::ProcessingInstruction { name, data } => {
+                let content = match data {
+                    Some(data) => format!("{name} {data}"),
+                    None => name.into_owned(),
+                };
+                self.writer
+                    .write_event(QuickXmlEvent::PI(BytesPI::new(content)))
+            }
+            Event::StartElement {
+                name,
+                attributes,
+                namespaces,
+            } => {
+                let name = name.qualified().into_owned();
+                let mut start = BytesStart::new(name.clone());
+                for (prefix, uri) in &namespaces {
+                    match prefix.as_ref() {
+                        "" => start.push_attribute(("xmlns", uri.as_ref())),
+                        prefix => {
+                            start.push_attribute((format!("xmlns:{prefix}").as_str(), uri.as_ref()))
+                        }
+                    }
+                }
+                for attribute in &attributes {
+                    start.push_attribute((
+                        attribute.name.qualified().as_ref(),
+                        attribute.value.as_ref(),
+                    ));
+                }
+                self.names.push(name);
+                self.started = Some(start);
+                Ok(())
+            }
+            Event::EndElement => {
+                let name = self.names.pop().unwrap_or_default();
+                self.writer
+                    .write_event(QuickXmlEvent::End(BytesEnd::new(name)))
+            }
+            Event::Characters(data) | Event::Whitespace(data) => self
+                .writer
+                .write_event(QuickXmlEvent::Text(BytesText::new(&data))),
+            Event::CData(data) => self
+                .writer
+                .write_event(QuickXmlEvent::CData(BytesCData::new(data))),
+            Event::Comment(text) => self
+                .writer
+                .write_event(QuickXmlEvent::Comment(BytesText::from_escaped(text))),
+            Event::EndDocument => Ok(()),
+        }
+    }
+}
+
+impl<W: std::io::Write> EventSink for QuickXmlWriter<W> {
+    fn write_event(&mut self, event: Event<'_>) -> crate::writer::Result<()> {
+        self.write(event)
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::quick::QuickXmlWriter;
+    use crate::core::OpenDrive;
+    use crate::fixtures::{document, TestRoad};
+    use crate::writer::WriteOptions;
+
+    #[test]
+    pub fn test_quick_xml_backend() {
+        let road = TestRoad::new("1")
+            .attribute("name", "A & B")
+            .child(r#"<!-- surveyed --><userData code="note" value="&lt;1 m"/>"#);
+        let xml = document(road);
+        let drive = OpenDrive::from_source(quick_xml::NsReader::from_str(&xml)).unwrap();
+        assert_eq!(drive, OpenDrive::from_xml_str(&xml).unwrap());
+        assert_eq!(Some("A & B"), drive.road[0].name.as_deref());
+
+        let mut writer = QuickXmlWriter::new(quick_xml::Writer::new(Vec::new()));
+        drive
+            .write_to_sink(&mut writer, &WriteOptions::default())
+            .unwrap();
+        let written = String::from_utf8(writer.into_inner().into_inner()).unwrap();
+        assert!(written.contains("<line/>"));
+        assert!(written.contains(r#"name="A &amp; B""#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+}
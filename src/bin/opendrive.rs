@@ -0,0 +1,233 @@
+//! The `opendrive` command line tool to inspect, validate, convert, extract and compare ASAM
+//! OpenDRIVE documents, built with `cargo install opendrive --features cli`.
+
+use clap::{Parser, Subcommand, ValueEnum};
+use opendrive::core::validation::{HeaderExtent, Validator};
+use opendrive::core::OpenDrive;
+use opendrive::edit::subnetwork::{DanglingLinks, Selection};
+use opendrive::parser::ParseOptions;
+use opendrive::road::geometry::point::Point;
+use opendrive::units::prelude::*;
+use opendrive::writer::WriteOptions;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints the header, the number of elements, the used features and the extent of a document
+    Info { file: PathBuf },
+    /// Prints the violations of the validation rules, fails if there are any
+    Validate {
+        file: PathBuf,
+        /// Also checks that the extent declared by the header matches the content
+        #[arg(long)]
+        extent: bool,
+    },
+    /// Converts a document into another format
+    Convert {
+        file: PathBuf,
+        #[arg(long, short, value_enum)]
+        to: Format,
+        /// Writes to the given file instead of the standard output
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Writes a document with only the selected roads and what belongs to them
+    Extract {
+        file: PathBuf,
+        /// Selects the road of the given id, can be repeated
+        #[arg(long = "road", value_name = "ID", required_unless_present = "bbox")]
+        roads: Vec<String>,
+        /// Selects the roads passing the area of the inertial x/y-plane
+        #[arg(
+            long,
+            num_args = 4,
+            value_names = ["MIN_X", "MIN_Y", "MAX_X", "MAX_Y"],
+            conflicts_with = "roads",
+            allow_negative_numbers = true
+        )]
+        bbox: Option<Vec<f64>>,
+        /// Keeps links to omitted roads by adding stub roads instead of removing them
+        #[arg(long)]
+        stub: bool,
+        /// Writes to the given file instead of the standard output
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Prints the changes between two documents, fails if there are any
+    Diff { old: PathBuf, new: PathBuf },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// ASAM OpenDRIVE, as written by this crate
+    Xodr,
+    /// GeoJSON of the reference lines and objects
+    Geojson,
+    /// SVG image of the lanes, reference lines and objects
+    Svg,
+    /// Wavefront OBJ mesh of the lane surfaces
+    Obj,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(command: Command) -> Result<ExitCode> {
+    match command {
+        Command::Info { file } => {
+            let drive = read(&file)?;
+            let header = &drive.header;
+            println!("revision:    {}.{}", header.rev_major, header.rev_minor);
+            if let Some(name) = &header.name {
+                println!("name:        {name}");
+            }
+            if let Some(vendor) = &header.vendor {
+                println!("vendor:      {vendor}");
+            }
+            let statistics = drive.statistics();
+            println!(
+                "roads:       {} ({} connecting, {:.3} m)",
+                statistics.roads,
+                statistics.connecting_roads,
+                statistics.road_length.get::<meter>()
+            );
+            for (lane_type, length) in &statistics.lane_length {
+                println!(
+                    "lanes:       {lane_type} {:.3} km",
+                    length.get::<meter>() / 1000.0
+                );
+            }
+            println!("junctions:   {}", statistics.junctions);
+            println!("signals:     {}", statistics.signals);
+            println!("objects:     {}", statistics.objects);
+            println!("controllers: {}", drive.controller.len());
+            println!("stations:    {}", drive.station.len());
+            let compatibility = drive.compatibility();
+            let (major, minor) = compatibility.required_revision;
+            println!("requires:    {major}.{minor}");
+            for usage in &compatibility.features {
+                let (major, minor) = usage.feature.revision();
+                println!(
+                    "uses:        {} ({major}.{minor}), {}",
+                    usage.feature, usage.count
+                );
+            }
+            if let Some(extent) = drive.extent() {
+                println!(
+                    "extent:      x {:.3} .. {:.3}, y {:.3} .. {:.3}",
+                    extent.west.get::<meter>(),
+                    extent.east.get::<meter>(),
+                    extent.south.get::<meter>(),
+                    extent.north.get::<meter>()
+                );
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Validate { file, extent } => {
+            let mut validator = Validator::new();
+            if extent {
+                validator = validator.with_rule(HeaderExtent::default());
+            }
+            let xml = std::fs::read_to_string(&file)?;
+            OpenDrive::from_str_with(
+                &xml,
+                ParseOptions {
+                    validator: Some(&mut validator),
+                    ..ParseOptions::default()
+                },
+            )?;
+            for violation in validator.violations() {
+                println!("{violation}");
+            }
+            Ok(if validator.violations().is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Command::Convert { file, to, output } => {
+            let drive = read(&file)?;
+            let converted = match to {
+                Format::Xodr => drive.to_xml_string()?,
+                Format::Geojson => drive.to_geojson(),
+                Format::Svg => drive.to_svg(),
+                Format::Obj => drive.to_obj(),
+            };
+            write(output.as_deref(), &converted)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Extract {
+            file,
+            roads,
+            bbox,
+            stub,
+            output,
+        } => {
+            let drive = read(&file)?;
+            let selection = match bbox.as_deref() {
+                Some(&[min_x, min_y, max_x, max_y]) => Selection::BoundingBox {
+                    min: point(min_x, min_y),
+                    max: point(max_x, max_y),
+                },
+                _ => Selection::Roads(roads),
+            };
+            let dangling = if stub {
+                DanglingLinks::Stub
+            } else {
+                DanglingLinks::Trim
+            };
+            let extracted = drive.extract(&selection, dangling);
+            match output {
+                Some(path) => extracted.to_path(path, &WriteOptions::default())?,
+                None => println!("{}", extracted.to_xml_string()?),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Diff { old, new } => {
+            let changes = read(&old)?.diff(&read(&new)?)?;
+            print!("{changes}");
+            Ok(if changes.is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<OpenDrive> {
+    OpenDrive::from_path(path, ParseOptions::default())
+        .map_err(|e| format!("{}: {e}", path.display()).into())
+}
+
+fn write(path: Option<&Path>, content: &str) -> Result<()> {
+    match path {
+        Some(path) => std::fs::write(path, content)?,
+        None => println!("{content}"),
+    }
+    Ok(())
+}
+
+fn point(x: f64, y: f64) -> Point {
+    Point {
+        x: Length::new::<meter>(x),
+        y: Length::new::<meter>(y),
+    }
+}
@@ -0,0 +1,473 @@
+//! A C interface to parse, query and write documents, see `include/opendrive.h`.
+//!
+//! Documents are passed as opaque [`OdrDocument`] handles that are created by
+//! [`odr_document_parse`] or [`odr_document_read_file`] and released by [`odr_document_free`].
+//! Functions that fail return a null pointer, `false` or `-1` and store a message that is
+//! returned by [`odr_last_error`] until the next failing call on the same thread. Panics do not
+//! unwind into the caller but fail the call likewise. Strings returned by this interface are
+//! released by [`odr_string_free`].
+//!
+//! The crate is not built as `cdylib` by default, build the shared library with
+//! `cargo rustc --lib --release --features capi --crate-type cdylib`.
+
+use crate::core::OpenDrive;
+use crate::units::prelude::*;
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// A parsed document
+pub struct OdrDocument {
+    drive: OpenDrive,
+}
+
+/// A position and heading in the inertial x/y-plane, in meter and radian
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OdrPose {
+    pub x: f64,
+    pub y: f64,
+    pub hdg: f64,
+}
+
+/// A position along a road, in meter
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OdrRoadPosition {
+    pub s: f64,
+    pub t: f64,
+}
+
+/// Summary of a road, `junction` is `true` for connecting roads of junctions
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OdrRoadInfo {
+    pub length: f64,
+    pub lane_section_count: usize,
+    pub junction: bool,
+}
+
+/// The inertial extent of a document, in meter
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OdrExtent {
+    pub north: f64,
+    pub south: f64,
+    pub east: f64,
+    pub west: f64,
+}
+
+fn fail(message: impl ToString) {
+    let message = message.to_string().replace('\0', " ");
+    LAST_ERROR.with(|error| *error.borrow_mut() = CString::new(message).ok());
+}
+
+/// Runs the body of an exported function, so that a panic fails the call with the given result
+/// instead of unwinding into the caller
+fn guarded<T>(failed: T, body: impl FnOnce() -> T) -> T {
+    catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        fail(format!("panicked: {message}"));
+        failed
+    })
+}
+
+/// The string as a C string to be released by [`odr_string_free`], null if it contains a nul
+fn into_raw(string: String) -> *mut c_char {
+    match CString::new(string) {
+        Ok(string) => string.into_raw(),
+        Err(e) => {
+            fail(e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Copies the string into the buffer of the given capacity, truncated at a character boundary
+/// and always terminated by a nul if the capacity is not zero, and returns the length of the
+/// whole string without the nul
+///
+/// # Safety
+/// `buffer` must be valid for writes of `capacity` bytes or null if `capacity` is zero.
+unsafe fn copy_into(string: &str, buffer: *mut c_char, capacity: usize) -> usize {
+    if !buffer.is_null() && capacity > 0 {
+        let mut count = string.len().min(capacity - 1);
+        while !string.is_char_boundary(count) {
+            count -= 1;
+        }
+        ptr::copy_nonoverlapping(string.as_ptr().cast::<c_char>(), buffer, count);
+        *buffer.add(count) = 0;
+    }
+    string.len()
+}
+
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet.
+unsafe fn road<'a>(document: *const OdrDocument, index: usize) -> Option<&'a crate::road::Road> {
+    match document.as_ref() {
+        Some(document) => {
+            let road = document.drive.road.get(index);
+            if road.is_none() {
+                fail(format!("there is no road at index {index}"));
+            }
+            road
+        }
+        None => {
+            fail("the document is null");
+            None
+        }
+    }
+}
+
+/// The message of the last failure on this thread, null if there is none. The message is valid
+/// until the next call of this interface on the same thread.
+#[no_mangle]
+pub extern "C" fn odr_last_error() -> *const c_char {
+    guarded(ptr::null(), || {
+        LAST_ERROR.with(|error| {
+            error
+                .borrow()
+                .as_ref()
+                .map_or(ptr::null(), |error| error.as_ptr())
+        })
+    })
+}
+
+/// Parses the document of `length` bytes of UTF-8 XML
+///
+/// # Safety
+/// `xml` must be valid for reads of `length` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn odr_document_parse(xml: *const c_char, length: usize) -> *mut OdrDocument {
+    guarded(ptr::null_mut(), || {
+        if xml.is_null() {
+            fail("the XML is null");
+            return ptr::null_mut();
+        }
+        let bytes = std::slice::from_raw_parts(xml.cast::<u8>(), length);
+        match OpenDrive::from_xml_read(bytes) {
+            Ok(drive) => Box::into_raw(Box::new(OdrDocument { drive })),
+            Err(e) => {
+                fail(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Parses the document in the file at the nul terminated UTF-8 path
+///
+/// # Safety
+/// `path` must be a nul terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn odr_document_read_file(path: *const c_char) -> *mut OdrDocument {
+    guarded(ptr::null_mut(), || {
+        if path.is_null() {
+            fail("the path is null");
+            return ptr::null_mut();
+        }
+        let parsed = CStr::from_ptr(path)
+            .to_str()
+            .map_err(|e| e.to_string())
+            .and_then(|path| std::fs::File::open(path).map_err(|e| e.to_string()))
+            .and_then(|file| OpenDrive::from_xml_read(file).map_err(|e| e.to_string()));
+        match parsed {
+            Ok(drive) => Box::into_raw(Box::new(OdrDocument { drive })),
+            Err(e) => {
+                fail(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Releases the document, null is ignored
+///
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn odr_document_free(document: *mut OdrDocument) {
+    guarded((), || {
+        if !document.is_null() {
+            drop(Box::from_raw(document));
+        }
+    })
+}
+
+/// The document as XML, to be released by [`odr_string_free`]
+///
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn odr_document_write(document: *const OdrDocument) -> *mut c_char {
+    guarded(ptr::null_mut(), || {
+        let Some(document) = document.as_ref() else {
+            fail("the document is null");
+            return ptr::null_mut();
+        };
+        match document.drive.to_xml_string() {
+            Ok(xml) => into_raw(xml),
+            Err(e) => {
+                fail(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Releases a string returned by this interface, null is ignored
+///
+/// # Safety
+/// `string` must be null or a string returned by this interface that is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn odr_string_free(string: *mut c_char) {
+    guarded((), || {
+        if !string.is_null() {
+            drop(CString::from_raw(string));
+        }
+    })
+}
+
+/// The number of roads of the document, zero if it is null
+///
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn odr_document_road_count(document: *const OdrDocument) -> usize {
+    guarded(0, || {
+        document
+            .as_ref()
+            .map_or(0, |document| document.drive.road.len())
+    })
+}
+
+/// The index of the road with the nul terminated id, `-1` if there is none
+///
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet and `id`
+/// must be a nul terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn odr_document_find_road(
+    document: *const OdrDocument,
+    id: *const c_char,
+) -> isize {
+    guarded(-1, || {
+        let (Some(document), false) = (document.as_ref(), id.is_null()) else {
+            fail("the document or id is null");
+            return -1;
+        };
+        let id = CStr::from_ptr(id).to_string_lossy();
+        document
+            .drive
+            .road
+            .iter()
+            .position(|road| road.id == id)
+            .map_or(-1, |index| index as isize)
+    })
+}
+
+/// Copies the id of the road at `index` into the buffer, see [`copy_into`] for the semantics of
+/// `buffer` and `capacity`. Returns the length of the id, or `-1` if there is no such road.
+///
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet and
+/// `buffer` must be valid for writes of `capacity` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn odr_road_id(
+    document: *const OdrDocument,
+    index: usize,
+    buffer: *mut c_char,
+    capacity: usize,
+) -> isize {
+    guarded(-1, || {
+        road(document, index).map_or(-1, |road| copy_into(&road.id, buffer, capacity) as isize)
+    })
+}
+
+/// Writes the summary of the road at `index` to `info`
+///
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet and
+/// `info` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn odr_road_info(
+    document: *const OdrDocument,
+    index: usize,
+    info: *mut OdrRoadInfo,
+) -> bool {
+    guarded(false, || {
+        let Some(road) = road(document, index) else {
+            return false;
+        };
+        if info.is_null() {
+            fail("the info is null");
+            return false;
+        }
+        *info = OdrRoadInfo {
+            length: road.length.get::<meter>(),
+            lane_section_count: road.lanes.lane_section.len(),
+            junction: road.junction != "-1",
+        };
+        true
+    })
+}
+
+/// Writes the inertial pose at `s` and `t` of the road at `index` to `pose`
+///
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet and
+/// `pose` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn odr_road_pose_at(
+    document: *const OdrDocument,
+    index: usize,
+    position: OdrRoadPosition,
+    pose: *mut OdrPose,
+) -> bool {
+    guarded(false, || {
+        let Some(road) = road(document, index) else {
+            return false;
+        };
+        if pose.is_null() {
+            fail("the pose is null");
+            return false;
+        }
+        let inertial = road.pose_at(
+            Length::new::<meter>(position.s),
+            Length::new::<meter>(position.t),
+        );
+        *pose = OdrPose {
+            x: inertial.x.get::<meter>(),
+            y: inertial.y.get::<meter>(),
+            hdg: inertial.hdg.get::<radian>(),
+        };
+        true
+    })
+}
+
+/// Writes the position on the road at `index` that is closest to the inertial position to
+/// `position`
+///
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet and
+/// `position` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn odr_road_project(
+    document: *const OdrDocument,
+    index: usize,
+    x: f64,
+    y: f64,
+    position: *mut OdrRoadPosition,
+) -> bool {
+    guarded(false, || {
+        let Some(road) = road(document, index) else {
+            return false;
+        };
+        if position.is_null() {
+            fail("the position is null");
+            return false;
+        }
+        let (s, t) = road.project(Length::new::<meter>(x), Length::new::<meter>(y));
+        *position = OdrRoadPosition {
+            s: s.get::<meter>(),
+            t: t.get::<meter>(),
+        };
+        true
+    })
+}
+
+/// Writes the extent of the content of the document to `extent`, `false` if it has no roads
+///
+/// # Safety
+/// `document` must be null or a handle returned by this interface that is not freed yet and
+/// `extent` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn odr_document_extent(
+    document: *const OdrDocument,
+    extent: *mut OdrExtent,
+) -> bool {
+    guarded(false, || {
+        let (Some(document), false) = (document.as_ref(), extent.is_null()) else {
+            fail("the document or extent is null");
+            return false;
+        };
+        let Some(content) = document.drive.extent() else {
+            fail("the document has no roads");
+            return false;
+        };
+        *extent = OdrExtent {
+            north: content.north.get::<meter>(),
+            south: content.south.get::<meter>(),
+            east: content.east.get::<meter>(),
+            west: content.west.get::<meter>(),
+        };
+        true
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::capi::*;
+    use crate::fixtures::{document, TestRoad};
+
+    #[test]
+    pub fn test_capi() {
+        let xml = document(
+            TestRoad::new("main")
+                .length(10.0)
+                .sides(&[], &[])
+                .to_string()
+                + &TestRoad::new("straße").sides(&[], &[]).to_string(),
+        );
+
+        unsafe {
+            let document = odr_document_parse(xml.as_ptr().cast(), xml.len());
+            assert!(!document.is_null());
+            assert_eq!(2, odr_document_road_count(document));
+            assert_eq!(0, odr_document_find_road(document, c"main".as_ptr()));
+            assert_eq!(-1, odr_document_find_road(document, c"side".as_ptr()));
+
+            let mut buffer = [0 as c_char; 3];
+            assert_eq!(4, odr_road_id(document, 0, buffer.as_mut_ptr(), 3));
+            assert_eq!(c"ma", CStr::from_ptr(buffer.as_ptr()));
+            assert_eq!(-1, odr_road_id(document, 2, buffer.as_mut_ptr(), 3));
+            // the 'ß' of two bytes does not fit in front of the nul
+            let mut buffer = [0 as c_char; 6];
+            assert_eq!(7, odr_road_id(document, 1, buffer.as_mut_ptr(), 6));
+            assert_eq!(c"stra", CStr::from_ptr(buffer.as_ptr()));
+
+            let mut pose = OdrPose::default();
+            let position = OdrRoadPosition { s: 5.0, t: 2.0 };
+            assert!(odr_road_pose_at(document, 0, position, &mut pose));
+            assert_eq!(
+                OdrPose {
+                    x: 5.0,
+                    y: 2.0,
+                    hdg: 0.0
+                },
+                pose
+            );
+            let mut projected = OdrRoadPosition::default();
+            assert!(odr_road_project(document, 0, 5.0, 2.0, &mut projected));
+            assert!((projected.s - 5.0).abs() < 1e-6 && (projected.t - 2.0).abs() < 1e-6);
+            assert!(!odr_road_pose_at(document, 2, position, &mut pose));
+            assert!(!odr_last_error().is_null());
+
+            let written = odr_document_write(document);
+            assert!(CStr::from_ptr(written).to_str().unwrap().contains("main"));
+            odr_string_free(written);
+            odr_document_free(document);
+
+            assert!(odr_document_parse(c"<OpenDRIVE>".as_ptr(), 11).is_null());
+        }
+    }
+}
@@ -1,6 +1,14 @@
+use crate::core::comment::Comment;
 use crate::core::data_quality::DataQuality;
 use crate::core::include::Include;
+use crate::core::unknown::Unknown;
 use crate::core::user_data::UserData;
+use crate::core::xml_element::XmlElement;
+use std::borrow::Cow;
+
+/// The namespace of the attribute by which elements merged from a kept `<include>` are marked
+/// with its file, see [`AdditionalData::included_from`]
+pub(crate) const INCLUDED_FROM_NAMESPACE: &str = "urn:opendrive-rs:included-from";
 
 /// ASAM OpenDRIVE offers the possibility to include external data. The processing of this data
 /// depends on the application.
@@ -11,23 +19,135 @@ pub struct AdditionalData {
     pub data_quality: Option<DataQuality>,
     pub include: Vec<Include>,
     pub user_data: Vec<UserData>,
+    /// Attributes and child elements of the element this belongs to that are not part of the
+    /// model
+    pub unknown: Unknown,
+    /// Comments in front of the element this belongs to
+    #[cfg_attr(feature = "fuzzing", arbitrary(default))]
+    pub leading_comments: Vec<String>,
+    /// Comments between the child elements of the element this belongs to that precede no child
+    /// element with leading comments of its own
+    #[cfg_attr(feature = "fuzzing", arbitrary(default))]
+    pub comments: Vec<Comment>,
+    /// The file of the kept `<include>` the element this belongs to was merged from, see
+    /// [`crate::core::include_resolver::IncludeResolver::keep_includes`]. Such elements are not
+    /// written, the `<include>` is.
+    #[cfg_attr(feature = "fuzzing", arbitrary(default))]
+    pub included_from: Option<String>,
 }
 
 impl AdditionalData {
+    /// Takes the comments in front of and within the element and keeps its attributes that have
+    /// not been read so far as unknown attributes, which requires all known attributes and
+    /// children to be read before. Attributes of other namespaces, such as
+    /// `xsi:noNamespaceSchemaLocation`, are not kept.
+    pub fn complete_from<I>(mut self, read: &crate::parser::ReadContext<I>) -> Self
+    where
+        I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
+    {
+        self.leading_comments = read.take_leading_comments();
+        self.comments = read.comments().to_vec();
+        for attribute in read.keep_unread_attributes() {
+            if attribute.name.prefix.is_none() {
+                self.unknown.attributes.push((
+                    attribute.name.local_name.to_string(),
+                    attribute.value.to_string(),
+                ));
+            } else if attribute.name.namespace.as_deref() == Some(INCLUDED_FROM_NAMESPACE) {
+                self.included_from = Some(attribute.value.to_string());
+            }
+        }
+        self
+    }
+
+    /// Wraps the attribute visitor of the element this belongs to, so that the unknown attributes
+    /// are written as well, and passes it the leading comments to write in front of the element
+    pub fn append_attributes<'s, V>(
+        &'s self,
+        visitor: V,
+    ) -> impl for<'b> FnOnce(
+        Cow<'b, [xml::attribute::Attribute<'b>]>,
+        &[String],
+    ) -> xml::writer::Result<()>
+           + 's
+    where
+        V: for<'b> FnOnce(
+                Cow<'b, [xml::attribute::Attribute<'b>]>,
+                &[String],
+            ) -> xml::writer::Result<()>
+            + 's,
+    {
+        let visitor = self.unknown.append_attributes(visitor);
+        move |attributes: Cow<'_, [xml::attribute::Attribute<'_>]>, _: &[String]| {
+            visitor(attributes, &self.leading_comments)
+        }
+    }
+
+    /// Wraps the children visitor of the element this belongs to, so that the comments are
+    /// written in front of the child elements they preceded. Comments after the last child element
+    /// are written by [`AdditionalData::append_children`].
+    pub fn interleave_comments<'s>(
+        &'s self,
+        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()> + 's,
+    ) -> impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()> + 's {
+        let mut depth = 0_usize;
+        let mut counts: Vec<(String, usize)> = Vec::new();
+        move |event: xml::writer::XmlEvent<'_>| {
+            match &event {
+                xml::writer::XmlEvent::StartElement { name, .. } => {
+                    if depth == 0 && !self.comments.is_empty() {
+                        let name = name.local_name;
+                        let index = match counts
+                            .iter_mut()
+                            .find(|(counted, _)| counted.eq_ignore_ascii_case(name))
+                        {
+                            Some((_, count)) => {
+                                *count += 1;
+                                *count - 1
+                            }
+                            None => {
+                                counts.push((name.to_string(), 1));
+                                0
+                            }
+                        };
+                        for comment in self.comments.iter().filter(|c| {
+                            c.before.as_ref().is_some_and(|(before, i)| {
+                                *i == index && before.eq_ignore_ascii_case(name)
+                            })
+                        }) {
+                            visitor(xml::writer::XmlEvent::Comment(&comment.text))?;
+                        }
+                    }
+                    depth += 1;
+                }
+                xml::writer::XmlEvent::EndElement { .. } => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            visitor(event)
+        }
+    }
+
     pub fn append_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         if let Some(data_quality) = &self.data_quality {
-            visit_children!(visitor, "dataQuality" => data_quality);
+            visit_children!(visitor, options, "dataQuality" => data_quality);
         }
 
         for include in &self.include {
-            visit_children!(visitor, "include" => include);
+            visit_children!(visitor, options, "include" => include);
         }
 
         for user_data in &self.user_data {
-            visit_children!(visitor, "userData" => user_data);
+            visit_children!(visitor, options, "userData" => user_data);
+        }
+
+        self.unknown.append_children(&mut visitor)?;
+
+        for comment in self.comments.iter().filter(|c| c.before.is_none()) {
+            visitor(xml::writer::XmlEvent::Comment(&comment.text))?;
         }
 
         Ok(())
@@ -35,7 +155,7 @@ impl AdditionalData {
 
     pub fn fill<I>(&mut self, read: crate::parser::ReadContext<I>) -> crate::parser::Result<()>
     where
-        I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+        I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
     {
         match read.element_name() {
             name if name.eq_ignore_ascii_case("dataQuality") => {
@@ -47,12 +167,7 @@ impl AdditionalData {
             name if name.eq_ignore_ascii_case("userData") => {
                 self.user_data.push(UserData::try_from(read)?)
             }
-            name => {
-                return Err(Box::new(crate::parser::Error::InvalidValueFor {
-                    name: core::any::type_name::<Self>().to_string(),
-                    value: name.to_string(),
-                }));
-            }
+            _ => self.unknown.elements.push(XmlElement::try_from(read)?),
         };
         Ok(())
     }
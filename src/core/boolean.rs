@@ -0,0 +1,110 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A boolean attribute value. The standard writes most booleans as `t_bool` (`true`/`false`),
+/// the `dynamic` attributes as `t_yesNo` (`yes`/`no`), and some exports write `1`/`0`, so all of
+/// these forms are accepted in any case. Values are written in the canonical form of their type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bool(pub bool);
+
+impl Bool {
+    /// `true` or `false`, the canonical form of `t_bool`
+    pub fn as_str(&self) -> &'static str {
+        if self.0 {
+            "true"
+        } else {
+            "false"
+        }
+    }
+
+    /// `yes` or `no`, the canonical form of `t_yesNo`
+    pub fn as_yes_no(&self) -> &'static str {
+        if self.0 {
+            "yes"
+        } else {
+            "no"
+        }
+    }
+}
+
+impl FromStr for Bool {
+    type Err = crate::parser::InvalidEnumValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        if ["true", "yes", "1"]
+            .iter()
+            .any(|t| value.eq_ignore_ascii_case(t))
+        {
+            Ok(Self(true))
+        } else if ["false", "no", "0"]
+            .iter()
+            .any(|f| value.eq_ignore_ascii_case(f))
+        {
+            Ok(Self(false))
+        } else {
+            Err(crate::parser::InvalidEnumValue {
+                r#type: core::any::type_name::<Self>().to_string(),
+                value: s.to_string(),
+            })
+        }
+    }
+}
+
+impl Display for Bool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<bool> for Bool {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bool> for bool {
+    fn from(value: Bool) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::boolean::Bool;
+    use crate::fixtures::{parse, TestRoad};
+
+    #[test]
+    pub fn test_bool() {
+        for (value, expected) in [("true", true), ("YES", true), ("1", true), (" no ", false)] {
+            assert_eq!(Ok(Bool(expected)), value.parse().map_err(|_| ()));
+        }
+        assert!("maybe".parse::<Bool>().is_err());
+
+        let drive = parse(
+            TestRoad::new("1")
+                .lanes(
+                    r#"<laneSection s="0" singleSide="1">
+                        <center><lane id="0" type="none"/></center>
+                        <right><lane id="-1" type="driving" level="No"><width sOffset="0" a="3" b="0" c="0" d="0"/></lane></right>
+                    </laneSection>"#,
+                )
+                .signals(
+                    r#"<signal s="0" t="0" id="s" dynamic="true" orientation="+" zOffset="0" type="206" subtype="-1"/>"#,
+                ),
+        );
+        let road = &drive.road[0];
+        let section = &road.lanes.lane_section[0];
+        assert_eq!(Some(true), section.single_side);
+        assert_eq!(
+            Some(false),
+            section.right.as_ref().unwrap().lane[0].base.level
+        );
+        assert!(road.signals.as_ref().unwrap().signal[0].dynamic);
+
+        let xml = drive.to_xml_string().unwrap();
+        assert!(xml.contains(r#"singleSide="true""#));
+        assert!(xml.contains(r#"level="false""#));
+        assert!(xml.contains(r#"dynamic="yes""#));
+    }
+}
@@ -0,0 +1,62 @@
+/// An XML comment within an element. Comments are kept when parsing and written again in front
+/// of the child element they preceded in the document. Comments in front of an element with
+/// [`crate::core::additional_data::AdditionalData`] belong to that element instead, see
+/// [`crate::core::additional_data::AdditionalData::leading_comments`], so that they move with it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Comment {
+    /// The child element the comment precedes, as its name and its index among the child
+    /// elements of that name, `None` if it follows the last child element
+    pub before: Option<(String, usize)>,
+    pub text: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::{parse, TestRoad};
+    use crate::road::Road;
+
+    #[test]
+    pub fn test_comments_round_trip() {
+        let road = TestRoad::new("1").sides(&[], &[]).geometry(
+            r#"<geometry s="0" x="0" y="0" hdg="0" length="100"><line/></geometry>
+            <!-- TODO: extend to the bridge -->"#,
+        );
+        let drive = parse(format!("<!-- main road, surveyed 2021 -->{road}"));
+
+        assert!(drive.additional_data.comments.is_empty());
+        let leading = &drive.road[0].additional_data.leading_comments;
+        assert_eq!(
+            &[" main road, surveyed 2021 ".to_string()],
+            leading.as_slice()
+        );
+        let plan_view = &drive.road[0].plan_view.additional_data.comments;
+        assert_eq!(None, plan_view[0].before);
+
+        let xml = drive.to_xml_string().unwrap();
+        assert!(xml.contains("<!-- main road, surveyed 2021 --><road"));
+        assert_eq!(drive, OpenDrive::from_xml_str(&xml).unwrap());
+    }
+
+    #[test]
+    pub fn test_comments_move_with_their_element() {
+        let mut drive = parse(format!(
+            "{}{}<!-- the bridge -->{}",
+            TestRoad::new("1"),
+            TestRoad::new("2").child(r#"<!-- survey --><userData code="survey"/>"#),
+            TestRoad::new("3"),
+        ));
+        assert_eq!(
+            Some(("userData".to_string(), 0)),
+            drive.road[1].additional_data.comments[0].before
+        );
+
+        let removed: Road = drive.road.remove(0);
+        assert_eq!("1", removed.id);
+        let xml = drive.to_xml_string().unwrap();
+        assert!(xml.contains(r#"<!-- the bridge --><road id="3""#));
+        assert!(xml.contains(r#"<!-- survey --><userData code="survey""#));
+        assert!(!xml.contains("<!-- survey --><planView"));
+        assert_eq!(drive, OpenDrive::from_xml_str(&xml).unwrap());
+    }
+}
@@ -20,21 +20,24 @@ impl DataQuality {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         if let Some(error) = &self.error {
-            visit_children!(visitor, "error" => error);
+            visit_children!(visitor, options, "error" => error);
         }
 
         if let Some(raw_data) = &self.raw_data {
-            visit_children!(visitor, "rawData" => raw_data);
+            visit_children!(visitor, options, "rawData" => raw_data);
         }
 
         Ok(())
@@ -43,7 +46,7 @@ impl DataQuality {
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for DataQuality
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -0,0 +1,433 @@
+use crate::core::xml_element::XmlElement;
+use crate::core::OpenDrive;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// A change of a single attribute of an element, see [`Change::Modified`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AttributeChange {
+    pub name: String,
+    /// Value before the change, `None` if the attribute was added
+    pub old: Option<String>,
+    /// Value after the change, `None` if the attribute was removed
+    pub new: Option<String>,
+}
+
+/// A change of the character data of an element, see [`Change::Modified`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CdataChange {
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// A change of a single element. The path of an element consists of the keys of the element
+/// and its ancestors, like `/OpenDRIVE/road[@id="1"]/planView/geometry[2]`. Elements with an
+/// `@id` that is unique among their siblings of the same name are keyed by it, other elements by
+/// their position among these siblings, starting at 1, which is omitted for the first one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Change {
+    Added {
+        path: String,
+        element: XmlElement,
+    },
+    Removed {
+        path: String,
+        element: XmlElement,
+    },
+    /// The attributes or the character data of the element changed, changes of its children are
+    /// separate changes
+    Modified {
+        path: String,
+        attributes: Vec<AttributeChange>,
+        cdata: Option<CdataChange>,
+    },
+}
+
+impl Change {
+    pub fn path(&self) -> &str {
+        match self {
+            Change::Added { path, .. }
+            | Change::Removed { path, .. }
+            | Change::Modified { path, .. } => path,
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Added { path, .. } => write!(f, "+ {path}"),
+            Change::Removed { path, .. } => write!(f, "- {path}"),
+            Change::Modified {
+                path,
+                attributes,
+                cdata,
+            } => {
+                write!(f, "~ {path}")?;
+                let value = |value: &Option<String>| match value {
+                    Some(value) => format!("{value:?}"),
+                    None => "-".to_string(),
+                };
+                for change in attributes {
+                    write!(
+                        f,
+                        " @{}: {} -> {}",
+                        change.name,
+                        value(&change.old),
+                        value(&change.new)
+                    )?;
+                }
+                if let Some(cdata) = cdata {
+                    write!(f, " text: {} -> {}", value(&cdata.old), value(&cdata.new))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The changes between two documents in document order, see [`OpenDrive::diff`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ChangeSet {
+    pub changes: Vec<Change>,
+}
+
+impl ChangeSet {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Display for ChangeSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+impl OpenDrive {
+    /// The changes that turn this document into `other`, compared on the element trees of both
+    /// documents, see [`OpenDrive::to_xml_element`]. Values are compared in their written form, so
+    /// a change of a quantity is only reported if it changes the written document.
+    pub fn diff(&self, other: &OpenDrive) -> xml::writer::Result<ChangeSet> {
+        let old = self.to_xml_element()?;
+        let new = other.to_xml_element()?;
+        let mut changes = Vec::new();
+        let path = format!("/{}", old.name);
+        if old.name == new.name {
+            diff(&path, &old, &new, &mut changes);
+        } else {
+            changes.push(Change::Removed { path, element: old });
+            changes.push(Change::Added {
+                path: format!("/{}", new.name),
+                element: new,
+            });
+        }
+        Ok(ChangeSet { changes })
+    }
+}
+
+/// Why a [`ChangeSet`] could not be applied, see [`OpenDrive::apply`]
+#[derive(Debug, thiserror::Error)]
+pub enum ApplyError {
+    /// The document differs from the document the change was computed on
+    #[error("Conflict at `{path}`: {reason}")]
+    Conflict { path: String, reason: String },
+    #[error("Failed to build the element tree: {0}")]
+    Write(#[from] xml::writer::Error),
+    #[error("The patched document is invalid: {0}")]
+    Parse(#[from] Box<crate::parser::Error>),
+}
+
+impl ApplyError {
+    fn conflict(path: &str, reason: impl Into<String>) -> Self {
+        Self::Conflict {
+            path: path.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl OpenDrive {
+    /// Applies the changes of [`OpenDrive::diff`] to this document, so that updates can be
+    /// distributed as change sets and applied to a base map. Each change is checked against this
+    /// document first: removed elements and old attribute values must match, and added elements
+    /// must not exist yet. On a conflict or if the patched document is invalid, this document is
+    /// left unchanged.
+    pub fn apply(&mut self, changes: &ChangeSet) -> Result<(), ApplyError> {
+        let mut root = XmlElement {
+            children: vec![self.to_xml_element()?],
+            ..XmlElement::default()
+        };
+        for change in &changes.changes {
+            apply(&mut root, change)?;
+        }
+        let element = root
+            .children
+            .pop()
+            .ok_or_else(|| ApplyError::conflict("/", "the root element is removed"))?;
+        *self = OpenDrive::from_xml_element(&element)?;
+        Ok(())
+    }
+}
+
+/// The keys of the path, see [`Change`]. Slashes within quoted ids do not separate keys.
+fn split_path(path: &str) -> Vec<&str> {
+    let mut keys = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    for (index, c) in path.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '/' if !quoted => {
+                keys.push(&path[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    keys.push(&path[start..]);
+    keys.into_iter().filter(|key| !key.is_empty()).collect()
+}
+
+/// The element at the given keys below `root`
+fn resolve<'e>(
+    root: &'e mut XmlElement,
+    keys: &[&str],
+    path: &str,
+) -> Result<&'e mut XmlElement, ApplyError> {
+    let mut element = root;
+    for key in keys {
+        let index = child_keys(element)
+            .iter()
+            .position(|k| k == key)
+            .ok_or_else(|| ApplyError::conflict(path, format!("`{key}` does not exist")))?;
+        element = &mut element.children[index];
+    }
+    Ok(element)
+}
+
+fn apply(root: &mut XmlElement, change: &Change) -> Result<(), ApplyError> {
+    let path = change.path();
+    let keys = split_path(path);
+    let (key, parent_keys) = keys
+        .split_last()
+        .ok_or_else(|| ApplyError::conflict(path, "the path is empty"))?;
+    let parent = resolve(root, parent_keys, path)?;
+    let index = child_keys(parent).iter().position(|k| k == key);
+    match change {
+        Change::Added { element, .. } => {
+            if index.is_some() {
+                return Err(ApplyError::conflict(path, "the element exists already"));
+            }
+            let position = parent
+                .children
+                .iter()
+                .rposition(|child| child.name == element.name)
+                .map_or(parent.children.len(), |index| index + 1);
+            parent.children.insert(position, element.clone());
+            if child_keys(parent).get(position) != Some(&key.to_string()) {
+                return Err(ApplyError::conflict(
+                    path,
+                    "the element cannot be added at this position",
+                ));
+            }
+        }
+        Change::Removed { element, .. } => {
+            let index =
+                index.ok_or_else(|| ApplyError::conflict(path, "the element does not exist"))?;
+            if parent.children[index] != *element {
+                return Err(ApplyError::conflict(path, "the element differs"));
+            }
+            parent.children.remove(index);
+        }
+        Change::Modified {
+            attributes, cdata, ..
+        } => {
+            let index =
+                index.ok_or_else(|| ApplyError::conflict(path, "the element does not exist"))?;
+            let element = &mut parent.children[index];
+            for change in attributes {
+                if element.attributes.get(&change.name) != change.old.as_ref() {
+                    return Err(ApplyError::conflict(
+                        path,
+                        format!("`@{}` differs", change.name),
+                    ));
+                }
+                match &change.new {
+                    Some(value) => element
+                        .attributes
+                        .insert(change.name.clone(), value.clone()),
+                    None => element.attributes.remove(&change.name),
+                };
+            }
+            if let Some(cdata) = cdata {
+                if element.cdata != cdata.old {
+                    return Err(ApplyError::conflict(path, "the text differs"));
+                }
+                element.cdata = cdata.new.clone();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The keys of the children of the element, see [`Change`]
+pub(crate) fn child_keys(element: &XmlElement) -> Vec<String> {
+    let mut ids = HashMap::<(&str, &str), usize>::new();
+    for child in &element.children {
+        if let Some(id) = child.attributes.get("id") {
+            *ids.entry((child.name.as_str(), id.as_str())).or_default() += 1;
+        }
+    }
+    let mut positions = HashMap::<&str, usize>::new();
+    element
+        .children
+        .iter()
+        .map(|child| {
+            let position = positions.entry(child.name.as_str()).or_default();
+            *position += 1;
+            match child.attributes.get("id") {
+                Some(id) if ids.get(&(child.name.as_str(), id.as_str())) == Some(&1) => {
+                    format!("{}[@id={id:?}]", child.name)
+                }
+                _ if *position == 1 => child.name.clone(),
+                _ => format!("{}[{position}]", child.name),
+            }
+        })
+        .collect()
+}
+
+fn diff(path: &str, old: &XmlElement, new: &XmlElement, changes: &mut Vec<Change>) {
+    let mut attributes = Vec::new();
+    for (name, value) in &old.attributes {
+        match new.attributes.get(name) {
+            Some(new_value) if new_value == value => {}
+            new_value => attributes.push(AttributeChange {
+                name: name.clone(),
+                old: Some(value.clone()),
+                new: new_value.cloned(),
+            }),
+        }
+    }
+    for (name, value) in &new.attributes {
+        if !old.attributes.contains_key(name) {
+            attributes.push(AttributeChange {
+                name: name.clone(),
+                old: None,
+                new: Some(value.clone()),
+            });
+        }
+    }
+    let cdata = (old.cdata != new.cdata).then(|| CdataChange {
+        old: old.cdata.clone(),
+        new: new.cdata.clone(),
+    });
+    if !attributes.is_empty() || cdata.is_some() {
+        changes.push(Change::Modified {
+            path: path.to_string(),
+            attributes,
+            cdata,
+        });
+    }
+
+    let old_keys = child_keys(old);
+    let new_keys = child_keys(new);
+    let new_children = new_keys
+        .iter()
+        .zip(&new.children)
+        .collect::<HashMap<_, _>>();
+    for (key, child) in old_keys.iter().zip(&old.children) {
+        let child_path = format!("{path}/{key}");
+        match new_children.get(key) {
+            Some(new_child) => diff(&child_path, child, new_child, changes),
+            None => changes.push(Change::Removed {
+                path: child_path,
+                element: child.clone(),
+            }),
+        }
+    }
+    for (key, child) in new_keys.iter().zip(&new.children) {
+        if !old_keys.contains(key) {
+            changes.push(Change::Added {
+                path: format!("{path}/{key}"),
+                element: child.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::diff::{ApplyError, AttributeChange, Change};
+    use crate::core::OpenDrive;
+    use crate::fixtures::{parse, TestRoad};
+
+    #[test]
+    pub fn test_diff() {
+        let road = |id: &str, length: f64| TestRoad::new(id).length(length).sides(&[], &[]);
+        let old = parse(format!("{}{}", road("1", 10.0), road("2", 5.0)));
+        assert!(old.diff(&old).unwrap().is_empty());
+
+        let changes = old.diff(&parse(road("1", 20.0))).unwrap().changes;
+        assert_eq!(3, changes.len());
+        assert_eq!(
+            Change::Modified {
+                path: r#"/OpenDRIVE/road[@id="1"]"#.to_string(),
+                attributes: vec![AttributeChange {
+                    name: "length".to_string(),
+                    old: Some("1.00000000000000000e1".to_string()),
+                    new: Some("2.00000000000000000e1".to_string()),
+                }],
+                cdata: None,
+            },
+            changes[0]
+        );
+        assert_eq!(
+            r#"/OpenDRIVE/road[@id="1"]/planView/geometry"#,
+            changes[1].path()
+        );
+        assert!(matches!(
+            &changes[2],
+            Change::Removed { path, .. } if path == r#"/OpenDRIVE/road[@id="2"]"#
+        ));
+        assert_eq!(r#"- /OpenDRIVE/road[@id="2"]"#, changes[2].to_string());
+    }
+
+    #[test]
+    pub fn test_apply() {
+        let document = |length: f64, name: &str| {
+            let road = TestRoad::new("a/b").length(length).sides(&[], &[]);
+            OpenDrive::from_xml_str(&format!(
+                r#"<OpenDRIVE><header revMajor="1" revMinor="7" name="{name}"/>{road}</OpenDRIVE>"#
+            ))
+            .unwrap()
+        };
+        let base = document(10.0, "base");
+        let mut updated = document(20.0, "base");
+        updated.road.push(updated.road[0].clone());
+        updated.road[1].id = "c".to_string();
+        let changes = base.diff(&updated).unwrap();
+
+        let mut patched = base.clone();
+        patched.apply(&changes).unwrap();
+        assert_eq!(updated, patched);
+
+        let mut diverged = document(15.0, "base");
+        assert!(matches!(
+            diverged.apply(&changes),
+            Err(ApplyError::Conflict { path, .. }) if path == r#"/OpenDRIVE/road[@id="a/b"]"#
+        ));
+        assert_eq!(document(15.0, "base"), diverged);
+
+        let mut renamed = document(10.0, "renamed");
+        renamed.apply(&changes).unwrap();
+        assert_eq!(Some("renamed"), renamed.header.name.as_deref());
+        assert!(patched.apply(&changes).is_err());
+    }
+}
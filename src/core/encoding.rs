@@ -0,0 +1,210 @@
+use std::io::Read;
+
+/// The characters of the bytes `0x80` to `0x9F` in Windows-1252, which otherwise equals
+/// ISO-8859-1. Undefined bytes are mapped to the control character of the same code point.
+const WINDOWS_1252: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// Labels of Windows-1252, which is declared by documents from older, mostly German, tooling
+const WINDOWS_1252_LABELS: &[&str] = &["windows-1252", "windows1252", "cp1252", "x-cp1252"];
+
+/// The number of bytes that are searched for the XML declaration
+const HEAD_SIZE: usize = 1024;
+
+/// Decodes a document according to the encoding given in its XML declaration while reading it.
+/// The XML parser decodes UTF-8, UTF-16, US-ASCII and ISO-8859-1 itself, this reader
+/// additionally decodes Windows-1252 to UTF-8 and declares the decoded document as UTF-8.
+pub struct DecodingReader<R> {
+    inner: R,
+    /// Whether the input is decoded already, so that only its declaration is rewritten
+    decoded: bool,
+    head_done: bool,
+    windows_1252: bool,
+    /// Decoded bytes that are yet to be read
+    pending: Vec<u8>,
+    pending_start: usize,
+}
+
+impl<R: Read> DecodingReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            decoded: false,
+            head_done: false,
+            windows_1252: false,
+            pending: Vec::new(),
+            pending_start: 0,
+        }
+    }
+
+    /// A reader for input that is UTF-8 regardless of its declaration, such as a document that
+    /// was decoded to a [`str`] already. Its declaration is rewritten to UTF-8, so that the parser
+    /// does not decode it a second time.
+    pub fn utf8(inner: R) -> Self {
+        Self {
+            decoded: true,
+            ..Self::new(inner)
+        }
+    }
+
+    /// Reads the XML declaration and rewrites its encoding if the document is decoded by this
+    /// reader
+    fn read_head(&mut self) -> std::io::Result<()> {
+        let mut head = Vec::new();
+        let mut chunk = [0_u8; 256];
+        while head.len() < HEAD_SIZE && !head.contains(&b'>') {
+            let read = self.inner.read(&mut chunk)?;
+            if read == 0 {
+                break;
+            }
+            head.extend_from_slice(&chunk[..read]);
+        }
+
+        let (start, end) = match Self::declared_encoding(&head) {
+            Some(range) => range,
+            None => {
+                self.pending = head;
+                return Ok(());
+            }
+        };
+        let label = String::from_utf8_lossy(&head[start..end]).to_string();
+        let is_utf8 = label.eq_ignore_ascii_case("utf-8") || label.eq_ignore_ascii_case("utf8");
+        self.windows_1252 = !self.decoded
+            && WINDOWS_1252_LABELS
+                .iter()
+                .any(|l| label.eq_ignore_ascii_case(l));
+
+        if (self.decoded && !is_utf8) || self.windows_1252 {
+            self.pending.extend_from_slice(&head[..start]);
+            self.pending.extend_from_slice(b"UTF-8");
+            self.decode(&head[end..]);
+        } else {
+            self.pending = head;
+        }
+        Ok(())
+    }
+
+    /// The range of the encoding name in the XML declaration at the start of `head`, if any
+    fn declared_encoding(head: &[u8]) -> Option<(usize, usize)> {
+        let declaration = head.strip_prefix(b"<?xml")?;
+        let declaration = &declaration[..declaration.windows(2).position(|w| w == b"?>")?];
+        let offset = b"<?xml".len();
+        let mut index = declaration
+            .windows(b"encoding".len())
+            .position(|w| w == b"encoding")?
+            + b"encoding".len();
+        let skip_whitespace = |index: &mut usize| {
+            while declaration.get(*index).is_some_and(u8::is_ascii_whitespace) {
+                *index += 1;
+            }
+        };
+        skip_whitespace(&mut index);
+        if declaration.get(index) != Some(&b'=') {
+            return None;
+        }
+        index += 1;
+        skip_whitespace(&mut index);
+        let quote = *declaration
+            .get(index)
+            .filter(|q| matches!(q, b'"' | b'\''))?;
+        let start = index + 1;
+        let length = declaration[start..].iter().position(|c| *c == quote)?;
+        Some((offset + start, offset + start + length))
+    }
+
+    /// Appends the given bytes to the pending bytes, decoded if required
+    fn decode(&mut self, bytes: &[u8]) {
+        if self.windows_1252 {
+            let mut buffer = [0_u8; 4];
+            for byte in bytes {
+                let c = match byte {
+                    0x80..=0x9F => WINDOWS_1252[usize::from(byte - 0x80)],
+                    _ => char::from(*byte),
+                };
+                self.pending
+                    .extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            }
+        } else {
+            self.pending.extend_from_slice(bytes);
+        }
+    }
+}
+
+impl<R: Read> Read for DecodingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if !self.head_done {
+            self.read_head()?;
+            self.head_done = true;
+        }
+        if self.pending_start == self.pending.len() && self.windows_1252 {
+            self.pending.clear();
+            self.pending_start = 0;
+            let mut chunk = [0_u8; 4096];
+            let read = self.inner.read(&mut chunk)?;
+            self.decode(&chunk[..read]);
+        }
+        if self.pending_start < self.pending.len() {
+            let pending = &self.pending[self.pending_start..];
+            let read = pending.len().min(buf.len());
+            buf[..read].copy_from_slice(&pending[..read]);
+            self.pending_start += read;
+            Ok(read)
+        } else {
+            self.inner.read(buf)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+
+    #[test]
+    pub fn test_encodings() {
+        let document = |encoding: &str, name: &[u8]| {
+            let mut bytes = format!(
+                r#"<?xml version="1.0" encoding="{encoding}"?><OpenDRIVE><header revMajor="1" revMinor="7" name=""#
+            )
+            .into_bytes();
+            bytes.extend_from_slice(name);
+            bytes.extend_from_slice(br#""/></OpenDRIVE>"#);
+            bytes
+        };
+        let name = |bytes: &[u8]| {
+            OpenDrive::from_slice(bytes)
+                .unwrap()
+                .header
+                .name
+                .unwrap_or_default()
+        };
+
+        assert_eq!("Straße", name(&document("ISO-8859-1", b"Stra\xDFe")));
+        assert_eq!(
+            "Straße €",
+            name(&document("Windows-1252", b"Stra\xDFe \x80"))
+        );
+        assert_eq!("Straße", name(&document("UTF-8", "Straße".as_bytes())));
+        assert_eq!(
+            "Straße €",
+            OpenDrive::from_xml_read(&document("cp1252", b"Stra\xDFe \x80")[..])
+                .unwrap()
+                .header
+                .name
+                .unwrap_or_default()
+        );
+
+        let decoded = String::from_utf8(document("ISO-8859-1", "Straße".as_bytes())).unwrap();
+        assert_eq!(
+            Some("Straße"),
+            OpenDrive::from_xml_str(&decoded)
+                .unwrap()
+                .header
+                .name
+                .as_deref()
+        );
+    }
+}
@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// The absolute or relative errors of road data are described by `<error>` elements within the
 /// `<dataQuality>` element.
@@ -21,29 +20,33 @@ impl Error {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
-            "xyAbsolute" => &self.xy_absolute.value.to_scientific_string(),
-            "xyRelative" => &self.xy_relative.value.to_scientific_string(),
-            "zAbsolute" => &self.z_absolute.value.to_scientific_string(),
-            "zRelative" => &self.z_relative.value.to_scientific_string(),
+            options,
+            "xyAbsolute" => &options.format("xyAbsolute", self.xy_absolute.get::<meter>()),
+            "xyRelative" => &options.format("xyRelative", self.xy_relative.get::<meter>()),
+            "zAbsolute" => &options.format("zAbsolute", self.z_absolute.get::<meter>()),
+            "zRelative" => &options.format("zRelative", self.z_relative.get::<meter>()),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Error
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
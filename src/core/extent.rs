@@ -0,0 +1,174 @@
+use crate::core::header::Header;
+use crate::core::OpenDrive;
+use crate::road::geometry::point::Point;
+use crate::road::Road;
+use crate::units::prelude::*;
+
+/// The inertial extent of a document, as given by `@north`, `@south`, `@east` and `@west` of the
+/// `<header>`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Extent {
+    pub north: Length,
+    pub south: Length,
+    pub east: Length,
+    pub west: Length,
+}
+
+impl Extent {
+    /// The smallest extent containing all given points, `None` if there are none
+    pub fn of(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        points.into_iter().fold(None, |extent, point| {
+            Some(match extent {
+                None => Extent {
+                    north: point.y,
+                    south: point.y,
+                    east: point.x,
+                    west: point.x,
+                },
+                Some(extent) => Extent {
+                    north: extent.north.max(point.y),
+                    south: extent.south.min(point.y),
+                    east: extent.east.max(point.x),
+                    west: extent.west.min(point.x),
+                },
+            })
+        })
+    }
+
+    /// The largest distance between the corresponding bounds of both extents
+    pub fn deviation(&self, other: &Extent) -> Length {
+        [
+            self.north - other.north,
+            self.south - other.south,
+            self.east - other.east,
+            self.west - other.west,
+        ]
+        .into_iter()
+        .map(Length::abs)
+        .fold(Length::new::<meter>(0.0), Length::max)
+    }
+}
+
+impl Header {
+    /// The extent declared by this header, `None` if any of its bounds is missing
+    pub fn extent(&self) -> Option<Extent> {
+        Some(Extent {
+            north: self.north?,
+            south: self.south?,
+            east: self.east?,
+            west: self.west?,
+        })
+    }
+
+    /// Sets `@north`, `@south`, `@east` and `@west` to the extent of the content of the given
+    /// document, see [`OpenDrive::extent`]. The bounds are removed if the document has no roads.
+    pub fn recompute_extent(&mut self, drive: &OpenDrive) {
+        let extent = drive.extent();
+        self.north = extent.map(|e| e.north);
+        self.south = extent.map(|e| e.south);
+        self.east = extent.map(|e| e.east);
+        self.west = extent.map(|e| e.west);
+    }
+}
+
+impl OpenDrive {
+    /// The extent of the roads and their objects in the inertial x/y-plane. Roads are sampled
+    /// every meter along their reference line at the outer borders of their outermost lanes,
+    /// objects contribute their [`crate::object::Object::footprint`].
+    pub fn extent(&self) -> Option<Extent> {
+        Extent::of(self.road.iter().flat_map(|road| {
+            let objects = road
+                .objects
+                .iter()
+                .flat_map(|objects| &objects.object)
+                .flat_map(|object| object.footprint(road));
+            outline(road).chain(objects)
+        }))
+    }
+}
+
+/// Samples of the outer borders of the outermost lanes of the road
+fn outline(road: &Road) -> impl Iterator<Item = Point> + '_ {
+    let length = road.length.get::<meter>().max(0.0);
+    let samples = length.ceil().max(1.0) as usize;
+    (0..=samples).flat_map(move |index| {
+        let s = (index as f64).min(length);
+        let (_, section) = road.lanes.lane_section_at(s);
+        let ds = s - section.s;
+        let offset = road.lanes.offset_at(s);
+        let outermost = [
+            section
+                .left
+                .iter()
+                .flat_map(|l| &l.lane)
+                .map(|l| l.id)
+                .max(),
+            section
+                .right
+                .iter()
+                .flat_map(|r| &r.lane)
+                .map(|l| l.id)
+                .min(),
+        ];
+        [0].into_iter()
+            .chain(outermost.into_iter().flatten())
+            .filter_map(move |id| section.lane_borders(id, ds))
+            .map(move |(_, outer)| {
+                road.pose_at(
+                    Length::new::<meter>(s),
+                    Length::new::<meter>(offset + outer),
+                )
+                .point()
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::validation::{HeaderExtent, Validator};
+    use crate::core::OpenDrive;
+    use crate::fixtures::TestRoad;
+    use crate::parser::ParseOptions;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_recompute_extent() {
+        let xml = format!(
+            r#"<OpenDRIVE>
+                <header revMajor="1" revMinor="7" north="1000" south="0" east="1000" west="0"/>
+                {}
+            </OpenDRIVE>"#,
+            TestRoad::new("1")
+                .length(10.0)
+                .at(5.0, 0.0, 0.0)
+                .sides(&[("driving", 3.0)], &[("driving", 3.0)])
+                .objects(r#"<object id="o" s="5" t="-5" zOffset="0" radius="1"/>"#),
+        );
+        let mut validator = Validator::default().with_rule(HeaderExtent::default());
+        let mut drive = OpenDrive::from_str_with(
+            &xml,
+            ParseOptions {
+                validator: Some(&mut validator),
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            vec!["header-extent"],
+            validator
+                .violations()
+                .iter()
+                .map(|v| v.rule)
+                .collect::<Vec<_>>()
+        );
+
+        let mut header = drive.header.clone();
+        header.recompute_extent(&drive);
+        drive.header = header;
+        let extent = drive.header.extent().unwrap();
+        assert!((extent.north.get::<meter>() - 3.0).abs() < 1e-9);
+        assert!((extent.south.get::<meter>() + 6.0).abs() < 1e-9);
+        assert!((extent.east.get::<meter>() - 15.0).abs() < 1e-9);
+        assert!((extent.west.get::<meter>() - 5.0).abs() < 1e-9);
+    }
+}
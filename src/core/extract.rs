@@ -0,0 +1,107 @@
+use crate::backend::{Event, XmlRsEvents};
+use crate::core::encoding::DecodingReader;
+use crate::core::OpenDrive;
+use crate::parser::ReadContext;
+use xml::reader::XmlEvent;
+use xml::EventReader;
+
+type Events<'h> = Box<dyn Iterator<Item = crate::parser::Result<Event<'static>>> + 'h>;
+type Handler<'h> =
+    Box<dyn for<'a> FnMut(ReadContext<'a, Events<'h>>) -> crate::parser::Result<()> + 'h>;
+
+/// Parses only selected elements of a document and passes each of them to a handler as soon as
+/// it is parsed, without building the whole model. This keeps single-purpose tools, like
+/// extracting all signals of a country map, fast and small in memory:
+///
+/// ```
+/// # use opendrive::core::extract::Extractor;
+/// # use opendrive::signal::Signal;
+/// let mut ids = Vec::new();
+/// Extractor::new()
+///     .on("signal", |signal: Signal| ids.push(signal.id))
+///     .skip("lanes")
+///     .extract_str(r#"
+///         <OpenDRIVE><road><signals>
+///             <signal s="0" t="0" id="s1" dynamic="no" orientation="+" zOffset="0" type="206" subtype="-1"/>
+///         </signals></road></OpenDRIVE>
+///     "#)
+///     .unwrap();
+/// assert_eq!(vec!["s1".to_string()], ids);
+/// ```
+///
+/// Elements that are handled are not searched for further handled elements, elements that are
+/// neither handled nor skipped are.
+#[derive(Default)]
+pub struct Extractor<'h> {
+    handlers: Vec<(String, Handler<'h>)>,
+    skipped: Vec<String>,
+}
+
+impl<'h> Extractor<'h> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses every element of the given name as `T` and passes it to the handler
+    pub fn on<T>(mut self, name: impl Into<String>, mut handler: impl FnMut(T) + 'h) -> Self
+    where
+        T: for<'a> TryFrom<ReadContext<'a, Events<'h>>, Error = Box<crate::parser::Error>>,
+    {
+        self.handlers.push((
+            name.into(),
+            Box::new(move |read| {
+                handler(T::try_from(read)?);
+                Ok(())
+            }),
+        ));
+        self
+    }
+
+    /// Skips every element of the given name together with all its children without parsing
+    /// them
+    pub fn skip(mut self, name: impl Into<String>) -> Self {
+        self.skipped.push(name.into());
+        self
+    }
+
+    pub fn extract_str(&mut self, s: &'h str) -> crate::parser::Result<()> {
+        self.extract_events(EventReader::new_with_config(
+            DecodingReader::utf8(s.trim().as_bytes()),
+            OpenDrive::parser_config(),
+        ))
+    }
+
+    pub fn extract_read(&mut self, r: impl std::io::Read + 'h) -> crate::parser::Result<()> {
+        self.extract_events(EventReader::new_with_config(
+            std::io::BufReader::with_capacity(OpenDrive::READ_BUFFER_SIZE, DecodingReader::new(r)),
+            OpenDrive::parser_config(),
+        ))
+    }
+
+    pub fn extract_events<E>(&mut self, events: E) -> crate::parser::Result<()>
+    where
+        E: IntoIterator<Item = xml::reader::Result<XmlEvent>>,
+        E::IntoIter: 'h,
+    {
+        let mut events: Events<'h> = Box::new(XmlRsEvents::new(events.into_iter()));
+        let mut read = ReadContext::from(&mut events);
+        self.walk(&mut read)
+    }
+
+    fn walk(&mut self, read: &mut ReadContext<'_, Events<'h>>) -> crate::parser::Result<()> {
+        read.children(|name, mut child| {
+            if let Some((_, handler)) = self
+                .handlers
+                .iter_mut()
+                .find(|(handled, _)| handled.eq_ignore_ascii_case(name))
+            {
+                handler(child)
+            } else if self.skipped.iter().any(|s| s.eq_ignore_ascii_case(name)) {
+                // dropping the context skips the element
+                Ok(())
+            } else {
+                self.walk(&mut child)
+            }
+        })
+    }
+}
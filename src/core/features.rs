@@ -0,0 +1,281 @@
+use crate::core::version::MINIMUM_REVISION;
+use crate::core::OpenDrive;
+use crate::junction::junction_type::JunctionType;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::Lane;
+use crate::road::geometry::geometry_type::GeometryType;
+use std::fmt::{Display, Formatter};
+
+/// A schema feature detected by [`OpenDrive::feature_usage`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Feature {
+    ParamPoly3,
+    LaneOffsets,
+    LaneBorders,
+    LaneHeights,
+    Crg,
+    Railroads,
+    Stations,
+    JunctionGroups,
+    GeoReference,
+    Outlines,
+    LateralShapes,
+    VirtualJunctions,
+    DirectJunctions,
+}
+
+impl Feature {
+    pub const ALL: &'static [Feature] = &[
+        Feature::ParamPoly3,
+        Feature::LaneOffsets,
+        Feature::LaneBorders,
+        Feature::LaneHeights,
+        Feature::Crg,
+        Feature::Railroads,
+        Feature::Stations,
+        Feature::JunctionGroups,
+        Feature::GeoReference,
+        Feature::Outlines,
+        Feature::LateralShapes,
+        Feature::VirtualJunctions,
+        Feature::DirectJunctions,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Feature::ParamPoly3 => "<paramPoly3> geometries",
+            Feature::LaneOffsets => "<laneOffset> of lanes",
+            Feature::LaneBorders => "<border> of lanes",
+            Feature::LaneHeights => "<height> of lanes",
+            Feature::Crg => "<CRG> of road surfaces",
+            Feature::Railroads => "<railroad> of roads",
+            Feature::Stations => "<station> elements",
+            Feature::JunctionGroups => "<junctionGroup> elements",
+            Feature::GeoReference => "<geoReference> of the header",
+            Feature::Outlines => "<outlines> of objects",
+            Feature::LateralShapes => "<shape> of lateral profiles",
+            Feature::VirtualJunctions => "virtual junctions",
+            Feature::DirectJunctions => "direct junctions",
+        }
+    }
+
+    /// The revision of ASAM OpenDRIVE that introduced this feature, [`MINIMUM_REVISION`] for
+    /// features that are older
+    pub fn revision(&self) -> (u16, u16) {
+        match self {
+            Feature::Outlines | Feature::LateralShapes => (1, 5),
+            Feature::VirtualJunctions | Feature::DirectJunctions => (1, 6),
+            _ => MINIMUM_REVISION,
+        }
+    }
+}
+
+impl Display for Feature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// How often a document uses a [`Feature`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FeatureUsage {
+    pub feature: Feature,
+    /// The number of elements using the feature
+    pub count: usize,
+}
+
+/// The features of a document and the revisions they need, see [`OpenDrive::compatibility`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompatibilityReport {
+    /// The used features in the order of [`Feature::ALL`]
+    pub features: Vec<FeatureUsage>,
+    /// The revision declared by the header
+    pub declared_revision: (u16, u16),
+    /// The oldest revision that supports all used features, see
+    /// [`OpenDrive::required_revision`]
+    pub required_revision: (u16, u16),
+}
+
+impl CompatibilityReport {
+    /// The used features that a tool reading up to the given revision cannot consume
+    pub fn unsupported(&self, revision: (u16, u16)) -> Vec<FeatureUsage> {
+        self.features
+            .iter()
+            .filter(|usage| usage.feature.revision() > revision)
+            .copied()
+            .collect()
+    }
+
+    /// Whether a tool reading up to the given revision can consume all used features
+    pub fn is_supported_by(&self, revision: (u16, u16)) -> bool {
+        self.required_revision <= revision
+    }
+}
+
+impl Display for CompatibilityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (major, minor) = self.required_revision;
+        writeln!(f, "requires {major}.{minor}")?;
+        for usage in &self.features {
+            let (major, minor) = usage.feature.revision();
+            writeln!(f, "{major}.{minor} {} ({})", usage.feature, usage.count)?;
+        }
+        Ok(())
+    }
+}
+
+impl OpenDrive {
+    /// The features of [`Feature::ALL`] this document uses, in that order, with the number of
+    /// elements using each
+    pub fn feature_usage(&self) -> Vec<FeatureUsage> {
+        let mut counts = [0; Feature::ALL.len()];
+        let mut count = |feature: Feature, n: usize| counts[feature as usize] += n;
+
+        for road in &self.road {
+            count(
+                Feature::ParamPoly3,
+                road.plan_view
+                    .geometry
+                    .iter()
+                    .filter(|g| matches!(g.r#type, GeometryType::ParamPoly3(_)))
+                    .count(),
+            );
+            count(Feature::LaneOffsets, road.lanes.lane_offset.len());
+            let lanes = road
+                .lanes
+                .lane_section
+                .iter()
+                .flat_map(|section| section.lanes().map(|(_, lane)| lane))
+                .collect::<Vec<&Lane>>();
+            count(
+                Feature::LaneBorders,
+                lanes
+                    .iter()
+                    .flat_map(|lane| &lane.choice)
+                    .filter(|choice| matches!(choice, LaneChoice::Border(_)))
+                    .count(),
+            );
+            count(
+                Feature::LaneHeights,
+                lanes.iter().map(|lane| lane.height.len()).sum(),
+            );
+            count(
+                Feature::Crg,
+                road.surface.iter().map(|surface| surface.crg.len()).sum(),
+            );
+            count(Feature::Railroads, road.railroad.iter().count());
+            count(
+                Feature::Outlines,
+                road.objects
+                    .iter()
+                    .flat_map(|objects| &objects.object)
+                    .filter(|object| object.outlines.is_some())
+                    .count(),
+            );
+            count(
+                Feature::LateralShapes,
+                road.lateral_profile.iter().map(|p| p.shape.len()).sum(),
+            );
+        }
+
+        for junction in &self.junction {
+            match junction.r#type {
+                Some(JunctionType::Virtual) => count(Feature::VirtualJunctions, 1),
+                Some(JunctionType::Direct) => count(Feature::DirectJunctions, 1),
+                _ => {}
+            }
+        }
+        count(Feature::Stations, self.station.len());
+        count(Feature::JunctionGroups, self.junction_group.len());
+        count(
+            Feature::GeoReference,
+            self.header.geo_reference.iter().count(),
+        );
+
+        Feature::ALL
+            .iter()
+            .zip(counts)
+            .filter(|(_, count)| *count > 0)
+            .map(|(feature, count)| FeatureUsage {
+                feature: *feature,
+                count,
+            })
+            .collect()
+    }
+
+    /// The used features and the revision they need, to decide whether a tool can consume this
+    /// document
+    pub fn compatibility(&self) -> CompatibilityReport {
+        let features = self.feature_usage();
+        let required_revision = features
+            .iter()
+            .map(|usage| usage.feature.revision())
+            .fold(MINIMUM_REVISION, Ord::max);
+        CompatibilityReport {
+            features,
+            declared_revision: (self.header.rev_major, self.header.rev_minor),
+            required_revision,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::features::{Feature, FeatureUsage};
+    use crate::core::OpenDrive;
+    use crate::fixtures::TestRoad;
+
+    #[test]
+    pub fn test_compatibility() {
+        let road = TestRoad::new("1").length(10.0).lanes(
+            r#"<laneSection s="0">
+                <center><lane id="0" type="none"/></center>
+                <right><lane id="-1" type="driving">
+                    <border sOffset="0" a="3" b="0" c="0" d="0"/>
+                    <border sOffset="5" a="3" b="0" c="0" d="0"/>
+                </lane></right>
+            </laneSection>"#,
+        );
+        let drive = OpenDrive::from_xml_str(&format!(
+            r#"<OpenDRIVE>
+                <header revMajor="1" revMinor="4"/>
+                {road}
+                <junction id="j" type="virtual">
+                    <connection id="0" incomingRoad="1" connectingRoad="1" contactPoint="start"/>
+                </junction>
+            </OpenDRIVE>"#
+        ))
+        .unwrap();
+
+        let report = drive.compatibility();
+        assert_eq!(
+            vec![
+                FeatureUsage {
+                    feature: Feature::LaneBorders,
+                    count: 2
+                },
+                FeatureUsage {
+                    feature: Feature::VirtualJunctions,
+                    count: 1
+                },
+            ],
+            report.features
+        );
+        assert_eq!((1, 4), report.declared_revision);
+        assert_eq!((1, 6), report.required_revision);
+        assert_eq!(drive.required_revision(), report.required_revision);
+        assert!(!report.is_supported_by((1, 5)));
+        assert_eq!(
+            vec![Feature::VirtualJunctions],
+            report
+                .unsupported((1, 5))
+                .iter()
+                .map(|usage| usage.feature)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "requires 1.6\n1.4 <border> of lanes (2)\n1.6 virtual junctions (1)\n",
+            report.to_string()
+        );
+    }
+}
@@ -23,28 +23,33 @@ impl GeoReference {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        let mut visitor = self.additional_data.interleave_comments(visitor);
+        visit_children!(visitor, options);
 
         if let Some(cdata) = self.proj.as_deref() {
             visitor(xml::writer::XmlEvent::CData(cdata))?;
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for GeoReference
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -62,7 +67,7 @@ where
 
         Ok(Self {
             proj,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -1,11 +1,10 @@
 use crate::core::additional_data::AdditionalData;
 use crate::core::geo_reference::GeoReference;
 use crate::core::offset::Offset;
+use crate::units::prelude::*;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use std::borrow::Cow;
 use std::str::FromStr;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// The `<header>` element is the very first element within the `<OpenDRIVE>` element.
 #[derive(Debug, Clone, PartialEq)]
@@ -30,42 +29,48 @@ impl Header {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "revMajor" => Some(self.rev_major.to_string()).as_deref(),
             "revMinor" => Some(self.rev_minor.to_string()).as_deref(),
             "name" => self.name.as_deref(),
             "version" => self.version.as_deref(),
             "date" => self.date.as_deref(),
-            "north" => self.north.map(|v| v.value.to_scientific_string()).as_deref(),
-            "south" => self.south.map(|v| v.value.to_scientific_string()).as_deref(),
-            "east" => self.east.map(|v| v.value.to_scientific_string()).as_deref(),
-            "west" => self.west.map(|v| v.value.to_scientific_string()).as_deref(),
+            "north" => self.north.map(|v| options.format("north", v.get::<meter>())).as_deref(),
+            "south" => self.south.map(|v| options.format("south", v.get::<meter>())).as_deref(),
+            "east" => self.east.map(|v| options.format("east", v.get::<meter>())).as_deref(),
+            "west" => self.west.map(|v| options.format("west", v.get::<meter>())).as_deref(),
             "vendor" => self.vendor.as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         if let Some(geo_reference) = &self.geo_reference {
-            visit_children!(visitor, "geoReference" => geo_reference);
+            visit_children!(visitor, options, "geoReference" => geo_reference);
         }
 
         if let Some(offset) = &self.offset {
-            visit_children!(visitor, "offset" => offset);
+            visit_children!(visitor, options, "offset" => offset);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Header
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -94,7 +99,7 @@ where
             vendor: read.attribute_opt("vendor")?,
             geo_reference,
             offset,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
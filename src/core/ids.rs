@@ -0,0 +1,289 @@
+use crate::core::OpenDrive;
+use crate::road::element_type::ElementType;
+use crate::signal::position::Position;
+use crate::signal::reference::ElementType as ReferenceType;
+use std::collections::{BTreeMap, HashMap};
+
+/// The kinds of elements that have an id that is unique within the document and that may be
+/// referenced by other elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IdKind {
+    Road,
+    Junction,
+    JunctionGroup,
+    /// `<object>`, `<tunnel>` and `<bridge>`
+    Object,
+    Signal,
+    Controller,
+    Station,
+    Switch,
+}
+
+/// Whether an id is the id of an element or a reference to the id of another element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdUse {
+    Definition,
+    Reference,
+}
+
+/// How [`OpenDrive::renumber_ids`] assigns new ids
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IdScheme {
+    /// Sequential numbers from `start` for each kind in document order
+    Sequential { start: u64 },
+    /// Sequential numbers from 1 for each kind in document order after a prefix of the kind, like
+    /// `road1` or `signal3`
+    Prefixed,
+}
+
+impl IdKind {
+    /// The prefix of [`IdScheme::Prefixed`]
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            IdKind::Road => "road",
+            IdKind::Junction => "junction",
+            IdKind::JunctionGroup => "junctionGroup",
+            IdKind::Object => "object",
+            IdKind::Signal => "signal",
+            IdKind::Controller => "controller",
+            IdKind::Station => "station",
+            IdKind::Switch => "switch",
+        }
+    }
+}
+
+impl OpenDrive {
+    /// Assigns new ids to all elements as given by the scheme and updates every reference to
+    /// them, see [`OpenDrive::rename_ids`]. If an id is defined more than once, references are
+    /// updated to the new id of its first definition. Returns the new ids by kind and old id.
+    pub fn renumber_ids(&mut self, scheme: &IdScheme) -> BTreeMap<(IdKind, String), String> {
+        let mut renumbered = BTreeMap::new();
+        let mut definitions = HashMap::<IdKind, Vec<String>>::new();
+        self.visit_ids(&mut |kind, usage, id| {
+            if usage == IdUse::Definition {
+                let ids = definitions.entry(kind).or_default();
+                let new_id = match scheme {
+                    IdScheme::Sequential { start } => (start + ids.len() as u64).to_string(),
+                    IdScheme::Prefixed => format!("{}{}", kind.prefix(), ids.len() + 1),
+                };
+                renumbered
+                    .entry((kind, id.clone()))
+                    .or_insert_with(|| new_id.clone());
+                ids.push(new_id);
+            }
+        });
+
+        let mut definitions = definitions
+            .into_iter()
+            .map(|(kind, ids)| (kind, ids.into_iter()))
+            .collect::<HashMap<_, _>>();
+        self.visit_ids(&mut |kind, usage, id| {
+            let new_id = match usage {
+                IdUse::Definition => definitions.get_mut(&kind).and_then(Iterator::next),
+                IdUse::Reference => renumbered.get(&(kind, id.clone())).cloned(),
+            };
+            if let Some(new_id) = new_id {
+                *id = new_id;
+            }
+        });
+        renumbered
+    }
+
+    /// Replaces the id of every element and every reference to it, like road links, junction
+    /// connections, controls or signal references, by the id that `rename` returns for it, if
+    /// any. As ids and references are renamed by the same function, they stay consistent.
+    pub fn rename_ids(&mut self, mut rename: impl FnMut(IdKind, &str) -> Option<String>) {
+        self.visit_ids(&mut |kind, _, id| {
+            if let Some(renamed) = rename(kind, id) {
+                *id = renamed;
+            }
+        });
+    }
+
+    /// Calls `visitor` for every id of an element and every reference to such an id
+    pub fn visit_ids(&mut self, visitor: &mut dyn FnMut(IdKind, IdUse, &mut String)) {
+        use IdUse::{Definition, Reference};
+
+        for road in &mut self.road {
+            visitor(IdKind::Road, Definition, &mut road.id);
+            if road.junction != "-1" {
+                visitor(IdKind::Junction, Reference, &mut road.junction);
+            }
+            if let Some(link) = &mut road.link {
+                for linked in link.predecessor.iter_mut().chain(link.successor.iter_mut()) {
+                    let kind = match linked.element_type {
+                        Some(ElementType::Junction) => IdKind::Junction,
+                        Some(ElementType::Road) | None => IdKind::Road,
+                    };
+                    visitor(kind, Reference, &mut linked.element_id);
+                }
+            }
+            if let Some(objects) = &mut road.objects {
+                for object in &mut objects.object {
+                    visitor(IdKind::Object, Definition, &mut object.id);
+                }
+                for tunnel in &mut objects.tunnel {
+                    visitor(IdKind::Object, Definition, &mut tunnel.id);
+                }
+                for bridge in &mut objects.bridge {
+                    visitor(IdKind::Object, Definition, &mut bridge.id);
+                }
+                for reference in &mut objects.object_reference {
+                    visitor(IdKind::Object, Reference, &mut reference.id);
+                }
+            }
+            if let Some(signals) = &mut road.signals {
+                for signal in &mut signals.signal {
+                    visitor(IdKind::Signal, Definition, &mut signal.id);
+                    for dependency in &mut signal.dependency {
+                        visitor(IdKind::Signal, Reference, &mut dependency.id);
+                    }
+                    for reference in &mut signal.reference {
+                        let kind = match reference.element_type {
+                            ReferenceType::Object => IdKind::Object,
+                            ReferenceType::Signal => IdKind::Signal,
+                        };
+                        visitor(kind, Reference, &mut reference.element_id);
+                    }
+                    if let Some(Position::Road(position)) = &mut signal.choice {
+                        visitor(IdKind::Road, Reference, &mut position.road_id);
+                    }
+                }
+                for reference in &mut signals.signal_reference {
+                    visitor(IdKind::Signal, Reference, &mut reference.id);
+                }
+            }
+            if let Some(railroad) = &mut road.railroad {
+                for switch in &mut railroad.switch {
+                    visitor(IdKind::Switch, Definition, &mut switch.id);
+                    visitor(IdKind::Road, Reference, &mut switch.main_track.id);
+                    visitor(IdKind::Road, Reference, &mut switch.side_track.id);
+                    if let Some(partner) = &mut switch.partner {
+                        visitor(IdKind::Switch, Reference, &mut partner.id);
+                    }
+                }
+            }
+        }
+
+        for controller in &mut self.controller {
+            visitor(IdKind::Controller, Definition, &mut controller.id);
+            for control in controller.control.iter_mut() {
+                visitor(IdKind::Signal, Reference, &mut control.signal_id);
+            }
+        }
+
+        for junction in &mut self.junction {
+            visitor(IdKind::Junction, Definition, &mut junction.id);
+            if let Some(main_road) = &mut junction.main_road {
+                visitor(IdKind::Road, Reference, main_road);
+            }
+            for connection in junction.connection.iter_mut() {
+                for road in [
+                    &mut connection.incoming_road,
+                    &mut connection.connecting_road,
+                    &mut connection.linked_road,
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    visitor(IdKind::Road, Reference, road);
+                }
+                for linked in connection
+                    .predecessor
+                    .iter_mut()
+                    .chain(connection.successor.iter_mut())
+                {
+                    let kind = if linked.element_type.eq_ignore_ascii_case("junction") {
+                        IdKind::Junction
+                    } else {
+                        IdKind::Road
+                    };
+                    visitor(kind, Reference, &mut linked.element_id);
+                }
+            }
+            for priority in &mut junction.priority {
+                for road in [&mut priority.high, &mut priority.low]
+                    .into_iter()
+                    .flatten()
+                {
+                    visitor(IdKind::Road, Reference, road);
+                }
+            }
+            for controller in &mut junction.controller {
+                visitor(IdKind::Controller, Reference, &mut controller.id);
+            }
+        }
+
+        for group in &mut self.junction_group {
+            visitor(IdKind::JunctionGroup, Definition, &mut group.id);
+            for reference in group.junction_reference.iter_mut() {
+                visitor(IdKind::Junction, Reference, &mut reference.junction);
+            }
+        }
+
+        for station in &mut self.station {
+            visitor(IdKind::Station, Definition, &mut station.id);
+            for platform in station.platform.iter_mut() {
+                for segment in platform.segment.iter_mut() {
+                    visitor(IdKind::Road, Reference, &mut segment.road_id);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::ids::{IdKind, IdScheme};
+    use crate::fixtures::{parse, TestRoad};
+
+    #[test]
+    pub fn test_renumber_ids() {
+        let mut drive = parse(format!(
+            r#"{}{}
+            <controller id="ctrl"><control signalId="light"/></controller>
+            <junction id="J7">
+                <connection id="0" incomingRoad="main" connectingRoad="conn" contactPoint="start"/>
+                <controller id="ctrl"/>
+            </junction>"#,
+            TestRoad::new("main")
+                .length(10.0)
+                .link(r#"<successor elementType="junction" elementId="J7"/>"#)
+                .sides(&[], &[])
+                .signals(
+                    r#"<signal s="0" t="0" id="light" dynamic="yes" orientation="+" zOffset="0" type="1000001" subtype="-1"/>"#
+                ),
+            TestRoad::new("conn")
+                .length(10.0)
+                .at(10.0, 0.0, 0.0)
+                .junction("J7")
+                .link(r#"<predecessor elementType="road" elementId="main" contactPoint="end"/>"#)
+                .sides(&[], &[]),
+        ));
+
+        let renumbered = drive.renumber_ids(&IdScheme::Sequential { start: 1 });
+        assert_eq!(
+            Some("2"),
+            renumbered
+                .get(&(IdKind::Road, "conn".to_string()))
+                .map(String::as_str)
+        );
+        assert_eq!("1", drive.road[0].id);
+        assert_eq!("1", drive.road[1].junction);
+        let successor = drive.road[0].link.as_ref().unwrap().successor.as_ref();
+        assert_eq!(Some("1"), successor.map(|s| s.element_id.as_str()));
+        let connection = drive.junction[0].connection.first();
+        assert_eq!(Some("1"), connection.incoming_road.as_deref());
+        assert_eq!(Some("2"), connection.connecting_road.as_deref());
+        assert_eq!("1", drive.controller[0].control.first().signal_id);
+        assert_eq!("1", drive.junction[0].controller[0].id);
+
+        drive.renumber_ids(&IdScheme::Prefixed);
+        assert_eq!("road2", drive.road[1].id);
+        assert_eq!("junction1", drive.road[1].junction);
+        assert_eq!(
+            "signal1",
+            drive.road[0].signals.as_ref().unwrap().signal[0].id
+        );
+    }
+}
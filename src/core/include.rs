@@ -4,7 +4,7 @@ use std::borrow::Cow;
 /// the files depends on the application.
 /// Included data is represented by `<include>` elements. They may be stored at any position in ASAM
 /// OpenDRIVE.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Include {
     /// Location of the file that is to be included
@@ -16,10 +16,13 @@ impl Include {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
+            options,
             "file" => self.file.as_str(),
         )
     }
@@ -27,15 +30,16 @@ impl Include {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Include
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -0,0 +1,327 @@
+use crate::core::additional_data::INCLUDED_FROM_NAMESPACE;
+use crate::core::encoding::DecodingReader;
+use crate::core::OpenDrive;
+use std::path::{Path, PathBuf};
+use xml::reader::XmlEvent;
+use xml::EventReader;
+
+/// The namespace of XInclude, whose `<xi:include href="...">` is resolved like `<include>`
+pub const XINCLUDE_NAMESPACE: &str = "http://www.w3.org/2001/XInclude";
+
+type Events = Box<dyn Iterator<Item = xml::reader::Result<XmlEvent>>>;
+
+/// Resolves `<include file="...">` and XInclude elements while parsing by merging the content of
+/// the included files, relative to the including file, into the document. If the root element
+/// of an included file is `<OpenDRIVE>`, its children are merged, otherwise the root element
+/// itself is, so that included files may contain, for example, a single `<road>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IncludeResolver {
+    /// Keeps the `<include>` elements directly within `<OpenDRIVE>` as references in the model in
+    /// addition to the merged content. The merged elements record the file they were merged from
+    /// in [`crate::core::additional_data::AdditionalData::included_from`] and are not written,
+    /// the references are, so that a written document includes the same files again. Includes
+    /// within other elements are merged only.
+    pub keep_includes: bool,
+}
+
+impl IncludeResolver {
+    /// The maximum depth of nested includes, to stop on cyclic includes
+    pub const MAX_DEPTH: usize = 16;
+
+    pub fn from_xml_file(&self, path: impl AsRef<Path>) -> crate::parser::Result<OpenDrive> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)
+            .map_err(xml::reader::Error::from)
+            .map_err(crate::parser::Error::from)
+            .map_err(Box::new)?;
+        let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        OpenDrive::from_events(self.resolve(Self::events(file), base))
+    }
+
+    /// Parses the given document, resolving includes relative to `base`
+    pub fn from_xml_str(
+        &self,
+        s: &str,
+        base: impl Into<PathBuf>,
+    ) -> crate::parser::Result<OpenDrive> {
+        let events: Events = Box::new(
+            EventReader::new_with_config(
+                DecodingReader::utf8(std::io::Cursor::new(s.trim().as_bytes().to_vec())),
+                OpenDrive::parser_config(),
+            )
+            .into_iter(),
+        );
+        OpenDrive::from_events(self.resolve(events, base.into()))
+    }
+
+    fn events(file: std::fs::File) -> Events {
+        Box::new(
+            EventReader::new_with_config(
+                std::io::BufReader::with_capacity(
+                    OpenDrive::READ_BUFFER_SIZE,
+                    DecodingReader::new(file),
+                ),
+                OpenDrive::parser_config(),
+            )
+            .into_iter(),
+        )
+    }
+
+    fn resolve(&self, events: Events, base: PathBuf) -> ResolvedEvents {
+        ResolvedEvents {
+            keep_includes: self.keep_includes,
+            stack: vec![Source {
+                events,
+                base,
+                depth: 0,
+                strip_root: false,
+                included_from: None,
+            }],
+            pending: Vec::new(),
+        }
+    }
+}
+
+struct Source {
+    events: Events,
+    base: PathBuf,
+    /// Depth of the currently open element within this source
+    depth: usize,
+    /// Whether the root element of this source is `<OpenDRIVE>` and is left out
+    strip_root: bool,
+    /// The kept `<include>` of the document this source is merged for
+    included_from: Option<String>,
+}
+
+impl Source {
+    /// Whether the current element, which is about to be opened, is merged into the including
+    /// element instead of within another merged element
+    fn is_merged_root(&self) -> bool {
+        self.depth == usize::from(self.strip_root)
+    }
+}
+
+struct ResolvedEvents {
+    keep_includes: bool,
+    stack: Vec<Source>,
+    /// Events to emit before reading on
+    pending: Vec<XmlEvent>,
+}
+
+impl ResolvedEvents {
+    fn included_file(
+        name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Option<String> {
+        let attribute = if name.namespace.as_deref() == Some(XINCLUDE_NAMESPACE) {
+            "href"
+        } else if name.namespace.is_none() && name.local_name.eq_ignore_ascii_case("include") {
+            "file"
+        } else {
+            return None;
+        };
+        attributes
+            .iter()
+            .find(|a| a.name.local_name.eq_ignore_ascii_case(attribute))
+            .map(|a| a.value.clone())
+    }
+
+    fn include(&mut self, file: &str, included_from: Option<String>) -> xml::reader::Result<()> {
+        if self.stack.len() > IncludeResolver::MAX_DEPTH {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("includes nested deeper than {}", IncludeResolver::MAX_DEPTH),
+            )
+            .into());
+        }
+        let base = &self.stack[self.stack.len() - 1].base;
+        let path = base.join(file);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(path = %path.display(), "including file");
+        let events = IncludeResolver::events(std::fs::File::open(&path)?);
+        self.stack.push(Source {
+            events,
+            base: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+            depth: 0,
+            strip_root: false,
+            included_from,
+        });
+        Ok(())
+    }
+}
+
+impl Iterator for ResolvedEvents {
+    type Item = xml::reader::Result<XmlEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.pending.pop() {
+                return Some(Ok(event));
+            }
+            let is_included = self.stack.len() > 1;
+            let source = self.stack.last_mut()?;
+            let event = match source.events.next() {
+                Some(Ok(event)) => event,
+                Some(Err(e)) => return Some(Err(e)),
+                None if is_included => {
+                    self.stack.pop();
+                    continue;
+                }
+                None => return None,
+            };
+
+            match event {
+                XmlEvent::StartDocument { .. } | XmlEvent::EndDocument if is_included => {
+                    if matches!(event, XmlEvent::EndDocument) {
+                        self.stack.pop();
+                    }
+                }
+                XmlEvent::StartElement {
+                    ref name,
+                    ref attributes,
+                    ref namespace,
+                } => {
+                    if is_included && source.depth == 0 {
+                        source.strip_root = name.local_name.eq_ignore_ascii_case("OpenDRIVE");
+                    }
+                    let strip = is_included && source.depth == 0 && source.strip_root;
+                    let merged_from = source
+                        .included_from
+                        .clone()
+                        .filter(|_| is_included && source.is_merged_root());
+                    source.depth += 1;
+                    if let Some(file) = Self::included_file(name, attributes) {
+                        // skip to the end of the include element, like past an `<xi:fallback>`
+                        let mut depth = 1_usize;
+                        for event in source.events.by_ref() {
+                            match event {
+                                Ok(XmlEvent::StartElement { .. }) => depth += 1,
+                                Ok(XmlEvent::EndElement { .. }) => {
+                                    depth -= 1;
+                                    if depth == 0 {
+                                        break;
+                                    }
+                                }
+                                Ok(_) => {}
+                                Err(e) => return Some(Err(e)),
+                            }
+                        }
+                        source.depth -= 1;
+                        // only includes directly within the `<OpenDRIVE>` of the document are kept
+                        let keep = self.keep_includes && !is_included && source.depth == 1;
+                        let included_from = match keep {
+                            true => Some(file.clone()),
+                            false => source.included_from.clone(),
+                        };
+                        if let Err(e) = self.include(&file, included_from) {
+                            return Some(Err(e));
+                        }
+                        if keep {
+                            // XInclude elements are kept as `<include file="...">` references
+                            let name = xml::name::OwnedName::local("include");
+                            self.pending
+                                .push(XmlEvent::EndElement { name: name.clone() });
+                            return Some(Ok(XmlEvent::StartElement {
+                                name,
+                                attributes: vec![xml::attribute::OwnedAttribute {
+                                    name: xml::name::OwnedName::local("file"),
+                                    value: file,
+                                }],
+                                namespace: xml::namespace::Namespace::empty(),
+                            }));
+                        }
+                    } else if let Some(file) = merged_from {
+                        let mut attributes = attributes.clone();
+                        attributes.push(xml::attribute::OwnedAttribute {
+                            name: xml::name::OwnedName::qualified(
+                                "file",
+                                INCLUDED_FROM_NAMESPACE,
+                                Some("included"),
+                            ),
+                            value: file,
+                        });
+                        return Some(Ok(XmlEvent::StartElement {
+                            name: name.clone(),
+                            attributes,
+                            namespace: namespace.clone(),
+                        }));
+                    } else if !strip {
+                        return Some(Ok(event));
+                    }
+                }
+                XmlEvent::EndElement { .. } => {
+                    source.depth -= 1;
+                    if !(is_included && source.depth == 0 && source.strip_root) {
+                        return Some(Ok(event));
+                    }
+                }
+                _ if is_included && source.depth == 0 => {}
+                // the content of kept includes is written as the include only
+                _ if is_included && source.included_from.is_some() && source.is_merged_root() => {}
+                event => return Some(Ok(event)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::include_resolver::IncludeResolver;
+    use crate::fixtures::TestRoad;
+
+    #[test]
+    pub fn test_resolve_includes() {
+        let dir = std::env::temp_dir().join(format!("opendrive-include-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("parts")).unwrap();
+        std::fs::write(
+            dir.join("parts/road.xodr"),
+            format!(
+                r#"<?xml version="1.0"?>{}"#,
+                TestRoad::new("1").sides(&[], &[])
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("parts/junctions.xodr"),
+            r#"<?xml version="1.0"?>
+            <OpenDRIVE><junction id="J" name="J"><connection id="0" incomingRoad="1" connectingRoad="1"/></junction></OpenDRIVE>"#,
+        )
+        .unwrap();
+        let xml = r#"
+            <?xml version="1.0" standalone="yes"?>
+            <OpenDRIVE xmlns:xi="http://www.w3.org/2001/XInclude">
+                <header revMajor="1" revMinor="7"/>
+                <include file="parts/road.xodr"/>
+                <xi:include href="parts/junctions.xodr">
+                    <xi:fallback><junction id="F" name="F"/></xi:fallback>
+                </xi:include>
+            </OpenDRIVE>
+        "#;
+
+        let drive = IncludeResolver::default().from_xml_str(xml, &dir).unwrap();
+        assert_eq!("1", drive.road[0].id);
+        assert_eq!(1, drive.junction.len());
+        assert_eq!("J", drive.junction[0].id);
+        assert!(drive.additional_data.include.is_empty());
+        assert_eq!(None, drive.road[0].additional_data.included_from);
+
+        let resolver = IncludeResolver {
+            keep_includes: true,
+        };
+        let drive = resolver.from_xml_str(xml, &dir).unwrap();
+        assert_eq!(1, drive.road.len());
+        assert_eq!("parts/road.xodr", drive.additional_data.include[0].file);
+        assert_eq!(
+            Some("parts/road.xodr"),
+            drive.road[0].additional_data.included_from.as_deref()
+        );
+
+        // the merged content is written as the includes only
+        let written = drive.to_xml_string().unwrap();
+        assert!(!written.contains("<road") && !written.contains("<junction"));
+        assert_eq!(2, written.matches("<include").count());
+        assert_eq!(drive, resolver.from_xml_str(&written, &dir).unwrap());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
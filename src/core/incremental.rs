@@ -0,0 +1,207 @@
+use crate::core::OpenDrive;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::Read;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread::JoinHandle;
+
+/// Parses a document while its bytes are still arriving, for example while downloading it from
+/// an object storage, instead of buffering the whole file first. The chunks are parsed on a
+/// separate thread as they are fed, so the parser works independently of any async runtime. With
+/// the `tokio` feature, `OpenDrive::from_async_reader` reads from an async reader instead.
+///
+/// ```
+/// # use opendrive::core::incremental::IncrementalParser;
+/// let mut parser = IncrementalParser::new();
+/// for chunk in [&b"<OpenDRIVE><header revMajor=\"1\" revMinor=\"7\"/>"[..], b"</OpenDRIVE>"] {
+///     parser.feed(chunk);
+/// }
+/// let drive = parser.finish().unwrap();
+/// assert_eq!(7, drive.header.rev_minor);
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub struct IncrementalParser {
+    sender: Option<SyncSender<Vec<u8>>>,
+    handle: JoinHandle<crate::parser::Result<OpenDrive>>,
+}
+
+/// The number of chunks that are buffered before the input waits for the parser
+#[cfg(not(target_arch = "wasm32"))]
+const BUFFERED_CHUNKS: usize = 16;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl IncrementalParser {
+    /// The number of chunks that are buffered before [`IncrementalParser::feed`] blocks
+    pub const BUFFERED_CHUNKS: usize = BUFFERED_CHUNKS;
+
+    pub fn new() -> Self {
+        let (sender, receiver): (_, Receiver<Vec<u8>>) = sync_channel(Self::BUFFERED_CHUNKS);
+        let handle = std::thread::spawn(move || {
+            OpenDrive::from_xml_read(ChunkReader::new(move || receiver.recv().ok().map(Ok)))
+        });
+        Self {
+            sender: Some(sender),
+            handle,
+        }
+    }
+
+    /// Passes the next chunk of the document to the parser. Blocks while
+    /// [`IncrementalParser::BUFFERED_CHUNKS`] chunks are waiting to be parsed. Returns `false` if
+    /// the parser does not accept any more input, because it finished or failed.
+    pub fn feed(&mut self, chunk: impl Into<Vec<u8>>) -> bool {
+        match &self.sender {
+            Some(sender) => sender.send(chunk.into()).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Signals the end of the input and waits for the parsed document
+    pub fn finish(mut self) -> crate::parser::Result<OpenDrive> {
+        drop(self.sender.take());
+        match self.handle.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for IncrementalParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl OpenDrive {
+    /// The size of the chunks [`OpenDrive::from_async_reader`] reads
+    pub const ASYNC_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// Parses a document from an async reader, like the body of a download, while its bytes are
+    /// still arriving. The chunks are parsed on a blocking thread of the tokio runtime, which
+    /// must be running. Reading waits, without blocking the runtime, while
+    /// [`IncrementalParser::BUFFERED_CHUNKS`] chunks are waiting to be parsed. On `wasm32`,
+    /// where there are no such threads, the whole document is read before it is parsed.
+    pub async fn from_async_reader(
+        mut reader: impl tokio::io::AsyncRead + Unpin,
+    ) -> crate::parser::Result<Self> {
+        use tokio::io::AsyncReadExt;
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(xml::reader::Error::from)
+                .map_err(crate::parser::Error::from)
+                .map_err(Box::new)?;
+            Self::from_xml_read(bytes.as_slice())
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (sender, mut receiver) = tokio::sync::mpsc::channel(BUFFERED_CHUNKS);
+            let parse = tokio::task::spawn_blocking(move || {
+                Self::from_xml_read(ChunkReader::new(move || receiver.blocking_recv()))
+            });
+            loop {
+                let mut chunk = vec![0; Self::ASYNC_CHUNK_SIZE];
+                let read = reader.read(&mut chunk).await.map(|count| {
+                    chunk.truncate(count);
+                    chunk
+                });
+                let end = !matches!(&read, Ok(chunk) if !chunk.is_empty());
+                // the parser does not accept any more input, because it finished or failed
+                if sender.send(read).await.is_err() || end {
+                    break;
+                }
+            }
+            drop(sender);
+            match parse.await {
+                Ok(result) => result,
+                Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+                Err(e) => Err(Box::new(crate::parser::Error::from(
+                    xml::reader::Error::from(std::io::Error::other(e)),
+                ))),
+            }
+        }
+    }
+}
+
+/// Reads the chunks an [`IncrementalParser`] or [`OpenDrive::from_async_reader`] receives until
+/// there are no more
+#[cfg(not(target_arch = "wasm32"))]
+struct ChunkReader<R> {
+    receive: R,
+    chunk: Vec<u8>,
+    position: usize,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R> ChunkReader<R>
+where
+    R: FnMut() -> Option<std::io::Result<Vec<u8>>>,
+{
+    fn new(receive: R) -> Self {
+        Self {
+            receive,
+            chunk: Vec::new(),
+            position: 0,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R> Read for ChunkReader<R>
+where
+    R: FnMut() -> Option<std::io::Result<Vec<u8>>>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.position >= self.chunk.len() {
+            match (self.receive)() {
+                Some(chunk) => {
+                    self.chunk = chunk?;
+                    self.position = 0;
+                }
+                // the input ended
+                None => return Ok(0),
+            }
+        }
+        let count = buf.len().min(self.chunk.len() - self.position);
+        buf[..count].copy_from_slice(&self.chunk[self.position..self.position + count]);
+        self.position += count;
+        Ok(count)
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::{document, TestRoad};
+
+    #[test]
+    pub fn test_from_async_reader() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let xml = document(TestRoad::new("1"));
+        // the pipe buffers 16 bytes only, so that the document arrives in many chunks
+        let (mut writer, reader) = tokio::io::duplex(16);
+        let bytes = xml.clone().into_bytes();
+        runtime.spawn(async move {
+            use tokio::io::AsyncWriteExt;
+            writer.write_all(&bytes).await.unwrap();
+        });
+        let drive = runtime
+            .block_on(OpenDrive::from_async_reader(reader))
+            .unwrap();
+        assert_eq!(OpenDrive::from_xml_str(&xml).unwrap(), drive);
+
+        let truncated = &xml.as_bytes()[..xml.len() / 2];
+        assert!(runtime
+            .block_on(OpenDrive::from_async_reader(truncated))
+            .is_err());
+    }
+}
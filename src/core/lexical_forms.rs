@@ -0,0 +1,164 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use xml::EventReader;
+
+/// The lexical form of the numeric attributes of a document as they were written in the source,
+/// such as `1.0000000000000000e+00` or `1`, which
+/// [`crate::core::OpenDrive::to_xml_string_preserving`] writes again for unchanged values.
+/// Attributes are identified by the path of their element, which consists of the element names
+/// and their index among the siblings of the same name, so that edits elsewhere in the document
+/// do not affect them.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LexicalForms {
+    forms: HashMap<String, Vec<(String, String)>>,
+}
+
+impl LexicalForms {
+    pub fn from_xml_str(s: &str) -> xml::reader::Result<Self> {
+        Self::from_events(EventReader::from_str(s.trim()))
+    }
+
+    pub fn from_xml_read<T: std::io::Read>(r: T) -> xml::reader::Result<Self> {
+        Self::from_events(EventReader::new(r))
+    }
+
+    pub fn from_events(
+        events: impl IntoIterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    ) -> xml::reader::Result<Self> {
+        let mut forms = HashMap::new();
+        let mut path = ElementPath::default();
+
+        for event in events {
+            match event? {
+                xml::reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } => {
+                    let key = path.enter(&name.local_name);
+                    let numeric = attributes
+                        .into_iter()
+                        .filter(|a| a.value.trim().parse::<f64>().is_ok())
+                        .map(|a| (a.name.local_name, a.value))
+                        .collect::<Vec<_>>();
+                    if !numeric.is_empty() {
+                        forms.insert(key, numeric);
+                    }
+                }
+                xml::reader::XmlEvent::EndElement { .. } => path.leave(),
+                _ => {}
+            }
+        }
+
+        Ok(Self { forms })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.forms.is_empty()
+    }
+
+    /// Wraps an event visitor, so that numeric attributes are written in their original lexical
+    /// form if their value did not change
+    pub fn preserve<'s>(
+        &'s self,
+        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()> + 's,
+    ) -> impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()> + 's {
+        let mut path = ElementPath::default();
+        move |event: xml::writer::XmlEvent<'_>| match event {
+            xml::writer::XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } => {
+                let key = path.enter(name.local_name);
+                let forms = self.forms.get(&key).map(Vec::as_slice).unwrap_or_default();
+                // always owned, as the borrowed attributes cannot be mixed with the original forms
+                let attributes = attributes
+                    .iter()
+                    .map(|attribute| {
+                        let original = forms
+                            .iter()
+                            .find(|(name, _)| name == attribute.name.local_name)
+                            .map(|(_, value)| value.as_str())
+                            .filter(|original| same_number(original, attribute.value));
+                        xml::attribute::Attribute::new(
+                            attribute.name,
+                            original.unwrap_or(attribute.value),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                visitor(xml::writer::XmlEvent::StartElement {
+                    name,
+                    attributes: Cow::Owned(attributes),
+                    namespace,
+                })
+            }
+            event @ xml::writer::XmlEvent::EndElement { .. } => {
+                path.leave();
+                visitor(event)
+            }
+            event => visitor(event),
+        }
+    }
+}
+
+fn same_number(a: &str, b: &str) -> bool {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a == b || (a.is_nan() && b.is_nan()),
+        _ => false,
+    }
+}
+
+/// Builds the keys of elements from the names of the open elements and their index among the
+/// siblings of the same name
+#[derive(Default)]
+struct ElementPath {
+    open: Vec<(String, HashMap<String, usize>)>,
+    root_siblings: HashMap<String, usize>,
+}
+
+impl ElementPath {
+    fn enter(&mut self, name: &str) -> String {
+        let siblings = match self.open.last_mut() {
+            Some((_, siblings)) => siblings,
+            None => &mut self.root_siblings,
+        };
+        let index = siblings.entry(name.to_string()).or_insert(0);
+        let segment = format!("{name}[{index}]");
+        *index += 1;
+
+        let key = match self.open.last() {
+            Some((parent, _)) => format!("{parent}/{segment}"),
+            None => segment,
+        };
+        self.open.push((key.clone(), HashMap::new()));
+        key
+    }
+
+    fn leave(&mut self) {
+        self.open.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::lexical_forms::LexicalForms;
+    use crate::core::OpenDrive;
+    use crate::fixtures::{document, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_preserve_lexical_forms() {
+        let xml = &document(TestRoad::new("1").geometry(
+            r#"<geometry s="0.0" x="1.0000000000000000e+00" y="-2.5" hdg="0" length="100"><line/></geometry>"#,
+        ));
+        let mut drive = OpenDrive::from_xml_str(xml).unwrap();
+        let forms = LexicalForms::from_xml_str(xml).unwrap();
+
+        drive.road[0].plan_view.geometry[0].y = Length::new::<meter>(-3.0);
+        let written = drive.to_xml_string_preserving(&forms).unwrap();
+        assert!(written.contains(r#"length="100""#));
+        assert!(written.contains(r#"x="1.0000000000000000e+00""#));
+        assert!(written.contains(r#"a="3.5""#));
+        assert!(written.contains(r#"y="-3.00000000000000000e0""#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+}
@@ -1,24 +1,55 @@
+use crate::backend::{Event, EventSink, EventSource, XmlRsEvents};
 use crate::core::additional_data::AdditionalData;
+use crate::core::encoding::DecodingReader;
 use crate::core::header::Header;
+use crate::core::lexical_forms::LexicalForms;
+use crate::core::recovery::PositionedEvents;
+use crate::core::recovery::Recovery;
+use crate::core::validation::ValidatingEvents;
 use crate::junction::junction_group::JunctionGroup;
 use crate::junction::Junction;
+use crate::parser::{Leniency, ParseOptions};
 use crate::railroad::station::Station;
 use crate::road::Road;
 use crate::signal::controller::Controller;
+use crate::writer::layout::{Layout, LayoutWriter};
+use crate::writer::WriteOptions;
 use std::borrow::Cow;
-use xml::{EventReader, EventWriter};
+use std::cell::Cell;
+use std::fmt::{Display, Formatter, Write};
+use xml::{EventReader, EventWriter, ParserConfig};
 
 pub mod additional_data;
+pub mod boolean;
+pub mod comment;
 pub mod data_quality;
+pub mod diff;
+pub mod encoding;
 pub mod error;
+pub mod extent;
+pub mod extract;
+pub mod features;
 pub mod geo_reference;
 pub mod header;
+pub mod ids;
 pub mod include;
+#[cfg(feature = "fs")]
+pub mod include_resolver;
+pub mod incremental;
+pub mod lexical_forms;
 pub mod offset;
 pub mod post_processing;
 pub mod raw_data;
+pub mod recovery;
+pub mod shared;
 pub mod source;
+pub mod statistics;
+pub mod unknown;
 pub mod user_data;
+pub mod validated;
+pub mod validation;
+pub mod version;
+pub mod xml_element;
 
 #[derive(Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
@@ -33,21 +64,177 @@ pub struct OpenDrive {
 }
 
 impl OpenDrive {
+    /// A multi-line overview of the document for logging and debugging, listing every road with
+    /// its lane sections and every junction, see the [`Display`] implementations of the
+    /// elements.
+    pub fn summary(&self) -> String {
+        let mut summary = self.to_string();
+        for road in &self.road {
+            let _ = write!(summary, "\n  {road}");
+            for section in &road.lanes.lane_section {
+                let _ = write!(summary, "\n    {section}");
+            }
+        }
+        for junction in &self.junction {
+            let _ = write!(summary, "\n  {junction}");
+        }
+        summary
+    }
+
+    /// The configuration of the readers created by [`OpenDrive::from_xml_str`] and
+    /// [`OpenDrive::from_xml_read`], which keeps comments
+    pub fn parser_config() -> ParserConfig {
+        ParserConfig::new().ignore_comments(false)
+    }
+
+    /// The capacity of the buffer [`OpenDrive::from_xml_read`] reads its input with
+    pub const READ_BUFFER_SIZE: usize = 64 * 1024;
+
     #[inline]
     pub fn from_xml_str(s: &str) -> crate::parser::Result<Self> {
-        Self::from_reader(EventReader::from_str(s.trim()))
+        Self::from_reader(EventReader::new_with_config(
+            DecodingReader::utf8(s.trim().as_bytes()),
+            Self::parser_config(),
+        ))
+    }
+
+    /// Parses a document from its bytes, for example of a memory-mapped file, which are decoded
+    /// according to the XML declaration, see [`DecodingReader`]. The bytes are parsed in place,
+    /// only the parsed model is allocated.
+    #[inline]
+    pub fn from_slice(bytes: &[u8]) -> crate::parser::Result<Self> {
+        Self::from_reader(EventReader::new_with_config(
+            DecodingReader::new(bytes),
+            Self::parser_config(),
+        ))
     }
 
+    /// Parses a document while reading it, for example from a file or a network stream. The
+    /// input is streamed through a buffer of [`OpenDrive::READ_BUFFER_SIZE`] bytes and never held
+    /// completely in memory, only the parsed model is.
     #[inline]
     pub fn from_xml_read<T: std::io::Read>(r: T) -> crate::parser::Result<Self> {
-        Self::from_reader(EventReader::new(r))
+        Self::from_reader(EventReader::new_with_config(
+            std::io::BufReader::with_capacity(Self::READ_BUFFER_SIZE, DecodingReader::new(r)),
+            Self::parser_config(),
+        ))
     }
 
+    /// Parses a document from the given reader, which should read from a buffered source, see
+    /// [`OpenDrive::from_xml_read`]
     pub fn from_reader<T: std::io::Read>(reader: EventReader<T>) -> crate::parser::Result<Self> {
-        let mut events = reader.into_iter();
+        Self::from_events(reader.into_iter())
+    }
+
+    /// Parses a document from the events of any XML backend, see [`crate::backend`]
+    pub fn from_source(source: impl EventSource) -> crate::parser::Result<Self> {
+        Self::read_events(source.into_events(), None)
+    }
+
+    /// Parses a document from a string according to the given options
+    pub fn from_str_with(s: &str, options: ParseOptions) -> crate::parser::Result<Self> {
+        Self::from_reader_with(
+            EventReader::new_with_config(
+                DecodingReader::utf8(s.trim().as_bytes()),
+                Self::parser_config(),
+            ),
+            options,
+        )
+    }
+
+    /// Parses the file at the given path according to the given options, see
+    /// [`OpenDrive::from_xml_read`]
+    #[cfg(feature = "fs")]
+    pub fn from_path(
+        path: impl AsRef<std::path::Path>,
+        options: ParseOptions,
+    ) -> crate::parser::Result<Self> {
+        let file = std::fs::File::open(path)
+            .map_err(xml::reader::Error::from)
+            .map_err(crate::parser::Error::from)
+            .map_err(Box::new)?;
+        Self::from_reader_with(
+            EventReader::new_with_config(
+                std::io::BufReader::with_capacity(
+                    Self::READ_BUFFER_SIZE,
+                    DecodingReader::new(file),
+                ),
+                Self::parser_config(),
+            ),
+            options,
+        )
+    }
+
+    /// Parses a document from the given reader according to the given options
+    pub fn from_reader_with<T: std::io::Read>(
+        reader: EventReader<T>,
+        options: ParseOptions,
+    ) -> crate::parser::Result<Self> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "parse",
+            recover = options.recover,
+            validate = options.validator.is_some()
+        )
+        .entered();
+        let leniency = options.leniency();
+        let position = Cell::new(None);
+        let mut events = ValidatingEvents::new(
+            PositionedEvents::new(reader, &position),
+            options.validator,
+            &position,
+        );
+        let read = if options.recover {
+            Self::recover(&mut events, &|| position.get(), leniency.as_ref()).map(|recovered| {
+                if let Some(diagnostics) = options.diagnostics {
+                    diagnostics.extend(recovered.diagnostics);
+                }
+                recovered.drive
+            })
+        } else {
+            Self::read_events(&mut events, leniency.as_ref())
+        };
+        // reading fails on the end of the events after a violation that aborts the parsing
+        let drive = read.map_err(|e| match events.aborted() {
+            Some(violation) => Box::new(crate::parser::Error::Violation(violation.clone())),
+            None => e,
+        })?;
+        events.finish(&drive)?;
+        if let (Some(sink), Some(leniency)) = (options.warnings, leniency) {
+            sink.extend(leniency.warnings.into_inner());
+        }
+
+        let revision = (drive.header.rev_major, drive.header.rev_minor);
+        match options.revisions {
+            Some(revisions) if !revisions.contains(&revision) => {
+                Err(Box::new(crate::parser::Error::UnsupportedRevision {
+                    rev_major: revision.0,
+                    rev_minor: revision.1,
+                }))
+            }
+            _ => Ok(drive),
+        }
+    }
+
+    /// Parses a document from the events of xml-rs, see [`OpenDrive::from_source`] for other
+    /// backends
+    #[inline]
+    pub fn from_events(
+        events: impl Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    ) -> crate::parser::Result<Self> {
+        Self::read_events(XmlRsEvents::new(events), None)
+    }
+
+    fn read_events(
+        mut events: impl Iterator<Item = crate::parser::Result<Event<'static>>>,
+        leniency: Option<&Leniency>,
+    ) -> crate::parser::Result<Self> {
         let mut drive = None;
 
         let mut read = crate::parser::ReadContext::from(&mut events);
+        if let Some(leniency) = leniency {
+            read = read.with_leniency(leniency);
+        }
 
         match_child_eq_ignore_ascii_case!(
             read,
@@ -93,63 +280,256 @@ impl OpenDrive {
             encoding: None,
             standalone: Some(true),
         })?;
-        self.visit_attributes(|attributes| {
-            writer.write(xml::writer::XmlEvent::StartElement {
-                name: xml::name::Name::local("OpenDRIVE"),
-                attributes,
-                namespace: std::borrow::Cow::Owned(xml::namespace::Namespace::empty()),
-            })
-        })?;
-        self.visit_children(|event| writer.write(event))?;
-        writer.write(xml::writer::XmlEvent::EndElement { name: None })?;
+        self.visit(|event| writer.write(event))
+    }
+
+    /// Writes this document indented with the default [`Layout`]
+    pub fn to_string_pretty(&self) -> crate::writer::Result<String> {
+        self.to_xml_string_with(&WriteOptions {
+            layout: Some(Layout::default()),
+            ..WriteOptions::default()
+        })
+    }
+
+    /// Writes this document to the file at the given path according to the given options,
+    /// replacing the file if it exists
+    #[cfg(feature = "fs")]
+    pub fn to_path(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        options: &WriteOptions,
+    ) -> crate::writer::Result<()> {
+        let file = std::fs::File::create(path)
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)?;
+        let mut writer = std::io::BufWriter::new(file);
+        self.to_xml_write_with(&mut writer, options)?;
+        std::io::Write::flush(&mut writer)
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)
+    }
+
+    /// Writes this document like [`OpenDrive::to_xml_string`], but keeps the lexical form of
+    /// numeric attributes whose value is unchanged since they were read, so that a diff against
+    /// the source only shows actual edits
+    pub fn to_xml_string_preserving(&self, forms: &LexicalForms) -> crate::writer::Result<String> {
+        self.to_xml_string_with(&WriteOptions {
+            lexical_forms: Some(forms),
+            ..WriteOptions::default()
+        })
+    }
+
+    /// Writes this document like [`OpenDrive::to_xml_string`] with numbers formatted according
+    /// to the given options
+    pub fn to_xml_string_with(&self, options: &WriteOptions) -> crate::writer::Result<String> {
+        let mut buffer = Vec::new();
+        self.to_xml_write_with(&mut buffer, options)?;
+        String::from_utf8(buffer)
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)
+    }
+
+    /// Writes this document like [`OpenDrive::to_xml_write`] with numbers formatted according
+    /// to the given options
+    pub fn to_xml_write_with(
+        &self,
+        w: impl std::io::Write,
+        options: &WriteOptions,
+    ) -> crate::writer::Result<()> {
+        let result = match &options.layout {
+            Some(layout) => {
+                let mut writer = LayoutWriter::new(w, layout);
+                self.write_events(|event| writer.write(event), options)
+            }
+            None => {
+                let mut writer = EventWriter::new(w);
+                self.write_events(|event| writer.write(event), options)
+            }
+        };
+        result
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)?;
         Ok(())
     }
 
+    /// Writes the events of this document with numbers formatted according to the given options
+    /// to any XML backend, see [`crate::backend`]. The layout of the options is up to the sink.
+    pub fn write_to_sink(
+        &self,
+        sink: &mut impl EventSink,
+        options: &WriteOptions,
+    ) -> crate::writer::Result<()> {
+        let mut failed = None;
+        let result = self.write_events(
+            |event| match sink.write_event(Event::from(event)) {
+                Ok(()) => Ok(()),
+                Err(e) => {
+                    // kept to be returned instead of the error that ends the visit
+                    failed = Some(e);
+                    let failed = std::io::Error::other("the event sink failed");
+                    Err(xml::writer::Error::from(failed))
+                }
+            },
+            options,
+        );
+        match failed {
+            Some(e) => Err(e),
+            None => result.map_err(crate::writer::Error::from).map_err(Box::new),
+        }
+    }
+
+    /// Writes the events of this document as the events of xml-rs with numbers formatted
+    /// according to the given options
+    fn write_events(
+        &self,
+        mut write: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &WriteOptions,
+    ) -> xml::writer::Result<()> {
+        write(xml::writer::XmlEvent::StartDocument {
+            version: xml::common::XmlVersion::Version10,
+            encoding: None,
+            standalone: Some(true),
+        })?;
+        self.visit_with(options.apply(write), options)
+    }
+
+    /// Visits the `<OpenDRIVE>` element and all its children with the numbers formatted like
+    /// [`OpenDrive::to_xml_string`] does
+    pub fn visit(
+        &self,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+    ) -> xml::writer::Result<()> {
+        self.visit_with(visitor, &WriteOptions::default())
+    }
+
+    /// Visits the `<OpenDRIVE>` element and all its children with the numbers formatted
+    /// according to the given options
+    pub fn visit_with(
+        &self,
+        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &WriteOptions,
+    ) -> xml::writer::Result<()> {
+        self.visit_attributes(
+            |attributes, comments| {
+                for comment in comments {
+                    visitor(xml::writer::XmlEvent::Comment(comment))?;
+                }
+                visitor(xml::writer::XmlEvent::StartElement {
+                    name: xml::name::Name::local("OpenDRIVE"),
+                    attributes,
+                    namespace: std::borrow::Cow::Owned(xml::namespace::Namespace::empty()),
+                })
+            },
+            options,
+        )?;
+        self.visit_children(&mut visitor, options)?;
+        visitor(xml::writer::XmlEvent::EndElement { name: None })
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor, "header" => self.header);
+        let mut visitor = self.additional_data.interleave_comments(visitor);
+        visit_children!(visitor, options, "header" => self.header);
 
-        for road in &self.road {
-            visit_children!(visitor, "road" => road);
+        // elements merged from kept includes are written as the `<include>` only
+        let written = |data: &AdditionalData| data.included_from.is_none();
+
+        for road in self.road.iter().filter(|r| written(&r.additional_data)) {
+            visit_children!(visitor, options, "road" => road);
         }
 
-        for controller in &self.controller {
-            visit_children!(visitor, "controller" => controller);
+        for controller in self
+            .controller
+            .iter()
+            .filter(|c| written(&c.additional_data))
+        {
+            visit_children!(visitor, options, "controller" => controller);
         }
 
-        for junction in &self.junction {
-            visit_children!(visitor, "junction" => junction);
+        for junction in self.junction.iter().filter(|j| written(&j.additional_data)) {
+            visit_children!(visitor, options, "junction" => junction);
         }
 
-        for junction_group in &self.junction_group {
-            visit_children!(visitor, "junctionGroup" => junction_group);
+        for junction_group in self
+            .junction_group
+            .iter()
+            .filter(|g| written(&g.additional_data))
+        {
+            visit_children!(visitor, options, "junctionGroup" => junction_group);
         }
 
-        for station in &self.station {
-            visit_children!(visitor, "station" => station);
+        for station in self.station.iter().filter(|s| written(&s.additional_data)) {
+            visit_children!(visitor, options, "station" => station);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
+impl Display for OpenDrive {
+    /// A one-line summary of the document, like
+    /// `OpenDRIVE 1.7 "Town": 12 roads, 2 junctions, 1 controller`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OpenDRIVE {}.{}",
+            self.header.rev_major, self.header.rev_minor
+        )?;
+        if let Some(name) = self.header.name.as_deref().filter(|n| !n.is_empty()) {
+            write!(f, " {name:?}")?;
+        }
+        let counts = [
+            (self.road.len(), "road"),
+            (self.junction.len(), "junction"),
+            (self.controller.len(), "controller"),
+        ];
+        for (index, (count, name)) in counts.into_iter().enumerate() {
+            let separator = if index == 0 { ": " } else { ", " };
+            let plural = if count == 1 { "" } else { "s" };
+            write!(f, "{separator}{count} {name}{plural}")?;
+        }
+        Ok(())
+    }
+}
+
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for OpenDrive
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
-    fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
+    #[inline]
+    fn try_from(read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
+        Self::read(read, None)
+    }
+}
+
+impl OpenDrive {
+    /// Reads the document, skipping roads and junctions that fail to parse if a recovery is
+    /// given, see [`OpenDrive::from_xml_str_recovering`]
+    fn read<I>(
+        mut read: crate::parser::ReadContext<'_, I>,
+        mut recovery: Option<&mut Recovery<'_>>,
+    ) -> crate::parser::Result<Self>
+    where
+        I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("read", recover = recovery.is_some()).entered();
         let mut header = None;
         let mut roads = Vec::new();
         let mut controller = Vec::new();
@@ -161,12 +541,22 @@ where
         match_child_eq_ignore_ascii_case!(
             read,
             "header" true => Header => |v| header = Some(v),
-            "road" => Road => |v| roads.push(v),
             "controller" => Controller => |v| controller.push(v),
-            "junction" => Junction => |v| junction.push(v),
             "junctionGroup" => JunctionGroup => |v| junction_group.push(v),
             "station" => Station => |v| station.push(v),
-            _ => |_name, context| additional_data.fill(context),
+            _ => |name: &str, context| {
+                if name.eq_ignore_ascii_case("road") {
+                    let parsed = roads.len();
+                    roads.extend(Recovery::read(recovery.as_deref_mut(), context, parsed)?);
+                    Ok(())
+                } else if name.eq_ignore_ascii_case("junction") {
+                    let parsed = junction.len();
+                    junction.extend(Recovery::read(recovery.as_deref_mut(), context, parsed)?);
+                    Ok(())
+                } else {
+                    additional_data.fill(context)
+                }
+            },
         );
 
         Ok(Self {
@@ -176,7 +566,7 @@ where
             junction,
             junction_group,
             station,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
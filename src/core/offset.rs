@@ -1,8 +1,6 @@
 use crate::core::additional_data::AdditionalData;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::angle::radian;
-use uom::si::f64::{Angle, Length};
-use uom::si::length::meter;
 
 /// To avoid large coordinates, an offset of the whole dataset may be applied using the `<offset>`
 /// element. It enables inertial relocation and re-orientation of datasets. The dataset is first
@@ -27,29 +25,35 @@ impl Offset {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes!(
             visitor,
-            "hdg" => &self.hdg.value.to_scientific_string(),
-            "x" => &self.x.value.to_scientific_string(),
-            "y" => &self.y.value.to_scientific_string(),
-            "z" => &self.z.value.to_scientific_string(),
+            options,
+            "hdg" => &options.format("hdg", self.hdg.get::<radian>()),
+            "x" => &options.format("x", self.x.get::<meter>()),
+            "y" => &options.format("y", self.y.get::<meter>()),
+            "z" => &options.format("z", self.z.get::<meter>()),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
-        self.additional_data.append_children(visitor)
+        let mut visitor = self.additional_data.interleave_comments(visitor);
+        visit_children!(visitor, options);
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Offset
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -63,7 +67,7 @@ where
             x: read.attribute("x").map(Length::new::<meter>)?,
             y: read.attribute("y").map(Length::new::<meter>)?,
             z: read.attribute("z").map(Length::new::<meter>)?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -4,7 +4,7 @@ use std::borrow::Cow;
 
 /// Some basic metadata containing information about raw data included in ASAM OpenDRIVE is
 /// described by the `<rawData>` element within the `<dataQuality`> element.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct RawData {
     /// Date of the delivery of raw data, to be given in ISO 8601 notification
@@ -28,10 +28,13 @@ impl RawData {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "date" => Some(self.date.as_str()),
             "postProcessing" => Some(self.post_processing.as_str()),
             "postProcessingComment" => self.post_processing_comment.as_deref(),
@@ -43,15 +46,16 @@ impl RawData {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for RawData
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -0,0 +1,255 @@
+use crate::backend::{Event, EventSource};
+use crate::core::encoding::DecodingReader;
+use crate::core::OpenDrive;
+use crate::parser::{Leniency, ReadContext, SubtreeEvents};
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use xml::common::{Position, TextPosition};
+use xml::EventReader;
+
+/// A `<road>` or `<junction>` that was skipped while parsing in recovery mode, see
+/// [`OpenDrive::from_xml_str_recovering`]
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// Local name of the skipped element
+    pub element: String,
+    pub id: Option<String>,
+    /// Index of the element among the elements of the same name in the document
+    pub index: usize,
+    /// Position of the start tag of the element, if the document was parsed from text
+    pub position: Option<TextPosition>,
+    pub error: Box<crate::parser::Error>,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "skipped {}[{}]", self.element, self.index)?;
+        if let Some(id) = &self.id {
+            write!(f, " with id `{id}`")?;
+        }
+        if let Some(position) = &self.position {
+            write!(f, " at {position}")?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
+/// A document parsed in recovery mode together with the elements that were skipped
+#[derive(Debug)]
+pub struct Recovered {
+    pub drive: OpenDrive,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+pub(crate) struct Recovery<'r> {
+    diagnostics: Vec<Diagnostic>,
+    position: &'r dyn Fn() -> Option<TextPosition>,
+}
+
+impl Recovery<'_> {
+    /// Reads the element as `T`. With a recovery, an element that fails to parse is skipped and
+    /// recorded as diagnostic, `parsed` is the number of elements of the same name read so far.
+    pub(crate) fn read<T, I>(
+        recovery: Option<&mut Self>,
+        context: ReadContext<'_, I>,
+        parsed: usize,
+    ) -> crate::parser::Result<Option<T>>
+    where
+        I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
+        T: for<'b> TryFrom<ReadContext<'b, I>, Error = Box<crate::parser::Error>>,
+        T: for<'b, 'c> TryFrom<
+            ReadContext<'b, SubtreeEvents<'c, I>>,
+            Error = Box<crate::parser::Error>,
+        >,
+    {
+        let recovery = match recovery {
+            Some(recovery) => recovery,
+            None => return T::try_from(context).map(Some),
+        };
+        let element = context.element_name().to_string();
+        let id = context.attribute_opt::<String>("id").ok().flatten();
+        let position = (recovery.position)();
+        match context.read_or_skip::<T>() {
+            Ok(value) => Ok(Some(value)),
+            Err(error) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(element, id, %error, "skipping element that failed to parse");
+                let skipped = recovery
+                    .diagnostics
+                    .iter()
+                    .filter(|d| d.element == element)
+                    .count();
+                recovery.diagnostics.push(Diagnostic {
+                    element,
+                    id,
+                    index: parsed + skipped,
+                    position,
+                    error,
+                });
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// The events of a reader, which remembers the position of the last event
+pub(crate) struct PositionedEvents<'p, R: std::io::Read> {
+    reader: EventReader<R>,
+    position: &'p Cell<Option<TextPosition>>,
+    finished: bool,
+}
+
+impl<'p, R: std::io::Read> PositionedEvents<'p, R> {
+    pub(crate) fn new(reader: EventReader<R>, position: &'p Cell<Option<TextPosition>>) -> Self {
+        Self {
+            reader,
+            position,
+            finished: false,
+        }
+    }
+}
+
+impl<R: std::io::Read> Iterator for PositionedEvents<'_, R> {
+    type Item = crate::parser::Result<Event<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let event = self.reader.next();
+        self.position.set(Some(self.reader.position()));
+        self.finished = matches!(event, Ok(xml::reader::XmlEvent::EndDocument) | Err(_));
+        Some(
+            event
+                .map(Event::from)
+                .map_err(crate::parser::Error::from)
+                .map_err(Box::new),
+        )
+    }
+}
+
+impl OpenDrive {
+    /// Parses the document in recovery mode: a `<road>` or `<junction>` that fails to parse is
+    /// skipped and recorded as [`Diagnostic`] with its id and position, so that a single corrupt
+    /// element does not make the whole document unusable. Errors outside of roads and junctions
+    /// still fail the parsing.
+    #[inline]
+    pub fn from_xml_str_recovering(s: &str) -> crate::parser::Result<Recovered> {
+        Self::from_reader_recovering(EventReader::new_with_config(
+            DecodingReader::utf8(s.trim().as_bytes()),
+            Self::parser_config(),
+        ))
+    }
+
+    /// Parses the document in recovery mode while reading it, see
+    /// [`OpenDrive::from_xml_str_recovering`] and [`OpenDrive::from_xml_read`]
+    #[inline]
+    pub fn from_xml_read_recovering<T: std::io::Read>(r: T) -> crate::parser::Result<Recovered> {
+        Self::from_reader_recovering(EventReader::new_with_config(
+            std::io::BufReader::with_capacity(Self::READ_BUFFER_SIZE, DecodingReader::new(r)),
+            Self::parser_config(),
+        ))
+    }
+
+    /// Parses the document in recovery mode, see [`OpenDrive::from_xml_str_recovering`]
+    pub fn from_reader_recovering<T: std::io::Read>(
+        reader: EventReader<T>,
+    ) -> crate::parser::Result<Recovered> {
+        let position = Cell::new(None);
+        let events = PositionedEvents::new(reader, &position);
+        Self::recover(events, &|| position.get(), None)
+    }
+
+    /// Parses the document in recovery mode from the events of any XML backend, see
+    /// [`crate::backend`]. The diagnostics carry no position, see
+    /// [`OpenDrive::from_xml_str_recovering`].
+    pub fn from_events_recovering(source: impl EventSource) -> crate::parser::Result<Recovered> {
+        Self::recover(source.into_events(), &|| None, None)
+    }
+
+    pub(crate) fn recover(
+        mut events: impl Iterator<Item = crate::parser::Result<Event<'static>>>,
+        position: &dyn Fn() -> Option<TextPosition>,
+        leniency: Option<&Leniency>,
+    ) -> crate::parser::Result<Recovered> {
+        let mut recovery = Recovery {
+            diagnostics: Vec::new(),
+            position,
+        };
+        let mut drive = None;
+
+        let mut read = ReadContext::from(&mut events);
+        if let Some(leniency) = leniency {
+            read = read.with_leniency(leniency);
+        }
+        read.children(|name, context| {
+            if name.eq_ignore_ascii_case("OpenDRIVE") {
+                drive = Some(OpenDrive::read(context, Some(&mut recovery))?);
+            }
+            Ok(())
+        })?;
+
+        match drive {
+            Some(drive) => Ok(Recovered {
+                drive,
+                diagnostics: recovery.diagnostics,
+            }),
+            None => Err(Box::new(crate::parser::Error::missing_element(
+                read.path().to_string(),
+                "OpenDRIVE",
+                core::any::type_name::<OpenDrive>(),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::{document, TestRoad};
+
+    #[test]
+    pub fn test_recovering() {
+        let road = |id: &str| TestRoad::new(id).length(10.0).sides(&[], &[]);
+        let xml = &document(format!(
+            r#"
+            {}
+            {}
+            <junction id="J">
+                <connection id="0" incomingRoad="1" connectingRoad="3"><laneLink from="-1"/></connection>
+            </junction>
+            {}"#,
+            road("1"),
+            road("2").lanes(
+                r#"<laneSection s="0"><center><lane id="zero" type="none"/></center></laneSection>"#
+            ),
+            road("3"),
+        ));
+        assert!(OpenDrive::from_xml_str(xml).is_err());
+
+        let recovered = OpenDrive::from_xml_str_recovering(xml).unwrap();
+        assert_eq!(
+            vec!["1", "3"],
+            recovered
+                .drive
+                .road
+                .iter()
+                .map(|r| r.id.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(recovered.drive.junction.is_empty());
+
+        let diagnostics = recovered.diagnostics;
+        assert_eq!(2, diagnostics.len());
+        assert_eq!("road", diagnostics[0].element);
+        assert_eq!(Some("2"), diagnostics[0].id.as_deref());
+        assert_eq!(1, diagnostics[0].index);
+        assert_eq!(Some(2), diagnostics[0].position.map(|p| p.row));
+        assert_eq!("junction", diagnostics[1].element);
+        assert_eq!(Some("J"), diagnostics[1].id.as_deref());
+        assert_eq!(0, diagnostics[1].index);
+        assert!(diagnostics[0]
+            .to_string()
+            .starts_with("skipped road[1] with id `2` at 3:"));
+    }
+}
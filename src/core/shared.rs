@@ -0,0 +1,302 @@
+use crate::core::OpenDrive;
+use crate::junction::Junction;
+use crate::query::object_index::{ObjectIndex, PlacedObject};
+use crate::road::geometry::point::Point;
+use crate::road::geometry::pose::Pose;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
+
+/// A read-only document for many threads, for example all vehicles of a simulation. Cloning is
+/// cheap and all clones share the document, its indices and its caches. The indices of roads and
+/// junctions by id are built by [`SharedMapBuilder::build`]; the sampled reference lines and the
+/// grids of [`SharedMap::roads_near`] and [`SharedMap::objects_within`] are caches that are
+/// filled on first use, by whichever thread asks first, unless the builder pre-warms them.
+/// Filling a cache blocks other threads asking for the same entry until it is filled, afterwards
+/// all reads are lock-free.
+///
+/// ```
+/// # use opendrive::core::OpenDrive;
+/// # use opendrive::core::shared::SharedMap;
+/// # let drive = OpenDrive::default();
+/// let map = SharedMap::builder(drive).prewarm(true).build();
+/// let threads = (0..4)
+///     .map(|_| {
+///         let map = map.clone();
+///         std::thread::spawn(move || map.drive().road.len())
+///     })
+///     .collect::<Vec<_>>();
+/// for thread in threads {
+///     assert_eq!(0, thread.join().unwrap());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SharedMap {
+    drive: Arc<OpenDrive>,
+    indices: Arc<Indices>,
+}
+
+#[derive(Debug)]
+struct Indices {
+    interval: f64,
+    cell_size: f64,
+    roads: HashMap<String, usize>,
+    junctions: HashMap<String, usize>,
+    /// The sampled reference line of each road, in the order of [`OpenDrive::road`]
+    reference_lines: Vec<OnceLock<Vec<Pose>>>,
+    /// The indices of the roads whose reference line samples fall into each grid cell
+    grid: OnceLock<HashMap<(i64, i64), Vec<usize>>>,
+    /// The objects in a grid of the same cell size
+    objects: OnceLock<ObjectIndex>,
+}
+
+/// Builds a [`SharedMap`], see [`SharedMap::builder`]
+#[derive(Debug)]
+pub struct SharedMapBuilder {
+    drive: Arc<OpenDrive>,
+    interval: Length,
+    cell_size: Length,
+    prewarm: bool,
+}
+
+impl SharedMapBuilder {
+    /// The distance between the samples of the reference lines, 1 m by default
+    pub fn sample_interval(mut self, interval: Length) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The edge length of the cells of the grids of [`SharedMap::roads_near`] and
+    /// [`SharedMap::objects_within`], 50 m by default
+    pub fn cell_size(mut self, size: Length) -> Self {
+        self.cell_size = size;
+        self
+    }
+
+    /// Fills all caches while building, so that no thread waits on first use
+    pub fn prewarm(mut self, prewarm: bool) -> Self {
+        self.prewarm = prewarm;
+        self
+    }
+
+    pub fn build(self) -> SharedMap {
+        let drive = self.drive;
+        let indices = Indices {
+            interval: self.interval.get::<meter>().max(1e-3),
+            cell_size: self.cell_size.get::<meter>().max(1e-3),
+            roads: drive
+                .road
+                .iter()
+                .enumerate()
+                .map(|(index, road)| (road.id.clone(), index))
+                .collect(),
+            junctions: drive
+                .junction
+                .iter()
+                .enumerate()
+                .map(|(index, junction)| (junction.id.clone(), index))
+                .collect(),
+            reference_lines: drive.road.iter().map(|_| OnceLock::new()).collect(),
+            grid: OnceLock::new(),
+            objects: OnceLock::new(),
+        };
+        let map = SharedMap {
+            drive,
+            indices: Arc::new(indices),
+        };
+        if self.prewarm {
+            for index in 0..map.drive.road.len() {
+                map.reference_line_at(index);
+            }
+            map.grid();
+            map.object_index();
+        }
+        map
+    }
+}
+
+impl SharedMap {
+    pub fn builder(drive: impl Into<Arc<OpenDrive>>) -> SharedMapBuilder {
+        SharedMapBuilder {
+            drive: drive.into(),
+            interval: Length::new::<meter>(1.0),
+            cell_size: Length::new::<meter>(50.0),
+            prewarm: false,
+        }
+    }
+
+    /// A map with the default settings of [`SharedMap::builder`] and cold caches
+    pub fn new(drive: impl Into<Arc<OpenDrive>>) -> Self {
+        Self::builder(drive).build()
+    }
+
+    pub fn drive(&self) -> &OpenDrive {
+        &self.drive
+    }
+
+    /// The shared document, to hand it to code that does not know this map
+    pub fn arc(&self) -> &Arc<OpenDrive> {
+        &self.drive
+    }
+
+    pub fn road(&self, id: &str) -> Option<&Road> {
+        let index = *self.indices.roads.get(id)?;
+        self.drive.road.get(index)
+    }
+
+    pub fn junction(&self, id: &str) -> Option<&Junction> {
+        let index = *self.indices.junctions.get(id)?;
+        self.drive.junction.get(index)
+    }
+
+    /// The poses of the reference line of the road every [`SharedMapBuilder::sample_interval`],
+    /// including both ends, `None` if there is no such road
+    pub fn reference_line(&self, id: &str) -> Option<&[Pose]> {
+        let index = *self.indices.roads.get(id)?;
+        Some(self.reference_line_at(index))
+    }
+
+    fn reference_line_at(&self, index: usize) -> &[Pose] {
+        self.indices.reference_lines[index].get_or_init(|| {
+            let road = &self.drive.road[index];
+            let length = road.length.get::<meter>().max(0.0);
+            let samples = (length / self.indices.interval).ceil().max(1.0) as usize;
+            (0..=samples)
+                .map(|i| {
+                    let s = (i as f64 * self.indices.interval).min(length);
+                    road.reference_pose(Length::new::<meter>(s))
+                })
+                .collect()
+        })
+    }
+
+    fn cell(&self, x: f64, y: f64) -> (i64, i64) {
+        let size = self.indices.cell_size;
+        ((x / size).floor() as i64, (y / size).floor() as i64)
+    }
+
+    fn grid(&self) -> &HashMap<(i64, i64), Vec<usize>> {
+        self.indices.grid.get_or_init(|| {
+            let mut grid = HashMap::<_, Vec<usize>>::new();
+            for index in 0..self.drive.road.len() {
+                let cells = self
+                    .reference_line_at(index)
+                    .iter()
+                    .map(|pose| self.cell(pose.x.get::<meter>(), pose.y.get::<meter>()))
+                    .collect::<HashSet<_>>();
+                for cell in cells {
+                    grid.entry(cell).or_default().push(index);
+                }
+            }
+            grid
+        })
+    }
+
+    /// The roads with a sample of their reference line within `radius` of the inertial
+    /// position, in the order of [`OpenDrive::road`]
+    pub fn roads_near(&self, x: Length, y: Length, radius: Length) -> Vec<&Road> {
+        let (x, y, radius) = (
+            x.get::<meter>(),
+            y.get::<meter>(),
+            radius.get::<meter>().max(0.0),
+        );
+        let (min, max) = (
+            self.cell(x - radius, y - radius),
+            self.cell(x + radius, y + radius),
+        );
+        let grid = self.grid();
+        let mut candidates = (min.0..=max.0)
+            .flat_map(|cx| (min.1..=max.1).map(move |cy| (cx, cy)))
+            .filter_map(|cell| grid.get(&cell))
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+            .into_iter()
+            .filter(|index| {
+                self.reference_line_at(*index).iter().any(|pose| {
+                    let (dx, dy) = (pose.x.get::<meter>() - x, pose.y.get::<meter>() - y);
+                    dx * dx + dy * dy <= radius * radius
+                })
+            })
+            .map(|index| &self.drive.road[index])
+            .collect()
+    }
+
+    fn object_index(&self) -> &ObjectIndex {
+        self.indices.objects.get_or_init(|| {
+            ObjectIndex::new(&self.drive, Length::new::<meter>(self.indices.cell_size))
+        })
+    }
+
+    /// The objects whose footprint is at most `radius` away from `center`, in the order of the
+    /// document, see [`ObjectIndex::within`]
+    pub fn objects_within(&self, center: Point, radius: Length) -> Vec<PlacedObject<'_>> {
+        self.object_index().within(&self.drive, center, radius)
+    }
+
+    /// The objects whose footprint overlaps the axis-aligned box from `min` to `max`, in the
+    /// order of the document, see [`ObjectIndex::in_aabb`]
+    pub fn objects_in_aabb(&self, min: Point, max: Point) -> Vec<PlacedObject<'_>> {
+        self.object_index().in_aabb(&self.drive, min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::shared::SharedMap;
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_shared_map() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedMap>();
+
+        let drive = parse(format!(
+            "{}{}",
+            TestRoad::new("1").sides(&[], &[]),
+            TestRoad::new("2")
+                .length(10.0)
+                .at(500.0, 500.0, 0.0)
+                .sides(&[], &[]),
+        ));
+
+        let map = SharedMap::builder(drive)
+            .sample_interval(Length::new::<meter>(10.0))
+            .build();
+        assert_eq!(11, map.reference_line("1").unwrap().len());
+        assert!(map.road("3").is_none());
+
+        let threads = (0..4)
+            .map(|i| {
+                let map = map.clone();
+                std::thread::spawn(move || {
+                    let near = map.roads_near(
+                        Length::new::<meter>(i as f64 * 30.0),
+                        Length::new::<meter>(3.0),
+                        Length::new::<meter>(5.0),
+                    );
+                    near.into_iter().map(|r| r.id.clone()).collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            assert_eq!(vec!["1".to_string()], thread.join().unwrap());
+        }
+        assert_eq!(
+            vec!["2"],
+            map.roads_near(
+                Length::new::<meter>(505.0),
+                Length::new::<meter>(500.0),
+                Length::new::<meter>(6.0)
+            )
+            .iter()
+            .map(|road| road.id.as_str())
+            .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::core::version::VersionRequirement;
+use crate::core::OpenDrive;
+use crate::object::orientation::ObjectType;
+use crate::units::prelude::*;
+use std::collections::BTreeMap;
+
+/// The number of values in consecutive ranges, see [`Statistics`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// The exclusive upper bounds of the ranges in ascending order
+    pub bounds: Vec<Length>,
+    /// The number of values in each range, with one more element than `bounds` for the values
+    /// at or above the last bound
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    /// The upper bounds of the length histograms of [`OpenDrive::statistics`] in meter
+    pub const LENGTH_BOUNDS: [f64; 6] = [10.0, 50.0, 100.0, 500.0, 1_000.0, 5_000.0];
+
+    /// The histogram of the given values with the given upper bounds
+    pub fn of(values: impl IntoIterator<Item = Length>, bounds: Vec<Length>) -> Self {
+        let mut counts = vec![0; bounds.len() + 1];
+        for value in values {
+            let index = bounds
+                .iter()
+                .position(|bound| value < *bound)
+                .unwrap_or(bounds.len());
+            counts[index] += 1;
+        }
+        Self { bounds, counts }
+    }
+
+    fn of_lengths(values: impl IntoIterator<Item = Length>) -> Self {
+        Self::of(
+            values,
+            Self::LENGTH_BOUNDS
+                .iter()
+                .map(|bound| Length::new::<meter>(*bound))
+                .collect(),
+        )
+    }
+}
+
+/// The totals of a document for delivery reports, see [`OpenDrive::statistics`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    pub roads: usize,
+    /// The roads that belong to a junction
+    pub connecting_roads: usize,
+    /// The summed length of the reference lines of all roads
+    pub road_length: Length,
+    /// The summed length of the lanes of each type, the lane-kilometers, keyed by the
+    /// `@type` of the lanes. A lane is as long as its lane section, the center lanes are not
+    /// counted.
+    pub lane_length: BTreeMap<String, Length>,
+    pub junctions: usize,
+    pub signals: usize,
+    /// The number of signals of each `@type`, like `"206"`
+    pub signals_by_type: BTreeMap<String, usize>,
+    pub objects: usize,
+    /// The number of objects of each `@type`, objects without type are only part of `objects`
+    pub objects_by_type: BTreeMap<&'static str, usize>,
+    /// The lengths of the roads in the ranges of [`Histogram::LENGTH_BOUNDS`]
+    pub road_lengths: Histogram,
+    /// The lengths of the lane sections in the ranges of [`Histogram::LENGTH_BOUNDS`]
+    pub lane_section_lengths: Histogram,
+    /// The features that need a revision newer than [`crate::core::version::MINIMUM_REVISION`],
+    /// see [`OpenDrive::version_requirements`]
+    pub version_requirements: Vec<VersionRequirement>,
+}
+
+impl OpenDrive {
+    /// The totals of this document: roads, lane-kilometers by lane type, junctions, signals and
+    /// objects by type, length histograms and the used features of newer revisions
+    pub fn statistics(&self) -> Statistics {
+        let mut lane_length = BTreeMap::new();
+        let mut signals_by_type = BTreeMap::new();
+        let mut objects_by_type = BTreeMap::new();
+        let mut section_lengths = Vec::new();
+        let (mut signals, mut objects) = (0, 0);
+
+        for road in &self.road {
+            for (section, start, end) in road.lanes.sections_with_ranges(road) {
+                let length = (end - start).max(Length::new::<meter>(0.0));
+                section_lengths.push(length);
+                for (_, lane) in section.lanes().filter(|(id, _)| *id != 0) {
+                    *lane_length
+                        .entry(lane.r#type.as_str().to_string())
+                        .or_insert(Length::new::<meter>(0.0)) += length;
+                }
+            }
+            for signal in road.signals.iter().flat_map(|s| &s.signal) {
+                signals += 1;
+                *signals_by_type.entry(signal.r#type.clone()).or_insert(0) += 1;
+            }
+            for object in road.objects.iter().flat_map(|o| &o.object) {
+                objects += 1;
+                if let Some(r#type) = &object.r#type {
+                    *objects_by_type
+                        .entry(ObjectType::as_str(r#type))
+                        .or_insert(0) += 1;
+                }
+            }
+        }
+
+        Statistics {
+            roads: self.road.len(),
+            connecting_roads: self.road.iter().filter(|r| r.junction != "-1").count(),
+            road_length: self
+                .road
+                .iter()
+                .fold(Length::new::<meter>(0.0), |sum, road| sum + road.length),
+            lane_length,
+            junctions: self.junction.len(),
+            signals,
+            signals_by_type,
+            objects,
+            objects_by_type,
+            road_lengths: Histogram::of_lengths(self.road.iter().map(|road| road.length)),
+            lane_section_lengths: Histogram::of_lengths(section_lengths),
+            version_requirements: self.version_requirements(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_statistics() {
+        let road = TestRoad::new("1")
+            .length(120.0)
+            .lanes(
+                r#"<laneSection s="0">
+                            <left><lane id="1" type="driving"><width sOffset="0" a="3" b="0" c="0" d="0"/></lane></left>
+                            <center><lane id="0" type="none"/></center>
+                            <right>
+                                <lane id="-1" type="driving"><width sOffset="0" a="3" b="0" c="0" d="0"/></lane>
+                                <lane id="-2" type="sidewalk"><width sOffset="0" a="2" b="0" c="0" d="0"/></lane>
+                            </right>
+                        </laneSection>
+                        <laneSection s="20">
+                            <center><lane id="0" type="none"/></center>
+                            <right><lane id="-1" type="driving"><width sOffset="0" a="3" b="0" c="0" d="0"/></lane></right>
+                        </laneSection>"#,
+            )
+            .objects(
+                r#"<object id="o1" s="5" t="5" zOffset="0" type="pole"/>
+                <object id="o2" s="6" t="5" zOffset="0"/>"#,
+            )
+            .signals(
+                r#"<signal id="s1" s="10" t="-5" zOffset="2" dynamic="no" orientation="+" type="206" subtype="-1" country="DE"/>"#,
+            );
+        let connecting = TestRoad::new("2")
+            .length(5.0)
+            .at(120.0, 0.0, 0.0)
+            .junction("j")
+            .sides(&[], &[]);
+        let drive = parse(format!(
+            r#"{road}{connecting}
+            <junction id="j"><connection id="0" incomingRoad="1" connectingRoad="2" contactPoint="start"/></junction>"#
+        ));
+
+        let statistics = drive.statistics();
+        assert_eq!(2, statistics.roads);
+        assert_eq!(1, statistics.connecting_roads);
+        assert_eq!(125.0, statistics.road_length.get::<meter>());
+        assert_eq!(
+            vec![("driving", 140.0), ("sidewalk", 20.0)],
+            statistics
+                .lane_length
+                .iter()
+                .map(|(t, l)| (t.as_str(), l.get::<meter>()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, statistics.junctions);
+        assert_eq!(Some(&1), statistics.signals_by_type.get("206"));
+        assert_eq!(2, statistics.objects);
+        assert_eq!(Some(&1), statistics.objects_by_type.get("pole"));
+        assert_eq!(vec![1, 0, 0, 1, 0, 0, 0], statistics.road_lengths.counts);
+        assert_eq!(
+            vec![1, 1, 0, 1, 0, 0, 0],
+            statistics.lane_section_lengths.counts
+        );
+        assert!(statistics.version_requirements.is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::core::xml_element::XmlElement;
+use std::borrow::Cow;
+
+/// Attributes and child elements that are not part of the model, such as vendor extensions or
+/// elements of later versions of ASAM OpenDRIVE. They are kept when parsing and written again
+/// after the known attributes and children, so that documents can be read, modified and written
+/// without losing data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Unknown {
+    /// Unknown attributes in document order
+    pub attributes: Vec<(String, String)>,
+    /// Unknown child elements in document order
+    pub elements: Vec<XmlElement>,
+}
+
+impl Unknown {
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.elements.is_empty()
+    }
+
+    /// Wraps the attribute visitor of the element this belongs to, so that the unknown attributes
+    /// are appended to the known attributes
+    pub fn append_attributes<'s, V>(
+        &'s self,
+        visitor: V,
+    ) -> impl for<'b> FnOnce(
+        Cow<'b, [xml::attribute::Attribute<'b>]>,
+        &[String],
+    ) -> xml::writer::Result<()>
+           + 's
+    where
+        V: for<'b> FnOnce(
+                Cow<'b, [xml::attribute::Attribute<'b>]>,
+                &[String],
+            ) -> xml::writer::Result<()>
+            + 's,
+    {
+        move |attributes: Cow<'_, [xml::attribute::Attribute<'_>]>, comments: &[String]| {
+            if self.attributes.is_empty() {
+                visitor(attributes, comments)
+            } else {
+                let mut attributes = attributes.into_owned();
+                attributes.extend(self.attributes.iter().map(|(name, value)| {
+                    xml::attribute::Attribute::new(xml::name::Name::local(name), value)
+                }));
+                visitor(Cow::Owned(attributes), comments)
+            }
+        }
+    }
+
+    pub fn append_children(
+        &self,
+        visitor: &mut impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+    ) -> xml::writer::Result<()> {
+        for element in &self.elements {
+            element.visit(visitor)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "fuzzing")]
+impl arbitrary::Arbitrary<'_> for Unknown {
+    fn arbitrary(_u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
+        // arbitrary names could collide with known attributes and elements, which would not
+        // survive a round trip
+        Ok(Self::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::{parse, TestRoad};
+
+    #[test]
+    pub fn test_unknown_round_trip() {
+        let road = TestRoad::new("1")
+            .attribute("vendorPriority", "high")
+            .lanes(
+                r#"<laneSection s="0">
+                    <center><lane id="0" type="none"/></center>
+                    <right><lane id="-1" type="driving">
+                        <width sOffset="0" a="3" b="0" c="0" d="0"/>
+                        <vendorSensor range="50"><note>lidar</note></vendorSensor>
+                    </lane></right>
+                </laneSection>"#,
+            );
+        let drive = parse(format!(r#"{road}<vendorExtension version="2"/>"#));
+
+        let road = &drive.road[0];
+        assert_eq!(
+            vec![("vendorPriority".to_string(), "high".to_string())],
+            road.additional_data.unknown.attributes
+        );
+        let lane = road.lanes.lane_section[0].lane(-1).unwrap();
+        let sensor = &lane.additional_data.unknown.elements[0];
+        assert_eq!("vendorSensor", sensor.name);
+        assert_eq!(Some("lidar"), sensor.children[0].cdata.as_deref());
+        assert_eq!(
+            "vendorExtension",
+            drive.additional_data.unknown.elements[0].name
+        );
+
+        let xml = drive.to_xml_string().unwrap();
+        assert!(xml.contains(r#"vendorPriority="high""#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&xml).unwrap());
+    }
+
+    #[test]
+    pub fn test_dropped_unknown_data() {
+        use crate::fixtures::document;
+        use crate::parser::{ParseOptions, Warning};
+
+        let road = TestRoad::new("1")
+            .attribute("vendorPriority", "high")
+            .geometry(
+                r#"<geometry s="0" x="0" y="0" hdg="0" length="100"><line><vendorCurve/></line></geometry>"#,
+            )
+            .lanes(
+                r#"<laneSection s="0">
+                    <center><lane id="0" type="none"/></center>
+                    <right><lane id="-1" type="driving">
+                        <width sOffset="0" a="3" b="0" c="0" d="0" vendorX="1"/>
+                    </lane></right>
+                </laneSection>"#,
+            );
+        let mut warnings = Vec::new();
+        OpenDrive::from_str_with(
+            &document(road),
+            ParseOptions {
+                warnings: Some(&mut warnings),
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                Warning::UnknownElement {
+                    path: ".OpenDRIVE.road.planView.geometry.line".to_string(),
+                    name: "vendorCurve".to_string(),
+                },
+                Warning::UnknownAttribute {
+                    path: ".OpenDRIVE.road.lanes.laneSection.right.lane.width".to_string(),
+                    name: "vendorX".to_string(),
+                    value: "1".to_string(),
+                },
+            ],
+            warnings
+        );
+    }
+}
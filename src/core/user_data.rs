@@ -21,10 +21,13 @@ impl UserData {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "code" => Some(self.code.as_str()),
             "value" => self.value.as_deref(),
         )
@@ -33,6 +36,7 @@ impl UserData {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        _options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         for element in &self.elements {
             element.visit(&mut visitor)?;
@@ -43,7 +47,7 @@ impl UserData {
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for UserData
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -98,7 +102,7 @@ impl Element {
 
 impl<'a, I> TryFrom<(String, crate::parser::ReadContext<'a, I>)> for Element
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -116,7 +120,7 @@ where
             name,
             attributes: read
                 .attributes()
-                .map(|a| (a.name.local_name.to_string(), a.value.clone()))
+                .map(|a| (a.name.local_name.to_string(), a.value.to_string()))
                 .collect(),
             children,
         })
@@ -0,0 +1,204 @@
+//! Wrappers that uphold invariants of the standard at construction time, so that invalid
+//! documents are harder to construct programmatically.
+
+use crate::lane::lane_section::LaneSection;
+use crate::lane::offset::Offset;
+use crate::object::Object;
+use crate::road::geometry::Geometry;
+use crate::road::profile::crossfall::Crossfall;
+use crate::road::profile::elevation::Elevation;
+use crate::road::profile::shape::Shape;
+use crate::road::profile::super_elevation::SuperElevation;
+use crate::road::road_type::RoadType;
+use crate::road::Road;
+use crate::signal::Signal;
+use crate::units::prelude::*;
+use std::ops::Deref;
+
+/// A value that is strictly greater than zero, such as the length of a road or geometry
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Positive<T>(T);
+
+impl<T: PartialOrd + Default> Positive<T> {
+    /// The given value, if it is greater than zero
+    pub fn new(value: T) -> Option<Self> {
+        (value > T::default()).then_some(Self(value))
+    }
+}
+
+impl<T> Positive<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Positive<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A position along the reference line of a road, which is never negative and, if created with
+/// [`SCoordinate::on`], does not exceed the length of the road
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SCoordinate(Length);
+
+impl SCoordinate {
+    /// The given s-coordinate, if it is not negative
+    pub fn new(s: Length) -> Option<Self> {
+        (s >= Length::new::<meter>(0.0)).then_some(Self(s))
+    }
+
+    /// The given s-coordinate, if it is within `[0, length]` of the given road
+    pub fn on(road: &Road, s: Length) -> Option<Self> {
+        Self::new(s).filter(|_| s <= road.length)
+    }
+
+    pub fn get(&self) -> Length {
+        self.0
+    }
+}
+
+impl From<SCoordinate> for Length {
+    fn from(s: SCoordinate) -> Self {
+        s.0
+    }
+}
+
+/// Elements that start at an s-coordinate along the reference line of a road or, for elements
+/// within lane sections and objects, relative to their parent
+pub trait StartS {
+    fn start_s(&self) -> Length;
+}
+
+macro_rules! impl_start_s {
+    ($($ty:ty => |$this:ident| $s:expr),* $(,)?) => {
+        $(
+            impl StartS for $ty {
+                fn start_s(&self) -> Length {
+                    let $this = self;
+                    $s
+                }
+            }
+        )*
+    };
+}
+
+impl_start_s!(
+    Geometry => |g| g.s,
+    LaneSection => |l| Length::new::<meter>(l.s),
+    Offset => |o| Length::new::<meter>(o.s),
+    Elevation => |e| Length::new::<meter>(e.s),
+    SuperElevation => |e| Length::new::<meter>(e.s),
+    Crossfall => |c| Length::new::<meter>(c.s),
+    Shape => |s| Length::new::<meter>(s.s),
+    RoadType => |t| t.s,
+    Object => |o| o.s,
+    Signal => |s| s.s,
+);
+
+/// Elements ordered by their ascending start s-coordinate, as the standard requires for the
+/// elements of the plan view, lanes and profiles. Elements with equal s-coordinates keep their
+/// insertion order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortedByS<T>(Vec<T>);
+
+impl<T> Default for SortedByS<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T: StartS> SortedByS<T> {
+    /// The given elements, sorted by their start s-coordinate
+    pub fn new(mut elements: Vec<T>) -> Self {
+        elements.sort_by(|a, b| compare_s(a.start_s(), b.start_s()));
+        Self(elements)
+    }
+
+    /// The given elements, if they are already sorted by their start s-coordinate
+    pub fn try_from_sorted(elements: Vec<T>) -> Option<Self> {
+        elements
+            .windows(2)
+            .all(|w| compare_s(w[0].start_s(), w[1].start_s()).is_le())
+            .then_some(Self(elements))
+    }
+
+    /// Inserts the element after all elements that start before or at the same s-coordinate
+    pub fn insert(&mut self, element: T) {
+        let s = element.start_s();
+        let index = self
+            .0
+            .partition_point(|e| compare_s(e.start_s(), s).is_le());
+        self.0.insert(index, element);
+    }
+
+    /// The last element that starts before or at `s`
+    pub fn at(&self, s: Length) -> Option<&T> {
+        let index = self
+            .0
+            .partition_point(|e| compare_s(e.start_s(), s).is_le());
+        index.checked_sub(1).map(|i| &self.0[i])
+    }
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+/// Orders s-coordinates totally, so that a `NaN` read from a document sorts after all others
+/// instead of breaking the order. Adding zero makes `-0` equal to `0`.
+fn compare_s(a: Length, b: Length) -> std::cmp::Ordering {
+    (a.get::<meter>() + 0.0).total_cmp(&(b.get::<meter>() + 0.0))
+}
+
+impl<T> Deref for SortedByS<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: StartS> FromIterator<T> for SortedByS<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::validated::{Positive, SCoordinate, SortedByS};
+    use crate::lane::lane_section::LaneSection;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_validated() {
+        assert!(Positive::new(Length::new::<meter>(0.0)).is_none());
+        assert_eq!(
+            Some(1.5),
+            Positive::new(Length::new::<meter>(1.5)).map(|l| l.get::<meter>())
+        );
+        assert!(SCoordinate::new(Length::new::<meter>(-0.1)).is_none());
+
+        let section = |s: f64| LaneSection {
+            s,
+            ..LaneSection::default()
+        };
+        let mut sections = SortedByS::new(vec![section(20.0), section(0.0)]);
+        sections.insert(section(10.0));
+        assert_eq!(
+            vec![0.0, 10.0, 20.0],
+            sections.iter().map(|s| s.s).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(10.0),
+            sections.at(Length::new::<meter>(15.0)).map(|s| s.s)
+        );
+        assert!(sections.at(Length::new::<meter>(-1.0)).is_none());
+        assert!(sections.at(Length::new::<meter>(-0.0)).is_some());
+        assert!(SortedByS::try_from_sorted(vec![section(1.0), section(0.0)]).is_none());
+    }
+}
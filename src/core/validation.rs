@@ -0,0 +1,446 @@
+use crate::backend::{Attribute, Event};
+use crate::core::OpenDrive;
+use crate::units::prelude::*;
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use xml::common::TextPosition;
+
+/// A violation of a [`ValidationRule`], found while parsing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Name of the violated rule
+    pub rule: &'static str,
+    /// Path of the violating element, like `OpenDRIVE.road.planView.geometry`
+    pub path: String,
+    /// Position of the start tag of the violating element, if the document is parsed from text
+    pub position: Option<TextPosition>,
+    /// Description of the violation
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at `{}`", self.rule, self.path)?;
+        if let Some(position) = &self.position {
+            write!(f, " ({position})")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// The start tag of an element as checked by a [`ValidationRule`]
+#[derive(Debug, Clone, Copy)]
+pub struct Element<'e> {
+    /// Local names of the element and its ancestors, starting with the root element
+    pub path: &'e [String],
+    pub attributes: &'e [Attribute<'static>],
+}
+
+impl Element<'_> {
+    pub fn name(&self) -> &str {
+        self.path.last().map(String::as_str).unwrap_or_default()
+    }
+
+    pub fn parent(&self) -> Option<&str> {
+        self.path
+            .len()
+            .checked_sub(2)
+            .map(|index| self.path[index].as_str())
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.name.local_name.eq_ignore_ascii_case(name))
+            .map(|a| a.value.as_ref())
+    }
+
+    /// The value of the attribute of the given name, if it is a number
+    pub fn number(&self, name: &str) -> Option<f64> {
+        self.attribute(name).and_then(|v| v.trim().parse().ok())
+    }
+}
+
+/// A rule that is checked against every element while parsing, so that violations are reported
+/// with their exact location without a second pass over the parsed model
+pub trait ValidationRule {
+    fn name(&self) -> &'static str;
+
+    /// Checks the start tag of an element, returning the message of a violation
+    fn start(&mut self, element: &Element<'_>) -> Option<String>;
+
+    /// Called at the end of every element, see [`Element::path`]
+    fn end(&mut self, _path: &[String]) {}
+
+    /// Checks the parsed document, for rules that depend on more than single elements, returning
+    /// the path and the message of a violation
+    fn document(&mut self, _drive: &OpenDrive) -> Option<(String, String)> {
+        None
+    }
+}
+
+/// Elements that the standard requires in ascending order of the given attribute within their
+/// parent element
+const ORDERED_BY: &[(&str, &str)] = &[
+    ("geometry", "s"),
+    ("laneSection", "s"),
+    ("laneOffset", "s"),
+    ("elevation", "s"),
+    ("superelevation", "s"),
+    ("shape", "s"),
+    ("type", "s"),
+    ("width", "sOffset"),
+    ("border", "sOffset"),
+    ("roadMark", "sOffset"),
+    ("material", "sOffset"),
+    ("speed", "sOffset"),
+    ("access", "sOffset"),
+    ("height", "sOffset"),
+];
+
+/// Checks that elements such as `<geometry>` or `<laneSection>` are ordered by ascending `@s`
+/// or `@sOffset` within their parent
+#[derive(Debug, Default)]
+pub struct AscendingS {
+    /// For each open element, the last value of each ordered child element
+    last: Vec<Vec<(String, f64)>>,
+}
+
+impl ValidationRule for AscendingS {
+    fn name(&self) -> &'static str {
+        "ascending-s"
+    }
+
+    fn start(&mut self, element: &Element<'_>) -> Option<String> {
+        let depth = element.path.len();
+        self.last.resize_with(depth, Vec::new);
+        let attribute = ORDERED_BY
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(element.name()))
+            .map(|(_, attribute)| *attribute)?;
+        let value = element.number(attribute)?;
+        let siblings = &mut self.last[depth.checked_sub(2)?];
+        match siblings.iter_mut().find(|(name, _)| name == element.name()) {
+            Some((_, last)) => {
+                let previous = std::mem::replace(last, value);
+                (value < previous).then(|| {
+                    format!(
+                        "`{attribute}` of {value} is less than {previous} of the previous `{}`",
+                        element.name()
+                    )
+                })
+            }
+            None => {
+                siblings.push((element.name().to_string(), value));
+                None
+            }
+        }
+    }
+
+    fn end(&mut self, path: &[String]) {
+        self.last.truncate(path.len());
+    }
+}
+
+/// Checks that no `@length` is negative
+#[derive(Debug, Default)]
+pub struct NonNegativeLength;
+
+impl ValidationRule for NonNegativeLength {
+    fn name(&self) -> &'static str {
+        "non-negative-length"
+    }
+
+    fn start(&mut self, element: &Element<'_>) -> Option<String> {
+        let length = element.number("length")?;
+        (length < 0.0).then(|| format!("`length` of {length} is negative"))
+    }
+}
+
+/// Checks that `@north`, `@south`, `@east` and `@west` of the `<header>` agree with the
+/// [`OpenDrive::extent`] of the content within the tolerance. Headers without extent are not
+/// checked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeaderExtent {
+    pub tolerance: Length,
+}
+
+impl Default for HeaderExtent {
+    fn default() -> Self {
+        Self {
+            tolerance: Length::new::<meter>(1.0),
+        }
+    }
+}
+
+impl ValidationRule for HeaderExtent {
+    fn name(&self) -> &'static str {
+        "header-extent"
+    }
+
+    fn start(&mut self, _element: &Element<'_>) -> Option<String> {
+        None
+    }
+
+    fn document(&mut self, drive: &OpenDrive) -> Option<(String, String)> {
+        let declared = drive.header.extent()?;
+        let actual = drive.extent()?;
+        let deviation = declared.deviation(&actual);
+        (deviation > self.tolerance).then(|| {
+            (
+                "OpenDRIVE.header".to_string(),
+                format!(
+                    "the extent deviates by {}m from the extent of the content",
+                    deviation.get::<meter>()
+                ),
+            )
+        })
+    }
+}
+
+/// Checks [`ValidationRule`]s while parsing, see [`crate::parser::ParseOptions::validator`]
+#[derive(Default)]
+pub struct Validator {
+    rules: Vec<Box<dyn ValidationRule>>,
+    abort: bool,
+    violations: Vec<Violation>,
+}
+
+impl std::fmt::Debug for Validator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Validator")
+            .field(
+                "rules",
+                &self.rules.iter().map(|r| r.name()).collect::<Vec<_>>(),
+            )
+            .field("abort", &self.abort)
+            .field("violations", &self.violations)
+            .finish()
+    }
+}
+
+impl Validator {
+    /// A validator with the [`AscendingS`] and [`NonNegativeLength`] rules
+    pub fn new() -> Self {
+        Self::default()
+            .with_rule(AscendingS::default())
+            .with_rule(NonNegativeLength)
+    }
+
+    pub fn with_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Fails the parsing on the first violation instead of reading the rest of the document
+    pub fn abort_on_violation(mut self, abort: bool) -> Self {
+        self.abort = abort;
+        self
+    }
+
+    /// The violations found so far
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    pub fn into_violations(self) -> Vec<Violation> {
+        self.violations
+    }
+
+    /// The given events, checked while they are read. The position of each event is taken from
+    /// `position` if given.
+    pub fn validate<'v, I>(
+        &'v mut self,
+        events: I,
+        position: Option<&'v Cell<Option<TextPosition>>>,
+    ) -> ValidatingEvents<'v, I> {
+        ValidatingEvents {
+            events,
+            validator: Some(self),
+            position,
+            path: Vec::new(),
+            aborted: None,
+        }
+    }
+}
+
+/// Events that are checked by a [`Validator`] while they are read, see [`Validator::validate`]
+pub struct ValidatingEvents<'v, I> {
+    events: I,
+    validator: Option<&'v mut Validator>,
+    position: Option<&'v Cell<Option<TextPosition>>>,
+    path: Vec<String>,
+    /// The violation that aborted the parsing
+    aborted: Option<Violation>,
+}
+
+impl<'v, I> ValidatingEvents<'v, I> {
+    /// The given events, checked by the given validator, if any
+    pub(crate) fn new(
+        events: I,
+        validator: Option<&'v mut Validator>,
+        position: &'v Cell<Option<TextPosition>>,
+    ) -> Self {
+        Self {
+            events,
+            validator,
+            position: Some(position),
+            path: Vec::new(),
+            aborted: None,
+        }
+    }
+}
+
+impl<I> ValidatingEvents<'_, I> {
+    /// The violation after which no more events are returned, if the [`Validator`] aborts on
+    /// violations, see [`Validator::abort_on_violation`]
+    pub fn aborted(&self) -> Option<&Violation> {
+        self.aborted.as_ref()
+    }
+
+    /// Checks [`ValidationRule::document`] of all rules against the parsed document
+    pub(crate) fn finish(self, drive: &OpenDrive) -> crate::parser::Result<()> {
+        if let Some(violation) = self.aborted {
+            return Err(Box::new(crate::parser::Error::Violation(violation)));
+        }
+        let Some(validator) = self.validator else {
+            return Ok(());
+        };
+        for rule in &mut validator.rules {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!("rule", name = rule.name()).entered();
+            if let Some((path, message)) = rule.document(drive) {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(rule = rule.name(), path, message, "violation");
+                validator.violations.push(Violation {
+                    rule: rule.name(),
+                    path,
+                    position: None,
+                    message,
+                });
+                if validator.abort {
+                    let violation = validator.violations[0].clone();
+                    return Err(Box::new(crate::parser::Error::Violation(violation)));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I> Iterator for ValidatingEvents<'_, I>
+where
+    I: Iterator<Item = crate::parser::Result<Event<'static>>>,
+{
+    type Item = crate::parser::Result<Event<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.aborted.is_some() {
+            return None;
+        }
+        let event = self.events.next();
+        let validator = match self.validator.as_deref_mut() {
+            Some(validator) => validator,
+            None => return event,
+        };
+        match &event {
+            Some(Ok(Event::StartElement {
+                name, attributes, ..
+            })) => {
+                self.path.push(name.local_name.to_string());
+                let element = Element {
+                    path: &self.path,
+                    attributes,
+                };
+                let position = self.position.and_then(Cell::get);
+                for rule in &mut validator.rules {
+                    if let Some(message) = rule.start(&element) {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(rule = rule.name(), ?position, message, "violation");
+                        validator.violations.push(Violation {
+                            rule: rule.name(),
+                            path: self.path.join("."),
+                            position,
+                            message,
+                        });
+                        if validator.abort {
+                            break;
+                        }
+                    }
+                }
+                if validator.abort {
+                    if let Some(violation) = validator.violations.first() {
+                        self.aborted = Some(violation.clone());
+                        return None;
+                    }
+                }
+            }
+            Some(Ok(Event::EndElement)) => {
+                for rule in &mut validator.rules {
+                    rule.end(&self.path[..self.path.len().saturating_sub(1)]);
+                }
+                self.path.pop();
+            }
+            _ => {}
+        }
+        event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::validation::{Validator, Violation};
+    use crate::core::OpenDrive;
+    use crate::fixtures::{document, TestRoad};
+    use crate::parser::ParseOptions;
+
+    #[test]
+    pub fn test_validation() {
+        let xml = &document(
+            TestRoad::new("1")
+                .geometry(
+                    r#"
+                    <geometry s="50" x="0" y="0" hdg="0" length="50"><line/></geometry>
+                    <geometry s="0" x="0" y="0" hdg="0" length="-50"><line/></geometry>"#,
+                )
+                .lanes(
+                    r#"<laneSection s="0"><center><lane id="0" type="none"/></center></laneSection>
+                    <laneSection s="20"><center><lane id="0" type="none"/></center></laneSection>"#,
+                ),
+        );
+
+        let mut validator = Validator::new();
+        OpenDrive::from_str_with(
+            xml,
+            ParseOptions {
+                validator: Some(&mut validator),
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
+        let violations = validator.into_violations();
+        assert_eq!(
+            vec!["ascending-s", "non-negative-length"],
+            violations.iter().map(|v| v.rule).collect::<Vec<_>>()
+        );
+        assert_eq!("OpenDRIVE.road.planView.geometry", violations[0].path);
+        assert_eq!(Some(2), violations[0].position.map(|p| p.row));
+
+        let mut validator = Validator::new().abort_on_violation(true);
+        let result = OpenDrive::from_str_with(
+            xml,
+            ParseOptions {
+                validator: Some(&mut validator),
+                ..ParseOptions::default()
+            },
+        );
+        assert!(matches!(
+            result.map_err(|e| *e),
+            Err(crate::parser::Error::Violation(Violation {
+                rule: "ascending-s",
+                ..
+            }))
+        ));
+        assert_eq!(1, validator.violations().len());
+    }
+}
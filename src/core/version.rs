@@ -0,0 +1,115 @@
+use crate::core::OpenDrive;
+
+/// A feature used by a document together with the revision of ASAM OpenDRIVE that introduced it,
+/// see [`OpenDrive::version_requirements`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VersionRequirement {
+    pub feature: &'static str,
+    pub rev_major: u16,
+    pub rev_minor: u16,
+}
+
+/// The oldest revision this crate reads
+pub const MINIMUM_REVISION: (u16, u16) = (1, 4);
+
+impl OpenDrive {
+    /// The features of this document that were introduced after [`MINIMUM_REVISION`], each
+    /// listed once, see [`OpenDrive::feature_usage`]
+    pub fn version_requirements(&self) -> Vec<VersionRequirement> {
+        self.feature_usage()
+            .into_iter()
+            .map(|usage| usage.feature)
+            .filter(|feature| feature.revision() > MINIMUM_REVISION)
+            .map(|feature| {
+                let (rev_major, rev_minor) = feature.revision();
+                VersionRequirement {
+                    feature: feature.name(),
+                    rev_major,
+                    rev_minor,
+                }
+            })
+            .collect()
+    }
+
+    /// The oldest revision of ASAM OpenDRIVE that supports all features of this document, see
+    /// [`OpenDrive::version_requirements`]
+    pub fn required_revision(&self) -> (u16, u16) {
+        self.version_requirements()
+            .into_iter()
+            .map(|r| (r.rev_major, r.rev_minor))
+            .fold(MINIMUM_REVISION, Ord::max)
+    }
+
+    /// The features of this document that are newer than the revision declared in its header
+    pub fn version_conflicts(&self) -> Vec<VersionRequirement> {
+        let declared = (self.header.rev_major, self.header.rev_minor);
+        self.version_requirements()
+            .into_iter()
+            .filter(|r| (r.rev_major, r.rev_minor) > declared)
+            .collect()
+    }
+
+    /// Prepares the header for writing this document: the declared revision is raised to
+    /// [`OpenDrive::required_revision`] if required, the date is set to the current time in UTC
+    /// and the vendor is set if given
+    pub fn update_header(&mut self, vendor: Option<&str>) {
+        let declared = (self.header.rev_major, self.header.rev_minor);
+        let (rev_major, rev_minor) = declared.max(self.required_revision());
+        self.header.rev_major = rev_major;
+        self.header.rev_minor = rev_minor;
+        self.header.date = Some(iso_8601_now());
+        if let Some(vendor) = vendor {
+            self.header.vendor = Some(vendor.to_string());
+        }
+    }
+}
+
+/// The current time in UTC like `2021-03-31T12:00:00`
+pub(crate) fn iso_8601_now() -> String {
+    chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::TestRoad;
+    use crate::writer::layout::Layout;
+    use crate::writer::WriteOptions;
+
+    #[test]
+    pub fn test_version_requirements() {
+        let road = TestRoad::new("1").sides(&[], &[]).objects(
+            r#"<object id="o" s="0" t="0" zOffset="0">
+                <outlines><outline><cornerLocal u="0" v="0" z="0" height="1"/></outline></outlines>
+            </object>"#,
+        );
+        let mut drive = OpenDrive::from_xml_str(&format!(
+            r#"<OpenDRIVE><header revMajor="1" revMinor="4"/>{road}</OpenDRIVE>"#
+        ))
+        .unwrap();
+
+        assert_eq!((1, 5), drive.required_revision());
+        assert_eq!(1, drive.version_conflicts().len());
+
+        drive.update_header(Some("opendrive-rs"));
+        assert!(drive.version_conflicts().is_empty());
+        assert_eq!(5, drive.header.rev_minor);
+        assert_eq!(Some("opendrive-rs"), drive.header.vendor.as_deref());
+        let date = drive.header.date.clone().unwrap();
+        assert_eq!(19, date.len());
+        assert_eq!(Some('T'), date.chars().nth(10));
+
+        for layout in [None, Some(Layout::default())] {
+            let xml = drive
+                .to_xml_string_with(&WriteOptions {
+                    schema_location: Some("opendrive_15_core.xsd"),
+                    layout,
+                    ..WriteOptions::default()
+                })
+                .unwrap();
+            assert!(xml.contains(r#"xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance""#));
+            assert!(xml.contains(r#"xsi:noNamespaceSchemaLocation="opendrive_15_core.xsd""#));
+            assert_eq!(drive, OpenDrive::from_xml_str(&xml).unwrap());
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::core::OpenDrive;
+use std::collections::BTreeMap;
+
+/// A generic XML element that mirrors the element and attribute structure of an ASAM OpenDRIVE
+/// document, see [`OpenDrive::to_xml_element`]. Attribute values are kept in their lexical form,
+/// so quantities are given in the units of the XML schema (meter, radian, ...). With the `serde`
+/// feature, this is also the representation [`OpenDrive`] is serialized to and deserialized from,
+/// which keeps JSON or YAML dumps stable, readable and re-loadable. Only whole documents are
+/// serializable, the element types like [`crate::road::Road`] do not implement the serde traits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XmlElement {
+    /// Local name of the element
+    pub name: String,
+    /// Attributes of the element, ordered by name
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub attributes: BTreeMap<String, String>,
+    /// Character data of the element, such as the PROJ string of `<geoReference>`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub cdata: Option<String>,
+    /// Child elements in document order
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    pub fn visit(
+        &self,
+        visitor: &mut impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+    ) -> xml::writer::Result<()> {
+        visitor(xml::writer::XmlEvent::StartElement {
+            name: xml::name::Name::local(&self.name),
+            attributes: std::borrow::Cow::Owned(
+                self.attributes
+                    .iter()
+                    .map(|(name, value)| {
+                        xml::attribute::Attribute::new(xml::name::Name::local(name), value)
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            namespace: std::borrow::Cow::Owned(xml::namespace::Namespace::empty()),
+        })?;
+        if let Some(cdata) = &self.cdata {
+            visitor(xml::writer::XmlEvent::CData(cdata))?;
+        }
+        for child in &self.children {
+            child.visit(visitor)?;
+        }
+        visitor(xml::writer::XmlEvent::EndElement { name: None })
+    }
+
+    /// The XML events of this element and its children, as emitted by a reader
+    fn reader_events(&self, events: &mut Vec<xml::reader::XmlEvent>) {
+        events.push(xml::reader::XmlEvent::StartElement {
+            name: xml::name::OwnedName::local(self.name.as_str()),
+            attributes: self
+                .attributes
+                .iter()
+                .map(|(name, value)| xml::attribute::OwnedAttribute {
+                    name: xml::name::OwnedName::local(name.as_str()),
+                    value: value.clone(),
+                })
+                .collect(),
+            namespace: xml::namespace::Namespace::empty(),
+        });
+        if let Some(cdata) = &self.cdata {
+            events.push(xml::reader::XmlEvent::CData(cdata.clone()));
+        }
+        for child in &self.children {
+            child.reader_events(events);
+        }
+        events.push(xml::reader::XmlEvent::EndElement {
+            name: xml::name::OwnedName::local(self.name.as_str()),
+        });
+    }
+}
+
+impl OpenDrive {
+    /// The element tree of this document, as it would be written by [`OpenDrive::to_writer`].
+    /// Comments are not part of the element tree.
+    pub fn to_xml_element(&self) -> xml::writer::Result<XmlElement> {
+        let mut stack = vec![XmlElement::default()];
+        let visitor = |event: xml::writer::XmlEvent| {
+            match event {
+                xml::writer::XmlEvent::StartElement {
+                    name, attributes, ..
+                } => stack.push(XmlElement {
+                    name: name.local_name.to_string(),
+                    attributes: attributes
+                        .iter()
+                        .map(|a| (a.name.local_name.to_string(), a.value.to_string()))
+                        .collect(),
+                    cdata: None,
+                    children: Vec::new(),
+                }),
+                xml::writer::XmlEvent::EndElement { .. } => {
+                    if let Some(element) = stack.pop() {
+                        match stack.last_mut() {
+                            Some(parent) => parent.children.push(element),
+                            None => stack.push(element),
+                        }
+                    }
+                }
+                xml::writer::XmlEvent::CData(data) | xml::writer::XmlEvent::Characters(data) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.cdata.get_or_insert_with(String::new).push_str(data);
+                    }
+                }
+                _ => {}
+            }
+            Ok(())
+        };
+
+        self.visit(visitor)?;
+
+        Ok(stack
+            .pop()
+            .and_then(|root| root.children.into_iter().next())
+            .unwrap_or_default())
+    }
+
+    /// Parses a document from its element tree, see [`OpenDrive::to_xml_element`]
+    pub fn from_xml_element(element: &XmlElement) -> crate::parser::Result<Self> {
+        let mut events = vec![xml::reader::XmlEvent::StartDocument {
+            version: xml::common::XmlVersion::Version10,
+            encoding: "UTF-8".to_string(),
+            standalone: Some(true),
+        }];
+        element.reader_events(&mut events);
+        events.push(xml::reader::XmlEvent::EndDocument);
+        Self::from_events(events.into_iter().map(Ok))
+    }
+}
+
+impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for XmlElement
+where
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
+{
+    type Error = Box<crate::parser::Error>;
+
+    fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
+        let mut children = Vec::new();
+        let mut cdata = None::<String>;
+
+        read.children_or_cdata(
+            |_name, context| {
+                children.push(XmlElement::try_from(context)?);
+                Ok(())
+            },
+            |data| {
+                cdata.get_or_insert_with(String::new).push_str(&data);
+                Ok(())
+            },
+        )?;
+
+        Ok(Self {
+            name: read.element_name().to_string(),
+            attributes: read
+                .attributes()
+                .map(|a| (a.name.local_name.to_string(), a.value.to_string()))
+                .collect(),
+            cdata,
+            children,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for OpenDrive {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_xml_element()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OpenDrive {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let element = XmlElement::deserialize(deserializer)?;
+        Self::from_xml_element(&element).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::TestRoad;
+
+    #[test]
+    pub fn test_xml_element() {
+        let road = TestRoad::new("1").sides(&[], &[("driving", 3.0)]);
+        let drive = OpenDrive::from_xml_str(&format!(
+            r#"<OpenDRIVE>
+                <header revMajor="1" revMinor="7" name="Test">
+                    <geoReference><![CDATA[+proj=utm +zone=32 +datum=WGS84]]></geoReference>
+                </header>
+                {road}
+            </OpenDRIVE>"#
+        ))
+        .unwrap();
+
+        let element = drive.to_xml_element().unwrap();
+        assert_eq!("OpenDRIVE", element.name);
+        let header = &element.children[0];
+        assert_eq!("header", header.name);
+        assert_eq!(
+            Some("Test"),
+            header.attributes.get("name").map(String::as_str)
+        );
+        assert_eq!(
+            Some("+proj=utm +zone=32 +datum=WGS84"),
+            header.children[0].cdata.as_deref()
+        );
+        let road = &element.children[1];
+        assert_eq!(
+            Some("-1"),
+            road.attributes.get("junction").map(String::as_str)
+        );
+
+        assert_eq!(drive, OpenDrive::from_xml_element(&element).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    pub fn test_serde_round_trip() {
+        let drive = crate::fixtures::parse(TestRoad::new("1").attribute("name", "Main"));
+
+        let json = serde_json::to_value(&drive).unwrap();
+        assert_eq!("OpenDRIVE", json["name"]);
+        let road = &json["children"][1];
+        assert_eq!("road", road["name"]);
+        // attributes keep their lexical form in the units of the schema
+        assert_eq!("Main", road["attributes"]["name"]);
+        assert!(road["attributes"]["length"].is_string());
+
+        let parsed: OpenDrive = serde_json::from_value(json).unwrap();
+        assert_eq!(drive, parsed);
+        assert!(serde_json::from_str::<OpenDrive>(r#"{"name":"road"}"#).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use crate::core::OpenDrive;
+use xml::attribute::OwnedAttribute;
+use xml::name::OwnedName;
+use xml::reader::XmlEvent;
+
+/// The categories of metadata that [`OpenDrive::anonymized`] removes. By default, all categories
+/// are removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Anonymization {
+    /// `@name` of the header, roads, junctions, signals, objects, controllers and all other
+    /// elements. Required names, like the ones of `<switch>` and `<station>`, are emptied. The
+    /// names of road mark types are kept, as they describe the road mark.
+    pub names: bool,
+    /// `<userData>` elements, which often carry tool specific data
+    pub user_data: bool,
+    /// `@vendor` of the header
+    pub vendor: bool,
+    /// `@date` of the header and of `<rawData>`, which is emptied as it is required
+    pub dates: bool,
+    /// `<dataQuality>` elements, which describe the sources and the processing of the data
+    pub data_quality: bool,
+    /// XML comments
+    pub comments: bool,
+}
+
+impl Default for Anonymization {
+    fn default() -> Self {
+        Self {
+            names: true,
+            user_data: true,
+            vendor: true,
+            dates: true,
+            data_quality: true,
+            comments: true,
+        }
+    }
+}
+
+impl Anonymization {
+    /// Whether the element and all its children are removed
+    fn removes(&self, element: &str) -> bool {
+        (self.user_data && element == "userData") || (self.data_quality && element == "dataQuality")
+    }
+
+    /// Removes the attributes of the element or empties them, if they are required, where `parent`
+    /// is the name of the parent element
+    fn strip(&self, element: &str, parent: Option<&str>, attributes: &mut Vec<OwnedAttribute>) {
+        let required = matches!(element, "switch" | "station" | "rawData");
+        attributes.retain_mut(|attribute| {
+            let strip = match attribute.name.local_name.as_str() {
+                "name" => self.names && !(element == "type" && parent == Some("roadMark")),
+                "vendor" => self.vendor && element == "header",
+                "date" => self.dates && matches!(element, "header" | "rawData"),
+                _ => false,
+            };
+            if strip && required {
+                attribute.value.clear();
+            }
+            !strip || required
+        });
+    }
+}
+
+impl OpenDrive {
+    /// A copy of this document without the given categories of metadata, so that maps can be
+    /// shared externally without leaking internal tool information
+    pub fn anonymized(&self, anonymization: &Anonymization) -> crate::parser::Result<OpenDrive> {
+        let mut events = vec![XmlEvent::StartDocument {
+            version: xml::common::XmlVersion::Version10,
+            encoding: "UTF-8".to_string(),
+            standalone: Some(true),
+        }];
+        let mut path = Vec::<String>::new();
+        let mut removed = None::<usize>;
+        self.visit(|event| {
+            match event {
+                xml::writer::XmlEvent::StartElement {
+                    name, attributes, ..
+                } => {
+                    let element = name.local_name.to_string();
+                    if removed.is_none() && anonymization.removes(&element) {
+                        removed = Some(path.len());
+                    }
+                    if removed.is_none() {
+                        let mut attributes =
+                            attributes.iter().map(|a| a.to_owned()).collect::<Vec<_>>();
+                        anonymization.strip(
+                            &element,
+                            path.last().map(String::as_str),
+                            &mut attributes,
+                        );
+                        events.push(XmlEvent::StartElement {
+                            name: OwnedName::local(element.as_str()),
+                            attributes,
+                            namespace: xml::namespace::Namespace::empty(),
+                        });
+                    }
+                    path.push(element);
+                }
+                xml::writer::XmlEvent::EndElement { .. } => {
+                    let element = path.pop().unwrap_or_default();
+                    if removed.is_none() {
+                        events.push(XmlEvent::EndElement {
+                            name: OwnedName::local(element),
+                        });
+                    }
+                    if removed == Some(path.len()) {
+                        removed = None;
+                    }
+                }
+                xml::writer::XmlEvent::Comment(text)
+                    if removed.is_none() && !anonymization.comments =>
+                {
+                    events.push(XmlEvent::Comment(text.to_string()));
+                }
+                xml::writer::XmlEvent::CData(data) if removed.is_none() => {
+                    events.push(XmlEvent::CData(data.to_string()));
+                }
+                xml::writer::XmlEvent::Characters(data) if removed.is_none() => {
+                    events.push(XmlEvent::Characters(data.to_string()));
+                }
+                _ => {}
+            }
+            Ok(())
+        })
+        .map_err(|e| Box::new(crate::parser::Error::from(e)))?;
+        events.push(XmlEvent::EndDocument);
+        Self::from_events(events.into_iter().map(Ok))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::edit::anonymize::Anonymization;
+    use crate::fixtures::TestRoad;
+
+    #[test]
+    pub fn test_anonymized() {
+        let road = TestRoad::new("1")
+            .length(10.0)
+            .attribute("name", "Secret St")
+            .lanes(
+                r#"<laneSection s="0"><center><lane id="0" type="none">
+                    <roadMark sOffset="0" type="solid" color="standard"><type name="custom" width="0.1"><line length="1" space="1" tOffset="0" sOffset="0"/></type></roadMark>
+                </lane></center></laneSection>"#,
+            );
+        let drive = OpenDrive::from_xml_str(&format!(
+            r#"<OpenDRIVE>
+                <header revMajor="1" revMinor="7" name="internal" vendor="ACME" date="2024-01-01">
+                    <userData code="tool" value="v1.2"/>
+                </header>
+                <!-- exported by the ACME tool -->
+                {road}
+            </OpenDRIVE>"#
+        ))
+        .unwrap();
+
+        let anonymized = drive.anonymized(&Anonymization::default()).unwrap();
+        assert_eq!(None, anonymized.header.name);
+        assert_eq!(None, anonymized.header.vendor);
+        assert_eq!(None, anonymized.header.date);
+        assert!(anonymized.header.additional_data.user_data.is_empty());
+        assert_eq!(None, anonymized.road[0].name);
+        let xml = anonymized.to_xml_string().unwrap();
+        assert!(!xml.contains("ACME"));
+        assert!(xml.contains("custom"));
+
+        let anonymized = drive
+            .anonymized(&Anonymization {
+                names: false,
+                comments: false,
+                ..Anonymization::default()
+            })
+            .unwrap();
+        assert_eq!(Some("Secret St"), anonymized.road[0].name.as_deref());
+        assert_eq!(None, anonymized.header.vendor);
+        assert!(anonymized.to_xml_string().unwrap().contains("ACME tool"));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::units::prelude::*;
+use std::io::{Read, Seek};
+use tiff::decoder::{Decoder, DecodingResult};
+use tiff::tags::Tag;
+use tiff::ColorType;
+
+/// Why a GeoTIFF could not be read as [`Dem`]
+#[derive(Debug, thiserror::Error)]
+pub enum DemError {
+    #[error("Failed to read the TIFF: {0}")]
+    Tiff(#[from] tiff::TiffError),
+    #[error("Failed to read the file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The `ModelPixelScale` or `ModelTiepoint` tag is missing, models given by a
+    /// `ModelTransformation` are not supported
+    #[error("The TIFF is not georeferenced by a pixel scale and a tie point")]
+    MissingGeoreference,
+    #[error("The TIFF has {0:?} pixels instead of a single band of heights")]
+    UnsupportedColorType(ColorType),
+}
+
+/// A raster digital elevation model, the terrain of [`crate::core::OpenDrive::drape`]. Its
+/// coordinates must be those of the inertial x/y-plane of the document, that is, the projected
+/// coordinates of the `<geoReference>` minus any `<offset>` of the header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dem {
+    /// The x-coordinate of the left edge of the raster
+    pub west: f64,
+    /// The y-coordinate of the top edge of the raster
+    pub north: f64,
+    /// The width of a cell along the x-axis
+    pub cell_width: f64,
+    /// The height of a cell along the y-axis, rows go from north to south
+    pub cell_height: f64,
+    /// The number of cells of a row
+    pub columns: usize,
+    /// The heights of the cells row by row
+    pub heights: Vec<f64>,
+    /// The height of cells without data
+    pub no_data: Option<f64>,
+}
+
+impl Dem {
+    /// Reads the first image of a single-band GeoTIFF, georeferenced by its `ModelPixelScale`
+    /// and `ModelTiepoint` tags, with the no-data value of the `GDAL_NODATA` tag
+    pub fn from_geotiff(reader: impl Read + Seek) -> Result<Self, DemError> {
+        let mut decoder = Decoder::new(reader)?;
+        let color_type = decoder.colortype()?;
+        if !matches!(color_type, ColorType::Gray(_)) {
+            return Err(DemError::UnsupportedColorType(color_type));
+        }
+        let (columns, _) = decoder.dimensions()?;
+        let scale = decoder.find_tag(Tag::ModelPixelScaleTag)?;
+        let tie_point = decoder.find_tag(Tag::ModelTiepointTag)?;
+        let (scale, tie_point) = match (scale, tie_point) {
+            (Some(scale), Some(tie_point)) => (scale.into_f64_vec()?, tie_point.into_f64_vec()?),
+            _ => return Err(DemError::MissingGeoreference),
+        };
+        let ([cell_width, cell_height, ..], [i, j, _, x, y, ..]) = (&scale[..], &tie_point[..])
+        else {
+            return Err(DemError::MissingGeoreference);
+        };
+        let no_data = decoder
+            .find_tag(Tag::GdalNodata)?
+            .map(|value| value.into_string())
+            .transpose()?
+            .and_then(|value| value.trim_matches(char::from(0)).trim().parse().ok());
+
+        let heights = match decoder.read_image()? {
+            DecodingResult::U8(v) => v.into_iter().map(f64::from).collect(),
+            DecodingResult::U16(v) => v.into_iter().map(f64::from).collect(),
+            DecodingResult::U32(v) => v.into_iter().map(f64::from).collect(),
+            DecodingResult::U64(v) => v.into_iter().map(|h| h as f64).collect(),
+            DecodingResult::I8(v) => v.into_iter().map(f64::from).collect(),
+            DecodingResult::I16(v) => v.into_iter().map(f64::from).collect(),
+            DecodingResult::I32(v) => v.into_iter().map(f64::from).collect(),
+            DecodingResult::I64(v) => v.into_iter().map(|h| h as f64).collect(),
+            DecodingResult::F32(v) => v.into_iter().map(f64::from).collect(),
+            DecodingResult::F64(v) => v,
+        };
+        Ok(Self {
+            west: x - i * cell_width,
+            north: y + j * cell_height,
+            cell_width: *cell_width,
+            cell_height: *cell_height,
+            columns: columns as usize,
+            heights,
+            no_data,
+        })
+    }
+
+    /// Reads the GeoTIFF at the given path, see [`Dem::from_geotiff`]
+    #[cfg(feature = "fs")]
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Result<Self, DemError> {
+        Self::from_geotiff(std::io::BufReader::new(std::fs::File::open(path)?))
+    }
+
+    /// The height of the terrain at the position, interpolated bilinearly between the centers
+    /// of the surrounding cells. `None` outside of the raster or next to cells without data.
+    pub fn height(&self, x: Length, y: Length) -> Option<Length> {
+        if self.columns == 0 || self.cell_width <= 0.0 || self.cell_height <= 0.0 {
+            return None;
+        }
+        let rows = self.heights.len() / self.columns;
+        let column = (x.get::<meter>() - self.west) / self.cell_width - 0.5;
+        let row = (self.north - y.get::<meter>()) / self.cell_height - 0.5;
+        let clamp = |value: f64, count: usize| value.clamp(0.0, (count - 1) as f64);
+        if !(-0.5..=self.columns as f64 - 0.5).contains(&column)
+            || !(-0.5..=rows as f64 - 0.5).contains(&row)
+        {
+            return None;
+        }
+        let (column, row) = (clamp(column, self.columns), clamp(row, rows));
+        let (left, top) = (column.floor() as usize, row.floor() as usize);
+        let (right, bottom) = ((left + 1).min(self.columns - 1), (top + 1).min(rows - 1));
+        let cell = |column: usize, row: usize| {
+            Some(self.heights[row * self.columns + column]).filter(|h| Some(*h) != self.no_data)
+        };
+        let (fx, fy) = (column - left as f64, row - top as f64);
+        let upper = cell(left, top)? * (1.0 - fx) + cell(right, top)? * fx;
+        let lower = cell(left, bottom)? * (1.0 - fx) + cell(right, bottom)? * fx;
+        Some(Length::new::<meter>(upper * (1.0 - fy) + lower * fy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::dem::Dem;
+    use crate::units::prelude::*;
+    use std::io::Cursor;
+    use tiff::encoder::{colortype, TiffEncoder};
+    use tiff::tags::Tag;
+
+    #[test]
+    pub fn test_dem_from_geotiff() {
+        let mut tiff = Cursor::new(Vec::new());
+        let mut encoder = TiffEncoder::new(&mut tiff).unwrap();
+        let mut image = encoder.new_image::<colortype::Gray32Float>(3, 2).unwrap();
+        let directory = image.encoder();
+        directory
+            .write_tag(Tag::ModelPixelScaleTag, &[10.0, 10.0, 0.0][..])
+            .unwrap();
+        directory
+            .write_tag(
+                Tag::ModelTiepointTag,
+                &[0.0, 0.0, 0.0, 100.0, 220.0, 0.0][..],
+            )
+            .unwrap();
+        directory.write_tag(Tag::GdalNodata, "-9999").unwrap();
+        image
+            .write_data(&[1.0, 2.0, 3.0, 3.0, 4.0, -9999.0])
+            .unwrap();
+        tiff.set_position(0);
+
+        let dem = Dem::from_geotiff(tiff).unwrap();
+        assert_eq!((100.0, 220.0, 3), (dem.west, dem.north, dem.columns));
+        let height = |x: f64, y: f64| {
+            dem.height(Length::new::<meter>(x), Length::new::<meter>(y))
+                .map(|h| h.get::<meter>())
+        };
+        assert_eq!(Some(1.0), height(105.0, 215.0));
+        assert_eq!(Some(2.5), height(110.0, 210.0));
+        assert_eq!(Some(1.0), height(101.0, 219.0));
+        assert_eq!(None, height(125.0, 205.0));
+        assert_eq!(None, height(99.0, 215.0));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::core::OpenDrive;
+use crate::road::profile::ElevationProfile;
+use crate::units::prelude::*;
+
+/// The settings of [`OpenDrive::drape`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrapeOptions {
+    /// The distance between the samples along the reference lines, 5 m by default
+    pub interval: Length,
+    /// The largest deviation of the fitted profiles from the sampled heights, 5 cm by default,
+    /// see [`ElevationProfile::fit`]
+    pub tolerance: Length,
+}
+
+impl Default for DrapeOptions {
+    fn default() -> Self {
+        Self {
+            interval: Length::new::<meter>(5.0),
+            tolerance: Length::new::<meter>(0.05),
+        }
+    }
+}
+
+impl OpenDrive {
+    /// Gives the roads of a planar map the elevation of a terrain. The `height` of the terrain
+    /// at a position of the inertial x/y-plane is sampled along the reference line of each road,
+    /// including both ends, and the samples are fitted into a new elevation profile that
+    /// replaces the existing one. `height` returns `None` outside of the terrain; those samples
+    /// are skipped and roads without any height are kept unchanged. The terrain is either a
+    /// user callback or, with the `geotiff` feature, a raster like
+    /// `|x, y| dem.height(x, y)` of a [`crate::edit::dem::Dem`]. Returns the ids of the draped
+    /// roads.
+    pub fn drape(
+        &mut self,
+        mut height: impl FnMut(Length, Length) -> Option<Length>,
+        options: DrapeOptions,
+    ) -> Vec<String> {
+        let interval = options.interval.get::<meter>().max(1e-3);
+        let mut draped = Vec::new();
+        for road in &mut self.road {
+            let length = road.length.get::<meter>().max(0.0);
+            let count = (length / interval).ceil().max(1.0) as usize;
+            let samples = (0..=count)
+                .filter_map(|i| {
+                    let s = (i as f64 * interval).min(length);
+                    let pose = road.reference_pose(Length::new::<meter>(s));
+                    height(pose.x, pose.y).map(|z| (s, z.get::<meter>()))
+                })
+                .collect::<Vec<_>>();
+            if samples.is_empty() {
+                continue;
+            }
+            let additional_data = road
+                .elevation_profile
+                .take()
+                .map(|profile| profile.additional_data)
+                .unwrap_or_default();
+            road.elevation_profile = Some(ElevationProfile {
+                additional_data,
+                ..ElevationProfile::fit(samples, options.tolerance.get::<meter>())
+            });
+            draped.push(road.id.clone());
+        }
+        draped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::drape::DrapeOptions;
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_drape() {
+        let mut drive = parse(format!(
+            "{}{}",
+            TestRoad::new("1").sides(&[], &[]),
+            TestRoad::new("2")
+                .length(10.0)
+                .at(500.0, 0.0, 0.0)
+                .sides(&[], &[]),
+        ));
+
+        let terrain = |x: Length, _y: Length| {
+            let x = x.get::<meter>();
+            (x <= 200.0).then(|| Length::new::<meter>(10.0 + 0.05 * x))
+        };
+        assert_eq!(
+            vec!["1".to_string()],
+            drive.drape(terrain, DrapeOptions::default())
+        );
+        let profile = drive.road[0].elevation_profile.as_ref().unwrap();
+        assert_eq!(1, profile.elevation.len());
+        assert!((12.5 - profile.elevation_at(50.0)).abs() < 1e-9);
+        assert!(drive.road[1].elevation_profile.is_none());
+    }
+}
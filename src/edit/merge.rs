@@ -0,0 +1,218 @@
+use crate::core::ids::{IdKind, IdUse};
+use crate::core::OpenDrive;
+use crate::road::geometry::pose::Pose;
+use crate::signal::position::Position;
+use crate::units::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// A rotation by `hdg` around the origin of the inertial x/y-plane followed by a translation by
+/// `x` and `y`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RigidTransform {
+    pub x: Length,
+    pub y: Length,
+    pub hdg: Angle,
+}
+
+impl RigidTransform {
+    /// The given pose moved by this transform
+    pub fn apply(&self, pose: Pose) -> Pose {
+        let rotation = Pose {
+            x: self.x,
+            y: self.y,
+            hdg: self.hdg,
+        };
+        let point = rotation.transform(pose.x, pose.y);
+        Pose {
+            x: point.x,
+            y: point.y,
+            hdg: pose.hdg + self.hdg,
+        }
+    }
+}
+
+/// Options of [`OpenDrive::merge`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MergeOptions {
+    /// Prefix of the colliding ids of the merged document. Without prefix, colliding numeric ids
+    /// are replaced by the next unused number and other colliding ids get an `_1`, `_2`, ...
+    /// suffix.
+    pub prefix: Option<String>,
+    /// Transform applied to the merged document before it is merged, to place a map tile
+    pub transform: Option<RigidTransform>,
+}
+
+impl OpenDrive {
+    /// Appends the roads, junctions, controllers, junction groups and stations of `other` to this
+    /// document. Ids of `other` that collide with ids in this document are renamed as given by
+    /// `options`, together with all references to them, so both documents stay consistent. The
+    /// header of this document is kept. Returns the renamed ids of `other` by kind and original
+    /// id.
+    pub fn merge(
+        &mut self,
+        mut other: OpenDrive,
+        options: &MergeOptions,
+    ) -> BTreeMap<(IdKind, String), String> {
+        let mut taken = HashMap::<IdKind, HashSet<String>>::new();
+        let mut collect = |kind, usage, id: &mut String| {
+            if usage == IdUse::Definition {
+                taken.entry(kind).or_default().insert(id.clone());
+            }
+        };
+        self.visit_ids(&mut collect);
+        let mut colliding = Vec::new();
+        other.visit_ids(&mut |kind, usage, id| {
+            if usage == IdUse::Definition {
+                if taken
+                    .get(&kind)
+                    .is_some_and(|ids| ids.contains(id.as_str()))
+                {
+                    colliding.push((kind, id.clone()));
+                } else {
+                    taken.entry(kind).or_default().insert(id.clone());
+                }
+            }
+        });
+
+        let mut renamed = BTreeMap::new();
+        for (kind, id) in colliding {
+            let ids = taken.entry(kind).or_default();
+            let new_id = match &options.prefix {
+                Some(prefix) => unused(ids, format!("{prefix}{id}")),
+                None if id.parse::<u64>().is_ok() => {
+                    let next = ids.iter().filter_map(|id| id.parse::<u64>().ok()).max();
+                    next.map_or(0, |n| n + 1).to_string()
+                }
+                None => unused(ids, id.clone()),
+            };
+            ids.insert(new_id.clone());
+            renamed.insert((kind, id), new_id);
+        }
+        other.rename_ids(|kind, id| renamed.get(&(kind, id.to_string())).cloned());
+
+        if let Some(transform) = &options.transform {
+            other.transform(transform);
+        }
+
+        self.road.append(&mut other.road);
+        self.controller.append(&mut other.controller);
+        self.junction.append(&mut other.junction);
+        self.junction_group.append(&mut other.junction_group);
+        self.station.append(&mut other.station);
+        renamed
+    }
+
+    /// Moves all inertial positions of this document by the given transform
+    pub fn transform(&mut self, transform: &RigidTransform) {
+        for road in &mut self.road {
+            for geometry in road.plan_view.geometry.iter_mut() {
+                let pose = transform.apply(Pose {
+                    x: geometry.x,
+                    y: geometry.y,
+                    hdg: geometry.hdg,
+                });
+                geometry.x = pose.x;
+                geometry.y = pose.y;
+                geometry.hdg = pose.hdg;
+            }
+            for signal in road.signals.iter_mut().flat_map(|s| &mut s.signal) {
+                if let Some(Position::Inertial(position)) = &mut signal.choice {
+                    let pose = transform.apply(Pose {
+                        x: position.x,
+                        y: position.y,
+                        hdg: position.hdg,
+                    });
+                    position.x = pose.x;
+                    position.y = pose.y;
+                    position.hdg = pose.hdg;
+                }
+            }
+        }
+    }
+}
+
+/// The given id, or the id with the first suffix `_1`, `_2`, ... that is not taken yet
+fn unused(taken: &HashSet<String>, id: String) -> String {
+    if !taken.contains(&id) {
+        return id;
+    }
+    (1..)
+        .map(|n| format!("{id}_{n}"))
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap_or(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::ids::IdKind;
+    use crate::core::OpenDrive;
+    use crate::edit::merge::{MergeOptions, RigidTransform};
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_merge() {
+        let tile = |x: f64| {
+            parse(format!(
+                r#"{}{}<controller id="c"><control signalId="7"/></controller>"#,
+                TestRoad::new("1")
+                    .length(10.0)
+                    .at(x, 0.0, 0.0)
+                    .link(r#"<successor elementType="road" elementId="A" contactPoint="start"/>"#)
+                    .sides(&[], &[])
+                    .signals(
+                        r#"<signal s="0" t="0" id="7" dynamic="yes" orientation="+" zOffset="0" type="1000001" subtype="-1"/>"#
+                    ),
+                TestRoad::new("A")
+                    .length(10.0)
+                    .at(x + 10.0, 0.0, 0.0)
+                    .link(r#"<predecessor elementType="road" elementId="1" contactPoint="end"/>"#)
+                    .sides(&[], &[]),
+            ))
+        };
+
+        let mut drive = tile(0.0);
+        let renamed = drive.merge(
+            tile(0.0),
+            &MergeOptions {
+                prefix: None,
+                transform: Some(RigidTransform {
+                    x: Length::new::<meter>(0.0),
+                    y: Length::new::<meter>(5.0),
+                    hdg: Angle::new::<radian>(std::f64::consts::FRAC_PI_2),
+                }),
+            },
+        );
+        assert_eq!(
+            Some("2"),
+            renamed
+                .get(&(IdKind::Road, "1".to_string()))
+                .map(String::as_str)
+        );
+        assert_eq!(
+            vec!["1", "A", "2", "A_1"],
+            drive.road.iter().map(|r| r.id.as_str()).collect::<Vec<_>>()
+        );
+        let merged = &drive.road[2];
+        let successor = merged.link.as_ref().unwrap().successor.as_ref().unwrap();
+        assert_eq!("A_1", successor.element_id);
+        assert_eq!("8", merged.signals.as_ref().unwrap().signal[0].id);
+        assert_eq!("c_1", drive.controller[1].id);
+        assert_eq!("8", drive.controller[1].control.first().signal_id);
+
+        let geometry = drive.road[3].plan_view.geometry.first();
+        assert!(geometry.x.get::<meter>().abs() < 1e-9);
+        assert!((geometry.y.get::<meter>() - 15.0).abs() < 1e-9);
+
+        let mut drive = tile(0.0);
+        drive.merge(
+            tile(20.0),
+            &MergeOptions {
+                prefix: Some("east.".to_string()),
+                transform: None,
+            },
+        );
+        assert_eq!("east.A", drive.road[3].id);
+        assert!(OpenDrive::from_xml_str(&drive.to_xml_string().unwrap()).is_ok());
+    }
+}
@@ -0,0 +1,10 @@
+pub mod anonymize;
+#[cfg(feature = "geotiff")]
+pub mod dem;
+pub mod drape;
+pub mod merge;
+pub mod normalize;
+pub mod resize;
+pub mod session;
+pub mod snap;
+pub mod subnetwork;
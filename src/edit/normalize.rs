@@ -0,0 +1,371 @@
+use crate::core::additional_data::AdditionalData;
+use crate::core::validated::StartS;
+use crate::core::xml_element::XmlElement;
+use crate::core::OpenDrive;
+use crate::junction::contact_point::ContactPoint;
+use crate::lane::lane_choice::LaneChoice;
+use crate::road::element_type::ElementType;
+use crate::road::predecessor_successor::PredecessorSuccessor;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use vec1::Vec1;
+
+/// The new ids of the lanes whose id is changed, by road id, lane section index and old lane id
+type Renumbering = HashMap<(String, usize), HashMap<i64, i64>>;
+
+impl OpenDrive {
+    /// Brings the document into a canonical form, so that semantically equal documents compare
+    /// equal and have the same [`OpenDrive::content_hash`]:
+    ///  - elements indexed by s-coordinates, like geometries, lane sections, widths or signals,
+    ///    are sorted by it, keeping the order of elements with the same s-coordinate
+    ///  - exact duplicates of such elements are removed
+    ///  - empty optional containers, like `<objects/>` or `<link/>`, are removed
+    ///  - lanes are sorted by descending id and renumbered to `n..1` on the left and `-1..-n` on
+    ///    the right, lane links of lanes and junctions are renumbered accordingly
+    pub fn normalize(&mut self) {
+        for road in &mut self.road {
+            sort_and_dedup(&mut road.plan_view.geometry);
+            sort_and_dedup(&mut road.r#type);
+            sort_and_dedup(&mut road.lanes.lane_offset);
+            sort_and_dedup(&mut road.lanes.lane_section);
+            if let Some(profile) = &mut road.elevation_profile {
+                sort_and_dedup(&mut profile.elevation);
+            }
+            if let Some(profile) = &mut road.lateral_profile {
+                sort_and_dedup(&mut profile.super_elevation);
+                sort_and_dedup(&mut profile.crossfall);
+                sort_and_dedup(&mut profile.shape);
+            }
+            if let Some(objects) = &mut road.objects {
+                sort_and_dedup(&mut objects.object);
+            }
+            if let Some(signals) = &mut road.signals {
+                sort_and_dedup(&mut signals.signal);
+            }
+            for section in road.lanes.lane_section.iter_mut() {
+                let lanes = section
+                    .left
+                    .iter_mut()
+                    .flat_map(|l| l.lane.iter_mut().map(|l| &mut l.base))
+                    .chain(section.center.lane.iter_mut().map(|l| &mut l.base))
+                    .chain(
+                        section
+                            .right
+                            .iter_mut()
+                            .flat_map(|r| r.lane.iter_mut().map(|l| &mut l.base)),
+                    );
+                for lane in lanes {
+                    sort_and_dedup_by(&mut lane.choice, |choice| match choice {
+                        LaneChoice::Border(b) => b.s_offset,
+                        LaneChoice::Width(w) => w.s_offset,
+                    });
+                    sort_and_dedup_by(&mut lane.road_mark, |m| m.s_offset);
+                    sort_and_dedup_by(&mut lane.material, |m| m.s_offset);
+                    sort_and_dedup_by(&mut lane.speed, |s| s.s_offset);
+                    sort_and_dedup_by(&mut lane.access, |a| a.s_offset);
+                    sort_and_dedup_by(&mut lane.height, |h| h.s_offset);
+                    sort_and_dedup_by(&mut lane.rule, |r| r.s_offset);
+                    if lane.link.as_ref().is_some_and(|l| {
+                        l.predecessor.is_empty()
+                            && l.successor.is_empty()
+                            && l.additional_data == AdditionalData::default()
+                    }) {
+                        lane.link = None;
+                    }
+                }
+            }
+            drop_empty_containers(road);
+        }
+
+        self.renumber_lanes();
+    }
+
+    /// A hash of the written form of the document, which is equal for documents that are equal
+    /// after [`OpenDrive::normalize`]. Numbers are hashed by value, so that `-0` and `0` hash
+    /// equal as they compare equal. The hash is only stable within the same build.
+    pub fn content_hash(&self) -> xml::writer::Result<u64> {
+        let mut element = self.to_xml_element()?;
+        normalize_numbers(&mut element);
+        let mut hasher = DefaultHasher::new();
+        element.hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
+    fn renumber_lanes(&mut self) {
+        let mut renumbering = Renumbering::new();
+        for road in &mut self.road {
+            for (index, section) in road.lanes.lane_section.iter_mut().enumerate() {
+                let mut ids = HashMap::new();
+                if let Some(left) = &mut section.left {
+                    left.lane.sort_by_key(|lane| std::cmp::Reverse(lane.id));
+                    let count = left.lane.len() as i64;
+                    for (position, lane) in left.lane.iter_mut().enumerate() {
+                        let id = count - position as i64;
+                        if lane.id != id {
+                            ids.insert(lane.id, id);
+                            lane.id = id;
+                        }
+                    }
+                }
+                if let Some(right) = &mut section.right {
+                    right.lane.sort_by_key(|lane| std::cmp::Reverse(lane.id));
+                    for (position, lane) in right.lane.iter_mut().enumerate() {
+                        let id = -(position as i64 + 1);
+                        if lane.id != id {
+                            ids.insert(lane.id, id);
+                            lane.id = id;
+                        }
+                    }
+                }
+                if !ids.is_empty() {
+                    renumbering.insert((road.id.clone(), index), ids);
+                }
+            }
+        }
+        if renumbering.is_empty() {
+            return;
+        }
+
+        let sections = self
+            .road
+            .iter()
+            .map(|road| (road.id.clone(), road.lanes.lane_section.len()))
+            .collect::<HashMap<_, _>>();
+        let section_at = |road: &str, contact: Option<&ContactPoint>| {
+            let index = match contact {
+                Some(ContactPoint::End) => sections.get(road)?.checked_sub(1)?,
+                _ => 0,
+            };
+            Some((road.to_string(), index))
+        };
+        let renumber = |target: &Option<(String, usize)>, id: &mut i64| {
+            if let Some(new_id) = target
+                .as_ref()
+                .and_then(|target| renumbering.get(target))
+                .and_then(|ids| ids.get(id))
+            {
+                *id = *new_id;
+            }
+        };
+
+        for road in &mut self.road {
+            let linked = |link: Option<&PredecessorSuccessor>| {
+                link.filter(|l| l.element_type != Some(ElementType::Junction))
+                    .and_then(|l| section_at(&l.element_id, l.contact_point.as_ref()))
+            };
+            let predecessor = linked(road.link.as_ref().and_then(|l| l.predecessor.as_ref()));
+            let successor = linked(road.link.as_ref().and_then(|l| l.successor.as_ref()));
+            let count = road.lanes.lane_section.len();
+            for (index, section) in road.lanes.lane_section.iter_mut().enumerate() {
+                let before = match index {
+                    0 => predecessor.clone(),
+                    _ => Some((road.id.clone(), index - 1)),
+                };
+                let after = match index + 1 == count {
+                    true => successor.clone(),
+                    false => Some((road.id.clone(), index + 1)),
+                };
+                let lanes = section
+                    .left
+                    .iter_mut()
+                    .flat_map(|l| l.lane.iter_mut().map(|l| &mut l.base))
+                    .chain(
+                        section
+                            .right
+                            .iter_mut()
+                            .flat_map(|r| r.lane.iter_mut().map(|l| &mut l.base)),
+                    );
+                for link in lanes.filter_map(|lane| lane.link.as_mut()) {
+                    for predecessor in &mut link.predecessor {
+                        renumber(&before, &mut predecessor.id);
+                    }
+                    for successor in &mut link.successor {
+                        renumber(&after, &mut successor.id);
+                    }
+                }
+            }
+        }
+
+        let incoming_ends = self
+            .road
+            .iter()
+            .map(|road| {
+                let successor = road.link.as_ref().and_then(|l| l.successor.as_ref());
+                (road.id.clone(), successor)
+            })
+            .map(|(id, successor)| {
+                let junction = successor
+                    .filter(|s| s.element_type == Some(ElementType::Junction))
+                    .map(|s| s.element_id.clone());
+                (id, junction)
+            })
+            .collect::<HashMap<_, _>>();
+        for junction in &mut self.junction {
+            for connection in junction.connection.iter_mut() {
+                let incoming = connection.incoming_road.as_deref().and_then(|road| {
+                    let ends = incoming_ends.get(road)?.as_ref() == Some(&junction.id);
+                    section_at(road, ends.then_some(&ContactPoint::End))
+                });
+                let connecting = connection
+                    .connecting_road
+                    .as_deref()
+                    .and_then(|road| section_at(road, connection.contact_point.as_ref()));
+                for lane_link in &mut connection.lane_link {
+                    renumber(&incoming, &mut lane_link.from);
+                    renumber(&connecting, &mut lane_link.to);
+                }
+            }
+        }
+    }
+}
+
+/// Removes the sign of written zeros, so that `-0` and `0` are written equally
+fn normalize_numbers(element: &mut XmlElement) {
+    for value in element.attributes.values_mut() {
+        if value.starts_with('-') && value.parse::<f64>().is_ok_and(|v| v == 0.0) {
+            value.remove(0);
+        }
+    }
+    for child in &mut element.children {
+        normalize_numbers(child);
+    }
+}
+
+/// Elements that can be sorted and shortened, but may not become empty
+trait Records<T>: std::ops::DerefMut<Target = [T]> {
+    fn truncate_to(&mut self, len: usize);
+}
+
+impl<T> Records<T> for Vec<T> {
+    fn truncate_to(&mut self, len: usize) {
+        self.truncate(len);
+    }
+}
+
+impl<T> Records<T> for Vec1<T> {
+    fn truncate_to(&mut self, len: usize) {
+        let _ = self.truncate(len.max(1));
+    }
+}
+
+fn sort_and_dedup<T: StartS + PartialEq>(elements: &mut impl Records<T>) {
+    sort_and_dedup_by(elements, |e| e.start_s())
+}
+
+/// Sorts the elements by the given s-coordinate, keeping the order of elements with the same
+/// s-coordinate, and removes exact duplicates
+fn sort_and_dedup_by<T: PartialEq>(elements: &mut impl Records<T>, s: impl Fn(&T) -> Length) {
+    elements.sort_by(|a, b| s(a).get::<meter>().total_cmp(&s(b).get::<meter>()));
+    let mut unique = 0;
+    for index in 0..elements.len() {
+        if !elements[..unique].contains(&elements[index]) {
+            elements.swap(unique, index);
+            unique += 1;
+        }
+    }
+    elements.truncate_to(unique);
+}
+
+fn drop_empty_containers(road: &mut Road) {
+    let empty = AdditionalData::default();
+    if road.link.as_ref().is_some_and(|l| {
+        l.predecessor.is_none() && l.successor.is_none() && l.additional_data == empty
+    }) {
+        road.link = None;
+    }
+    if road
+        .elevation_profile
+        .as_ref()
+        .is_some_and(|p| *p == Default::default())
+    {
+        road.elevation_profile = None;
+    }
+    if road
+        .lateral_profile
+        .as_ref()
+        .is_some_and(|p| *p == Default::default())
+    {
+        road.lateral_profile = None;
+    }
+    if road.objects.as_ref().is_some_and(|o| {
+        o.object.is_empty()
+            && o.object_reference.is_empty()
+            && o.tunnel.is_empty()
+            && o.bridge.is_empty()
+            && o.additional_data == empty
+    }) {
+        road.objects = None;
+    }
+    if road.signals.as_ref().is_some_and(|s| {
+        s.signal.is_empty() && s.signal_reference.is_empty() && s.additional_data == empty
+    }) {
+        road.signals = None;
+    }
+    if road
+        .railroad
+        .as_ref()
+        .is_some_and(|r| r.switch.is_empty() && r.additional_data == empty)
+    {
+        road.railroad = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+
+    #[test]
+    pub fn test_normalize() {
+        let document = |sections: &str| {
+            parse(
+                TestRoad::new("1")
+                    .length(20.0)
+                    .lanes(sections)
+                    .child("<link/><objects/>"),
+            )
+        };
+        let mut drive = document(
+            r#"
+            <laneSection s="10">
+                <center><lane id="0" type="none"/></center>
+                <right>
+                    <lane id="-3" type="driving"><link><predecessor id="-2"/></link><width sOffset="0" a="3" b="0" c="0" d="0"/></lane>
+                </right>
+            </laneSection>
+            <laneSection s="0">
+                <center><lane id="0" type="none"/></center>
+                <right>
+                    <lane id="-2" type="driving"><width sOffset="5" a="3" b="0" c="0" d="0"/><width sOffset="0" a="3" b="0" c="0" d="0"/><width sOffset="0" a="3" b="0" c="0" d="0"/></lane>
+                </right>
+            </laneSection>"#,
+        );
+        let mut canonical = document(
+            r#"
+            <laneSection s="0">
+                <center><lane id="0" type="none"/></center>
+                <right>
+                    <lane id="-1" type="driving"><width sOffset="0" a="3" b="0" c="0" d="0"/><width sOffset="5" a="3" b="0" c="0" d="0"/></lane>
+                </right>
+            </laneSection>
+            <laneSection s="10">
+                <center><lane id="0" type="none"/></center>
+                <right>
+                    <lane id="-1" type="driving"><link><predecessor id="-1"/></link><width sOffset="0" a="3" b="0" c="0" d="0"/></lane>
+                </right>
+            </laneSection>"#,
+        );
+        assert_ne!(drive, canonical);
+
+        drive.normalize();
+        assert!(drive.road[0].link.is_none());
+        assert!(drive.road[0].objects.is_none());
+        canonical.normalize();
+        assert_eq!(canonical, drive);
+        assert_eq!(
+            canonical.content_hash().unwrap(),
+            drive.content_hash().unwrap()
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use crate::core::OpenDrive;
+use crate::object::corner::Corner;
+use crate::road::element_type::ElementType;
+use crate::signal::position::Position;
+use crate::units::prelude::*;
+
+/// How [`OpenDrive::resize_road`] moves the s-coordinates anchored at a road
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SAnchor {
+    /// Scales the s-coordinates by the ratio of the new to the old length, for roads whose
+    /// geometry was reshaped as a whole
+    Proportional,
+    /// Keeps the s-coordinates, for roads that were extended or shortened at their end
+    Start,
+    /// Shifts the s-coordinates by the change of the length, for roads that were extended or
+    /// shortened at their start
+    End,
+}
+
+impl SAnchor {
+    fn apply(&self, s: f64, old: f64, new: f64) -> f64 {
+        let s = match self {
+            SAnchor::Proportional if old > 0.0 => s * new / old,
+            SAnchor::Proportional | SAnchor::Start => s,
+            SAnchor::End => s + new - old,
+        };
+        s.clamp(0.0, new)
+    }
+}
+
+impl OpenDrive {
+    /// Sets the length of the road with the given id after its geometry was changed and moves
+    /// everything anchored at its s-coordinates accordingly: road types, lane sections, lane
+    /// offsets, elevation, superelevation and shapes, objects with their repeats and outline
+    /// corners, object references, tunnels, bridges, signals, signal references and CRG
+    /// surfaces of the road, as well as the `@elementS` of links to the road, the positions of
+    /// signals of other roads on the road and the range of virtual junctions on the road.
+    /// Moved s-coordinates are clamped into `[0, length]`; records starting at 0 of the
+    /// sequences that cover the whole road, like the first lane section, stay at 0. The
+    /// geometries, the s-coordinates relative to their parent, like the `@sOffset` of lane
+    /// widths, and the polynomial coefficients are kept. `false` if there is no such road.
+    pub fn resize_road(&mut self, id: &str, length: Length, anchor: SAnchor) -> bool {
+        let Some(road) = self.road.iter_mut().find(|road| road.id == id) else {
+            return false;
+        };
+        let (old, new) = (road.length.get::<meter>(), length.get::<meter>().max(0.0));
+        let map = |s: f64| anchor.apply(s, old, new);
+        let map_length = |s: Length| Length::new::<meter>(map(s.get::<meter>()));
+        let map_sequence = |s: f64| if s == 0.0 { s } else { map(s) };
+        road.length = Length::new::<meter>(new);
+
+        for road_type in &mut road.r#type {
+            road_type.s = Length::new::<meter>(map_sequence(road_type.s.get::<meter>()));
+        }
+        for section in road.lanes.lane_section.iter_mut() {
+            section.s = map_sequence(section.s);
+        }
+        for offset in &mut road.lanes.lane_offset {
+            offset.s = map_sequence(offset.s);
+        }
+        if let Some(profile) = &mut road.elevation_profile {
+            for elevation in &mut profile.elevation {
+                elevation.s = map_sequence(elevation.s);
+            }
+        }
+        if let Some(profile) = &mut road.lateral_profile {
+            for super_elevation in &mut profile.super_elevation {
+                super_elevation.s = map_sequence(super_elevation.s);
+            }
+            for crossfall in &mut profile.crossfall {
+                crossfall.s = map_sequence(crossfall.s);
+            }
+            for shape in &mut profile.shape {
+                shape.s = map_sequence(shape.s);
+            }
+        }
+        if let Some(objects) = &mut road.objects {
+            for object in &mut objects.object {
+                object.s = map_length(object.s);
+                for repeat in &mut object.repeat {
+                    repeat.s = map_length(repeat.s);
+                }
+                let outlines = object.outline.iter_mut().chain(
+                    object
+                        .outlines
+                        .iter_mut()
+                        .flat_map(|outlines| outlines.outline.iter_mut()),
+                );
+                for corner in outlines.flat_map(|outline| outline.choice.iter_mut()) {
+                    if let Corner::Road(corner) = corner {
+                        corner.s = map_length(corner.s);
+                    }
+                }
+            }
+            for reference in &mut objects.object_reference {
+                reference.s = map_length(reference.s);
+            }
+            for tunnel in &mut objects.tunnel {
+                tunnel.s = map_length(tunnel.s);
+            }
+            for bridge in &mut objects.bridge {
+                bridge.s = map_length(bridge.s);
+            }
+        }
+        if let Some(signals) = &mut road.signals {
+            for signal in &mut signals.signal {
+                signal.s = map_length(signal.s);
+            }
+            for reference in &mut signals.signal_reference {
+                reference.s = map_length(reference.s);
+            }
+        }
+        if let Some(surface) = &mut road.surface {
+            for crg in &mut surface.crg {
+                crg.s_start = map_length(crg.s_start);
+                crg.s_end = map_length(crg.s_end);
+            }
+        }
+
+        for road in &mut self.road {
+            let links = road
+                .link
+                .iter_mut()
+                .flat_map(|link| link.predecessor.iter_mut().chain(link.successor.iter_mut()))
+                .filter(|link| {
+                    link.element_id == id && link.element_type != Some(ElementType::Junction)
+                });
+            for link in links {
+                link.element_s = link.element_s.map(map_length);
+            }
+            let positions = road
+                .signals
+                .iter_mut()
+                .flat_map(|signals| signals.signal.iter_mut())
+                .filter_map(|signal| match &mut signal.choice {
+                    Some(Position::Road(position)) if position.road_id == id => Some(position),
+                    _ => None,
+                });
+            for position in positions {
+                position.s = map_length(position.s);
+            }
+        }
+        for junction in &mut self.junction {
+            if junction.main_road.as_deref() == Some(id) {
+                junction.s_start = junction.s_start.map(map_length);
+                junction.s_end = junction.s_end.map(map_length);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::resize::SAnchor;
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_resize_road() {
+        let drive = parse(format!(
+            "{}{}",
+            TestRoad::new("1")
+                .lanes(
+                    r#"<laneSection s="0"><center><lane id="0" type="none"/></center></laneSection>
+                    <laneSection s="50"><center><lane id="0" type="none"/></center></laneSection>"#
+                )
+                .objects(r#"<object id="o" s="80" t="2" zOffset="0"/>"#)
+                .signals(
+                    r#"<signal id="s" s="90" t="-2" zOffset="2" dynamic="no" orientation="+" type="206" subtype="-1" country="DE"/>"#
+                ),
+            TestRoad::new("2")
+                .length(10.0)
+                .at(60.0, 5.0, std::f64::consts::FRAC_PI_2)
+                .link(r#"<predecessor elementType="road" elementId="1" elementS="60" elementDir="+"/>"#)
+                .sides(&[], &[]),
+        ));
+
+        let resized = |anchor| {
+            let mut drive = drive.clone();
+            assert!(drive.resize_road("1", Length::new::<meter>(120.0), anchor));
+            let road = &drive.road[0];
+            (
+                road.lanes
+                    .lane_section
+                    .iter()
+                    .map(|l| l.s)
+                    .collect::<Vec<_>>(),
+                road.objects.as_ref().unwrap().object[0].s.get::<meter>(),
+                road.signals.as_ref().unwrap().signal[0].s.get::<meter>(),
+                drive.road[1]
+                    .link
+                    .as_ref()
+                    .unwrap()
+                    .predecessor
+                    .as_ref()
+                    .unwrap()
+                    .element_s
+                    .unwrap()
+                    .get::<meter>(),
+            )
+        };
+        assert_eq!(
+            (vec![0.0, 60.0], 96.0, 108.0, 72.0),
+            resized(SAnchor::Proportional)
+        );
+        assert_eq!((vec![0.0, 50.0], 80.0, 90.0, 60.0), resized(SAnchor::Start));
+        assert_eq!((vec![0.0, 70.0], 100.0, 110.0, 80.0), resized(SAnchor::End));
+
+        let mut shortened = drive.clone();
+        shortened.resize_road("1", Length::new::<meter>(85.0), SAnchor::Start);
+        assert_eq!(
+            85.0,
+            shortened.road[0].signals.as_ref().unwrap().signal[0]
+                .s
+                .get::<meter>()
+        );
+        assert!(!shortened.resize_road("3", Length::new::<meter>(1.0), SAnchor::Start));
+    }
+}
@@ -0,0 +1,497 @@
+use crate::core::additional_data::AdditionalData;
+use crate::core::version::iso_8601_now;
+use crate::core::OpenDrive;
+use crate::export::geojson::string;
+use crate::object::objects::Objects;
+use crate::object::Object;
+use crate::road::link::Link;
+use crate::road::Road;
+use std::fmt::{Display, Formatter};
+
+/// Why an edit of an [`EditSession`] was rejected, the document is unchanged
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EditError {
+    #[error("There is no road with the id `{0}`")]
+    UnknownRoad(String),
+    #[error("There already is a road with the id `{0}`")]
+    DuplicateRoad(String),
+    #[error("The road `{road}` has no object with the id `{object}`")]
+    UnknownObject { road: String, object: String },
+}
+
+/// A reversible mutation of the document of an [`EditSession`]. Applying an operation returns
+/// the operation that reverts it.
+#[derive(Debug, Clone, PartialEq)]
+enum Operation {
+    SetRoad {
+        index: usize,
+        road: Box<Road>,
+    },
+    InsertRoad {
+        index: usize,
+        road: Box<Road>,
+    },
+    RemoveRoad {
+        index: usize,
+    },
+    SetLink {
+        road: usize,
+        link: Box<Option<Link>>,
+    },
+    InsertObject {
+        road: usize,
+        index: usize,
+        object: Box<Object>,
+    },
+    /// Removes the `<objects>` as well if `created`, because the insertion created them
+    RemoveObject {
+        road: usize,
+        index: usize,
+        created: bool,
+    },
+}
+
+impl Operation {
+    fn apply(self, drive: &mut OpenDrive) -> Operation {
+        match self {
+            Operation::SetRoad { index, mut road } => {
+                std::mem::swap(&mut drive.road[index], &mut road);
+                Operation::SetRoad { index, road }
+            }
+            Operation::InsertRoad { index, road } => {
+                drive.road.insert(index, *road);
+                Operation::RemoveRoad { index }
+            }
+            Operation::RemoveRoad { index } => Operation::InsertRoad {
+                index,
+                road: Box::new(drive.road.remove(index)),
+            },
+            Operation::SetLink { road, mut link } => {
+                std::mem::swap(&mut drive.road[road].link, &mut *link);
+                Operation::SetLink { road, link }
+            }
+            Operation::InsertObject {
+                road,
+                index,
+                object,
+            } => {
+                let created = drive.road[road].objects.is_none();
+                drive.road[road]
+                    .objects
+                    .get_or_insert_with(|| Objects {
+                        object: Vec::new(),
+                        object_reference: Vec::new(),
+                        tunnel: Vec::new(),
+                        bridge: Vec::new(),
+                        additional_data: AdditionalData::default(),
+                    })
+                    .object
+                    .insert(index, *object);
+                Operation::RemoveObject {
+                    road,
+                    index,
+                    created,
+                }
+            }
+            Operation::RemoveObject {
+                road,
+                index,
+                created,
+            } => {
+                let objects = &mut drive.road[road].objects;
+                let object = objects.as_mut().expect("objects").object.remove(index);
+                if created {
+                    *objects = None;
+                }
+                Operation::InsertObject {
+                    road,
+                    index,
+                    object: Box::new(object),
+                }
+            }
+        }
+    }
+}
+
+/// The kind of an edit of an [`EditSession`], named after its method
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EditKind {
+    EditRoad,
+    AddRoad,
+    RemoveRoad,
+    SetLink,
+    AddObject,
+    RemoveObject,
+}
+
+impl EditKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EditKind::EditRoad => "edit-road",
+            EditKind::AddRoad => "add-road",
+            EditKind::RemoveRoad => "remove-road",
+            EditKind::SetLink => "set-link",
+            EditKind::AddObject => "add-object",
+            EditKind::RemoveObject => "remove-object",
+        }
+    }
+}
+
+/// Who changed what when, see [`EditSession::change_log`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChangeLogEntry {
+    /// The author of the session at the time of the edit, see [`EditSession::set_author`]
+    pub author: Option<String>,
+    /// The time of the edit in UTC like `2021-03-31T12:00:00`
+    pub time: String,
+    pub kind: EditKind,
+    /// The path of the changed element before the edit, in the form of the paths of
+    /// [`crate::core::diff::Change`], like `/OpenDRIVE/road[@id="1"]/link`
+    pub path: String,
+}
+
+/// The edits in effect in an [`EditSession`] in the order they were made
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ChangeLog {
+    pub entries: Vec<ChangeLogEntry>,
+}
+
+impl ChangeLog {
+    /// The entries as JSON array of objects with the keys `author`, `time`, `kind` and `path`,
+    /// `author` is `null` if unknown
+    pub fn to_json(&self) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    r#"{{"author":{},"time":{},"kind":{},"path":{}}}"#,
+                    entry.author.as_deref().map_or("null".to_string(), string),
+                    string(&entry.time),
+                    string(entry.kind.as_str()),
+                    string(&entry.path)
+                )
+            })
+            .collect::<Vec<_>>();
+        format!("[{}]", entries.join(","))
+    }
+}
+
+impl Display for ChangeLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{} {} {} {}",
+                entry.time,
+                entry.author.as_deref().unwrap_or("-"),
+                entry.kind.as_str(),
+                entry.path
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Edits of a document that can be undone and redone, for map editors. Each edit is recorded as
+/// reversible operation; a new edit after undoing discards the undone edits.
+/// [`EditSession::commit`] returns the edited document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditSession {
+    drive: OpenDrive,
+    author: Option<String>,
+    undo: Vec<(Operation, ChangeLogEntry)>,
+    redo: Vec<(Operation, ChangeLogEntry)>,
+}
+
+impl EditSession {
+    pub fn new(drive: OpenDrive) -> Self {
+        Self {
+            drive,
+            author: None,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// The author recorded for the following edits, `None` by default
+    pub fn with_author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// The author recorded for the following edits, see [`EditSession::change_log`]
+    pub fn set_author(&mut self, author: Option<String>) {
+        self.author = author;
+    }
+
+    /// The edits in effect, undone edits are not part of it
+    pub fn change_log(&self) -> ChangeLog {
+        ChangeLog {
+            entries: self.undo.iter().map(|(_, entry)| entry.clone()).collect(),
+        }
+    }
+
+    /// The document with all edits that are not undone
+    pub fn drive(&self) -> &OpenDrive {
+        &self.drive
+    }
+
+    /// Finishes the session, the history of edits is discarded
+    pub fn commit(self) -> OpenDrive {
+        self.drive
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Reverts the last edit that is not undone yet, `false` if there is none
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some((operation, entry)) => {
+                self.redo.push((operation.apply(&mut self.drive), entry));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Repeats the last undone edit, `false` if there is none
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some((operation, entry)) => {
+                self.undo.push((operation.apply(&mut self.drive), entry));
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn record(&mut self, operation: Operation, kind: EditKind, path: String) {
+        let entry = ChangeLogEntry {
+            author: self.author.clone(),
+            time: iso_8601_now(),
+            kind,
+            path,
+        };
+        self.undo.push((operation.apply(&mut self.drive), entry));
+        self.redo.clear();
+    }
+
+    fn road_index(&self, id: &str) -> Result<usize, EditError> {
+        self.drive
+            .road
+            .iter()
+            .position(|road| road.id == id)
+            .ok_or_else(|| EditError::UnknownRoad(id.to_string()))
+    }
+
+    /// Changes the road with the given id as one edit. Changing its id to the id of another road
+    /// is rejected.
+    pub fn edit_road(&mut self, id: &str, edit: impl FnOnce(&mut Road)) -> Result<(), EditError> {
+        let index = self.road_index(id)?;
+        let mut road = self.drive.road[index].clone();
+        edit(&mut road);
+        if road.id != id && self.road_index(&road.id).is_ok() {
+            return Err(EditError::DuplicateRoad(road.id));
+        }
+        self.record(
+            Operation::SetRoad {
+                index,
+                road: Box::new(road),
+            },
+            EditKind::EditRoad,
+            road_path(id),
+        );
+        Ok(())
+    }
+
+    /// Appends the road, which must have an id that is not used by another road yet
+    pub fn add_road(&mut self, road: Road) -> Result<(), EditError> {
+        if self.road_index(&road.id).is_ok() {
+            return Err(EditError::DuplicateRoad(road.id));
+        }
+        let path = road_path(&road.id);
+        self.record(
+            Operation::InsertRoad {
+                index: self.drive.road.len(),
+                road: Box::new(road),
+            },
+            EditKind::AddRoad,
+            path,
+        );
+        Ok(())
+    }
+
+    /// Removes the road with the given id, links and references to it are kept
+    pub fn remove_road(&mut self, id: &str) -> Result<(), EditError> {
+        let index = self.road_index(id)?;
+        self.record(
+            Operation::RemoveRoad { index },
+            EditKind::RemoveRoad,
+            road_path(id),
+        );
+        Ok(())
+    }
+
+    /// Replaces the `<link>` of the road with the given id, `None` removes it
+    pub fn set_link(&mut self, id: &str, link: Option<Link>) -> Result<(), EditError> {
+        let road = self.road_index(id)?;
+        self.record(
+            Operation::SetLink {
+                road,
+                link: Box::new(link),
+            },
+            EditKind::SetLink,
+            format!("{}/link", road_path(id)),
+        );
+        Ok(())
+    }
+
+    /// Appends the object to the `<objects>` of the road with the given id
+    pub fn add_object(&mut self, id: &str, object: Object) -> Result<(), EditError> {
+        let road = self.road_index(id)?;
+        let index = self.drive.road[road]
+            .objects
+            .as_ref()
+            .map_or(0, |objects| objects.object.len());
+        let path = object_path(id, &object.id);
+        self.record(
+            Operation::InsertObject {
+                road,
+                index,
+                object: Box::new(object),
+            },
+            EditKind::AddObject,
+            path,
+        );
+        Ok(())
+    }
+
+    /// Removes the object with the given id from the road with the given id
+    pub fn remove_object(&mut self, id: &str, object: &str) -> Result<(), EditError> {
+        let road = self.road_index(id)?;
+        let index = self.drive.road[road]
+            .objects
+            .iter()
+            .flat_map(|objects| objects.object.iter())
+            .position(|o| o.id == object)
+            .ok_or_else(|| EditError::UnknownObject {
+                road: id.to_string(),
+                object: object.to_string(),
+            })?;
+        self.record(
+            Operation::RemoveObject {
+                road,
+                index,
+                created: false,
+            },
+            EditKind::RemoveObject,
+            object_path(id, object),
+        );
+        Ok(())
+    }
+}
+
+fn road_path(id: &str) -> String {
+    format!("/OpenDRIVE/road[@id={id:?}]")
+}
+
+fn object_path(road: &str, object: &str) -> String {
+    format!("{}/objects/object[@id={object:?}]", road_path(road))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::session::{EditError, EditKind, EditSession};
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_edit_session() {
+        let drive = parse(
+            TestRoad::new("1")
+                .length(10.0)
+                .link(r#"<successor elementType="road" elementId="2" contactPoint="start"/>"#)
+                .sides(&[], &[])
+                .objects(r#"<object id="pole" s="1" t="2" zOffset="0" type="pole"/>"#),
+        );
+        let object = drive.road[0].objects.as_ref().unwrap().object[0].clone();
+
+        let mut session = EditSession::new(drive.clone());
+        session
+            .edit_road("1", |road| road.length = Length::new::<meter>(20.0))
+            .unwrap();
+        let mut road = drive.road[0].clone();
+        road.id = "2".to_string();
+        session.add_road(road.clone()).unwrap();
+        assert_eq!(
+            Err(EditError::DuplicateRoad("2".to_string())),
+            session.add_road(road)
+        );
+        session.set_link("1", None).unwrap();
+        session.remove_object("1", "pole").unwrap();
+        session.add_object("2", object.clone()).unwrap();
+        session.edit_road("2", |road| road.objects = None).unwrap();
+        session.add_object("2", object).unwrap();
+        session.undo();
+        assert!(session.drive().road[1].objects.is_none());
+        session.redo();
+        assert_eq!(
+            Err(EditError::UnknownRoad("3".to_string())),
+            session.remove_road("3")
+        );
+
+        let edited = session.drive().clone();
+        assert_eq!(2, edited.road.len());
+        assert!(edited.road[0].link.is_none());
+        assert!(edited.road[0].objects.as_ref().unwrap().object.is_empty());
+
+        while session.undo() {}
+        assert_eq!(&drive, session.drive());
+        assert!(!session.can_undo());
+        while session.redo() {}
+        assert_eq!(&edited, session.drive());
+
+        session.undo();
+        session.set_author(Some("qa".to_string()));
+        session.remove_road("2").unwrap();
+        assert!(!session.can_redo());
+
+        let log = session.change_log();
+        assert_eq!(
+            vec![
+                (EditKind::EditRoad, r#"/OpenDRIVE/road[@id="1"]"#),
+                (EditKind::AddRoad, r#"/OpenDRIVE/road[@id="2"]"#),
+                (EditKind::SetLink, r#"/OpenDRIVE/road[@id="1"]/link"#),
+                (
+                    EditKind::RemoveObject,
+                    r#"/OpenDRIVE/road[@id="1"]/objects/object[@id="pole"]"#
+                ),
+                (
+                    EditKind::AddObject,
+                    r#"/OpenDRIVE/road[@id="2"]/objects/object[@id="pole"]"#
+                ),
+                (EditKind::EditRoad, r#"/OpenDRIVE/road[@id="2"]"#),
+                (EditKind::RemoveRoad, r#"/OpenDRIVE/road[@id="2"]"#),
+            ],
+            log.entries
+                .iter()
+                .map(|entry| (entry.kind, entry.path.as_str()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(None, log.entries[0].author);
+        let last = log.entries.last().unwrap();
+        assert_eq!(Some("qa"), last.author.as_deref());
+        assert!(log.to_json().ends_with(&format!(
+            r#"{{"author":"qa","time":"{}","kind":"remove-road","path":"/OpenDRIVE/road[@id=\"2\"]"}}]"#,
+            last.time
+        )));
+        assert_eq!(1, session.commit().road.len());
+    }
+}
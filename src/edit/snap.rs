@@ -0,0 +1,209 @@
+use crate::core::OpenDrive;
+use crate::edit::resize::SAnchor;
+use crate::junction::contact_point::ContactPoint;
+use crate::road::element_type::ElementType;
+use crate::road::geometry::pose::Pose;
+use crate::road::predecessor_successor::PredecessorSuccessor;
+use crate::units::prelude::*;
+use std::collections::HashSet;
+use std::f64::consts::PI;
+
+/// The largest gaps [`OpenDrive::snap_links`] repairs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapTolerance {
+    /// The largest distance between the start of a road and the road it continues, 1 cm by
+    /// default
+    pub distance: Length,
+    /// The largest difference of their headings, 0.01 rad by default
+    pub heading: Angle,
+}
+
+impl Default for SnapTolerance {
+    fn default() -> Self {
+        Self {
+            distance: Length::new::<meter>(0.01),
+            heading: Angle::new::<radian>(0.01),
+        }
+    }
+}
+
+/// A road whose start was moved by [`OpenDrive::snap_links`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnappedLink {
+    pub road: String,
+    /// The road it now continues without gap
+    pub linked_road: String,
+    /// How far the start was moved
+    pub distance: Length,
+    /// How far the heading at the start was turned, counter-clockwise
+    pub heading: Angle,
+}
+
+impl OpenDrive {
+    /// Closes the gaps within the tolerance at road links by moving and turning the first
+    /// geometry of each road that starts at another road so that it continues exactly where the
+    /// other road connects. Roads are repaired in document order and each road at most once.
+    ///
+    /// The length of the moved geometry changes by how far its start moved along its heading and
+    /// the road is resized with [`OpenDrive::resize_road`] anchored at its end. A line that is not
+    /// turned keeps its end, a turned geometry may leave a gap of up to its length times the
+    /// heading difference to the following geometry.
+    pub fn snap_links(&mut self, tolerance: SnapTolerance) -> Vec<SnappedLink> {
+        let mut candidates = Vec::new();
+        for (index, road) in self.road.iter().enumerate() {
+            let Some(link) = &road.link else {
+                continue;
+            };
+            if let Some(target) = link.predecessor.as_ref().and_then(|p| self.contact(p)) {
+                candidates.push((index, target));
+            }
+            if let Some(successor) = &link.successor {
+                if let (Some(ContactPoint::Start), Some((successor, _))) =
+                    (&successor.contact_point, self.contact(successor))
+                {
+                    candidates.push((successor, (index, ContactPoint::End)));
+                }
+            }
+        }
+
+        let mut repaired = HashSet::new();
+        let mut snapped = Vec::new();
+        for (index, (linked, contact_point)) in candidates {
+            if index == linked || repaired.contains(&index) {
+                continue;
+            }
+            let target = self.contact_pose(linked, contact_point);
+            let road = &mut self.road[index];
+            let first = road.plan_view.geometry.first_mut();
+            let (dx, dy) = (
+                (target.x - first.x).get::<meter>(),
+                (target.y - first.y).get::<meter>(),
+            );
+            let distance = dx.hypot(dy);
+            let heading = ((target.hdg - first.hdg).get::<radian>() + PI).rem_euclid(2.0 * PI) - PI;
+            if distance > tolerance.distance.get::<meter>()
+                || heading.abs() > tolerance.heading.get::<radian>()
+                || (distance == 0.0 && heading == 0.0)
+            {
+                continue;
+            }
+
+            let (sin, cos) = target.hdg.get::<radian>().sin_cos();
+            let change = Length::new::<meter>(-(dx * cos + dy * sin))
+                .max(-first.length)
+                .max(-road.length);
+            first.x = target.x;
+            first.y = target.y;
+            first.hdg = target.hdg;
+            first.length += change;
+            for geometry in road.plan_view.geometry.iter_mut().skip(1) {
+                geometry.s += change;
+            }
+            let (id, length) = (road.id.clone(), road.length + change);
+            self.resize_road(&id, length, SAnchor::End);
+
+            repaired.insert(index);
+            snapped.push(SnappedLink {
+                road: id,
+                linked_road: self.road[linked].id.clone(),
+                distance: Length::new::<meter>(distance),
+                heading: Angle::new::<radian>(heading),
+            });
+        }
+        snapped
+    }
+
+    /// The index of the linked road and where it is linked, `None` for junctions, unknown roads
+    /// and links within a road
+    fn contact(&self, link: &PredecessorSuccessor) -> Option<(usize, ContactPoint)> {
+        if link.element_type == Some(ElementType::Junction) {
+            return None;
+        }
+        let index = self.road.iter().position(|r| r.id == link.element_id)?;
+        Some((index, link.contact_point.clone()?))
+    }
+
+    /// The pose a road continuing the road of the given index at the contact point starts with
+    fn contact_pose(&self, index: usize, contact_point: ContactPoint) -> Pose {
+        let road = &self.road[index];
+        match contact_point {
+            ContactPoint::Start => {
+                let pose = road.reference_pose(Length::new::<meter>(0.0));
+                Pose {
+                    hdg: pose.hdg + Angle::new::<radian>(PI),
+                    ..pose
+                }
+            }
+            ContactPoint::End => road.reference_pose(road.length),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit::snap::SnapTolerance;
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_snap_links() {
+        let mut drive = parse(format!(
+            "{}{}{}",
+            TestRoad::new("1")
+                .length(10.0)
+                .link(r#"<successor elementType="road" elementId="2" contactPoint="start"/>"#)
+                .sides(&[], &[]),
+            TestRoad::new("2")
+                .length(30.0)
+                .link(r#"<successor elementType="road" elementId="3" contactPoint="start"/>"#)
+                .geometry(
+                    r#"<geometry s="0" x="10.004" y="0.003" hdg="0.002" length="20"><line/></geometry>
+                    <geometry s="20" x="30" y="0" hdg="0" length="10"><line/></geometry>"#
+                )
+                .lanes(
+                    r#"<laneSection s="0"><center><lane id="0" type="none"/></center></laneSection>
+                    <laneSection s="15"><center><lane id="0" type="none"/></center></laneSection>"#,
+                )
+                .signals(
+                    r#"<signal s="25" t="0" id="s" dynamic="no" orientation="+" zOffset="0" type="206" subtype="-1"/>"#,
+                ),
+            TestRoad::new("3")
+                .length(10.0)
+                .at(41.0, 0.0, 0.0)
+                .link(r#"<predecessor elementType="road" elementId="2" contactPoint="end"/>"#)
+                .sides(&[], &[]),
+        ));
+
+        let snapped = drive.snap_links(SnapTolerance::default());
+        assert_eq!(1, snapped.len());
+        assert_eq!(
+            ("2", "1"),
+            (snapped[0].road.as_str(), snapped[0].linked_road.as_str())
+        );
+        assert!((snapped[0].distance.get::<meter>() - 0.005).abs() < 1e-9);
+        assert!((snapped[0].heading.get::<radian>() + 0.002).abs() < 1e-9);
+
+        let road = &drive.road[1];
+        let first = road.plan_view.geometry.first();
+        assert_eq!(
+            (10.0, 0.0, 0.0),
+            (
+                first.x.get::<meter>(),
+                first.y.get::<meter>(),
+                first.hdg.get::<radian>()
+            )
+        );
+        assert!((first.length.get::<meter>() - 20.004).abs() < 1e-9);
+        assert!((road.plan_view.geometry[1].s.get::<meter>() - 20.004).abs() < 1e-9);
+        assert!((road.length.get::<meter>() - 30.004).abs() < 1e-9);
+        // what is anchored at the road keeps its place along the reference line
+        let sections = &road.lanes.lane_section;
+        assert_eq!(0.0, sections[0].s);
+        assert!((sections[1].s - 15.004).abs() < 1e-9);
+        let signal = &road.signals.as_ref().unwrap().signal[0];
+        assert!((signal.s.get::<meter>() - 25.004).abs() < 1e-9);
+        assert_eq!(41.0, drive.road[2].plan_view.geometry[0].x.get::<meter>());
+
+        assert!(drive.snap_links(SnapTolerance::default()).is_empty());
+    }
+}
@@ -0,0 +1,416 @@
+use crate::core::OpenDrive;
+use crate::junction::contact_point::ContactPoint;
+use crate::road::element_type::ElementType;
+use crate::road::geometry::geometry_type::GeometryType;
+use crate::road::geometry::plan_view::PlanView;
+use crate::road::geometry::point::Point;
+use crate::road::geometry::Geometry;
+use crate::road::link::Link;
+use crate::road::predecessor_successor::PredecessorSuccessor;
+use crate::road::Road;
+use crate::signal::reference::ElementType as ReferenceType;
+use crate::units::prelude::*;
+use std::collections::HashSet;
+use vec1::Vec1;
+
+/// The roads selected by [`OpenDrive::extract`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+    /// The roads of the given ids
+    Roads(Vec<String>),
+    /// The roads whose reference line passes the given area of the inertial x/y-plane
+    BoundingBox { min: Point, max: Point },
+}
+
+/// How [`OpenDrive::extract`] handles links of selected roads to roads that are not selected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DanglingLinks {
+    /// Removes the links
+    #[default]
+    Trim,
+    /// Keeps the links and adds a stub road of at most [`DanglingLinks::STUB_LENGTH`] for each
+    /// linked road. A stub is a straight line along the linked end of the omitted road with the
+    /// lanes of the lane section at that end and no further links.
+    Stub,
+}
+
+impl DanglingLinks {
+    /// The maximum length of the reference line of a stub road in meters
+    pub const STUB_LENGTH: f64 = 10.0;
+}
+
+impl OpenDrive {
+    /// A new document with only the selected roads, the junctions they are linked to or belong
+    /// to, and the connecting roads of these junctions that join two selected roads. Signals and
+    /// objects are kept with their roads. All references to omitted elements are removed, like
+    /// junction connections, signal references, controls or junction group members, so that the
+    /// document is consistent. Links of roads to omitted roads are handled as given by
+    /// `dangling`. This creates small test maps from big ones.
+    pub fn extract(&self, selection: &Selection, dangling: DanglingLinks) -> OpenDrive {
+        let mut roads = self
+            .road
+            .iter()
+            .filter(|road| match selection {
+                Selection::Roads(ids) => ids.contains(&road.id),
+                Selection::BoundingBox { min, max } => passes(road, min, max),
+            })
+            .map(|road| road.id.as_str())
+            .collect::<HashSet<_>>();
+
+        let junctions = self
+            .junction
+            .iter()
+            .filter(|junction| {
+                self.road.iter().any(|road| {
+                    roads.contains(road.id.as_str())
+                        && (road.junction == junction.id
+                            || links(road).any(|l| {
+                                l.element_type == Some(ElementType::Junction)
+                                    && l.element_id == junction.id
+                            }))
+                })
+            })
+            .map(|junction| junction.id.as_str())
+            .collect::<HashSet<_>>();
+
+        let connecting = self
+            .road
+            .iter()
+            .filter(|road| junctions.contains(road.junction.as_str()))
+            .filter(|road| {
+                let mut linked =
+                    links(road).filter(|l| l.element_type != Some(ElementType::Junction));
+                linked.clone().count() == 2 && linked.all(|l| roads.contains(l.element_id.as_str()))
+            })
+            .map(|road| road.id.as_str())
+            .collect::<Vec<_>>();
+        roads.extend(connecting);
+
+        let mut drive = OpenDrive {
+            header: self.header.clone(),
+            road: self
+                .road
+                .iter()
+                .filter(|road| roads.contains(road.id.as_str()))
+                .cloned()
+                .collect(),
+            controller: Vec::new(),
+            junction: self
+                .junction
+                .iter()
+                .filter(|junction| junctions.contains(junction.id.as_str()))
+                .cloned()
+                .collect(),
+            junction_group: Vec::new(),
+            station: Vec::new(),
+            additional_data: self.additional_data.clone(),
+        };
+
+        if dangling == DanglingLinks::Stub {
+            let stubs = self
+                .road
+                .iter()
+                .filter(|road| !roads.contains(road.id.as_str()))
+                .filter_map(|road| {
+                    let contact = drive
+                        .road
+                        .iter()
+                        .flat_map(links)
+                        .find(|l| {
+                            l.element_type != Some(ElementType::Junction) && l.element_id == road.id
+                        })?
+                        .contact_point
+                        .clone();
+                    Some(stub(road, contact, &roads))
+                })
+                .collect::<Vec<_>>();
+            drive.road.extend(stubs);
+        }
+
+        drive.trim(self);
+        drive
+    }
+
+    /// Removes all references to elements that are not part of this document, `source` is the
+    /// document this one was extracted from
+    fn trim(&mut self, source: &OpenDrive) {
+        let road_ids = self
+            .road
+            .iter()
+            .map(|road| road.id.clone())
+            .collect::<HashSet<_>>();
+        let signal_ids = self
+            .road
+            .iter()
+            .flat_map(|road| &road.signals)
+            .flat_map(|signals| &signals.signal)
+            .map(|signal| signal.id.clone())
+            .collect::<HashSet<_>>();
+        let object_ids = self
+            .road
+            .iter()
+            .flat_map(|road| &road.objects)
+            .flat_map(|objects| &objects.object)
+            .map(|object| object.id.clone())
+            .collect::<HashSet<_>>();
+
+        self.controller = source
+            .controller
+            .iter()
+            .filter_map(|controller| {
+                let control = controller
+                    .control
+                    .iter()
+                    .filter(|control| signal_ids.contains(&control.signal_id))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let mut controller = controller.clone();
+                controller.control = Vec1::try_from_vec(control).ok()?;
+                Some(controller)
+            })
+            .collect();
+        let controller_ids = self
+            .controller
+            .iter()
+            .map(|controller| controller.id.clone())
+            .collect::<HashSet<_>>();
+
+        self.junction.retain_mut(|junction| {
+            let connection = junction
+                .connection
+                .iter()
+                .filter(|c| {
+                    [&c.incoming_road, &c.connecting_road, &c.linked_road]
+                        .into_iter()
+                        .flatten()
+                        .all(|id| road_ids.contains(id))
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            match Vec1::try_from_vec(connection) {
+                Ok(connection) => junction.connection = connection,
+                Err(_) => return false,
+            }
+            junction.priority.retain(|priority| {
+                [&priority.high, &priority.low]
+                    .into_iter()
+                    .flatten()
+                    .all(|id| road_ids.contains(id))
+            });
+            junction
+                .controller
+                .retain(|controller| controller_ids.contains(&controller.id));
+            true
+        });
+        let junction_ids = self
+            .junction
+            .iter()
+            .map(|junction| junction.id.clone())
+            .collect::<HashSet<_>>();
+
+        self.junction_group = source
+            .junction_group
+            .iter()
+            .filter_map(|group| {
+                let references = group
+                    .junction_reference
+                    .iter()
+                    .filter(|reference| junction_ids.contains(&reference.junction))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let mut group = group.clone();
+                group.junction_reference = Vec1::try_from_vec(references).ok()?;
+                Some(group)
+            })
+            .collect();
+
+        let exists = |link: &PredecessorSuccessor| match link.element_type {
+            Some(ElementType::Junction) => junction_ids.contains(&link.element_id),
+            Some(ElementType::Road) | None => road_ids.contains(&link.element_id),
+        };
+        for road in &mut self.road {
+            if let Some(link) = &mut road.link {
+                link.predecessor = link.predecessor.take().filter(exists);
+                link.successor = link.successor.take().filter(exists);
+                if link.predecessor.is_none() && link.successor.is_none() {
+                    road.link = None;
+                }
+            }
+            if !junction_ids.contains(&road.junction) {
+                road.junction = "-1".to_string();
+            }
+            if let Some(signals) = &mut road.signals {
+                signals
+                    .signal_reference
+                    .retain(|reference| signal_ids.contains(&reference.id));
+                for signal in &mut signals.signal {
+                    signal
+                        .dependency
+                        .retain(|dependency| signal_ids.contains(&dependency.id));
+                    signal
+                        .reference
+                        .retain(|reference| match reference.element_type {
+                            ReferenceType::Signal => signal_ids.contains(&reference.element_id),
+                            ReferenceType::Object => object_ids.contains(&reference.element_id),
+                        });
+                }
+            }
+            if let Some(objects) = &mut road.objects {
+                objects
+                    .object_reference
+                    .retain(|reference| object_ids.contains(&reference.id));
+            }
+        }
+    }
+}
+
+/// The predecessor and successor of the road
+fn links(road: &Road) -> impl Iterator<Item = &PredecessorSuccessor> + Clone {
+    road.link
+        .iter()
+        .flat_map(|link| link.predecessor.iter().chain(link.successor.iter()))
+}
+
+/// Whether the reference line of the road passes the area between `min` and `max`, sampled
+/// every meter
+fn passes(road: &Road, min: &Point, max: &Point) -> bool {
+    let length = road.length.get::<meter>();
+    let samples = length.ceil().max(1.0) as usize;
+    (0..=samples).any(|index| {
+        let s = (index as f64).min(length);
+        let point = road.plan_view.pose_at(Length::new::<meter>(s)).point();
+        (min.x..=max.x).contains(&point.x) && (min.y..=max.y).contains(&point.y)
+    })
+}
+
+/// A stub of the road at the given contact point, with only the links to the selected roads
+fn stub(road: &Road, contact: Option<ContactPoint>, selected: &HashSet<&str>) -> Road {
+    let length = road.length.get::<meter>();
+    let stub_length = length.min(DanglingLinks::STUB_LENGTH);
+    let start = match contact {
+        Some(ContactPoint::End) => length - stub_length,
+        _ => 0.0,
+    };
+    let pose = road.plan_view.pose_at(Length::new::<meter>(start));
+    let mut stub = Road::new(
+        road.id.clone(),
+        PlanView::new(Geometry {
+            hdg: pose.hdg,
+            length: Length::new::<meter>(stub_length),
+            s: Length::new::<meter>(0.0),
+            x: pose.x,
+            y: pose.y,
+            r#type: GeometryType::Line(Default::default()),
+            additional_data: Default::default(),
+        }),
+    );
+    stub.name = road.name.clone();
+    stub.rule = road.rule.clone();
+    let (_, section) = road.lanes.lane_section_at(start);
+    let mut section = section.clone();
+    section.s = 0.0;
+    stub.lanes.lane_section = Vec1::new(section);
+
+    let selected = |link: &Option<PredecessorSuccessor>, end: ContactPoint| {
+        link.clone().filter(|l| {
+            contact.as_ref().is_none_or(|c| *c == end)
+                && l.element_type != Some(ElementType::Junction)
+                && selected.contains(l.element_id.as_str())
+        })
+    };
+    stub.link = road.link.as_ref().map(|link| Link {
+        predecessor: selected(&link.predecessor, ContactPoint::Start),
+        successor: selected(&link.successor, ContactPoint::End),
+        additional_data: Default::default(),
+    });
+    stub
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::edit::subnetwork::{DanglingLinks, Selection};
+    use crate::fixtures::{parse, TestRoad};
+    use crate::road::geometry::point::Point;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_extract() {
+        let road = |id: &str, x: f64| {
+            TestRoad::new(id)
+                .at(x, 0.0, 0.0)
+                .sides(&[], &[("driving", 3.0)])
+                .signals(&format!(
+                    r#"<signal s="0" t="0" id="s{id}" dynamic="yes" orientation="+" zOffset="0" type="1000001" subtype="-1"/>"#
+                ))
+        };
+        let drive = parse(format!(
+            r#"{}{}{}{}
+            <controller id="c"><control signalId="s1"/><control signalId="s4"/></controller>
+            <junction id="J">
+                <connection id="0" incomingRoad="2" connectingRoad="3" contactPoint="start"/>
+                <controller id="c"/>
+            </junction>"#,
+            road("1", 0.0).link(r#"<successor elementType="road" elementId="2" contactPoint="start"/>"#),
+            road("2", 100.0).link(
+                r#"<predecessor elementType="road" elementId="1" contactPoint="end"/><successor elementType="junction" elementId="J"/>"#
+            ),
+            road("3", 200.0).junction("J").link(
+                r#"<predecessor elementType="road" elementId="2" contactPoint="end"/><successor elementType="road" elementId="4" contactPoint="start"/>"#
+            ),
+            road("4", 300.0).link(r#"<predecessor elementType="road" elementId="3" contactPoint="end"/>"#),
+        ));
+        let ids = |drive: &OpenDrive| drive.road.iter().map(|r| r.id.clone()).collect::<Vec<_>>();
+
+        let trimmed = drive.extract(
+            &Selection::Roads(vec!["2".to_string(), "4".to_string()]),
+            DanglingLinks::Trim,
+        );
+        assert_eq!(vec!["2", "3", "4"], ids(&trimmed));
+        assert!(trimmed.road[0].link.as_ref().unwrap().predecessor.is_none());
+        assert_eq!(1, trimmed.junction.len());
+        assert_eq!(
+            vec!["s4"],
+            trimmed.controller[0]
+                .control
+                .iter()
+                .map(|c| c.signal_id.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        let trimmed = drive.extract(
+            &Selection::Roads(vec!["4".to_string()]),
+            DanglingLinks::Trim,
+        );
+        assert_eq!(vec!["4"], ids(&trimmed));
+        assert!(trimmed.road[0].link.is_none());
+        assert!(trimmed.junction.is_empty());
+
+        let stubbed = drive.extract(
+            &Selection::BoundingBox {
+                min: Point {
+                    x: Length::new::<meter>(120.0),
+                    y: Length::new::<meter>(-10.0),
+                },
+                max: Point {
+                    x: Length::new::<meter>(180.0),
+                    y: Length::new::<meter>(10.0),
+                },
+            },
+            DanglingLinks::Stub,
+        );
+        assert_eq!(vec!["2", "1"], ids(&stubbed));
+        let stub = &stubbed.road[1];
+        assert_eq!(DanglingLinks::STUB_LENGTH, stub.length.get::<meter>());
+        assert_eq!(90.0, stub.plan_view.geometry.first().x.get::<meter>());
+        assert_eq!(
+            Some("2"),
+            stub.link
+                .as_ref()
+                .and_then(|l| l.successor.as_ref())
+                .map(|s| s.element_id.as_str())
+        );
+        assert!(stub.signals.is_none());
+        assert!(OpenDrive::from_xml_str(&stubbed.to_xml_string().unwrap()).is_ok());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::core::OpenDrive;
+use crate::object::orientation::ObjectType;
+use crate::road::geometry::point::Point;
+use crate::units::prelude::*;
+use std::fmt::Write;
+
+impl OpenDrive {
+    /// The roads and objects of this document as GeoJSON `FeatureCollection`, for map viewers.
+    /// Roads are `LineString`s of their reference line sampled every meter, objects are
+    /// `Polygon`s of their [`crate::object::Object::footprint`] or `Point`s for objects without
+    /// extent. Coordinates are the inertial x/y-coordinates in meter, not longitude and latitude,
+    /// see [`crate::core::header::Header::geo_reference`] for their projection.
+    pub fn to_geojson(&self) -> String {
+        let mut features = Vec::new();
+        for road in &self.road {
+            let length = road.length.get::<meter>().max(0.0);
+            let samples = length.ceil().max(1.0) as usize;
+            let line = (0..=samples)
+                .map(|index| {
+                    let s = (index as f64).min(length);
+                    road.reference_pose(Length::new::<meter>(s)).point()
+                })
+                .collect::<Vec<_>>();
+            features.push(feature(
+                &format!(
+                    r#"{{"type":"LineString","coordinates":{}}}"#,
+                    coordinates(&line)
+                ),
+                &[
+                    ("kind", Some(string("road"))),
+                    ("id", Some(string(&road.id))),
+                    ("name", road.name.as_deref().map(string)),
+                    ("junction", Some(string(&road.junction))),
+                    ("length", Some(number(length))),
+                ],
+            ));
+
+            for object in road.objects.iter().flat_map(|objects| &objects.object) {
+                let mut footprint = object.footprint(road);
+                let geometry = if footprint.len() >= 3 {
+                    footprint.push(footprint[0]);
+                    format!(
+                        r#"{{"type":"Polygon","coordinates":[{}]}}"#,
+                        coordinates(&footprint)
+                    )
+                } else if let Some(point) = footprint.first() {
+                    format!(
+                        r#"{{"type":"Point","coordinates":[{},{}]}}"#,
+                        number(point.x.get::<meter>()),
+                        number(point.y.get::<meter>())
+                    )
+                } else {
+                    continue;
+                };
+                features.push(feature(
+                    &geometry,
+                    &[
+                        ("kind", Some(string("object"))),
+                        ("id", Some(string(&object.id))),
+                        ("name", object.name.as_deref().map(string)),
+                        ("road", Some(string(&road.id))),
+                        (
+                            "type",
+                            object.r#type.as_ref().map(ObjectType::as_str).map(string),
+                        ),
+                    ],
+                ));
+            }
+        }
+        format!(
+            r#"{{"type":"FeatureCollection","features":[{}]}}"#,
+            features.join(",")
+        )
+    }
+}
+
+/// A feature with the given geometry and the properties that are present
+fn feature(geometry: &str, properties: &[(&str, Option<String>)]) -> String {
+    let properties = properties
+        .iter()
+        .filter_map(|(key, value)| Some(format!("{}:{}", string(key), value.as_ref()?)))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(r#"{{"type":"Feature","geometry":{geometry},"properties":{{{properties}}}}}"#)
+}
+
+fn coordinates(points: &[Point]) -> String {
+    let points = points
+        .iter()
+        .map(|p| {
+            format!(
+                "[{},{}]",
+                number(p.x.get::<meter>()),
+                number(p.y.get::<meter>())
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", points.join(","))
+}
+
+/// The number as JSON, `null` if it is not finite as JSON has no such numbers
+fn number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+/// The text as JSON string
+pub(crate) fn string(text: &str) -> String {
+    let mut string = String::with_capacity(text.len() + 2);
+    string.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            '\n' => string.push_str("\\n"),
+            '\r' => string.push_str("\\r"),
+            '\t' => string.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(string, "\\u{:04x}", c as u32);
+            }
+            c => string.push(c),
+        }
+    }
+    string.push('"');
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+
+    #[test]
+    pub fn test_to_geojson() {
+        let drive = parse(
+            TestRoad::new("1")
+                .length(2.0)
+                .attribute("name", r#"Main "St""#)
+                .sides(&[], &[])
+                .objects(r#"<object id="pole" s="1" t="2" zOffset="0" type="pole"/>"#),
+        );
+
+        assert_eq!(
+            concat!(
+                r#"{"type":"FeatureCollection","features":["#,
+                r#"{"type":"Feature","geometry":{"type":"LineString","coordinates":[[0,0],[1,0],[2,0]]},"#,
+                r#""properties":{"kind":"road","id":"1","name":"Main \"St\"","junction":"-1","length":2}},"#,
+                r#"{"type":"Feature","geometry":{"type":"Point","coordinates":[1,2]},"#,
+                r#""properties":{"kind":"object","id":"pole","road":"1","type":"pole"}}"#,
+                "]}"
+            ),
+            drive.to_geojson()
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::core::OpenDrive;
+use crate::lane::Lane;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::fmt::Write;
+
+/// The surface of a single lane of a lane section, sampled at most every meter along the
+/// reference line and raised by the height of the lane. Both borders have the same number of
+/// samples.
+pub(crate) struct LaneSurface<'a> {
+    pub lane_id: i64,
+    pub lane: &'a Lane,
+    /// The inertial x-, y- and z-coordinates of the border closer to the center lane
+    pub inner: Vec<[f64; 3]>,
+    /// The inertial x-, y- and z-coordinates of the border farther away from the center lane
+    pub outer: Vec<[f64; 3]>,
+}
+
+/// The surfaces of all lanes of the road except the center lane, in the order of its lane
+/// sections
+pub(crate) fn lane_surfaces(road: &Road) -> Vec<LaneSurface<'_>> {
+    let mut surfaces = Vec::new();
+    for (section, start, end) in road.lanes.sections_with_ranges(road) {
+        let (start, end) = (
+            start.get::<meter>(),
+            end.get::<meter>().max(start.get::<meter>()),
+        );
+        let samples = (end - start).ceil().max(1.0) as usize;
+        let step = (end - start) / samples as f64;
+        for (lane_id, lane) in section.lanes().filter(|(id, _)| *id != 0) {
+            let mut inner = Vec::with_capacity(samples + 1);
+            let mut outer = Vec::with_capacity(samples + 1);
+            for index in 0..=samples {
+                let s = start + index as f64 * step;
+                let Some(borders) = section.lane_borders(lane_id, s - start) else {
+                    continue;
+                };
+                let offset = road.lanes.offset_at(s);
+                let height = lane.height_at(s - start);
+                let lift = |[x, y, z]: [f64; 3], height: f64| [x, y, z + height];
+                inner.push(lift(vertex(road, s, offset + borders.0), height.0));
+                outer.push(lift(vertex(road, s, offset + borders.1), height.1));
+            }
+            surfaces.push(LaneSurface {
+                lane_id,
+                lane,
+                inner,
+                outer,
+            });
+        }
+    }
+    surfaces
+}
+
+/// The inertial position at `s` and `t`, lifted by the elevation and tilted by the
+/// superelevation of the road
+fn vertex(road: &Road, s: f64, t: f64) -> [f64; 3] {
+    let point = road
+        .pose_at(Length::new::<meter>(s), Length::new::<meter>(t))
+        .point();
+    let elevation = road
+        .elevation_profile
+        .as_ref()
+        .map(|profile| profile.elevation_at(s))
+        .unwrap_or(0.0);
+    let roll = road.superelevation_at(Length::new::<meter>(s));
+    [
+        point.x.get::<meter>(),
+        point.y.get::<meter>(),
+        elevation + t * roll.get::<radian>().tan(),
+    ]
+}
+
+impl OpenDrive {
+    /// The lane surfaces of all roads as Wavefront OBJ mesh, for 3D viewers and engines. Each
+    /// lane of each lane section is an object `road_<road id>_lane_<lane id>` of two triangles
+    /// per meter. Vertices are inertial coordinates in meter with the elevation and the
+    /// superelevation of the road and the heights of the lanes, like raised sidewalks, the
+    /// lateral shape is ignored.
+    pub fn to_obj(&self) -> String {
+        let mut obj = String::new();
+        let mut vertices = 0;
+        for road in &self.road {
+            for surface in lane_surfaces(road) {
+                let _ = writeln!(obj, "o road_{}_lane_{}", road.id, surface.lane_id);
+                for [x, y, z] in surface.inner.iter().chain(&surface.outer) {
+                    let _ = writeln!(obj, "v {x} {y} {z}");
+                }
+                let samples = surface.inner.len();
+                // 1-based indices of the inner and outer border vertices
+                let inner = |index: usize| vertices + index + 1;
+                let outer = |index: usize| vertices + samples + index + 1;
+                for index in 1..samples {
+                    // counter-clockwise seen from above, on both sides of the center lane
+                    let (a, b, c, d) = (
+                        inner(index - 1),
+                        inner(index),
+                        outer(index),
+                        outer(index - 1),
+                    );
+                    if surface.lane_id < 0 {
+                        let _ = writeln!(obj, "f {a} {d} {c}\nf {a} {c} {b}");
+                    } else {
+                        let _ = writeln!(obj, "f {a} {b} {c}\nf {a} {c} {d}");
+                    }
+                }
+                vertices += 2 * samples;
+            }
+        }
+        obj
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_to_obj() {
+        let drive = parse(
+            TestRoad::new("1")
+                .length(1.0)
+                .lanes(
+                    r#"<laneSection s="0">
+                        <left><lane id="1" type="driving"><width sOffset="0" a="3" b="0" c="0" d="0"/></lane></left>
+                        <center><lane id="0" type="none"/></center>
+                        <right><lane id="-1" type="sidewalk"><width sOffset="0" a="2" b="0" c="0" d="0"/><height sOffset="0" inner="0.15" outer="0.25"/></lane></right>
+                    </laneSection>"#,
+                )
+                .child(r#"<elevationProfile><elevation s="0" a="2" b="0" c="0" d="0"/></elevationProfile>"#),
+        );
+
+        assert_eq!(
+            concat!(
+                "o road_1_lane_1\n",
+                "v 0 0 2\nv 1 0 2\nv 0 3 2\nv 1 3 2\n",
+                "f 1 2 4\nf 1 4 3\n",
+                "o road_1_lane_-1\n",
+                "v 0 0 2.15\nv 1 0 2.15\nv 0 -2 2.25\nv 1 -2 2.25\n",
+                "f 5 7 8\nf 5 8 6\n",
+            ),
+            drive.to_obj()
+        );
+        // halfway across the sidewalk
+        let z = drive.road[0].surface_z_at(Length::new::<meter>(0.5), Length::new::<meter>(-1.0));
+        assert!((z.get::<meter>() - 2.2).abs() < 1e-9);
+    }
+}
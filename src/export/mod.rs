@@ -0,0 +1,3 @@
+pub mod geojson;
+pub mod mesh;
+pub mod svg;
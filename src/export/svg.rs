@@ -0,0 +1,140 @@
+use crate::core::OpenDrive;
+use crate::export::mesh::lane_surfaces;
+use crate::lane::lane_type::LaneType;
+use crate::road::geometry::point::Point;
+use crate::units::prelude::*;
+use std::fmt::Write;
+use xml::escape::escape_str_attribute;
+
+impl OpenDrive {
+    /// The lanes, reference lines and objects of all roads as SVG image, for quick looks at a
+    /// map. Lanes are filled by their type, reference lines are white and objects are the
+    /// [`crate::object::Object::footprint`]s. The image spans the [`OpenDrive::extent`], one unit
+    /// is one meter and the y-axis points up, as in the inertial x/y-plane.
+    pub fn to_svg(&self) -> String {
+        let (west, north, width, height) = match self.extent() {
+            Some(e) => (
+                e.west.get::<meter>(),
+                e.north.get::<meter>(),
+                (e.east - e.west).get::<meter>().max(1.0),
+                (e.north - e.south).get::<meter>().max(1.0),
+            ),
+            None => (0.0, 0.0, 1.0, 1.0),
+        };
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}"><g transform="scale(1,-1)">"#,
+            west,
+            0.0 - north,
+            width,
+            height
+        );
+
+        for road in &self.road {
+            let id = escape_str_attribute(&road.id);
+            for surface in lane_surfaces(road) {
+                let points = surface
+                    .inner
+                    .iter()
+                    .chain(surface.outer.iter().rev())
+                    .map(|[x, y, _]| format!("{x},{y}"))
+                    .collect::<Vec<_>>();
+                let _ = write!(
+                    svg,
+                    r#"<polygon data-road="{id}" data-lane="{}" data-type="{}" points="{}" fill="{}"/>"#,
+                    surface.lane_id,
+                    surface.lane.r#type.as_str(),
+                    points.join(" "),
+                    fill(&surface.lane.r#type),
+                );
+            }
+        }
+
+        for road in &self.road {
+            let length = road.length.get::<meter>().max(0.0);
+            let samples = length.ceil().max(1.0) as usize;
+            let line = (0..=samples)
+                .map(|index| {
+                    let s = (index as f64).min(length);
+                    road.reference_pose(Length::new::<meter>(s)).point()
+                })
+                .collect::<Vec<_>>();
+            let _ = write!(
+                svg,
+                r##"<polyline data-road="{}" points="{}" fill="none" stroke="#ffffff" stroke-width="0.15"/>"##,
+                escape_str_attribute(&road.id),
+                points(&line)
+            );
+
+            for object in road.objects.iter().flat_map(|objects| &objects.object) {
+                let footprint = object.footprint(road);
+                let id = escape_str_attribute(&object.id);
+                if footprint.len() >= 3 {
+                    let _ = write!(
+                        svg,
+                        r##"<polygon data-object="{id}" points="{}" fill="#2e7d32"/>"##,
+                        points(&footprint)
+                    );
+                } else if let Some(point) = footprint.first() {
+                    let _ = write!(
+                        svg,
+                        r##"<circle data-object="{id}" cx="{}" cy="{}" r="0.25" fill="#2e7d32"/>"##,
+                        point.x.get::<meter>(),
+                        point.y.get::<meter>()
+                    );
+                }
+            }
+        }
+
+        svg.push_str("</g></svg>");
+        svg
+    }
+}
+
+fn points(points: &[Point]) -> String {
+    points
+        .iter()
+        .map(|p| format!("{},{}", p.x.get::<meter>(), p.y.get::<meter>()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The fill color of lanes of the given type
+fn fill(lane_type: &LaneType) -> &'static str {
+    if lane_type.is_drivable() || lane_type.is_restricted_vehicle() {
+        "#505050"
+    } else if lane_type.is_pedestrian() {
+        "#bdbdbd"
+    } else if lane_type.is_bicycle() {
+        "#a1887f"
+    } else if lane_type.is_parking() {
+        "#5c6bc0"
+    } else if lane_type.is_rail() {
+        "#795548"
+    } else {
+        "#8d8d8d"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+
+    #[test]
+    pub fn test_to_svg() {
+        let drive = parse(
+            TestRoad::new("a&b")
+                .length(1.0)
+                .sides(&[], &[("driving", 2.0)]),
+        );
+
+        assert_eq!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1 2"><g transform="scale(1,-1)">"#,
+                r##"<polygon data-road="a&amp;b" data-lane="-1" data-type="driving" points="0,0 1,0 1,-2 0,-2" fill="#505050"/>"##,
+                r##"<polyline data-road="a&amp;b" points="0,0 1,0" fill="none" stroke="#ffffff" stroke-width="0.15"/>"##,
+                "</g></svg>"
+            ),
+            drive.to_svg()
+        );
+    }
+}
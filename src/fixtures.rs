@@ -0,0 +1,196 @@
+//! Builders of the documents used by the tests of the crate
+
+use crate::core::OpenDrive;
+use std::fmt::{Display, Formatter};
+
+/// A `<road>` of a test document, by default a straight road of 100 m along the x-axis from the
+/// origin that belongs to no junction and has a single right driving lane of 3.5 m
+#[derive(Debug, Clone)]
+pub(crate) struct TestRoad {
+    id: String,
+    junction: String,
+    length: f64,
+    attributes: String,
+    link: String,
+    geometry: Option<String>,
+    origin: (f64, f64, f64),
+    curvature: Option<f64>,
+    lanes: String,
+    children: String,
+}
+
+impl TestRoad {
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            junction: "-1".to_string(),
+            length: 100.0,
+            attributes: String::new(),
+            link: String::new(),
+            geometry: None,
+            origin: (0.0, 0.0, 0.0),
+            curvature: None,
+            lanes: String::new(),
+            children: String::new(),
+        }
+        .sides(&[], &[("driving", 3.5)])
+    }
+
+    /// The length of the road and of its single geometry
+    pub fn length(mut self, length: f64) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// The start and heading of the single geometry
+    pub fn at(mut self, x: f64, y: f64, hdg: f64) -> Self {
+        self.origin = (x, y, hdg);
+        self
+    }
+
+    /// Makes the single geometry an arc of the given curvature instead of a line
+    pub fn arc(mut self, curvature: f64) -> Self {
+        self.curvature = Some(curvature);
+        self
+    }
+
+    /// Replaces the single geometry by the given `<geometry>` elements
+    pub fn geometry(mut self, geometry: &str) -> Self {
+        self.geometry = Some(geometry.to_string());
+        self
+    }
+
+    pub fn junction(mut self, junction: &str) -> Self {
+        self.junction = junction.to_string();
+        self
+    }
+
+    /// Adds an attribute to the `<road>`, like `name` or `rule`
+    pub fn attribute(mut self, name: &str, value: &str) -> Self {
+        self.attributes
+            .push_str(&format!(r#" {name}="{}""#, escape(value)));
+        self
+    }
+
+    /// The content of the `<link>`, which is left out if empty
+    pub fn link(mut self, link: &str) -> Self {
+        self.link = link.to_string();
+        self
+    }
+
+    /// A single lane section of lanes of the given types and widths, from the center outwards
+    pub fn sides(mut self, left: &[(&str, f64)], right: &[(&str, f64)]) -> Self {
+        let side = |name: &str, lanes: &[(&str, f64)], sign: i64| {
+            if lanes.is_empty() {
+                return String::new();
+            }
+            let lanes = lanes
+                .iter()
+                .enumerate()
+                .map(|(index, (r#type, width))| {
+                    let id = sign * (index as i64 + 1);
+                    format!(
+                        r#"<lane id="{id}" type="{type}"><width sOffset="0" a="{width}" b="0" c="0" d="0"/></lane>"#
+                    )
+                })
+                .collect::<String>();
+            format!("<{name}>{lanes}</{name}>")
+        };
+        self.lanes = format!(
+            r#"<laneSection s="0">{}<center><lane id="0" type="none"/></center>{}</laneSection>"#,
+            side("left", left, 1),
+            side("right", right, -1),
+        );
+        self
+    }
+
+    /// Replaces the lane sections by the given `<laneSection>` and `<laneOffset>` elements
+    pub fn lanes(mut self, lanes: &str) -> Self {
+        self.lanes = lanes.to_string();
+        self
+    }
+
+    /// Adds a child element after the `<lanes>`, like `<objects>` or `<signals>`
+    pub fn child(mut self, child: &str) -> Self {
+        self.children.push_str(child);
+        self
+    }
+
+    /// Adds the given `<signal>` elements within `<signals>`
+    pub fn signals(self, signals: &str) -> Self {
+        self.child(&format!("<signals>{signals}</signals>"))
+    }
+
+    /// Adds the given `<object>` elements within `<objects>`
+    pub fn objects(self, objects: &str) -> Self {
+        self.child(&format!("<objects>{objects}</objects>"))
+    }
+}
+
+impl Display for TestRoad {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            id,
+            junction,
+            length,
+            attributes,
+            link,
+            geometry,
+            origin: (x, y, hdg),
+            curvature,
+            lanes,
+            children,
+        } = self;
+        write!(
+            f,
+            r#"<road id="{}" junction="{junction}" length="{length}"{attributes}>"#,
+            escape(id)
+        )?;
+        if !link.is_empty() {
+            write!(f, "<link>{link}</link>")?;
+        }
+        match geometry {
+            Some(geometry) => write!(f, "<planView>{geometry}</planView>")?,
+            None => {
+                let shape = match curvature {
+                    Some(curvature) => format!(r#"<arc curvature="{curvature}"/>"#),
+                    None => "<line/>".to_string(),
+                };
+                write!(
+                    f,
+                    r#"<planView><geometry s="0" x="{x}" y="{y}" hdg="{hdg}" length="{length}">{shape}</geometry></planView>"#
+                )?;
+            }
+        }
+        write!(f, "<lanes>{lanes}</lanes>{children}</road>")
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+/// A document of revision 1.7 with the given content after the `<header>`
+pub(crate) fn document(content: impl Display) -> String {
+    document_of_revision(7, content)
+}
+
+/// A document of revision 1.`minor` with the given content after the `<header>`
+pub(crate) fn document_of_revision(minor: u32, content: impl Display) -> String {
+    format!(
+        r#"<?xml version="1.0" standalone="yes"?><OpenDRIVE><header revMajor="1" revMinor="{minor}"/>{content}</OpenDRIVE>"#
+    )
+}
+
+/// Parses the [`document`] of the given content
+pub(crate) fn parse(content: impl Display) -> OpenDrive {
+    OpenDrive::from_xml_str(&document(content)).unwrap()
+}
+
+/// Parses the [`document_of_revision`] 1.`minor` of the given content
+pub(crate) fn parse_revision(minor: u32, content: impl Display) -> OpenDrive {
+    OpenDrive::from_xml_str(&document_of_revision(minor, content)).unwrap()
+}
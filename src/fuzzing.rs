@@ -1,3 +1,10 @@
+use crate::core::ids::{IdKind, IdScheme, IdUse};
+use crate::core::OpenDrive;
+use crate::road::element_type::ElementType;
+use crate::road::Road;
+use crate::signal::reference::ElementType as ReferenceType;
+use crate::units::prelude::*;
+use std::collections::HashMap;
 use std::ops::RangeInclusive;
 
 pub trait NotNan {
@@ -49,3 +56,244 @@ impl<'a> ArbitraryStrings for arbitrary::Unstructured<'a> {
         Ok(string)
     }
 }
+
+pub trait ArbitraryOther {
+    /// A value that is unknown to the schema for the `Other` variant of an enum, which is read
+    /// back as such with [`crate::parser::ParseOptions::tolerant_enums`]
+    fn arbitrary_other(&mut self) -> arbitrary::Result<String>;
+}
+
+impl<'a> ArbitraryOther for arbitrary::Unstructured<'a> {
+    fn arbitrary_other(&mut self) -> arbitrary::Result<String> {
+        // none of the values of the schema contains a dash
+        Ok(format!(
+            "vendor-{}",
+            self.arbitrary_string(1..=8, &['a'..='z'])?
+        ))
+    }
+}
+
+/// A complete document that is internally consistent, for differential fuzzing of the parser and
+/// writer and of consumers that expect valid input:
+///  - ids are unique per [`IdKind`] and every link and reference points to an existing element
+///  - geometries are consecutive and their positive lengths add up to the length of the road
+///  - s-coordinates of lane sections, objects, signals and profiles are within the road and
+///    ordered, as by [`OpenDrive::normalize`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsistentOpenDrive(pub OpenDrive);
+
+impl<'a> arbitrary::Arbitrary<'a> for ConsistentOpenDrive {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let road = (0..u.int_in_range(1..=16)?)
+            .map(|_| u.arbitrary())
+            .collect::<arbitrary::Result<_>>()?;
+        let junction = (0..u.int_in_range(0..=4)?)
+            .map(|_| u.arbitrary())
+            .collect::<arbitrary::Result<_>>()?;
+        let mut drive = OpenDrive {
+            road,
+            junction,
+            controller: u.arbitrary()?,
+            junction_group: u.arbitrary()?,
+            station: u.arbitrary()?,
+            header: u.arbitrary()?,
+            additional_data: u.arbitrary()?,
+        };
+        drive.renumber_ids(&IdScheme::Sequential { start: 1 });
+        resolve_references(&mut drive, u);
+        for road in &mut drive.road {
+            fit_to_length(road);
+        }
+        drive.normalize();
+        Ok(Self(drive))
+    }
+}
+
+/// Replaces every reference to a missing id by an arbitrary id of the same kind, references for
+/// which there is no such id are removed
+fn resolve_references(drive: &mut OpenDrive, u: &mut arbitrary::Unstructured) {
+    let mut defined = HashMap::<IdKind, Vec<String>>::new();
+    drive.visit_ids(&mut |kind, usage, id| {
+        if usage == IdUse::Definition {
+            defined.entry(kind).or_default().push(id.clone());
+        }
+    });
+    drive.visit_ids(&mut |kind, usage, id| {
+        let ids = defined.get(&kind).map(Vec::as_slice).unwrap_or_default();
+        if usage == IdUse::Reference && !ids.contains(id) {
+            if let Some(defined) = u.choose(ids).ok().or(ids.first()) {
+                *id = defined.clone();
+            }
+        }
+    });
+
+    let undefined = |kind| !defined.contains_key(&kind);
+    if undefined(IdKind::Road) {
+        drive.junction.clear();
+        drive.junction_group.clear();
+        drive.station.clear();
+    }
+    if undefined(IdKind::Junction) {
+        drive.junction_group.clear();
+    }
+    if undefined(IdKind::Signal) {
+        drive.controller.clear();
+    }
+    if undefined(IdKind::Controller) || undefined(IdKind::Signal) {
+        for junction in &mut drive.junction {
+            junction.controller.clear();
+        }
+    }
+    for road in &mut drive.road {
+        if undefined(IdKind::Junction) {
+            road.junction = "-1".to_string();
+            if let Some(link) = &mut road.link {
+                for linked in [&mut link.predecessor, &mut link.successor] {
+                    if linked
+                        .as_ref()
+                        .is_some_and(|l| l.element_type == Some(ElementType::Junction))
+                    {
+                        *linked = None;
+                    }
+                }
+            }
+        }
+        if let Some(signals) = &mut road.signals {
+            if undefined(IdKind::Object) {
+                for signal in &mut signals.signal {
+                    signal
+                        .reference
+                        .retain(|r| r.element_type != ReferenceType::Object);
+                }
+            }
+            if undefined(IdKind::Signal) {
+                signals.signal_reference.clear();
+            }
+        }
+        if let Some(objects) = &mut road.objects {
+            if undefined(IdKind::Object) {
+                objects.object_reference.clear();
+            }
+        }
+    }
+}
+
+/// Makes the geometries of the road consecutive with positive lengths, sets the length of the
+/// road accordingly and moves s-coordinates onto the road
+fn fit_to_length(road: &mut Road) {
+    let mut s = 0.0;
+    for geometry in road.plan_view.geometry.iter_mut() {
+        geometry.s = Length::new::<meter>(s);
+        geometry.length =
+            Length::new::<meter>(finite(geometry.length.get::<meter>()).abs().clamp(0.1, 1e4));
+        geometry.x = Length::new::<meter>(finite(geometry.x.get::<meter>()));
+        geometry.y = Length::new::<meter>(finite(geometry.y.get::<meter>()));
+        geometry.hdg = Angle::new::<radian>(finite(geometry.hdg.get::<radian>()));
+        s += geometry.length.get::<meter>();
+    }
+    road.length = Length::new::<meter>(s);
+
+    let length = s;
+    let on_road = |s: f64| finite(s).abs() % length;
+    let on_road_length = |s: Length| Length::new::<meter>(on_road(s.get::<meter>()));
+    for section in road.lanes.lane_section.iter_mut() {
+        section.s = on_road(section.s);
+    }
+    road.lanes.lane_section.sort_by(|a, b| a.s.total_cmp(&b.s));
+    road.lanes.lane_section.first_mut().s = 0.0;
+    for offset in &mut road.lanes.lane_offset {
+        offset.s = on_road(offset.s);
+    }
+    for road_type in &mut road.r#type {
+        road_type.s = on_road_length(road_type.s);
+    }
+    if let Some(profile) = &mut road.elevation_profile {
+        for elevation in &mut profile.elevation {
+            elevation.s = on_road(elevation.s);
+        }
+    }
+    if let Some(profile) = &mut road.lateral_profile {
+        for super_elevation in &mut profile.super_elevation {
+            super_elevation.s = on_road(super_elevation.s);
+        }
+        for crossfall in &mut profile.crossfall {
+            crossfall.s = on_road(crossfall.s);
+        }
+        for shape in &mut profile.shape {
+            shape.s = on_road(shape.s);
+        }
+    }
+    if let Some(objects) = &mut road.objects {
+        for object in &mut objects.object {
+            object.s = on_road_length(object.s);
+        }
+    }
+    if let Some(signals) = &mut road.signals {
+        for signal in &mut signals.signal {
+            signal.s = on_road_length(signal.s);
+        }
+    }
+}
+
+/// The value, or zero if it is infinite
+fn finite(value: f64) -> f64 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::ids::{IdKind, IdUse};
+    use crate::fuzzing::ConsistentOpenDrive;
+    use crate::units::prelude::*;
+    use arbitrary::{Arbitrary, Unstructured};
+    use std::collections::HashSet;
+
+    #[test]
+    pub fn test_consistent_open_drive() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut roads = 0;
+        for _ in 0..32 {
+            let bytes = (0..4096)
+                .map(|_| {
+                    // xorshift, for deterministic pseudo-random input
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect::<Vec<_>>();
+            let Ok(ConsistentOpenDrive(mut drive)) =
+                ConsistentOpenDrive::arbitrary(&mut Unstructured::new(&bytes))
+            else {
+                continue;
+            };
+
+            let mut definitions = HashSet::new();
+            let mut references = Vec::new();
+            drive.visit_ids(&mut |kind: IdKind, usage, id: &mut String| match usage {
+                IdUse::Definition => assert!(definitions.insert((kind, id.clone()))),
+                IdUse::Reference => references.push((kind, id.clone())),
+            });
+            assert!(references.iter().all(|r| definitions.contains(r)));
+
+            roads += drive.road.len();
+            for road in &drive.road {
+                let mut s = Length::new::<meter>(0.0);
+                for geometry in road.plan_view.geometry.iter() {
+                    assert_eq!(s, geometry.s);
+                    assert!(geometry.length > Length::new::<meter>(0.0));
+                    s += geometry.length;
+                }
+                assert_eq!(s, road.length);
+                let sections = &road.lanes.lane_section;
+                assert!(sections.windows(2).all(|w| w[0].s <= w[1].s));
+                assert!(sections.iter().all(|l| l.s < road.length.get::<meter>()));
+            }
+        }
+        assert!(roads > 0);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::core::additional_data::AdditionalData;
+use crate::core::header::Header;
+use crate::core::user_data::{Element, UserData};
+use crate::core::OpenDrive;
+use crate::junction::contact_point::ContactPoint;
+use crate::lane::center::Center;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::lane_section::LaneSection;
+use crate::lane::lane_type::LaneType;
+use crate::lane::right::Right;
+use crate::lane::right_lane::RightLane;
+use crate::lane::width::Width;
+use crate::lane::Lane;
+use crate::road::element_type::ElementType;
+use crate::road::geometry::geometry_type::GeometryType;
+use crate::road::geometry::plan_view::PlanView;
+use crate::road::geometry::Geometry;
+use crate::road::link::Link;
+use crate::road::predecessor_successor::PredecessorSuccessor;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::collections::HashMap;
+use vec1::Vec1;
+
+/// Documents that are valid according to the schema, but exercise the limits of readers of
+/// ASAM OpenDRIVE, to stress-test their ingestion. The documents are deterministic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeCase {
+    /// Lane sections of zero length, as consecutive lane sections start at the same `@s`, and a
+    /// lane section at the very end of the road
+    ZeroLengthLaneSections,
+    /// Lanes whose width is zero everywhere or becomes zero within the lane section
+    ZeroWidthLanes,
+    /// The given number of linked roads of 10m each, arranged in rows of 1000 roads
+    ManyRoads { count: usize },
+    /// Roads far from the origin, with headings of many revolutions and with lengths close to
+    /// zero and far beyond real roads
+    ExtremeCoordinates,
+    /// A `<userData>` of the header with child elements nested to the given depth. Readers that
+    /// recurse into child elements, like this crate, may need a larger stack than the default of
+    /// spawned threads for a thousand levels.
+    DeepUserData { depth: usize },
+}
+
+impl EdgeCase {
+    /// All edge cases, with a million roads and user data nested a thousand levels deep
+    pub const ALL: [EdgeCase; 5] = [
+        EdgeCase::ZeroLengthLaneSections,
+        EdgeCase::ZeroWidthLanes,
+        EdgeCase::ManyRoads { count: 1_000_000 },
+        EdgeCase::ExtremeCoordinates,
+        EdgeCase::DeepUserData { depth: 1_000 },
+    ];
+
+    /// A short name of the edge case, suitable as a file name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EdgeCase::ZeroLengthLaneSections => "zero-length-lane-sections",
+            EdgeCase::ZeroWidthLanes => "zero-width-lanes",
+            EdgeCase::ManyRoads { .. } => "many-roads",
+            EdgeCase::ExtremeCoordinates => "extreme-coordinates",
+            EdgeCase::DeepUserData { .. } => "deep-user-data",
+        }
+    }
+
+    /// The document of this edge case
+    pub fn generate(&self) -> OpenDrive {
+        let header = Header {
+            name: Some(self.name().to_string()),
+            date: None,
+            ..Header::default()
+        };
+        let mut drive = OpenDrive {
+            header,
+            ..OpenDrive::default()
+        };
+        match *self {
+            EdgeCase::ZeroLengthLaneSections => {
+                let mut road = road("1", 0.0, 0.0, 0.0, 100.0);
+                let section = |s| LaneSection {
+                    s,
+                    ..lane_section(3.5, 0.0)
+                };
+                road.lanes.lane_section = Vec1::try_from_vec(vec![
+                    section(0.0),
+                    section(0.0),
+                    section(50.0),
+                    section(50.0),
+                    section(50.0),
+                    section(100.0),
+                ])
+                .expect("lane sections");
+                drive.road.push(road);
+            }
+            EdgeCase::ZeroWidthLanes => {
+                let mut zero = road("1", 0.0, 0.0, 0.0, 100.0);
+                zero.lanes.lane_section = Vec1::new(lane_section(0.0, 0.0));
+                let mut vanishing = road("2", 0.0, 10.0, 0.0, 100.0);
+                vanishing.lanes.lane_section = Vec1::new(lane_section(3.5, -0.035));
+                drive.road.extend([zero, vanishing]);
+            }
+            EdgeCase::ManyRoads { count } => {
+                drive.road.reserve(count);
+                for index in 0..count {
+                    let (column, row) = (index % 1000, index / 1000);
+                    let mut road = road(
+                        (index + 1).to_string(),
+                        column as f64 * 10.0,
+                        row as f64 * 10.0,
+                        0.0,
+                        10.0,
+                    );
+                    let linked = |id: usize, contact_point| PredecessorSuccessor {
+                        contact_point: Some(contact_point),
+                        element_dir: None,
+                        element_id: id.to_string(),
+                        element_s: None,
+                        element_type: Some(ElementType::Road),
+                    };
+                    road.link = Some(Link {
+                        predecessor: (column > 0).then(|| linked(index, ContactPoint::End)),
+                        successor: (column < 999 && index + 1 < count)
+                            .then(|| linked(index + 2, ContactPoint::Start)),
+                        additional_data: AdditionalData::default(),
+                    });
+                    drive.road.push(road);
+                }
+            }
+            EdgeCase::ExtremeCoordinates => {
+                drive.road.extend([
+                    road("1", 1e15, -1e15, 0.0, 100.0),
+                    road("2", -1e15, 1e15, 1000.0 * std::f64::consts::PI, 100.0),
+                    road("3", 0.0, 0.0, -1e6, 1e-9),
+                    road("4", 0.0, 0.0, f64::MIN_POSITIVE, 1e9),
+                ]);
+            }
+            EdgeCase::DeepUserData { depth } => {
+                let mut element = Element {
+                    name: "level".to_string(),
+                    attributes: HashMap::from([("depth".to_string(), depth.to_string())]),
+                    children: Vec::new(),
+                };
+                for level in (1..depth).rev() {
+                    element = Element {
+                        name: "level".to_string(),
+                        attributes: HashMap::from([("depth".to_string(), level.to_string())]),
+                        children: vec![element],
+                    };
+                }
+                drive.header.additional_data.user_data.push(UserData {
+                    code: self.name().to_string(),
+                    value: None,
+                    elements: if depth > 0 { vec![element] } else { Vec::new() },
+                });
+                drive.road.push(road("1", 0.0, 0.0, 0.0, 100.0));
+            }
+        }
+        drive
+    }
+}
+
+/// A straight road of a single right lane of 3.5m, that belongs to no junction
+fn road(id: impl Into<String>, x: f64, y: f64, hdg: f64, length: f64) -> Road {
+    let mut road = Road::new(
+        id,
+        PlanView::new(Geometry {
+            hdg: Angle::new::<radian>(hdg),
+            length: Length::new::<meter>(length),
+            s: Length::new::<meter>(0.0),
+            x: Length::new::<meter>(x),
+            y: Length::new::<meter>(y),
+            r#type: GeometryType::Line(Default::default()),
+            additional_data: AdditionalData::default(),
+        }),
+    );
+    road.lanes.lane_section = Vec1::new(lane_section(3.5, 0.0));
+    road
+}
+
+/// A lane section at `s = 0` of a single right driving lane with the given linear width
+fn lane_section(a: f64, b: f64) -> LaneSection {
+    LaneSection {
+        right: Some(Right {
+            lane: Vec1::new(RightLane {
+                id: -1,
+                base: Lane {
+                    choice: vec![LaneChoice::Width(Width {
+                        a,
+                        b,
+                        c: 0.0,
+                        d: 0.0,
+                        s_offset: Length::new::<meter>(0.0),
+                    })],
+                    r#type: LaneType::Driving,
+                    ..Lane::default()
+                },
+            }),
+            additional_data: AdditionalData::default(),
+        }),
+        center: Center::default(),
+        ..LaneSection::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::generate::corpus::EdgeCase;
+
+    #[test]
+    pub fn test_edge_cases_round_trip() {
+        for case in EdgeCase::ALL {
+            let case = match case {
+                EdgeCase::ManyRoads { .. } => EdgeCase::ManyRoads { count: 2_500 },
+                EdgeCase::DeepUserData { .. } => EdgeCase::DeepUserData { depth: 200 },
+                case => case,
+            };
+            let drive = case.generate();
+            let xml = drive.to_xml_string().unwrap();
+            assert_eq!(drive, OpenDrive::from_xml_str(&xml).unwrap(), "{case:?}");
+        }
+    }
+}
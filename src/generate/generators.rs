@@ -0,0 +1,816 @@
+use crate::core::additional_data::AdditionalData;
+use crate::core::header::Header;
+use crate::core::validated::SortedByS;
+use crate::core::OpenDrive;
+use crate::junction::connection::Connection;
+use crate::junction::contact_point::ContactPoint;
+use crate::junction::lane_link::LaneLink;
+use crate::junction::Junction;
+use crate::lane::center::Center;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::lane_section::LaneSection;
+use crate::lane::lane_type::LaneType;
+use crate::lane::lanes::Lanes;
+use crate::lane::left::Left;
+use crate::lane::left_lane::LeftLane;
+use crate::lane::right::Right;
+use crate::lane::right_lane::RightLane;
+use crate::lane::width::Width;
+use crate::lane::Lane;
+use crate::road::element_type::ElementType;
+use crate::road::geometry::arc::Arc;
+use crate::road::geometry::geometry_type::GeometryType;
+use crate::road::geometry::plan_view::PlanView;
+use crate::road::geometry::pose::Pose;
+use crate::road::geometry::synthesis::ConnectOptions;
+use crate::road::geometry::Geometry;
+use crate::road::link::Link;
+use crate::road::predecessor_successor::PredecessorSuccessor;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::collections::HashMap;
+use std::f64::consts::{FRAC_PI_2, PI};
+use vec1::Vec1;
+
+/// The width of all lanes of the standard maps
+const LANE_WIDTH: f64 = 3.5;
+/// The distance of the roads from the center of the intersections of the grid city
+const INTERSECTION: f64 = 10.0;
+/// The length of the junctions along the highways of the cloverleaf, where ramps leave or join
+const RAMP_JUNCTION: f64 = 40.0;
+/// The length of the roads leading away from roundabouts and cloverleafs
+const ARM_LENGTH: f64 = 100.0;
+
+/// Maps of common road layouts with parameters, for unit tests and smoke tests of simulators.
+/// The documents are deterministic and valid: plan views are continuous across all links,
+/// junctions connect the lanes of their roads by lane links and the header declares the extent
+/// of the map. All lanes are driving lanes of 3.5m for right-hand traffic, the ids of roads and
+/// junctions are consecutive numbers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StandardMap {
+    /// A straight road along the x-axis with the given number of lanes in each direction, at
+    /// least one
+    Highway { lanes: usize, length: Length },
+    /// A single closed road of a lane in each direction, forming two loops of the given radius
+    /// that cross at the origin without junction, as seen from above a bridge. The radius is at
+    /// least 10m.
+    FigureEight { radius: Length },
+    /// `columns` by `rows` intersections `block` apart along the axes, connected by roads of a
+    /// lane in each direction. Each intersection of at least two roads is a junction with
+    /// connections from each of its roads to all others, there are no U-turns. Blocks are at
+    /// least 30m.
+    GridCity {
+        columns: usize,
+        rows: usize,
+        block: Length,
+    },
+    /// Two highways with the given number of lanes in each direction, at least one, crossing at
+    /// the origin without junction, as seen from above a bridge. Each quadrant has a loop ramp
+    /// of the given radius for turning left and an outer ramp of six times the radius for
+    /// turning right. The ramps of a lane leave and join the outermost lanes of the highways at
+    /// junctions. The loops have a radius of at least 20m.
+    Cloverleaf { lanes: usize, loop_radius: Length },
+    /// A ring of one lane with the given radius, driven counter-clockwise, and the given number
+    /// of evenly spaced arms, at least one, of a lane in each direction. Each arm joins the ring
+    /// at a junction. The radius is at least 10m.
+    Roundabout { arms: usize, radius: Length },
+}
+
+impl StandardMap {
+    /// A short name of the map, suitable as a file name
+    pub fn name(&self) -> &'static str {
+        match self {
+            StandardMap::Highway { .. } => "highway",
+            StandardMap::FigureEight { .. } => "figure-eight",
+            StandardMap::GridCity { .. } => "grid-city",
+            StandardMap::Cloverleaf { .. } => "cloverleaf",
+            StandardMap::Roundabout { .. } => "roundabout",
+        }
+    }
+
+    /// The document of this map
+    pub fn generate(&self) -> OpenDrive {
+        let mut network = Network::default();
+        match *self {
+            StandardMap::Highway { lanes, length } => {
+                network.highway(lanes.max(1), length.get::<meter>().max(0.0))
+            }
+            StandardMap::FigureEight { radius } => {
+                network.figure_eight(radius.get::<meter>().max(10.0))
+            }
+            StandardMap::GridCity {
+                columns,
+                rows,
+                block,
+            } => network.grid_city(columns, rows, block.get::<meter>().max(3.0 * INTERSECTION)),
+            StandardMap::Cloverleaf { lanes, loop_radius } => {
+                network.cloverleaf(lanes.max(1), loop_radius.get::<meter>().max(20.0))
+            }
+            StandardMap::Roundabout { arms, radius } => {
+                network.roundabout(arms.max(1), radius.get::<meter>().max(10.0))
+            }
+        }
+
+        let mut drive = OpenDrive {
+            header: Header {
+                name: Some(self.name().to_string()),
+                date: None,
+                ..Header::default()
+            },
+            road: network.roads,
+            junction: network.junctions,
+            ..OpenDrive::default()
+        };
+        let mut header = drive.header.clone();
+        header.recompute_extent(&drive);
+        drive.header = header;
+        drive
+    }
+}
+
+/// A piece of a plan view, see [`plan_view`]
+enum Segment {
+    Line { length: f64 },
+    Arc { length: f64, curvature: f64 },
+}
+
+/// The lanes of a road at its contact point with a junction, ordered from the reference line of
+/// the connecting road outwards
+struct Approach<'a> {
+    road: &'a str,
+    contact_point: ContactPoint,
+    lanes: Vec<i64>,
+}
+
+impl<'a> Approach<'a> {
+    fn new(
+        road: &'a str,
+        contact_point: ContactPoint,
+        lanes: impl IntoIterator<Item = i64>,
+    ) -> Self {
+        Self {
+            road,
+            contact_point,
+            lanes: lanes.into_iter().collect(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Network {
+    roads: Vec<Road>,
+    junctions: Vec<Junction>,
+    ids: usize,
+}
+
+impl Network {
+    /// The next unused id, shared by roads and junctions
+    fn id(&mut self) -> String {
+        self.ids += 1;
+        self.ids.to_string()
+    }
+
+    fn ids(&mut self, count: usize) -> Vec<String> {
+        (0..count).map(|_| self.id()).collect()
+    }
+
+    fn add_road(
+        &mut self,
+        id: &str,
+        plan_view: PlanView,
+        lanes: Lanes,
+        predecessor: Option<PredecessorSuccessor>,
+        successor: Option<PredecessorSuccessor>,
+    ) {
+        let mut road = Road::new(id, plan_view);
+        road.lanes = lanes;
+        if predecessor.is_some() || successor.is_some() {
+            road.link = Some(Link {
+                predecessor,
+                successor,
+                additional_data: AdditionalData::default(),
+            });
+        }
+        self.roads.push(road);
+    }
+
+    /// Adds a connecting road to the junction that leads the lanes of `from` onto the lanes of
+    /// `to`, both approaches need the same number of lanes. Its reference line starts at the
+    /// inner border of the first lane of `from` and ends at the inner border of the first lane
+    /// of `to`, its right lanes carry the traffic.
+    fn connect(&mut self, junction: &str, from: &Approach, to: &Approach) {
+        let start = self.pose(from, true);
+        let end = self.pose(to, false);
+        let id = self.id();
+        let plan_view = PlanView::connect(start.into(), end.into(), ConnectOptions::default());
+        let mut road = Road::new(id.clone(), plan_view);
+        road.junction = junction.to_string();
+        road.link = Some(Link {
+            predecessor: Some(road_link(from.road, from.contact_point.clone())),
+            successor: Some(road_link(to.road, to.contact_point.clone())),
+            additional_data: AdditionalData::default(),
+        });
+        road.lanes = lanes(0, from.lanes.len(), |lane| {
+            let index = (-lane - 1) as usize;
+            (Some(from.lanes[index]), Some(to.lanes[index]))
+        });
+        self.roads.push(road);
+
+        let mut connection = Connection {
+            incoming_road: Some(from.road.to_string()),
+            connecting_road: Some(id),
+            contact_point: Some(ContactPoint::Start),
+            lane_link: (1..)
+                .zip(&from.lanes)
+                .map(|(index, &from)| LaneLink { from, to: -index })
+                .collect(),
+            ..Connection::default()
+        };
+        match self.junctions.iter_mut().find(|j| j.id == junction) {
+            Some(existing) => {
+                connection.id = existing.connection.len().to_string();
+                existing.connection.push(connection);
+            }
+            None => {
+                connection.id = "0".to_string();
+                self.junctions.push(Junction::new(junction, connection));
+            }
+        }
+    }
+
+    /// The pose at the inner border of the first lane of the approach, heading into the junction
+    /// if `incoming`, otherwise out of it
+    fn pose(&self, approach: &Approach, incoming: bool) -> Pose {
+        let road = self
+            .roads
+            .iter()
+            .find(|road| road.id == approach.road)
+            .expect("roads are added before the junctions connecting them");
+        let s = match approach.contact_point {
+            ContactPoint::Start => Length::new::<meter>(0.0),
+            ContactPoint::End => road.length,
+        };
+        let first = approach.lanes[0];
+        let t = first.signum() as f64 * (first.abs() - 1) as f64 * LANE_WIDTH;
+        let pose = road.pose_at(s, Length::new::<meter>(t));
+        if incoming == (approach.contact_point == ContactPoint::End) {
+            pose
+        } else {
+            Pose {
+                hdg: pose.hdg + Angle::new::<radian>(PI),
+                ..pose
+            }
+        }
+    }
+
+    fn highway(&mut self, lanes_per_direction: usize, length: f64) {
+        let id = self.id();
+        self.add_road(
+            &id,
+            plan_view(pose(0.0, 0.0, 0.0), &[Segment::Line { length }]),
+            lanes(lanes_per_direction, lanes_per_direction, |_| (None, None)),
+            None,
+            None,
+        );
+    }
+
+    fn figure_eight(&mut self, radius: f64) {
+        let id = self.id();
+        let arc = |curvature| Segment::Arc {
+            length: 1.5 * PI * radius,
+            curvature,
+        };
+        let segments = [
+            Segment::Line { length: radius },
+            arc(-1.0 / radius),
+            Segment::Line {
+                length: 2.0 * radius,
+            },
+            arc(1.0 / radius),
+            Segment::Line { length: radius },
+        ];
+        self.add_road(
+            &id,
+            plan_view(pose(0.0, 0.0, PI / 4.0), &segments),
+            lanes(1, 1, |lane| (Some(lane), Some(lane))),
+            Some(road_link(&id, ContactPoint::End)),
+            Some(road_link(&id, ContactPoint::Start)),
+        );
+    }
+
+    fn grid_city(&mut self, columns: usize, rows: usize, block: f64) {
+        let roads_at = |column: usize, row: usize| {
+            usize::from(column > 0)
+                + usize::from(column + 1 < columns)
+                + usize::from(row > 0)
+                + usize::from(row + 1 < rows)
+        };
+        let mut junctions = HashMap::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                if roads_at(column, row) >= 2 {
+                    junctions.insert((column, row), self.id());
+                }
+            }
+        }
+
+        let mut approaches = HashMap::<_, Vec<(String, ContactPoint)>>::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                let (x, y) = (column as f64 * block, row as f64 * block);
+                let neighbors = [
+                    (
+                        column + 1 < columns,
+                        (column + 1, row),
+                        pose(x + INTERSECTION, y, 0.0),
+                    ),
+                    (
+                        row + 1 < rows,
+                        (column, row + 1),
+                        pose(x, y + INTERSECTION, FRAC_PI_2),
+                    ),
+                ];
+                for (_, next, start) in neighbors.into_iter().filter(|(exists, ..)| *exists) {
+                    let id = self.id();
+                    let junction_link = |at| junctions.get(&at).map(|j: &String| junction_link(j));
+                    self.add_road(
+                        &id,
+                        plan_view(
+                            start,
+                            &[Segment::Line {
+                                length: block - 2.0 * INTERSECTION,
+                            }],
+                        ),
+                        lanes(1, 1, |_| (None, None)),
+                        junction_link((column, row)),
+                        junction_link(next),
+                    );
+                    let mut approach = |at, contact_point| {
+                        approaches
+                            .entry(at)
+                            .or_default()
+                            .push((id.clone(), contact_point))
+                    };
+                    approach((column, row), ContactPoint::Start);
+                    approach(next, ContactPoint::End);
+                }
+            }
+        }
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let Some(junction) = junctions.get(&(column, row)) else {
+                    continue;
+                };
+                let roads = &approaches[&(column, row)];
+                for (from, from_contact) in roads {
+                    for (to, to_contact) in roads.iter().filter(|(to, _)| to != from) {
+                        let toward = |contact: &ContactPoint| match contact {
+                            ContactPoint::Start => 1,
+                            ContactPoint::End => -1,
+                        };
+                        self.connect(
+                            junction,
+                            &Approach::new(from, from_contact.clone(), [toward(from_contact)]),
+                            &Approach::new(to, to_contact.clone(), [-toward(to_contact)]),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// The arms point along the axes, numbered counter-clockwise from the positive x-axis. Each
+    /// arm has an inner junction, where the loop ramps meet it, and an outer junction, where the
+    /// outer ramps meet it. The ramps of quadrant `k` lie between arm `k` and the arm before it.
+    fn cloverleaf(&mut self, lanes_per_direction: usize, loop_radius: f64) {
+        let n = lanes_per_direction as i64;
+        let offset = n as f64 * LANE_WIDTH + 2.0;
+        let outer_radius = 6.0 * loop_radius;
+        let (inner, outer) = (loop_radius + offset, outer_radius + offset);
+        let half = RAMP_JUNCTION / 2.0;
+        let [inner_junctions, outer_junctions] = [self.ids(4), self.ids(4)];
+        let [near, middle, far, loops, ramps] = [
+            self.ids(4),
+            self.ids(4),
+            self.ids(4),
+            self.ids(4),
+            self.ids(4),
+        ];
+        let before = |k: usize| (k + 3) % 4;
+        let after = |k: usize| (k + 1) % 4;
+
+        for k in 0..4 {
+            let at = |x: f64, y: f64, hdg: f64| rotated(pose(x, y, hdg), k as f64 * FRAC_PI_2);
+            let highway = |length| plan_view(at(0.0, 0.0, 0.0), &[Segment::Line { length }]);
+            self.add_road(
+                &near[k],
+                highway(inner - half),
+                lanes(n as usize, n as usize, |lane| (Some(-lane), None)),
+                Some(road_link(&near[(k + 2) % 4], ContactPoint::Start)),
+                Some(junction_link(&inner_junctions[k])),
+            );
+            self.add_road(
+                &middle[k],
+                plan_view(
+                    at(inner + half, 0.0, 0.0),
+                    &[Segment::Line {
+                        length: outer - inner - 2.0 * half,
+                    }],
+                ),
+                lanes(n as usize, n as usize, |_| (None, None)),
+                Some(junction_link(&inner_junctions[k])),
+                Some(junction_link(&outer_junctions[k])),
+            );
+            self.add_road(
+                &far[k],
+                plan_view(
+                    at(outer + half, 0.0, 0.0),
+                    &[Segment::Line { length: ARM_LENGTH }],
+                ),
+                lanes(n as usize, n as usize, |_| (None, None)),
+                Some(junction_link(&outer_junctions[k])),
+                None,
+            );
+            self.add_road(
+                &loops[k],
+                plan_view(
+                    at(inner, -offset, 0.0),
+                    &[Segment::Arc {
+                        length: 1.5 * PI * loop_radius,
+                        curvature: -1.0 / loop_radius,
+                    }],
+                ),
+                lanes(0, 1, |_| (None, None)),
+                Some(junction_link(&inner_junctions[k])),
+                Some(junction_link(&inner_junctions[before(k)])),
+            );
+            self.add_road(
+                &ramps[k],
+                plan_view(
+                    at(offset, -outer, FRAC_PI_2),
+                    &[Segment::Arc {
+                        length: FRAC_PI_2 * outer_radius,
+                        curvature: -1.0 / outer_radius,
+                    }],
+                ),
+                lanes(0, 1, |_| (None, None)),
+                Some(junction_link(&outer_junctions[before(k)])),
+                Some(junction_link(&outer_junctions[k])),
+            );
+        }
+
+        use ContactPoint::{End, Start};
+        for k in 0..4 {
+            let (outward, inward) = ((1..=n).map(|lane| -lane), 1..=n);
+            let (near, middle, far) = (&near[k], &middle[k], &far[k]);
+            let junction = &inner_junctions[k];
+            self.connect(
+                junction,
+                &Approach::new(near, End, outward.clone()),
+                &Approach::new(middle, Start, outward.clone()),
+            );
+            self.connect(
+                junction,
+                &Approach::new(middle, Start, inward.clone()),
+                &Approach::new(near, End, inward.clone()),
+            );
+            self.connect(
+                junction,
+                &Approach::new(near, End, [-n]),
+                &Approach::new(&loops[k], Start, [-1]),
+            );
+            self.connect(
+                junction,
+                &Approach::new(&loops[after(k)], End, [-1]),
+                &Approach::new(near, End, [n]),
+            );
+
+            let junction = &outer_junctions[k];
+            self.connect(
+                junction,
+                &Approach::new(middle, End, outward.clone()),
+                &Approach::new(far, Start, outward),
+            );
+            self.connect(
+                junction,
+                &Approach::new(far, Start, inward.clone()),
+                &Approach::new(middle, End, inward),
+            );
+            self.connect(
+                junction,
+                &Approach::new(&ramps[k], End, [-1]),
+                &Approach::new(far, Start, [-n]),
+            );
+            self.connect(
+                junction,
+                &Approach::new(far, Start, [n]),
+                &Approach::new(&ramps[after(k)], Start, [-1]),
+            );
+        }
+    }
+
+    /// Junction `k` covers the quarter of the sector of arm `k` on each side of the arm, the
+    /// ring roads cover the remaining half of the ring between the junctions.
+    fn roundabout(&mut self, arms: usize, radius: f64) {
+        let sector = 2.0 * PI / arms as f64;
+        let half = sector / 4.0;
+        let junctions = self.ids(arms);
+        let (rings, spokes) = (self.ids(arms), self.ids(arms));
+        for k in 0..arms {
+            let angle = k as f64 * sector;
+            let start = angle + half;
+            self.add_road(
+                &rings[k],
+                plan_view(
+                    pose(
+                        radius * start.cos(),
+                        radius * start.sin(),
+                        start + FRAC_PI_2,
+                    ),
+                    &[Segment::Arc {
+                        length: radius * (sector - 2.0 * half),
+                        curvature: 1.0 / radius,
+                    }],
+                ),
+                lanes(0, 1, |_| (None, None)),
+                Some(junction_link(&junctions[k])),
+                Some(junction_link(&junctions[(k + 1) % arms])),
+            );
+            let distance = radius + LANE_WIDTH + INTERSECTION;
+            self.add_road(
+                &spokes[k],
+                plan_view(
+                    pose(distance * angle.cos(), distance * angle.sin(), angle),
+                    &[Segment::Line { length: ARM_LENGTH }],
+                ),
+                lanes(1, 1, |_| (None, None)),
+                Some(junction_link(&junctions[k])),
+                None,
+            );
+        }
+
+        use ContactPoint::{End, Start};
+        for k in 0..arms {
+            let (ring, previous, spoke) = (&rings[k], &rings[(k + arms - 1) % arms], &spokes[k]);
+            let junction = &junctions[k];
+            self.connect(
+                junction,
+                &Approach::new(previous, End, [-1]),
+                &Approach::new(ring, Start, [-1]),
+            );
+            self.connect(
+                junction,
+                &Approach::new(spoke, Start, [1]),
+                &Approach::new(ring, Start, [-1]),
+            );
+            self.connect(
+                junction,
+                &Approach::new(previous, End, [-1]),
+                &Approach::new(spoke, Start, [-1]),
+            );
+        }
+    }
+}
+
+fn pose(x: f64, y: f64, hdg: f64) -> Pose {
+    Pose {
+        x: Length::new::<meter>(x),
+        y: Length::new::<meter>(y),
+        hdg: Angle::new::<radian>(hdg),
+    }
+}
+
+/// The pose turned counter-clockwise around the origin
+fn rotated(pose: Pose, angle: f64) -> Pose {
+    let (sin, cos) = angle.sin_cos();
+    let (x, y) = (pose.x.get::<meter>(), pose.y.get::<meter>());
+    Pose {
+        x: Length::new::<meter>(x * cos - y * sin),
+        y: Length::new::<meter>(x * sin + y * cos),
+        hdg: pose.hdg + Angle::new::<radian>(angle),
+    }
+}
+
+fn geometry(s: Length, start: Pose, length: f64, r#type: GeometryType) -> Geometry {
+    Geometry {
+        hdg: start.hdg,
+        length: Length::new::<meter>(length),
+        s,
+        x: start.x,
+        y: start.y,
+        r#type,
+        additional_data: AdditionalData::default(),
+    }
+}
+
+/// A plan view of the segments, each starting at the end of its predecessor
+fn plan_view(start: Pose, segments: &[Segment]) -> PlanView {
+    let mut geometries = segments.iter().map(|segment| match *segment {
+        Segment::Line { length } => (length, GeometryType::Line(Default::default())),
+        Segment::Arc { length, curvature } => (
+            length,
+            GeometryType::Arc(Arc {
+                curvature: Curvature::new::<radian_per_meter>(curvature),
+            }),
+        ),
+    });
+    let (length, r#type) = geometries.next().expect("at least one segment");
+    let mut sorted = SortedByS::default();
+    sorted.insert(geometry(Length::new::<meter>(0.0), start, length, r#type));
+    for (length, r#type) in geometries {
+        let previous = &sorted[sorted.len() - 1];
+        let geometry = geometry(
+            previous.s + previous.length,
+            previous.pose_at(previous.length),
+            length,
+            r#type,
+        );
+        sorted.insert(geometry);
+    }
+    PlanView::from_geometries(sorted).expect("at least one segment")
+}
+
+fn road_link(id: &str, contact_point: ContactPoint) -> PredecessorSuccessor {
+    PredecessorSuccessor {
+        contact_point: Some(contact_point),
+        element_dir: None,
+        element_id: id.to_string(),
+        element_s: None,
+        element_type: Some(ElementType::Road),
+    }
+}
+
+fn junction_link(id: &str) -> PredecessorSuccessor {
+    PredecessorSuccessor {
+        contact_point: None,
+        element_dir: None,
+        element_id: id.to_string(),
+        element_s: None,
+        element_type: Some(ElementType::Junction),
+    }
+}
+
+/// Lanes of a single lane section with `left` and `right` driving lanes, `link` gives the ids
+/// of the predecessor and the successor of each lane
+fn lanes(left: usize, right: usize, link: impl Fn(i64) -> (Option<i64>, Option<i64>)) -> Lanes {
+    let lane = |id: i64| {
+        let (predecessor, successor) = link(id);
+        let linked = |id: Option<i64>| {
+            id.map(|id| crate::lane::predecessor_successor::PredecessorSuccessor { id })
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+        Lane {
+            link: (predecessor.is_some() || successor.is_some()).then(|| {
+                crate::lane::lane_link::LaneLink {
+                    predecessor: linked(predecessor),
+                    successor: linked(successor),
+                    additional_data: AdditionalData::default(),
+                }
+            }),
+            choice: vec![LaneChoice::Width(Width::constant(0.0, LANE_WIDTH))],
+            r#type: LaneType::Driving,
+            ..Lane::default()
+        }
+    };
+    let left = (1..=left as i64)
+        .rev()
+        .map(|id| LeftLane { id, base: lane(id) });
+    let right = (1..=right as i64).map(|id| RightLane {
+        id: -id,
+        base: lane(-id),
+    });
+    Lanes {
+        lane_section: Vec1::new(LaneSection {
+            left: Vec1::try_from_vec(left.collect()).ok().map(|lane| Left {
+                lane,
+                additional_data: AdditionalData::default(),
+            }),
+            center: Center::default(),
+            right: Vec1::try_from_vec(right.collect()).ok().map(|lane| Right {
+                lane,
+                additional_data: AdditionalData::default(),
+            }),
+            ..LaneSection::default()
+        }),
+        ..Lanes::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::validation::{HeaderExtent, Validator};
+    use crate::core::OpenDrive;
+    use crate::generate::generators::StandardMap;
+    use crate::parser::ParseOptions;
+    use crate::road::element_type::ElementType;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_standard_maps() {
+        let length = Length::new::<meter>;
+        let maps = [
+            (
+                StandardMap::Highway {
+                    lanes: 3,
+                    length: length(1000.0),
+                },
+                1,
+                0,
+            ),
+            (
+                StandardMap::FigureEight {
+                    radius: length(50.0),
+                },
+                1,
+                0,
+            ),
+            (
+                StandardMap::GridCity {
+                    columns: 3,
+                    rows: 2,
+                    block: length(100.0),
+                },
+                7 + 4 * 2 + 2 * 6,
+                6,
+            ),
+            (
+                StandardMap::Cloverleaf {
+                    lanes: 2,
+                    loop_radius: length(50.0),
+                },
+                20 + 32,
+                8,
+            ),
+            (
+                StandardMap::Roundabout {
+                    arms: 4,
+                    radius: length(30.0),
+                },
+                8 + 12,
+                4,
+            ),
+        ];
+        for (map, roads, junctions) in maps {
+            let drive = map.generate();
+            assert_eq!(
+                (roads, junctions),
+                (drive.road.len(), drive.junction.len()),
+                "{map:?}"
+            );
+
+            let xml = drive.to_xml_string().unwrap();
+            let mut validator = Validator::new().with_rule(HeaderExtent::default());
+            let parsed = OpenDrive::from_str_with(
+                &xml,
+                ParseOptions {
+                    validator: Some(&mut validator),
+                    ..ParseOptions::default()
+                },
+            )
+            .unwrap();
+            assert!(validator.violations().is_empty(), "{map:?}");
+            assert_eq!(drive, parsed, "{map:?}");
+
+            // each road continues where the roads it is linked to end, within their lanes
+            for road in &drive.road {
+                let links = road.link.iter().flat_map(|link| {
+                    [(&link.predecessor, 0.0), (&link.successor, 1.0)]
+                        .into_iter()
+                        .filter_map(|(l, end)| l.as_ref().map(|l| (l, end)))
+                });
+                for (link, end) in links.filter(|(l, _)| l.element_type == Some(ElementType::Road))
+                {
+                    let linked = drive.road.iter().find(|r| r.id == link.element_id).unwrap();
+                    let pose = road.reference_pose(road.length * end);
+                    let (s, t) = linked.project(pose.x, pose.y);
+                    let s = s.get::<meter>();
+                    assert!(
+                        s < 1e-6 || (linked.length.get::<meter>() - s) < 1e-6,
+                        "{map:?} {} {}",
+                        road.id,
+                        linked.id
+                    );
+                    assert!(t.get::<meter>().abs() <= 7.0 + 1e-6, "{map:?} {}", road.id);
+                    let heading = (linked.reference_pose(Length::new::<meter>(s)).hdg - pose.hdg)
+                        .get::<radian>()
+                        .sin();
+                    assert!(heading.abs() < 1e-6, "{map:?} {} {}", road.id, linked.id);
+                }
+            }
+        }
+
+        let roundabout = StandardMap::Roundabout {
+            arms: 3,
+            radius: Length::new::<meter>(20.0),
+        }
+        .generate();
+        let graph = roundabout.road_graph();
+        for from in ["7", "8", "9"] {
+            for to in ["7", "8", "9"] {
+                assert!(graph.is_reachable(from, to));
+            }
+        }
+    }
+}
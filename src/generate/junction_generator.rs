@@ -0,0 +1,415 @@
+use crate::core::additional_data::AdditionalData;
+use crate::core::OpenDrive;
+use crate::junction::connection::Connection;
+use crate::junction::contact_point::ContactPoint;
+use crate::junction::lane_link::LaneLink;
+use crate::junction::Junction;
+use crate::lane::center::Center;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::lane_section::LaneSection;
+use crate::lane::lane_type::LaneType;
+use crate::lane::lanes::Lanes;
+use crate::lane::left::Left;
+use crate::lane::left_lane::LeftLane;
+use crate::lane::right::Right;
+use crate::lane::right_lane::RightLane;
+use crate::lane::travel_direction::TravelDirection;
+use crate::lane::width::Width;
+use crate::lane::Lane;
+use crate::road::element_type::ElementType;
+use crate::road::geometry::plan_view::PlanView;
+use crate::road::geometry::pose::Pose;
+use crate::road::geometry::synthesis::ConnectOptions;
+use crate::road::link::Link;
+use crate::road::predecessor_successor::PredecessorSuccessor;
+use crate::road::rule::Rule;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::f64::consts::PI;
+use vec1::Vec1;
+
+/// An end of a road that meets a junction, see [`OpenDrive::generate_junction`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RoadEnd {
+    pub road: String,
+    pub contact_point: ContactPoint,
+}
+
+impl RoadEnd {
+    pub fn new(road: impl Into<String>, contact_point: ContactPoint) -> Self {
+        Self {
+            road: road.into(),
+            contact_point,
+        }
+    }
+}
+
+/// Why [`OpenDrive::generate_junction`] could not create the junction
+#[derive(Debug, thiserror::Error)]
+pub enum JunctionGenerationError {
+    #[error("There is already a junction with the id {0}")]
+    DuplicateJunction(String),
+    #[error("There is no road with the id {0}")]
+    UnknownRoad(String),
+    #[error("The road {0} is a connecting road of the junction {1}")]
+    ConnectingRoad(String, String),
+    #[error("No drivable lanes lead from one of the roads into another")]
+    NoConnections,
+}
+
+/// The drivable lanes of a road end that lead into or out of the junction
+struct Approach<'a> {
+    end: &'a RoadEnd,
+    road: &'a Road,
+    /// The lane ids ordered from the reference line outwards with their width at the end
+    incoming: Vec<(i64, f64)>,
+    outgoing: Vec<(i64, f64)>,
+}
+
+impl<'a> Approach<'a> {
+    fn new(end: &'a RoadEnd, road: &'a Road) -> Self {
+        let (section, ds) = match end.contact_point {
+            ContactPoint::Start => (road.lanes.lane_section.first(), 0.0),
+            ContactPoint::End => {
+                let section = road.lanes.lane_section.last();
+                (section, road.length.get::<meter>() - section.s)
+            }
+        };
+        let mut lanes = section
+            .lanes()
+            .filter(|(id, lane)| *id != 0 && lane.r#type.is_drivable())
+            .filter_map(|(id, lane)| {
+                let (inner, outer) = section.lane_borders(id, ds)?;
+                Some((id, (outer - inner).abs(), road.lane_direction(id, lane)?))
+            })
+            .collect::<Vec<_>>();
+        lanes.sort_by_key(|(id, ..)| id.abs());
+        let toward = match end.contact_point {
+            ContactPoint::Start => TravelDirection::Backward,
+            ContactPoint::End => TravelDirection::Forward,
+        };
+        let lanes_in = |incoming: bool| {
+            lanes
+                .iter()
+                .filter(|(.., direction)| (*direction == toward) == incoming)
+                .filter(|(.., direction)| *direction != TravelDirection::Both)
+                .map(|(id, width, _)| (*id, *width))
+                .collect()
+        };
+        Self {
+            end,
+            road,
+            incoming: lanes_in(true),
+            outgoing: lanes_in(false),
+        }
+    }
+
+    /// The pose at the inner border of the lane at the road end, heading into the junction if
+    /// `incoming`, otherwise out of it
+    fn pose(&self, lane: i64, incoming: bool) -> Pose {
+        let s = match self.end.contact_point {
+            ContactPoint::Start => Length::new::<meter>(0.0),
+            ContactPoint::End => self.road.length,
+        };
+        let (_, section) = self.road.lanes.lane_section_at(s.get::<meter>());
+        let (inner, _) = section
+            .lane_borders(lane, s.get::<meter>() - section.s)
+            .unwrap_or_default();
+        let t = self.road.lanes.offset_at(s.get::<meter>()) + inner;
+        let pose = self.road.pose_at(s, Length::new::<meter>(t));
+        if incoming == (self.end.contact_point == ContactPoint::End) {
+            pose
+        } else {
+            Pose {
+                hdg: pose.hdg + Angle::new::<radian>(PI),
+                ..pose
+            }
+        }
+    }
+}
+
+impl OpenDrive {
+    /// Creates a junction with the given id where the given road ends meet, so that junctions no
+    /// longer need to be authored by hand. For each pair of different road ends, a connecting
+    /// road leads the drivable lanes arriving at the first into the drivable lanes leaving the
+    /// second, pairing them from the reference lines outwards; U-turns are not generated. Each
+    /// connecting road starts at the inner border of its first incoming lane and ends at the
+    /// inner border of its first outgoing lane, on lines and clothoids where the headings allow,
+    /// see [`PlanView::connect`]. Its lanes follow the traffic rule of the incoming road and
+    /// change their width smoothly from the incoming to the outgoing lanes. The roads are linked
+    /// to the junction, replacing their links at the given ends, and the connecting roads get the
+    /// numbers after the largest numeric road id. Returns the ids of the connecting roads.
+    pub fn generate_junction(
+        &mut self,
+        id: impl Into<String>,
+        ends: &[RoadEnd],
+    ) -> Result<Vec<String>, JunctionGenerationError> {
+        let id = id.into();
+        if self.junction.iter().any(|junction| junction.id == id) {
+            return Err(JunctionGenerationError::DuplicateJunction(id));
+        }
+        let approaches = ends
+            .iter()
+            .map(|end| {
+                let road = self
+                    .road
+                    .iter()
+                    .find(|road| road.id == end.road)
+                    .ok_or_else(|| JunctionGenerationError::UnknownRoad(end.road.clone()))?;
+                if road.junction != "-1" {
+                    return Err(JunctionGenerationError::ConnectingRoad(
+                        road.id.clone(),
+                        road.junction.clone(),
+                    ));
+                }
+                Ok(Approach::new(end, road))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut next_id = self
+            .road
+            .iter()
+            .filter_map(|road| road.id.parse::<u64>().ok())
+            .max()
+            .map_or(1, |max| max + 1);
+        let mut roads = Vec::new();
+        let mut connections = Vec::new();
+        for from in &approaches {
+            for to in approaches.iter().filter(|to| to.end != from.end) {
+                let count = from.incoming.len().min(to.outgoing.len());
+                if count == 0 {
+                    continue;
+                }
+                let start = from.pose(from.incoming[0].0, true);
+                let end = to.pose(to.outgoing[0].0, false);
+                let plan_view =
+                    PlanView::connect(start.into(), end.into(), ConnectOptions::default());
+                let road_id = next_id.to_string();
+                next_id += 1;
+
+                let mut road = Road::new(road_id.clone(), plan_view);
+                road.junction = id.clone();
+                road.rule = from.road.rule.clone();
+                road.link = Some(Link {
+                    predecessor: Some(road_link(from.end)),
+                    successor: Some(road_link(to.end)),
+                    additional_data: AdditionalData::default(),
+                });
+                let side = match road.rule {
+                    Some(Rule::LeftHandTraffic) => 1,
+                    _ => -1,
+                };
+                let length = road.length.get::<meter>();
+                let lanes = (0..count).map(|index| {
+                    let ((incoming, from_width), (outgoing, to_width)) =
+                        (from.incoming[index], to.outgoing[index]);
+                    let lane = Lane {
+                        link: Some(crate::lane::lane_link::LaneLink {
+                            predecessor: vec![
+                                crate::lane::predecessor_successor::PredecessorSuccessor {
+                                    id: incoming,
+                                },
+                            ],
+                            successor: vec![
+                                crate::lane::predecessor_successor::PredecessorSuccessor {
+                                    id: outgoing,
+                                },
+                            ],
+                            additional_data: AdditionalData::default(),
+                        }),
+                        choice: vec![LaneChoice::Width(Width::taper(
+                            0.0, from_width, to_width, length,
+                        ))],
+                        r#type: LaneType::Driving,
+                        ..Lane::default()
+                    };
+                    (side * (index as i64 + 1), incoming, lane)
+                });
+                let (mut left, mut right, mut lane_links) = (Vec::new(), Vec::new(), Vec::new());
+                for (lane_id, incoming, lane) in lanes {
+                    lane_links.push(LaneLink {
+                        from: incoming,
+                        to: lane_id,
+                    });
+                    if lane_id > 0 {
+                        left.insert(
+                            0,
+                            LeftLane {
+                                id: lane_id,
+                                base: lane,
+                            },
+                        );
+                    } else {
+                        right.push(RightLane {
+                            id: lane_id,
+                            base: lane,
+                        });
+                    }
+                }
+                road.lanes = Lanes {
+                    lane_section: Vec1::new(LaneSection {
+                        left: Vec1::try_from_vec(left).ok().map(|lane| Left {
+                            lane,
+                            additional_data: AdditionalData::default(),
+                        }),
+                        center: Center::default(),
+                        right: Vec1::try_from_vec(right).ok().map(|lane| Right {
+                            lane,
+                            additional_data: AdditionalData::default(),
+                        }),
+                        ..LaneSection::default()
+                    }),
+                    ..Lanes::default()
+                };
+                connections.push(Connection {
+                    id: connections.len().to_string(),
+                    incoming_road: Some(from.end.road.clone()),
+                    connecting_road: Some(road_id),
+                    contact_point: Some(ContactPoint::Start),
+                    lane_link: lane_links,
+                    ..Connection::default()
+                });
+                roads.push(road);
+            }
+        }
+
+        let mut connections = connections.into_iter();
+        let Some(first) = connections.next() else {
+            return Err(JunctionGenerationError::NoConnections);
+        };
+        let mut junction = Junction::new(id.clone(), first);
+        junction.connection.extend(connections);
+        self.junction.push(junction);
+
+        for end in ends {
+            let road = self
+                .road
+                .iter_mut()
+                .find(|road| road.id == end.road)
+                .expect("the roads were found before");
+            let link = road.link.get_or_insert_with(Link::default);
+            let junction_link = PredecessorSuccessor {
+                contact_point: None,
+                element_dir: None,
+                element_id: id.clone(),
+                element_s: None,
+                element_type: Some(ElementType::Junction),
+            };
+            match end.contact_point {
+                ContactPoint::Start => link.predecessor = Some(junction_link),
+                ContactPoint::End => link.successor = Some(junction_link),
+            }
+        }
+        let ids = roads.iter().map(|road| road.id.clone()).collect();
+        self.road.extend(roads);
+        Ok(ids)
+    }
+}
+
+fn road_link(end: &RoadEnd) -> PredecessorSuccessor {
+    PredecessorSuccessor {
+        contact_point: Some(end.contact_point.clone()),
+        element_dir: None,
+        element_id: end.road.clone(),
+        element_s: None,
+        element_type: Some(ElementType::Road),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::validation::Validator;
+    use crate::core::OpenDrive;
+    use crate::fixtures::{parse, TestRoad};
+    use crate::generate::junction_generator::{JunctionGenerationError, RoadEnd};
+    use crate::junction::contact_point::ContactPoint;
+    use crate::parser::ParseOptions;
+    use crate::road::geometry::geometry_type::GeometryType;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_generate_junction() {
+        let road = |id: &str, x: f64, y: f64, hdg: f64| {
+            TestRoad::new(id)
+                .at(x, y, hdg)
+                .sides(&[("driving", 3.5)], &[("driving", 3.5), ("sidewalk", 2.0)])
+        };
+        let mut drive = parse(format!(
+            "{}{}{}",
+            road("1", -110.0, 0.0, 0.0),
+            road("2", 10.0, 0.0, 0.0),
+            road("3", 0.0, -110.0, std::f64::consts::FRAC_PI_2),
+        ));
+        let ends = [
+            RoadEnd::new("1", ContactPoint::End),
+            RoadEnd::new("2", ContactPoint::Start),
+            RoadEnd::new("3", ContactPoint::End),
+        ];
+
+        let connecting = drive.generate_junction("j", &ends).unwrap();
+        assert_eq!(6, connecting.len());
+        assert_eq!("4", connecting[0]);
+        assert!(matches!(
+            drive.generate_junction("j", &ends),
+            Err(JunctionGenerationError::DuplicateJunction(_))
+        ));
+        assert!(matches!(
+            drive.generate_junction("k", &[RoadEnd::new("99", ContactPoint::End)]),
+            Err(JunctionGenerationError::UnknownRoad(_))
+        ));
+
+        let junction = &drive.junction[0];
+        assert_eq!(6, junction.connection.len());
+        for road in ["1", "2", "3"] {
+            let road = drive.road.iter().find(|r| r.id == road).unwrap();
+            assert!(road.junction_contact_point("j").is_some());
+        }
+
+        // the left turn from road 3 onto road 1 turns by a pair of clothoids
+        let left_turn = drive
+            .road
+            .iter()
+            .filter(|road| road.junction == "j")
+            .find(|road| {
+                let link = road.link.as_ref().unwrap();
+                link.predecessor.as_ref().unwrap().element_id == "3"
+                    && link.successor.as_ref().unwrap().element_id == "1"
+            })
+            .unwrap();
+        assert!(left_turn
+            .plan_view
+            .geometry
+            .iter()
+            .any(|g| matches!(g.r#type, GeometryType::Spiral(_))));
+        let start = left_turn.reference_pose(Length::new::<meter>(0.0));
+        let end = left_turn.reference_pose(left_turn.length);
+        assert!(start.x.get::<meter>().abs() < 1e-9);
+        assert!((start.y.get::<meter>() + 10.0).abs() < 1e-9);
+        assert!((end.x.get::<meter>() + 10.0).abs() < 1e-6);
+        assert!(end.y.get::<meter>().abs() < 1e-6);
+        assert!((end.hdg.get::<radian>() - std::f64::consts::PI).abs() < 1e-6);
+        assert_eq!(
+            1,
+            left_turn.lanes.lane_section[0]
+                .right
+                .as_ref()
+                .unwrap()
+                .lane
+                .len()
+        );
+
+        let xml = drive.to_xml_string().unwrap();
+        let mut validator = Validator::new();
+        OpenDrive::from_str_with(
+            &xml,
+            ParseOptions {
+                validator: Some(&mut validator),
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(validator.violations().is_empty());
+        let graph = drive.road_graph();
+        assert!(graph.is_reachable("3", "1") && graph.is_reachable("2", "3"));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod corpus;
+pub mod generators;
+pub mod junction_generator;
+pub mod parking_lot;
@@ -0,0 +1,574 @@
+use crate::core::additional_data::AdditionalData;
+use crate::core::ids::{IdKind, IdUse};
+use crate::core::validated::{Positive, SCoordinate};
+use crate::core::OpenDrive;
+use crate::lane::center::Center;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::lane_section::LaneSection;
+use crate::lane::lane_type::LaneType;
+use crate::lane::lanes::Lanes;
+use crate::lane::left::Left;
+use crate::lane::left_lane::LeftLane;
+use crate::lane::right::Right;
+use crate::lane::right_lane::RightLane;
+use crate::lane::taper::{LaneSide, TaperError};
+use crate::lane::width::Width;
+use crate::lane::Lane;
+use crate::object::access::Access;
+use crate::object::objects::Objects;
+use crate::object::orientation::ObjectType;
+use crate::object::parking_space::ParkingSpace;
+use crate::object::Object;
+use crate::road::geometry::geometry_type::GeometryType;
+use crate::road::geometry::plan_view::PlanView;
+use crate::road::geometry::point::Point;
+use crate::road::geometry::polygon;
+use crate::road::geometry::pose::Pose;
+use crate::road::geometry::Geometry;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::f64::consts::FRAC_PI_2;
+use vec1::Vec1;
+
+/// The stalls of [`OpenDrive::add_parking_row`] and [`OpenDrive::add_parking_lot`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParkingLayout {
+    /// The angle between the stalls and their row, 90° for perpendicular and 0° for parallel
+    /// parking
+    pub angle: Angle,
+    /// The width of a stall, which is the extent across the parking vehicle
+    pub width: Positive<Length>,
+    /// The length of a stall, which is the extent along the parking vehicle
+    pub length: Positive<Length>,
+    /// Who may use the stalls, see [`ParkingSpace::access`]
+    pub access: Access,
+    /// The width of the aisles between the rows of a parking lot
+    pub aisle: Length,
+    /// Whether lanes are generated along with the stalls: a lane of type parking under a row
+    /// along a road and an access road along each aisle of a parking lot
+    pub lanes: bool,
+}
+
+impl Default for ParkingLayout {
+    fn default() -> Self {
+        Self {
+            angle: Angle::new::<radian>(FRAC_PI_2),
+            width: Positive::new(Length::new::<meter>(2.5)).unwrap(),
+            length: Positive::new(Length::new::<meter>(5.0)).unwrap(),
+            access: Access::All,
+            aisle: Length::new::<meter>(6.0),
+            lanes: false,
+        }
+    }
+}
+
+impl ParkingLayout {
+    fn is_valid(&self) -> bool {
+        let angle = self.angle.get::<radian>();
+        self.aisle.get::<meter>() >= 0.0 && (0.0..=FRAC_PI_2 + 1e-9).contains(&angle)
+    }
+
+    /// The extent of a stall along and across its row
+    fn extent(&self) -> (f64, f64) {
+        let (sin, cos) = self.angle.get::<radian>().sin_cos();
+        let (width, length) = (self.width.get::<meter>(), self.length.get::<meter>());
+        (width * sin + length * cos, width * cos + length * sin)
+    }
+
+    /// The distance between the centers of neighboring stalls of a row, which share a side
+    fn spacing(&self) -> f64 {
+        let (sin, cos) = self.angle.get::<radian>().sin_cos();
+        (self.width.get::<meter>() / sin).min(self.length.get::<meter>() / cos)
+    }
+
+    /// The heading of the stalls on the given side of their row relative to the row. The u-axis
+    /// of a stall runs across the parking vehicle, so perpendicular stalls have no heading.
+    fn heading(&self, side: LaneSide) -> f64 {
+        let angle = self.angle.get::<radian>() - FRAC_PI_2;
+        match side {
+            LaneSide::Left => angle,
+            LaneSide::Right => -angle,
+        }
+    }
+
+    /// The positions of the centers of the stalls of a row from `start` to `end` along it
+    fn centers(&self, start: f64, end: f64) -> impl Iterator<Item = f64> {
+        let (along, _) = self.extent();
+        let spacing = self.spacing();
+        let count = if end - start + 1e-9 >= along {
+            ((end - start - along) / spacing + 1e-9).floor() as usize + 1
+        } else {
+            0
+        };
+        (0..count).map(move |i| start + along / 2.0 + i as f64 * spacing)
+    }
+
+    fn stall(&self, id: String, s: Length, t: Length, hdg: f64) -> Object {
+        Object {
+            dynamic: None,
+            hdg: Some(Angle::new::<radian>(hdg)),
+            height: None,
+            id,
+            length: Some(*self.length),
+            name: None,
+            orientation: None,
+            perp_to_road: None,
+            pitch: None,
+            radius: None,
+            roll: None,
+            s,
+            subtype: None,
+            t,
+            r#type: Some(ObjectType::ParkingSpace),
+            valid_length: None,
+            width: Some(*self.width),
+            z_offset: Length::new::<meter>(0.0),
+            repeat: Vec::new(),
+            outline: None,
+            outlines: None,
+            material: Vec::new(),
+            validity: Vec::new(),
+            parking_space: Some(ParkingSpace {
+                access: self.access.clone(),
+                restrictions: None,
+            }),
+            markings: None,
+            borders: None,
+            surface: None,
+            additional_data: AdditionalData::default(),
+        }
+    }
+}
+
+/// What [`OpenDrive::add_parking_row`] and [`OpenDrive::add_parking_lot`] added
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParkingLot {
+    /// The ids of the parking space objects
+    pub objects: Vec<String>,
+    /// The id of the parking lane under a row along a road
+    pub lane: Option<i64>,
+    /// The ids of the access roads along the aisles of a parking lot
+    pub roads: Vec<String>,
+}
+
+/// Why the stalls could not be laid out
+#[derive(Debug, thiserror::Error)]
+pub enum ParkingError {
+    #[error("There is no road with the id {0}")]
+    UnknownRoad(String),
+    #[error("The stalls need an angle between 0° and 90° and aisles that are not negative")]
+    InvalidLayout,
+    #[error("The outline of a parking lot needs at least three corners")]
+    InvalidOutline,
+    #[error("The row from s={start} to s={end} is too short for a parking lane")]
+    TooShort { start: f64, end: f64 },
+    #[error("Failed to add the parking lane: {0}")]
+    Lane(#[from] TaperError),
+}
+
+impl OpenDrive {
+    /// Lays out a row of parking spaces from `start` to `end` on the given side of a road,
+    /// next to its outermost lane. The stalls are [`ObjectType::ParkingSpace`] objects with a
+    /// `<parkingSpace>` of the access class of the layout and get the numbers after the largest
+    /// numeric object id. With [`ParkingLayout::lanes`], the stalls stand on a new outermost
+    /// lane of type parking, which widens over the length of a stall after `start` and narrows
+    /// again before `end`, see [`Road::add_lane_taper`]; the row must then lie within a single
+    /// lane section.
+    pub fn add_parking_row(
+        &mut self,
+        road: &str,
+        side: LaneSide,
+        start: SCoordinate,
+        end: SCoordinate,
+        layout: &ParkingLayout,
+    ) -> Result<ParkingLot, ParkingError> {
+        if !layout.is_valid() {
+            return Err(ParkingError::InvalidLayout);
+        }
+        let mut next_id = self.next_object_id();
+        let road = self
+            .road
+            .iter_mut()
+            .find(|r| r.id == road)
+            .ok_or_else(|| ParkingError::UnknownRoad(road.to_string()))?;
+        let (start, end) = (start.get().get::<meter>(), end.get().get::<meter>());
+        let (along, across) = layout.extent();
+        let (first, last) = if layout.lanes {
+            let (index, _) = road.lanes.lane_section_at(start);
+            let section_end = road
+                .lanes
+                .lane_section
+                .get(index + 1)
+                .map_or(road.length.get::<meter>(), |next| next.s);
+            if end > section_end + 1e-9 {
+                return Err(TaperError::CrossesLaneSection { start, end }.into());
+            }
+            if end - start < 2.0 * along {
+                return Err(ParkingError::TooShort { start, end });
+            }
+            (start + along, end - along)
+        } else {
+            (start, end)
+        };
+
+        let sign = match side {
+            LaneSide::Left => 1.0,
+            LaneSide::Right => -1.0,
+        };
+        let stalls = layout
+            .centers(first, last)
+            .map(|s| {
+                let t = outer_border(road, side, s) + sign * across / 2.0;
+                let id = next_id.to_string();
+                next_id += 1;
+                layout.stall(
+                    id,
+                    Length::new::<meter>(s),
+                    Length::new::<meter>(t),
+                    layout.heading(side),
+                )
+            })
+            .collect::<Vec<_>>();
+        let lane = if layout.lanes {
+            let id = road.add_lane_taper(side, start, along, across, LaneType::Parking)?;
+            road.drop_lane_taper(side, end - along, along)?;
+            Some(id)
+        } else {
+            None
+        };
+        Ok(ParkingLot {
+            objects: add_objects(road, stalls),
+            lane,
+            roads: Vec::new(),
+        })
+    }
+
+    /// Fills the polygon of the inertial x/y-plane with rows of parking spaces, which belong to
+    /// the given road. The rows run along the longest edge of the polygon, two rows face each
+    /// aisle and every stall lies completely within the polygon. Stalls are positioned by
+    /// projecting them onto the reference line of the road, so those beyond its ends are left
+    /// out. As with [`OpenDrive::add_parking_row`], the stalls get the numbers after the largest
+    /// numeric object id. With [`ParkingLayout::lanes`], an access road along each aisle with
+    /// stalls is added, which is one-way for angled stalls, and the access roads get the numbers
+    /// after the largest numeric road id.
+    pub fn add_parking_lot(
+        &mut self,
+        road: &str,
+        outline: &[Point],
+        layout: &ParkingLayout,
+    ) -> Result<ParkingLot, ParkingError> {
+        if !layout.is_valid() {
+            return Err(ParkingError::InvalidLayout);
+        }
+        if outline.len() < 3 {
+            return Err(ParkingError::InvalidOutline);
+        }
+        let index = self
+            .road
+            .iter()
+            .position(|r| r.id == road)
+            .ok_or_else(|| ParkingError::UnknownRoad(road.to_string()))?;
+        let polygon = outline
+            .iter()
+            .map(|p| (p.x.get::<meter>(), p.y.get::<meter>()))
+            .collect::<Vec<_>>();
+
+        // the rows run along the longest edge, the aisles are stacked across it
+        let (dx, dy) = (0..polygon.len())
+            .map(|i| {
+                let ((x0, y0), (x1, y1)) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                (x1 - x0, y1 - y0)
+            })
+            .max_by(|a, b| a.0.hypot(a.1).total_cmp(&b.0.hypot(b.1)))
+            .unwrap_or((1.0, 0.0));
+        let hdg = dy.atan2(dx);
+        let (sin, cos) = hdg.sin_cos();
+        let range = |project: &dyn Fn(f64, f64) -> f64| {
+            polygon
+                .iter()
+                .fold((f64::MAX, f64::MIN), |(min, max), (x, y)| {
+                    let value = project(*x, *y);
+                    (min.min(value), max.max(value))
+                })
+        };
+        let (start, end) = range(&|x, y| x * cos + y * sin);
+        let (bottom, top) = range(&|x, y| y * cos - x * sin);
+        let at = |along: f64, across: f64, hdg: f64| Pose {
+            x: Length::new::<meter>(along * cos - across * sin),
+            y: Length::new::<meter>(along * sin + across * cos),
+            hdg: Angle::new::<radian>(hdg),
+        };
+
+        let (along, across) = layout.extent();
+        let aisle = layout.aisle.get::<meter>();
+        let (mut stalls, mut aisles) = (Vec::new(), Vec::new());
+        let mut row = bottom;
+        while row + across <= top + 1e-9 {
+            let center = row + across + aisle / 2.0;
+            let mut extent = None::<(f64, f64)>;
+            for side in [LaneSide::Right, LaneSide::Left] {
+                let offset = match side {
+                    LaneSide::Left => aisle / 2.0 + across / 2.0,
+                    LaneSide::Right => -aisle / 2.0 - across / 2.0,
+                };
+                for position in layout.centers(start, end) {
+                    let pose = at(position, center + offset, hdg + layout.heading(side));
+                    // corners on the edges of the polygon count as inside
+                    let margin = Length::new::<meter>(1e-6);
+                    let (u, v) = (*layout.width / 2.0 - margin, *layout.length / 2.0 - margin);
+                    let inside = [(u, v), (-u, v), (-u, -v), (u, -v)].iter().all(|(u, v)| {
+                        let corner = pose.transform(*u, *v);
+                        polygon::contains(outline, &corner)
+                    });
+                    if inside {
+                        stalls.push(pose);
+                        let (from, to) = extent.unwrap_or((f64::MAX, f64::MIN));
+                        extent = Some((
+                            from.min(position - along / 2.0),
+                            to.max(position + along / 2.0),
+                        ));
+                    }
+                }
+            }
+            if let Some((from, to)) = extent {
+                aisles.push((at(from, center, hdg), to - from));
+            }
+            row += 2.0 * across + aisle;
+        }
+
+        let mut next_id = self.next_object_id();
+        let road = &mut self.road[index];
+        let stalls = stalls
+            .into_iter()
+            .filter_map(|pose| {
+                let (s, t) = road.project(pose.x, pose.y);
+                let projected = road.pose_at(s, t);
+                let distance = (projected.x - pose.x)
+                    .get::<meter>()
+                    .hypot((projected.y - pose.y).get::<meter>());
+                if distance > 1e-3 {
+                    return None;
+                }
+                let id = next_id.to_string();
+                next_id += 1;
+                Some(layout.stall(id, s, t, (pose.hdg - projected.hdg).get::<radian>()))
+            })
+            .collect::<Vec<_>>();
+        let objects = add_objects(road, stalls);
+
+        let mut roads = Vec::new();
+        if layout.lanes {
+            let next_id = self
+                .road
+                .iter()
+                .filter_map(|road| road.id.parse::<u64>().ok())
+                .max()
+                .map_or(1, |max| max + 1);
+            let two_way = layout.angle.get::<radian>() >= FRAC_PI_2 - 1e-9;
+            for ((pose, length), id) in aisles.into_iter().zip(next_id..) {
+                let id = id.to_string();
+                let mut road = Road::new(
+                    id.clone(),
+                    PlanView::new(Geometry {
+                        hdg: pose.hdg,
+                        length: Length::new::<meter>(length),
+                        s: Length::new::<meter>(0.0),
+                        x: pose.x,
+                        y: pose.y,
+                        r#type: GeometryType::Line(Default::default()),
+                        additional_data: AdditionalData::default(),
+                    }),
+                );
+                road.lanes = access_lanes(aisle, two_way);
+                self.road.push(road);
+                roads.push(id);
+            }
+        }
+        Ok(ParkingLot {
+            objects,
+            lane: None,
+            roads,
+        })
+    }
+
+    /// The number after the largest numeric object id
+    fn next_object_id(&mut self) -> u64 {
+        let mut max = None;
+        self.visit_ids(&mut |kind, usage, id| {
+            if kind == IdKind::Object && usage == IdUse::Definition {
+                if let Ok(id) = id.parse::<u64>() {
+                    max = max.max(Some(id));
+                }
+            }
+        });
+        max.map_or(1, |max| max + 1)
+    }
+}
+
+/// The t-coordinate of the outer border of the outermost lane on the given side
+fn outer_border(road: &Road, side: LaneSide, s: f64) -> f64 {
+    let (_, section) = road.lanes.lane_section_at(s);
+    let outermost = section
+        .lanes()
+        .map(|(id, _)| id)
+        .filter(|id| match side {
+            LaneSide::Left => *id > 0,
+            LaneSide::Right => *id < 0,
+        })
+        .max_by_key(|id| id.abs())
+        .unwrap_or(0);
+    let (_, outer) = section
+        .lane_borders(outermost, s - section.s)
+        .unwrap_or_default();
+    road.lanes.offset_at(s) + outer
+}
+
+/// Adds the objects to the road and returns their ids
+fn add_objects(road: &mut Road, objects: Vec<Object>) -> Vec<String> {
+    let ids = objects.iter().map(|object| object.id.clone()).collect();
+    road.objects
+        .get_or_insert_with(|| Objects {
+            object: Vec::new(),
+            object_reference: Vec::new(),
+            tunnel: Vec::new(),
+            bridge: Vec::new(),
+            additional_data: AdditionalData::default(),
+        })
+        .object
+        .extend(objects);
+    ids
+}
+
+/// A driving lane in each direction sharing the width of the aisle or, if not `two_way`, a
+/// single driving lane on the right
+fn access_lanes(aisle: f64, two_way: bool) -> Lanes {
+    let lane = |width: f64| Lane {
+        choice: vec![LaneChoice::Width(Width::constant(0.0, width))],
+        r#type: LaneType::Driving,
+        ..Lane::default()
+    };
+    let width = if two_way { aisle / 2.0 } else { aisle };
+    Lanes {
+        lane_section: Vec1::new(LaneSection {
+            left: two_way.then(|| Left {
+                lane: Vec1::new(LeftLane {
+                    id: 1,
+                    base: lane(width),
+                }),
+                additional_data: AdditionalData::default(),
+            }),
+            center: Center::default(),
+            right: Some(Right {
+                lane: Vec1::new(RightLane {
+                    id: -1,
+                    base: lane(width),
+                }),
+                additional_data: AdditionalData::default(),
+            }),
+            ..LaneSection::default()
+        }),
+        ..Lanes::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::validated::SCoordinate;
+    use crate::core::validation::{HeaderExtent, Validator};
+    use crate::core::OpenDrive;
+    use crate::generate::generators::StandardMap;
+    use crate::generate::parking_lot::{ParkingError, ParkingLayout};
+    use crate::lane::lane_type::LaneType;
+    use crate::lane::taper::LaneSide;
+    use crate::object::access::Access;
+    use crate::parser::ParseOptions;
+    use crate::road::geometry::point::Point;
+    use crate::units::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    pub fn test_parking_lot() {
+        let length = Length::new::<meter>;
+        let s = |s| SCoordinate::new(length(s)).unwrap();
+        let mut drive = StandardMap::Highway {
+            lanes: 2,
+            length: length(200.0),
+        }
+        .generate();
+        let road = drive.road[0].id.clone();
+
+        let perpendicular = ParkingLayout {
+            lanes: true,
+            ..ParkingLayout::default()
+        };
+        let row = drive
+            .add_parking_row(&road, LaneSide::Right, s(20.0), s(80.0), &perpendicular)
+            .unwrap();
+        assert_eq!((22, Some(-3)), (row.objects.len(), row.lane));
+        let (_, section) = drive.road[0].lanes.lane_section_at(50.0);
+        assert_eq!(LaneType::Parking, section.lane(-3).unwrap().r#type);
+        assert_eq!(Some((-7.0, -12.0)), section.lane_borders(-3, 0.0));
+        let stall = &drive.road[0].objects.as_ref().unwrap().object[0];
+        assert_eq!(
+            (23.75, -9.5),
+            (stall.s.get::<meter>(), stall.t.get::<meter>())
+        );
+        assert_eq!(0.0, stall.hdg.unwrap().get::<radian>());
+
+        let parallel = ParkingLayout {
+            angle: Angle::new::<radian>(0.0),
+            access: Access::Electric,
+            ..ParkingLayout::default()
+        };
+        let row = drive
+            .add_parking_row(&road, LaneSide::Left, s(100.0), s(150.0), &parallel)
+            .unwrap();
+        assert_eq!((10, None), (row.objects.len(), row.lane));
+
+        let corner = |x, y| Point {
+            x: length(x),
+            y: length(y),
+        };
+        let outline = [
+            corner(20.0, -60.0),
+            corner(120.0, -60.0),
+            corner(120.0, -15.0),
+            corner(20.0, -15.0),
+        ];
+        let lot = drive
+            .add_parking_lot(&road, &outline, &perpendicular)
+            .unwrap();
+        assert_eq!((5 * 40, 3), (lot.objects.len(), lot.roads.len()));
+        assert!(matches!(
+            drive.add_parking_lot(&road, &outline[..2], &perpendicular),
+            Err(ParkingError::InvalidOutline)
+        ));
+
+        let objects = &drive.road[0].objects.as_ref().unwrap().object;
+        let ids = objects.iter().map(|o| &o.id).collect::<HashSet<_>>();
+        assert_eq!(22 + 10 + 200, ids.len());
+        for object in &objects[32..] {
+            for point in object.footprint(&drive.road[0]) {
+                let (x, y) = (point.x.get::<meter>(), point.y.get::<meter>());
+                assert!((20.0 - 1e-6..=120.0 + 1e-6).contains(&x), "{x}");
+                assert!((-60.0 - 1e-6..=-15.0 + 1e-6).contains(&y), "{y}");
+            }
+        }
+
+        let mut header = drive.header.clone();
+        header.recompute_extent(&drive);
+        drive.header = header;
+        let xml = drive.to_xml_string().unwrap();
+        let mut validator = Validator::new().with_rule(HeaderExtent::default());
+        let parsed = OpenDrive::from_str_with(
+            &xml,
+            ParseOptions {
+                validator: Some(&mut validator),
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(validator.violations().is_empty());
+        assert_eq!(drive, parsed);
+    }
+}
@@ -5,7 +5,7 @@ use crate::junction::predecessor_successor::PredecessorSuccessor;
 use std::borrow::Cow;
 
 /// Provides information about a single connection within a junction.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Connection {
     pub predecessor: Option<PredecessorSuccessor>,
@@ -31,10 +31,13 @@ impl Connection {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "connectingRoad" => self.connecting_road.as_deref(),
             "contactPoint" => self.contact_point.as_ref().map(ContactPoint::as_str),
             "id" => Some(self.id.as_str()),
@@ -47,17 +50,18 @@ impl Connection {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         if let Some(predecessor) = &self.predecessor {
-            visit_children!(visitor, "predecessor" => predecessor);
+            visit_children!(visitor, options, "predecessor" => predecessor);
         }
 
         if let Some(successor) = &self.successor {
-            visit_children!(visitor, "successor" => successor);
+            visit_children!(visitor, options, "successor" => successor);
         }
 
         for lane_link in &self.lane_link {
-            visit_children!(visitor, "laneLink" => lane_link);
+            visit_children!(visitor, options, "laneLink" => lane_link);
         }
 
         Ok(())
@@ -65,7 +69,7 @@ impl Connection {
 }
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Connection
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
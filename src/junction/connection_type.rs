@@ -1,6 +1,8 @@
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum ConnectionType {
+    /// Regular connections are of type "default"
+    #[default]
     Default,
     Virtual,
 }
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 /// Lists the controllers that are used for the management of a junction.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Controller {
     /// ID of the controller
@@ -18,10 +18,13 @@ impl Controller {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "id" => Some(self.id.as_str()),
             "sequence" => self.sequence.map(|v| v.to_string()).as_deref(),
             "type" => self.r#type.as_deref(),
@@ -31,14 +34,15 @@ impl Controller {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Controller
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -24,10 +24,14 @@ impl JunctionGroup {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "id" => Some(self.id.as_str()),
             "name" => self.name.as_deref(),
             "type" => Some(self.r#type.as_str()),
@@ -36,19 +40,21 @@ impl JunctionGroup {
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for junction_reference in &self.junction_reference {
-            visit_children!(visitor, "junctionReference" => junction_reference);
+            visit_children!(visitor, options, "junctionReference" => junction_reference);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for JunctionGroup
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -67,7 +73,7 @@ where
             id: read.attribute("id")?,
             name: read.attribute_opt("name")?,
             r#type: read.attribute("type")?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
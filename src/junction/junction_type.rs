@@ -1,6 +1,8 @@
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum JunctionType {
+    /// If the attribute is not specified, the junction type is "default"
+    #[default]
     Default,
     Virtual,
     Direct,
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 /// Provides information about the lanes that are linked between an incoming road and a connecting
 /// road. It is strongly recommended to provide this element. It is deprecated to omit the
 /// `<laneLink>` element.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct LaneLink {
     /// ID of the incoming lane
@@ -17,10 +17,13 @@ impl LaneLink {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
+            options,
             "from" => &self.from.to_string(),
             "to" => &self.to.to_string(),
         )
@@ -29,15 +32,16 @@ impl LaneLink {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for LaneLink
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
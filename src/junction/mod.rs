@@ -4,10 +4,10 @@ use crate::junction::controller::Controller;
 use crate::junction::priority::Priority;
 use crate::junction::surface::Surface;
 use crate::object::orientation::Orientation;
+use crate::units::prelude::*;
 use junction_type::JunctionType;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
+use std::fmt::{Display, Formatter};
 use vec1::Vec1;
 
 pub mod connection;
@@ -61,51 +61,110 @@ pub struct Junction {
 }
 
 impl Junction {
+    /// A junction of the default type with the given id and connection
+    pub fn new(id: impl Into<String>, connection: Connection) -> Self {
+        Self {
+            connection: Vec1::new(connection),
+            priority: Vec::new(),
+            controller: Vec::new(),
+            surface: None,
+            id: id.into(),
+            main_road: None,
+            name: None,
+            orientation: None,
+            s_end: None,
+            s_start: None,
+            r#type: None,
+            additional_data: AdditionalData::default(),
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "id" => Some(self.id.as_str()),
             "mainRoad" => self.main_road.as_deref(),
             "name" => self.name.as_deref(),
             "orientation" => self.orientation.as_ref().map(Orientation::as_str),
-            "sEnd" => self.s_end.map(|v| v.value.to_scientific_string()).as_deref(),
-            "sStart" => self.s_start.map(|v| v.value.to_scientific_string()).as_deref(),
+            "sEnd" => self.s_end.map(|v| options.format("sEnd", v.get::<meter>())).as_deref(),
+            "sStart" => self.s_start.map(|v| options.format("sStart", v.get::<meter>())).as_deref(),
             "type" => self.r#type.as_ref().map(JunctionType::as_str),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for connection in &self.connection {
-            visit_children!(visitor, "connection" => connection);
+            visit_children!(visitor, options, "connection" => connection);
         }
 
         for priority in &self.priority {
-            visit_children!(visitor, "priority" => priority);
+            visit_children!(visitor, options, "priority" => priority);
         }
 
         for controller in &self.controller {
-            visit_children!(visitor, "controller" => controller);
+            visit_children!(visitor, options, "controller" => controller);
         }
 
         if let Some(surface) = &self.surface {
-            visit_children!(visitor, "surface" => surface);
+            visit_children!(visitor, options, "surface" => surface);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
+    }
+}
+
+impl Display for Junction {
+    /// A one-line summary of the junction, like
+    /// `junction J "Crossing" (default): 4 connections, incoming roads 1, 2`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "junction {}", self.id)?;
+        if let Some(name) = self.name.as_deref().filter(|n| !n.is_empty()) {
+            write!(f, " {name:?}")?;
+        }
+        let connections = self.connection.len();
+        write!(
+            f,
+            " ({}): {connections} connection{}",
+            self.r#type
+                .as_ref()
+                .unwrap_or(&JunctionType::Default)
+                .as_str(),
+            if connections == 1 { "" } else { "s" },
+        )?;
+        let mut incoming = Vec::new();
+        for road in self
+            .connection
+            .iter()
+            .filter_map(|c| c.incoming_road.as_deref())
+        {
+            if !incoming.contains(&road) {
+                incoming.push(road);
+            }
+        }
+        if !incoming.is_empty() {
+            write!(f, ", incoming roads {}", incoming.join(", "))?;
+        }
+        Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Junction
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -137,7 +196,7 @@ where
             s_end: read.attribute_opt("sEnd")?.map(Length::new::<meter>),
             s_start: read.attribute_opt("sStart")?.map(Length::new::<meter>),
             r#type: read.attribute_opt("type")?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
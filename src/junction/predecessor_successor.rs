@@ -1,7 +1,6 @@
 use crate::junction::element_dir::ElementDir;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Provides detailed information about the predecessor / successor road of a virtual connection.
 /// Currently, only the @elementType “road” is allowed.
@@ -22,13 +21,16 @@ impl PredecessorSuccessor {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
+            options,
             "elementDir" => self.element_dir.as_str(),
             "elementId" => self.element_id.as_str(),
-            "elementS" => self.element_s.value.to_scientific_string().as_str(),
+            "elementS" => options.format("elementS", self.element_s.get::<meter>()).as_str(),
             "elementType" => self.element_type.as_str(),
         )
     }
@@ -36,15 +38,16 @@ impl PredecessorSuccessor {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for PredecessorSuccessor
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -17,25 +17,30 @@ impl Surface {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for crg in &self.crg {
-            visit_children!(visitor, "CRG" => crg);
+            visit_children!(visitor, options, "CRG" => crg);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Surface
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -51,7 +56,7 @@ where
 
         Ok(Self {
             crg,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -1,8 +1,7 @@
 use crate::lane::access::restriction_type::AccessRestrictionType;
+use crate::units::prelude::*;
 use rule::AccessRule;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 pub mod restriction_type;
 pub mod rule;
@@ -27,34 +26,38 @@ impl Access {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "restriction" => Some(self.restriction.as_str()),
             "rule" => self.rule.as_ref().map(AccessRule::as_str),
-            "sOffset" => Some(self.s_offset.value.to_scientific_string()).as_deref(),
+            "sOffset" => Some(options.format("sOffset", self.s_offset.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Access
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
     fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
         read.expecting_no_child_elements_for(Self {
-            restriction: read.attribute("restriction")?,
+            restriction: read.enum_attribute("restriction")?,
             rule: read.attribute_opt("rule")?,
             s_offset: read.attribute("sOffset").map(Length::new::<meter>)?,
         })
@@ -1,5 +1,4 @@
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum AccessRestrictionType {
     Simulator,
     AutonomousTraffic,
@@ -15,10 +14,13 @@ pub enum AccessRestrictionType {
     Motorcycle,
     None,
     Trucks,
+    /// A value unknown to the schema, see [`crate::parser::ParseOptions::tolerant_enums`]
+    Other(String),
 }
 
 impl_from_str_as_str!(
     AccessRestrictionType,
+    other => Other,
     "simulator" => Simulator,
     "autonomousTraffic" => AutonomousTraffic,
     "pedestrian" => Pedestrian,
@@ -34,3 +36,27 @@ impl_from_str_as_str!(
     "none" => None,
     "trucks" => Trucks,
 );
+
+#[cfg(feature = "fuzzing")]
+impl arbitrary::Arbitrary<'_> for AccessRestrictionType {
+    fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
+        use crate::fuzzing::ArbitraryOther;
+        Ok(match u.int_in_range(0..=14)? {
+            0 => Self::Simulator,
+            1 => Self::AutonomousTraffic,
+            2 => Self::Pedestrian,
+            3 => Self::PassengerCar,
+            4 => Self::Bus,
+            5 => Self::Delivery,
+            6 => Self::Emergency,
+            7 => Self::Taxi,
+            8 => Self::ThroughTraffic,
+            9 => Self::Truck,
+            10 => Self::Bicycle,
+            11 => Self::Motorcycle,
+            12 => Self::None,
+            13 => Self::Trucks,
+            _ => Self::Other(u.arbitrary_other()?),
+        })
+    }
+}
@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Lane borders are another method to describe the width of lanes. Instead of defining the width
 /// directly, lane borders describe the outer limits of a lane, independent of the parameters of
@@ -34,34 +33,45 @@ pub struct Border {
 }
 
 impl Border {
+    /// The distance of the outer border from the center lane at `ds`, relative to the start of
+    /// the lane section
+    pub fn value(&self, ds: f64) -> f64 {
+        let ds = ds - self.s_offset.get::<meter>();
+        self.a + (self.b * ds) + (self.c * ds * ds) + (self.d * ds * ds * ds)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
-            "sOffset" => &self.s_offset.value.to_scientific_string(),
+            options,
+            "a" => &options.format("a", self.a),
+            "b" => &options.format("b", self.b),
+            "c" => &options.format("c", self.c),
+            "d" => &options.format("d", self.d),
+            "sOffset" => &options.format("sOffset", self.s_offset.get::<meter>()),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Border
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -12,31 +12,46 @@ pub struct Center {
     pub additional_data: AdditionalData,
 }
 
+impl Default for Center {
+    /// A single center lane of type [`crate::lane::lane_type::LaneType::None`]
+    fn default() -> Self {
+        Self {
+            lane: Vec1::new(CenterLane::default()),
+            additional_data: AdditionalData::default(),
+        }
+    }
+}
+
 impl Center {
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for lane in &self.lane {
-            visit_children!(visitor, "lane" => lane);
+            visit_children!(visitor, options, "lane" => lane);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Center
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -52,7 +67,7 @@ where
 
         Ok(Self {
             lane: Vec1::try_from_vec(lane).unwrap(),
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
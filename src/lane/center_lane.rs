@@ -3,7 +3,7 @@ use std::borrow::Cow;
 
 /// Lane elements are included in left/center/right elements. Lane elements should represent the
 /// lanes from left to right, that is, with descending ID.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct CenterLane {
     /// ID of the lane
@@ -16,30 +16,36 @@ impl CenterLane {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        self.base.visit_attributes(|attributes| {
-            let mut attributes = attributes.to_vec();
-            let value = self.id.to_string();
-            attributes.push(xml::attribute::Attribute::new(
-                xml::name::Name::local("id"),
-                &value,
-            ));
-            visitor(Cow::Owned(attributes))
-        })
+        self.base.visit_attributes(
+            |attributes, comments| {
+                let mut attributes = attributes.to_vec();
+                let value = self.id.to_string();
+                attributes.push(xml::attribute::Attribute::new(
+                    xml::name::Name::local("id"),
+                    &value,
+                ));
+                visitor(Cow::Owned(attributes), comments)
+            },
+            options,
+        )
     }
 
     pub fn visit_children(
         &self,
         visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        self.base.visit_children(visitor)
+        self.base.visit_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for CenterLane
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
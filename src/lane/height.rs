@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Lane height shall be defined along the h-coordinate. Lane height may be used to elevate a lane
 /// independent from the road elevation. Lane height is used to implement small-scale elevation such
@@ -22,28 +21,32 @@ impl Height {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "inner" => Some(self.inner.value.to_scientific_string()).as_deref(),
-            "outer" => Some(self.outer.value.to_scientific_string()).as_deref(),
-            "sOffset" => Some(self.s_offset.value.to_scientific_string()).as_deref(),
+            options,
+            "inner" => Some(options.format("inner", self.inner.get::<meter>())).as_deref(),
+            "outer" => Some(options.format("outer", self.outer.get::<meter>())).as_deref(),
+            "sOffset" => Some(options.format("sOffset", self.s_offset.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Height
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
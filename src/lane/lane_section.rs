@@ -1,8 +1,11 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::boolean::Bool;
 use crate::lane::center::Center;
 use crate::lane::left::Left;
 use crate::lane::right::Right;
+use crate::lane::Lane;
 use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
 
 /// Lanes may be split into multiple lane sections. Each lane section contains a fixed number of
 /// lanes. Every time the number of lanes changes, a new lane section is required. The distance
@@ -20,41 +23,145 @@ pub struct LaneSection {
     pub additional_data: AdditionalData,
 }
 
+impl Default for LaneSection {
+    /// A lane section at s = 0 with only the center lane
+    fn default() -> Self {
+        Self {
+            s: 0.0,
+            single_side: None,
+            left: None,
+            center: Center::default(),
+            right: None,
+            additional_data: AdditionalData::default(),
+        }
+    }
+}
+
 impl LaneSection {
+    /// All lanes of this lane section together with their ids, in the order of the document
+    /// (left, center, right).
+    pub fn lanes(&self) -> impl Iterator<Item = (i64, &Lane)> {
+        let left = self
+            .left
+            .iter()
+            .flat_map(|l| l.lane.iter().map(|l| (l.id, &l.base)));
+        let center = self.center.lane.iter().map(|l| (l.id, &l.base));
+        let right = self
+            .right
+            .iter()
+            .flat_map(|r| r.lane.iter().map(|l| (l.id, &l.base)));
+        left.chain(center).chain(right)
+    }
+
+    /// The lane with the given id
+    pub fn lane(&self, id: i64) -> Option<&Lane> {
+        self.lanes().find(|(i, _)| *i == id).map(|(_, lane)| lane)
+    }
+
+    /// The adjacent lane on the same side that is closer to the center lane, `None` for the
+    /// innermost lanes
+    pub fn inner_neighbor(&self, id: i64) -> Option<(i64, &Lane)> {
+        let neighbor = id - id.signum();
+        Some(neighbor)
+            .filter(|n| *n != 0)
+            .and_then(|n| Some((n, self.lane(n)?)))
+    }
+
+    /// The adjacent lane on the same side that is farther away from the center lane
+    pub fn outer_neighbor(&self, id: i64) -> Option<(i64, &Lane)> {
+        let neighbor = id + id.signum();
+        Some(neighbor)
+            .filter(|_| id != 0)
+            .and_then(|n| Some((n, self.lane(n)?)))
+    }
+
+    /// The innermost lane on the other side of the center lane, `None` if the given lane is not
+    /// an innermost lane itself
+    pub fn opposite_direction_neighbor(&self, id: i64) -> Option<(i64, &Lane)> {
+        Some(-id)
+            .filter(|_| id.abs() == 1)
+            .and_then(|n| Some((n, self.lane(n)?)))
+    }
+
+    /// The inner and outer border of the given lane at `ds`, relative to the start of this lane
+    /// section, as t-coordinates relative to the center lane. Widths take precedence over
+    /// borders, see [`crate::lane::width::Width`].
+    pub fn lane_borders(&self, id: i64, ds: f64) -> Option<(f64, f64)> {
+        if id == 0 {
+            // the center lane has no width
+            return self.lane(0).map(|_| (0.0, 0.0));
+        }
+
+        let sign = id.signum() as f64;
+        let mut inner = 0.0;
+        for current in (1..=id.abs()).map(|i| i * id.signum()) {
+            let lane = self.lane(current)?;
+            let outer = match (lane.width_at(ds), lane.border_at(ds)) {
+                (Some(width), _) => inner + width,
+                (None, Some(border)) => border,
+                (None, None) => inner,
+            };
+            if current == id {
+                return Some((sign * inner, sign * outer));
+            }
+            inner = outer;
+        }
+        None
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
-            "s" => Some(self.s.to_scientific_string()).as_deref(),
-            "singleSide" => self.single_side.map(|b| b.to_string()).as_deref()
+            options,
+            "s" => Some(options.format("s", self.s)).as_deref(),
+            "singleSide" => self.single_side.map(|v| Bool(v).as_str())
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         if let Some(left) = &self.left {
-            visit_children!(visitor, "left" => left);
+            visit_children!(visitor, options, "left" => left);
         }
 
-        visit_children!(visitor, "center" => self.center);
+        visit_children!(visitor, options, "center" => self.center);
 
         if let Some(right) = &self.right {
-            visit_children!(visitor, "right" => right);
+            visit_children!(visitor, options, "right" => right);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
+    }
+}
+
+impl Display for LaneSection {
+    /// A one-line summary of the lane section, like `lane section at s = 0 m: 1 left, 2 right`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "lane section at s = {} m: {} left, {} right",
+            self.s,
+            self.left.as_ref().map_or(0, |l| l.lane.len()),
+            self.right.as_ref().map_or(0, |r| r.lane.len()),
+        )
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for LaneSection
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -74,11 +181,11 @@ where
 
         Ok(Self {
             s: read.attribute("s")?,
-            single_side: read.attribute_opt("singleSide")?,
+            single_side: read.attribute_opt::<Bool>("singleSide")?.map(bool::from),
             left,
             center: center.unwrap(),
             right,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
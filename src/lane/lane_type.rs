@@ -1,7 +1,6 @@
 /// The lane type is defined per lane. A lane type defines the main purpose of a lane and its
 /// corresponding traffic rules.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum LaneType {
     /// Describes a soft shoulder  at the edge of the roa
     Shoulder,
@@ -46,10 +45,100 @@ pub enum LaneType {
     Bus,
     Taxi,
     HOV,
+    /// A value unknown to the schema, see [`crate::parser::ParseOptions::tolerant_enums`]
+    Other(String),
+}
+
+impl LaneType {
+    /// All lane types
+    pub const ALL: &'static [LaneType] = &[
+        LaneType::Shoulder,
+        LaneType::Border,
+        LaneType::Driving,
+        LaneType::Stop,
+        LaneType::None,
+        LaneType::Restricted,
+        LaneType::Parking,
+        LaneType::Median,
+        LaneType::Biking,
+        LaneType::Sidewalk,
+        LaneType::Curb,
+        LaneType::Exit,
+        LaneType::Entry,
+        LaneType::OnRamp,
+        LaneType::OffRamp,
+        LaneType::ConnectingRamp,
+        LaneType::Bidirectional,
+        LaneType::Special1,
+        LaneType::Special2,
+        LaneType::Special3,
+        LaneType::RoadWorks,
+        LaneType::Tram,
+        LaneType::Rail,
+        LaneType::Bus,
+        LaneType::Taxi,
+        LaneType::HOV,
+    ];
+
+    /// Whether this lane type is meant to be driven on by regular motorized traffic
+    pub fn is_drivable(&self) -> bool {
+        matches!(
+            self,
+            LaneType::Driving
+                | LaneType::Entry
+                | LaneType::Exit
+                | LaneType::OnRamp
+                | LaneType::OffRamp
+                | LaneType::ConnectingRamp
+                | LaneType::Bidirectional
+        )
+    }
+
+    /// Whether this lane type is reserved for specific motorized vehicles, that are buses, taxis
+    /// and high-occupancy vehicles
+    pub fn is_restricted_vehicle(&self) -> bool {
+        matches!(self, LaneType::Bus | LaneType::Taxi | LaneType::HOV)
+    }
+
+    /// Whether this lane type is meant for pedestrians
+    pub fn is_pedestrian(&self) -> bool {
+        matches!(self, LaneType::Sidewalk)
+    }
+
+    /// Whether this lane type is meant for cyclists
+    pub fn is_bicycle(&self) -> bool {
+        matches!(self, LaneType::Biking)
+    }
+
+    /// Whether this lane type provides parking space
+    pub fn is_parking(&self) -> bool {
+        matches!(self, LaneType::Parking)
+    }
+
+    /// Whether this lane type is meant for rail-bound vehicles
+    pub fn is_rail(&self) -> bool {
+        matches!(self, LaneType::Tram | LaneType::Rail)
+    }
+
+    /// Whether this lane type is not meant to be used by traffic in regular operation, such as
+    /// shoulders, borders, medians and curbs
+    pub fn is_non_traffic(&self) -> bool {
+        matches!(
+            self,
+            LaneType::Shoulder
+                | LaneType::Border
+                | LaneType::Stop
+                | LaneType::None
+                | LaneType::Restricted
+                | LaneType::Median
+                | LaneType::Curb
+        )
+    }
 }
 
 impl_from_str_as_str!(
     LaneType,
+    other => Other,
     "shoulder" => Shoulder,
     "border" => Border,
     "driving" => Driving,
@@ -77,3 +166,83 @@ impl_from_str_as_str!(
     "taxi" => Taxi,
     "HOV" => HOV,
 );
+
+#[cfg(feature = "fuzzing")]
+impl arbitrary::Arbitrary<'_> for LaneType {
+    fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
+        use crate::fuzzing::ArbitraryOther;
+        Ok(match Self::ALL.get(u.int_in_range(0..=Self::ALL.len())?) {
+            Some(r#type) => r#type.clone(),
+            None => Self::Other(u.arbitrary_other()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lane::lane_type::LaneType;
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    #[test]
+    pub fn test_lane_type_predicates() {
+        // drivable, restricted vehicle, pedestrian, bicycle, parking, rail, non-traffic; the
+        // match is exhaustive so that new lane types have to be classified here and in `ALL`
+        let expected = |r#type: &LaneType| match r#type {
+            LaneType::Driving
+            | LaneType::Exit
+            | LaneType::Entry
+            | LaneType::OnRamp
+            | LaneType::OffRamp
+            | LaneType::ConnectingRamp
+            | LaneType::Bidirectional => [true, false, false, false, false, false, false],
+            LaneType::Bus | LaneType::Taxi | LaneType::HOV => {
+                [false, true, false, false, false, false, false]
+            }
+            LaneType::Sidewalk => [false, false, true, false, false, false, false],
+            LaneType::Biking => [false, false, false, true, false, false, false],
+            LaneType::Parking => [false, false, false, false, true, false, false],
+            LaneType::Tram | LaneType::Rail => [false, false, false, false, false, true, false],
+            LaneType::Shoulder
+            | LaneType::Border
+            | LaneType::Stop
+            | LaneType::None
+            | LaneType::Restricted
+            | LaneType::Median
+            | LaneType::Curb => [false, false, false, false, false, false, true],
+            LaneType::Special1
+            | LaneType::Special2
+            | LaneType::Special3
+            | LaneType::RoadWorks
+            | LaneType::Other(_) => [false; 7],
+        };
+
+        assert_eq!(26, LaneType::ALL.len());
+        let names = LaneType::ALL
+            .iter()
+            .map(LaneType::as_str)
+            .collect::<HashSet<_>>();
+        assert_eq!(LaneType::ALL.len(), names.len());
+        for r#type in LaneType::ALL {
+            assert!(!matches!(r#type, LaneType::Other(_)));
+            assert_eq!(
+                Some(r#type),
+                LaneType::from_str(r#type.as_str()).ok().as_ref(),
+                "{type:?}"
+            );
+            let predicates = [
+                r#type.is_drivable(),
+                r#type.is_restricted_vehicle(),
+                r#type.is_pedestrian(),
+                r#type.is_bicycle(),
+                r#type.is_parking(),
+                r#type.is_rail(),
+                r#type.is_non_traffic(),
+            ];
+            assert_eq!(expected(r#type), predicates, "{type:?}");
+        }
+        let other = LaneType::Other("vendorLane".to_string());
+        assert_eq!([false; 7], expected(&other));
+        assert!(!other.is_drivable() && !other.is_non_traffic());
+    }
+}
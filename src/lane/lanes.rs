@@ -1,6 +1,9 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::validated::SortedByS;
 use crate::lane::lane_section::LaneSection;
 use crate::lane::offset::Offset;
+use crate::road::Road;
+use crate::units::prelude::*;
 use std::borrow::Cow;
 use vec1::Vec1;
 
@@ -13,35 +16,120 @@ pub struct Lanes {
     pub additional_data: AdditionalData,
 }
 
+impl Default for Lanes {
+    /// No lane offset and a single [`LaneSection::default`]
+    fn default() -> Self {
+        Self {
+            lane_offset: Vec::new(),
+            lane_section: Vec1::new(LaneSection::default()),
+            additional_data: AdditionalData::default(),
+        }
+    }
+}
+
 impl Lanes {
+    /// Lanes consisting of the given lane sections and lane offsets, `None` if there are no lane
+    /// sections
+    pub fn from_sections(
+        lane_section: SortedByS<LaneSection>,
+        lane_offset: SortedByS<Offset>,
+    ) -> Option<Self> {
+        Some(Self {
+            lane_offset: lane_offset.into_inner(),
+            lane_section: Vec1::try_from_vec(lane_section.into_inner()).ok()?,
+            additional_data: AdditionalData::default(),
+        })
+    }
+
+    /// The lateral shift of the center lane from the reference line at the s-coordinate `s`
+    pub fn offset_at(&self, s: f64) -> f64 {
+        self.lane_offset
+            .iter()
+            .rev()
+            .find(|o| o.s <= s)
+            .map(|o| o.value(s))
+            .unwrap_or(0.0)
+    }
+
+    /// The t-coordinates of the outer borders of the outermost right and left lanes at the
+    /// s-coordinate `s`, which are those of the center lane if there are no lanes on a side
+    pub fn extent_at(&self, s: f64) -> (f64, f64) {
+        let (_, section) = self.lane_section_at(s);
+        let offset = self.offset_at(s);
+        let (min, max) = section
+            .lanes()
+            .filter_map(|(id, _)| section.lane_borders(id, s - section.s))
+            .fold((0.0_f64, 0.0_f64), |(min, max), (_, outer)| {
+                (min.min(outer), max.max(outer))
+            });
+        (offset + min, offset + max)
+    }
+
+    /// Each lane section together with the s-coordinates of its start and end. A lane section ends
+    /// where the next lane section starts, the last lane section ends at the end of the given
+    /// road, which is expected to be the road these lanes belong to.
+    pub fn sections_with_ranges<'a>(
+        &'a self,
+        road: &Road,
+    ) -> impl Iterator<Item = (&'a LaneSection, Length, Length)> {
+        let road_length = road.length;
+        self.lane_section
+            .iter()
+            .enumerate()
+            .map(move |(index, section)| {
+                let end = self
+                    .lane_section
+                    .get(index + 1)
+                    .map(|next| Length::new::<meter>(next.s))
+                    .unwrap_or(road_length);
+                (section, Length::new::<meter>(section.s), end)
+            })
+    }
+
+    /// The lane section that contains the s-coordinate `s` together with its index. Positions
+    /// before the first lane section resolve to the first lane section.
+    pub fn lane_section_at(&self, s: f64) -> (usize, &LaneSection) {
+        self.lane_section
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, section)| section.s <= s)
+            .unwrap_or((0, self.lane_section.first()))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for lane_offset in &self.lane_offset {
-            visit_children!(visitor, "laneOffset" => lane_offset);
+            visit_children!(visitor, options, "laneOffset" => lane_offset);
         }
 
         for lane_section in &self.lane_section {
-            visit_children!(visitor, "laneSection" => lane_section);
+            visit_children!(visitor, options, "laneSection" => lane_section);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Lanes
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -60,7 +148,7 @@ where
         Ok(Self {
             lane_offset,
             lane_section: Vec1::try_from_vec(lane_section).unwrap(),
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -79,3 +167,36 @@ impl arbitrary::Arbitrary<'_> for Lanes {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_sections_with_ranges() {
+        let ranges = |road: TestRoad| {
+            let drive = parse(road);
+            let road = &drive.road[0];
+            road.lanes
+                .sections_with_ranges(road)
+                .map(|(section, start, end)| {
+                    assert_eq!(section.s, start.get::<meter>());
+                    (start.get::<meter>(), end.get::<meter>())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // the first section starts at 0 and the last one ends at the end of the road
+        let sections = TestRoad::new("1").length(80.0).lanes(
+            r#"<laneSection s="0"><center><lane id="0" type="none"/></center></laneSection>
+            <laneSection s="25"><center><lane id="0" type="none"/></center></laneSection>
+            <laneSection s="60.5"><center><lane id="0" type="none"/></center></laneSection>"#,
+        );
+        assert_eq!(
+            vec![(0.0, 25.0), (25.0, 60.5), (60.5, 80.0)],
+            ranges(sections)
+        );
+        assert_eq!(vec![(0.0, 42.0)], ranges(TestRoad::new("1").length(42.0)));
+    }
+}
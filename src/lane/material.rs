@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Stores information about the material of lanes. Each element is valid until a new element is
 /// defined. If multiple elements are defined, they must be listed in ascending order.
@@ -22,13 +21,16 @@ impl Material {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "friction" => Some(self.friction.to_scientific_string()).as_deref(),
-            "roughness" => self.roughness.map(|v| v.to_scientific_string()).as_deref(),
-            "sOffset" => Some(self.s_offset.value.to_scientific_string()).as_deref(),
+            options,
+            "friction" => Some(options.format("friction", self.friction)).as_deref(),
+            "roughness" => self.roughness.map(|v| options.format("roughness", v)).as_deref(),
+            "sOffset" => Some(options.format("sOffset", self.s_offset.get::<meter>())).as_deref(),
             "surface" => self.surface.as_deref(),
         )
     }
@@ -36,15 +38,16 @@ impl Material {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Material
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -1,4 +1,5 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::boolean::Bool;
 use crate::lane::access::Access;
 use crate::lane::border::Border;
 use crate::lane::height::Height;
@@ -7,6 +8,7 @@ use crate::lane::road_mark::RoadMark;
 use crate::lane::rule::Rule;
 use crate::lane::speed::Speed;
 use crate::lane::width::Width;
+use crate::units::prelude::*;
 use lane_choice::LaneChoice;
 use lane_link::LaneLink;
 use lane_type::LaneType;
@@ -31,7 +33,10 @@ pub mod right;
 pub mod right_lane;
 pub mod road_mark;
 pub mod rule;
+pub mod shift;
 pub mod speed;
+pub mod taper;
+pub mod travel_direction;
 pub mod type_link;
 pub mod width;
 
@@ -55,66 +60,147 @@ pub struct Lane {
     pub additional_data: AdditionalData,
 }
 
+impl Default for Lane {
+    fn default() -> Self {
+        Self {
+            link: None,
+            choice: Vec::new(),
+            road_mark: Vec::new(),
+            material: Vec::new(),
+            speed: Vec::new(),
+            access: Vec::new(),
+            height: Vec::new(),
+            rule: Vec::new(),
+            level: None,
+            r#type: LaneType::None,
+            additional_data: AdditionalData::default(),
+        }
+    }
+}
+
 impl Lane {
+    /// The width of this lane at `ds`, relative to the start of the lane section, or `None` if
+    /// the lane has no `<width>` elements
+    pub fn width_at(&self, ds: f64) -> Option<f64> {
+        let widths = self.choice.iter().filter_map(|c| match c {
+            LaneChoice::Width(width) => Some(width),
+            LaneChoice::Border(_) => None,
+        });
+        widths
+            .clone()
+            .rev()
+            .find(|w| w.s_offset.get::<meter>() <= ds)
+            .or_else(|| widths.clone().next())
+            .map(|w| w.value(ds))
+    }
+
+    /// The inner and outer offset of this lane from the road surface at `ds`, relative to the
+    /// start of the lane section, zero before its first `<height>` element
+    pub fn height_at(&self, ds: f64) -> (f64, f64) {
+        self.height
+            .iter()
+            .rev()
+            .find(|h| h.s_offset.get::<meter>() <= ds)
+            .map_or((0.0, 0.0), |h| {
+                (h.inner.get::<meter>(), h.outer.get::<meter>())
+            })
+    }
+
+    /// The `<material>` of this lane that applies at `ds`, relative to the start of the lane
+    /// section, or `None` if there is none
+    pub fn material_at(&self, ds: f64) -> Option<&Material> {
+        self.material
+            .iter()
+            .rev()
+            .find(|m| m.s_offset.get::<meter>() <= ds)
+    }
+
+    /// The friction coefficient of this lane at `ds`, relative to the start of the lane section,
+    /// or `None` if no `<material>` applies there
+    pub fn friction_at(&self, ds: f64) -> Option<f64> {
+        self.material_at(ds).map(|m| m.friction)
+    }
+
+    /// The distance of the outer border of this lane from the center lane at `ds`, relative to
+    /// the start of the lane section, or `None` if the lane has no `<border>` elements
+    pub fn border_at(&self, ds: f64) -> Option<f64> {
+        let borders = self.choice.iter().filter_map(|c| match c {
+            LaneChoice::Border(border) => Some(border),
+            LaneChoice::Width(_) => None,
+        });
+        borders
+            .clone()
+            .rev()
+            .find(|b| b.s_offset.get::<meter>() <= ds)
+            .or_else(|| borders.clone().next())
+            .map(|b| b.value(ds))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
-            "level" => self.level.map(|v| v.to_string()).as_deref(),
+            options,
+            "level" => self.level.map(|v| Bool(v).as_str()),
             "type" => Some(self.r#type.as_str()),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         if let Some(link) = &self.link {
-            visit_children!(visitor, "link" => link);
+            visit_children!(visitor, options, "link" => link);
         }
 
         for choice in &self.choice {
             match choice {
-                LaneChoice::Border(border) => visit_children!(visitor, "border" => border),
-                LaneChoice::Width(width) => visit_children!(visitor, "width" => width),
+                LaneChoice::Border(border) => visit_children!(visitor, options, "border" => border),
+                LaneChoice::Width(width) => visit_children!(visitor, options, "width" => width),
             }
         }
 
         for road_mark in &self.road_mark {
-            visit_children!(visitor, "roadMark" => road_mark);
+            visit_children!(visitor, options, "roadMark" => road_mark);
         }
 
         for material in &self.material {
-            visit_children!(visitor, "material" => material);
+            visit_children!(visitor, options, "material" => material);
         }
 
         for speed in &self.speed {
-            visit_children!(visitor, "speed" => speed);
+            visit_children!(visitor, options, "speed" => speed);
         }
 
         for access in &self.access {
-            visit_children!(visitor, "access" => access);
+            visit_children!(visitor, options, "access" => access);
         }
 
         for height in &self.height {
-            visit_children!(visitor, "height" => height);
+            visit_children!(visitor, options, "height" => height);
         }
 
         for rule in &self.rule {
-            visit_children!(visitor, "rule" => rule);
+            visit_children!(visitor, options, "rule" => rule);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Lane
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -152,9 +238,9 @@ where
             access,
             height,
             rule,
-            level: read.attribute_opt("level")?,
-            r#type: read.attribute("type")?,
-            additional_data,
+            level: read.attribute_opt::<Bool>("level")?.map(bool::from),
+            r#type: read.enum_attribute("type")?,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
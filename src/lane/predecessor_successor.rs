@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct PredecessorSuccessor {
     /// ID of the preceding / succeeding linked lane
@@ -12,14 +12,17 @@ impl PredecessorSuccessor {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor, "id" => &self.id.to_string())
+        visit_attributes!(visitor, options, "id" => &self.id.to_string())
     }
 
     pub fn visit_children(
         &self,
         _visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        _options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         Ok(())
     }
@@ -27,7 +30,7 @@ impl PredecessorSuccessor {
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for PredecessorSuccessor
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
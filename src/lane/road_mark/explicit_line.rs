@@ -1,7 +1,6 @@
 use crate::lane::road_mark::rule::Rule;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExplicitLine {
@@ -25,30 +24,34 @@ impl ExplicitLine {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "length" => Some(self.length.value.to_scientific_string()).as_deref(),
+            options,
+            "length" => Some(options.format("length", self.length.get::<meter>())).as_deref(),
             "rule" => self.rule.as_ref().map(Rule::as_str),
-            "sOffset" => Some(self.s_offset.value.to_scientific_string()).as_deref(),
-            "tOffset" => Some(self.t_offset.value.to_scientific_string()).as_deref(),
-            "width" => self.width.map(|v| v.value.to_scientific_string()).as_deref(),
+            "sOffset" => Some(options.format("sOffset", self.s_offset.get::<meter>())).as_deref(),
+            "tOffset" => Some(options.format("tOffset", self.t_offset.get::<meter>())).as_deref(),
+            "width" => self.width.map(|v| options.format("width", v.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for ExplicitLine
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
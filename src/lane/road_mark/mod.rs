@@ -1,19 +1,19 @@
 use crate::core::additional_data::AdditionalData;
 use crate::lane::road_mark::color::Color;
 use crate::lane::road_mark::weight::Weight;
+use crate::units::prelude::*;
 use explicit::Explicit;
 use lane_change::LaneChange;
 use r#type::Type;
 use std::borrow::Cow;
 use sway::Sway;
 use type_simplified::TypeSimplified;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 pub mod color;
 pub mod explicit;
 pub mod explicit_line;
 pub mod lane_change;
+pub mod presets;
 pub mod rule;
 pub mod sway;
 pub mod r#type;
@@ -52,48 +52,65 @@ pub struct RoadMark {
 }
 
 impl RoadMark {
+    /// Whether this road mark may be crossed when changing from the lane with id `from` to the
+    /// adjacent lane with id `to`, see [`RoadMark::lane_change`].
+    pub fn allows_lane_change(&self, from: i64, to: i64) -> bool {
+        match self.lane_change.as_ref().unwrap_or(&LaneChange::Both) {
+            LaneChange::Increase => to > from,
+            LaneChange::Decrease => to < from,
+            LaneChange::Both => true,
+            LaneChange::None => false,
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "color" => Some(self.color.as_str()),
-            "height" => self.height.map(|v| v.value.to_scientific_string()).as_deref(),
+            "height" => self.height.map(|v| options.format("height", v.get::<meter>())).as_deref(),
             "laneChange" => self.lane_change.as_ref().map(LaneChange::as_str),
             "material" => self.material.as_deref(),
-            "sOffset" => Some(self.s_offset.value.to_scientific_string()).as_deref(),
+            "sOffset" => Some(options.format("sOffset", self.s_offset.get::<meter>())).as_deref(),
             "type" => Some(self.type_simplified.as_str()),
             "weight" => self.weight.as_ref().map(Weight::as_str),
-            "width" => self.width.map(|v| v.value.to_scientific_string()).as_deref(),
+            "width" => self.width.map(|v| options.format("width", v.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for sway in &self.sway {
-            visit_children!(visitor, "sway" => sway);
+            visit_children!(visitor, options, "sway" => sway);
         }
 
         if let Some(r#type) = &self.r#type {
-            visit_children!(visitor, "type" => r#type);
+            visit_children!(visitor, options, "type" => r#type);
         }
 
         if let Some(explicit) = &self.explicit {
-            visit_children!(visitor, "explicit" => explicit);
+            visit_children!(visitor, options, "explicit" => explicit);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for RoadMark
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -127,7 +144,7 @@ where
             type_simplified: read.attribute("type")?,
             weight: read.attribute_opt("weight")?,
             width: read.attribute_opt("width")?.map(Length::new::<meter>),
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -0,0 +1,247 @@
+use crate::core::additional_data::AdditionalData;
+use crate::core::OpenDrive;
+use crate::lane::lane_section::LaneSection;
+use crate::lane::road_mark::color::Color;
+use crate::lane::road_mark::lane_change::LaneChange;
+use crate::lane::road_mark::r#type::Type;
+use crate::lane::road_mark::type_simplified::TypeSimplified;
+use crate::lane::road_mark::weight::Weight;
+use crate::lane::road_mark::RoadMark;
+use crate::lane::type_link::TypeLine;
+use crate::lane::Lane;
+use crate::road::country_code::CountryCode;
+use crate::road::Road;
+use crate::units::prelude::*;
+use vec1::Vec1;
+
+/// The road marking conventions of a country, which give the color, width and dash pattern of
+/// the lines between and beside lanes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoadMarkPreset {
+    /// Germany (DE), after the Richtlinien für die Markierung von Straßen: white lines, broken
+    /// lines of 6 m with gaps of 12 m as narrow lines, edge lines as bold lines
+    Germany,
+    /// United States (US), after the MUTCD: yellow lines between opposing directions and at the
+    /// left edge of one-way roadways, otherwise white lines, broken lines of 10 ft with gaps of
+    /// 30 ft, all lines 4 in wide
+    UnitedStates,
+    /// Japan (JP): white lines, broken lines of 5 m with gaps of 5 m, all lines 15 cm wide
+    Japan,
+}
+
+/// The line a road mark of a [`RoadMarkPreset`] draws
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarkedLine {
+    /// Between the lanes of opposing directions
+    Center,
+    /// Between lanes of the same direction
+    Lane,
+    /// At the outer edge of the roadway
+    Edge,
+    /// At the inner edge of a roadway with lanes on one side of the center lane only, like the
+    /// carriageways of a divided highway
+    InnerEdge,
+}
+
+impl RoadMarkPreset {
+    /// The preset for the country, if there is one
+    pub fn for_country(country: &CountryCode) -> Option<Self> {
+        match country.iso3166alpha2()? {
+            "DE" => Some(Self::Germany),
+            "US" => Some(Self::UnitedStates),
+            "JP" => Some(Self::Japan),
+            _ => None,
+        }
+    }
+
+    /// The ISO 3166-1 alpha-2 code of the country of this preset
+    pub fn country(&self) -> &'static str {
+        match self {
+            Self::Germany => "DE",
+            Self::UnitedStates => "US",
+            Self::Japan => "JP",
+        }
+    }
+
+    /// The road mark of the line by the conventions of this preset. Broken lines may be crossed,
+    /// solid lines may not.
+    pub fn road_mark(&self, line: MarkedLine) -> RoadMark {
+        let broken = matches!(line, MarkedLine::Center | MarkedLine::Lane);
+        let (length, space, width) = match self {
+            Self::Germany if broken => (6.0, 12.0, 0.12),
+            Self::Germany => (6.0, 0.0, 0.25),
+            Self::UnitedStates if broken => (3.048, 9.144, 0.1016),
+            Self::UnitedStates => (3.048, 0.0, 0.1016),
+            Self::Japan if broken => (5.0, 5.0, 0.15),
+            Self::Japan => (5.0, 0.0, 0.15),
+        };
+        let color = match (self, line) {
+            (Self::UnitedStates, MarkedLine::Center | MarkedLine::InnerEdge) => Color::Yellow,
+            _ => Color::White,
+        };
+        let weight = match self {
+            Self::Germany if !broken => Weight::Bold,
+            _ => Weight::Standard,
+        };
+        let type_simplified = if broken {
+            TypeSimplified::Broken
+        } else {
+            TypeSimplified::Solid
+        };
+        let width = Length::new::<meter>(width);
+        RoadMark {
+            sway: Vec::new(),
+            r#type: Some(Type {
+                line: Vec1::new(TypeLine {
+                    color: None,
+                    length: Length::new::<meter>(length),
+                    rule: None,
+                    s_offset: Length::new::<meter>(0.0),
+                    space: Length::new::<meter>(space),
+                    t_offset: Length::new::<meter>(0.0),
+                    width: Some(width),
+                }),
+                name: format!("{} {}", self.country(), type_simplified.as_str()),
+                width,
+                additional_data: AdditionalData::default(),
+            }),
+            explicit: None,
+            color,
+            height: None,
+            lane_change: Some(if broken {
+                LaneChange::Both
+            } else {
+                LaneChange::None
+            }),
+            material: None,
+            s_offset: Length::new::<meter>(0.0),
+            type_simplified,
+            weight: Some(weight),
+            width: Some(width),
+            additional_data: AdditionalData::default(),
+        }
+    }
+
+    /// Adds road marks by this preset to the lanes of the lane section that have none. The
+    /// center lane gets a [`MarkedLine::Center`] between drivable lanes on both sides and a
+    /// [`MarkedLine::InnerEdge`] next to drivable lanes on one side. A drivable lane gets a
+    /// [`MarkedLine::Lane`] if its outer neighbor is drivable as well, otherwise a
+    /// [`MarkedLine::Edge`]. Lanes that are not drivable are left without road marks.
+    pub fn apply(&self, section: &mut LaneSection) {
+        let drivable = |id: i64| section.lane(id).is_some_and(|l| l.r#type.is_drivable());
+        let lines = section
+            .lanes()
+            .filter(|(_, lane)| lane.road_mark.is_empty())
+            .filter_map(|(id, lane)| {
+                let line = if id == 0 {
+                    match (drivable(1), drivable(-1)) {
+                        (true, true) => MarkedLine::Center,
+                        (false, false) => return None,
+                        _ => MarkedLine::InnerEdge,
+                    }
+                } else if !lane.r#type.is_drivable() {
+                    return None;
+                } else if drivable(id + id.signum()) {
+                    MarkedLine::Lane
+                } else {
+                    MarkedLine::Edge
+                };
+                Some((id, line))
+            })
+            .collect::<Vec<_>>();
+        for (id, line) in lines {
+            if let Some(lane) = lane_mut(section, id) {
+                lane.road_mark.push(self.road_mark(line));
+            }
+        }
+    }
+}
+
+impl Road {
+    /// Adds road marks by the preset to all lanes of this road that have none, see
+    /// [`RoadMarkPreset::apply`]. Lanes created by the generators of this crate have no road
+    /// marks, so this gives them the markings of a country.
+    pub fn apply_road_mark_preset(&mut self, preset: RoadMarkPreset) {
+        for section in &mut self.lanes.lane_section {
+            preset.apply(section);
+        }
+    }
+}
+
+impl OpenDrive {
+    /// Adds road marks by the preset to all lanes of all roads that have none, see
+    /// [`Road::apply_road_mark_preset`]
+    pub fn apply_road_mark_preset(&mut self, preset: RoadMarkPreset) {
+        for road in &mut self.road {
+            road.apply_road_mark_preset(preset);
+        }
+    }
+}
+
+fn lane_mut(section: &mut LaneSection, id: i64) -> Option<&mut Lane> {
+    let left = section
+        .left
+        .iter_mut()
+        .flat_map(|l| l.lane.iter_mut().map(|l| (l.id, &mut l.base)));
+    let center = section.center.lane.iter_mut().map(|l| (l.id, &mut l.base));
+    let right = section
+        .right
+        .iter_mut()
+        .flat_map(|r| r.lane.iter_mut().map(|l| (l.id, &mut l.base)));
+    left.chain(center)
+        .chain(right)
+        .find(|(i, _)| *i == id)
+        .map(|(_, lane)| lane)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::generate::generators::StandardMap;
+    use crate::lane::road_mark::color::Color;
+    use crate::lane::road_mark::presets::RoadMarkPreset;
+    use crate::lane::road_mark::type_simplified::TypeSimplified;
+    use crate::road::country_code::CountryCode;
+    use crate::units::prelude::*;
+    use std::str::FromStr;
+
+    #[test]
+    pub fn test_road_mark_presets() {
+        let mut drive = StandardMap::Highway {
+            lanes: 2,
+            length: Length::new::<meter>(100.0),
+        }
+        .generate();
+        drive.apply_road_mark_preset(RoadMarkPreset::UnitedStates);
+        drive.apply_road_mark_preset(RoadMarkPreset::Germany);
+
+        let section = drive.road[0].lanes.lane_section.first();
+        let marks = |id| {
+            let marks = &section.lane(id).unwrap().road_mark;
+            assert_eq!(1, marks.len());
+            let mark = &marks[0];
+            let line = &mark.r#type.as_ref().unwrap().line[0];
+            (
+                mark.type_simplified.clone(),
+                mark.color.clone(),
+                line.length.get::<meter>(),
+                line.space.get::<meter>(),
+            )
+        };
+        let broken = (TypeSimplified::Broken, Color::Yellow, 3.048, 9.144);
+        assert_eq!(broken, marks(0));
+        let broken = (TypeSimplified::Broken, Color::White, 3.048, 9.144);
+        assert_eq!(broken, marks(1));
+        assert_eq!(broken, marks(-1));
+        let solid = (TypeSimplified::Solid, Color::White, 3.048, 0.0);
+        assert_eq!(solid, marks(2));
+        assert_eq!(solid, marks(-2));
+
+        assert_eq!(
+            Some(RoadMarkPreset::Japan),
+            RoadMarkPreset::for_country(&CountryCode::from_str("JP").unwrap())
+        );
+        let xml = drive.to_xml_string().unwrap();
+        assert_eq!(drive, OpenDrive::from_xml_str(&xml).unwrap());
+    }
+}
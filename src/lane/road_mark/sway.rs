@@ -23,30 +23,34 @@ impl Sway {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "a" => Some(self.a.to_scientific_string()).as_deref(),
-            "b" => Some(self.b.to_scientific_string()).as_deref(),
-            "c" => Some(self.c.to_scientific_string()).as_deref(),
-            "d" => Some(self.d.to_scientific_string()).as_deref(),
-            "d_s" => Some(self.d_s.to_scientific_string()).as_deref(),
+            options,
+            "a" => Some(options.format("a", self.a)).as_deref(),
+            "b" => Some(options.format("b", self.b)).as_deref(),
+            "c" => Some(options.format("c", self.c)).as_deref(),
+            "d" => Some(options.format("d", self.d)).as_deref(),
+            "d_s" => Some(options.format("d_s", self.d_s)).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Sway
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
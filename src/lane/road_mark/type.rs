@@ -1,8 +1,7 @@
 use crate::core::additional_data::AdditionalData;
 use crate::lane::type_link::TypeLine;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 use vec1::Vec1;
 
 /// Each type definition shall contain one or more line definitions with additional information
@@ -24,30 +23,36 @@ impl Type {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes!(
             visitor,
+            options,
             "name" => &self.name,
-            "width" => &self.width.value.to_scientific_string(),
+            "width" => &options.format("width", self.width.get::<meter>()),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for line in &self.line {
-            visit_children!(visitor, "line" => line);
+            visit_children!(visitor, options, "line" => line);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Type
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -65,7 +70,7 @@ where
             line: Vec1::try_from_vec(line).unwrap(),
             name: read.attribute("name")?,
             width: read.attribute("width").map(Length::new::<meter>)?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -0,0 +1,289 @@
+use crate::lane::border::Border;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::offset::Offset;
+use crate::lane::taper::lanes_mut;
+use crate::lane::width::Width;
+use crate::road::Road;
+use crate::units::prelude::*;
+
+/// A cubic polynomial `a + b·ds + c·ds² + d·ds³` with `ds` relative to `s`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Cubic {
+    s: f64,
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+}
+
+impl Cubic {
+    const ZERO: Cubic = Cubic {
+        s: 0.0,
+        a: 0.0,
+        b: 0.0,
+        c: 0.0,
+        d: 0.0,
+    };
+
+    /// The same polynomial relative to `s`
+    fn rebased(&self, s: f64) -> Self {
+        let ds = s - self.s;
+        let (b, c, d) = (self.b, self.c, self.d);
+        Self {
+            s,
+            a: self.a + b * ds + c * ds.powi(2) + d * ds.powi(3),
+            b: b + 2.0 * c * ds + 3.0 * d * ds.powi(2),
+            c: c + 3.0 * d * ds,
+            d,
+        }
+    }
+
+    fn plus(&self, other: &Cubic, sign: f64) -> Self {
+        Self {
+            s: self.s,
+            a: self.a + sign * other.a,
+            b: self.b + sign * other.b,
+            c: self.c + sign * other.c,
+            d: self.d + sign * other.d,
+        }
+    }
+}
+
+/// The piece of the sorted polynomials in effect at `s`, relative to `s`. Before the first piece
+/// this is the first piece if `extend`, as for widths and borders, otherwise zero, as for lane
+/// offsets.
+fn piece_at(pieces: &[Cubic], s: f64, extend: bool) -> Cubic {
+    pieces
+        .iter()
+        .rev()
+        .find(|piece| piece.s <= s)
+        .or_else(|| pieces.first().filter(|_| extend))
+        .map_or(Cubic { s, ..Cubic::ZERO }, |piece| piece.rebased(s))
+}
+
+/// The pieces of the sum of `pieces` and `sign` times the lane offsets `shift`, starting at each
+/// start of either of them and at `from`, with all `s` relative to `origin`
+fn shifted(
+    pieces: &[Cubic],
+    extend: bool,
+    shift: &[Cubic],
+    sign: f64,
+    origin: f64,
+    (from, to): (f64, f64),
+) -> Vec<Cubic> {
+    let mut starts = pieces
+        .iter()
+        .map(|piece| piece.s)
+        .chain(shift.iter().map(|piece| piece.s - origin))
+        .filter(|s| *s > from && *s < to)
+        .chain([from])
+        .collect::<Vec<_>>();
+    starts.sort_by(f64::total_cmp);
+    starts.dedup();
+    starts
+        .into_iter()
+        .map(|s| {
+            let shift = piece_at(shift, origin + s, false);
+            piece_at(pieces, s, extend).plus(&Cubic { s, ..shift }, sign)
+        })
+        .collect()
+}
+
+impl Road {
+    /// Shifts the center lane laterally by the piecewise cubic `shift`, which is given like
+    /// `<laneOffset>` records and is zero before its first one, for example to recenter a road
+    /// that was widened on one side. The shift is added to the lane offsets; the widths of the
+    /// innermost lanes and all borders are recomputed so that the other lane borders keep their
+    /// absolute position. The widths of the innermost lanes become negative where the center lane
+    /// is shifted beyond their outer border.
+    pub fn shift_lane_reference(&mut self, shift: &[Offset]) {
+        let mut shift = shift
+            .iter()
+            .map(|offset| Cubic {
+                s: offset.s,
+                a: offset.a,
+                b: offset.b,
+                c: offset.c,
+                d: offset.d,
+            })
+            .collect::<Vec<_>>();
+        shift.sort_by(|a, b| a.s.total_cmp(&b.s));
+        let Some(first) = shift.first().map(|piece| piece.s) else {
+            return;
+        };
+
+        let offsets = self
+            .lanes
+            .lane_offset
+            .iter()
+            .map(|offset| Cubic {
+                s: offset.s,
+                a: offset.a,
+                b: offset.b,
+                c: offset.c,
+                d: offset.d,
+            })
+            .collect::<Vec<_>>();
+        let from = offsets.first().map_or(first, |offset| offset.s.min(first));
+        self.lanes.lane_offset = shifted(&offsets, false, &shift, 1.0, 0.0, (from, f64::INFINITY))
+            .into_iter()
+            .map(|piece| Offset {
+                a: piece.a,
+                b: piece.b,
+                c: piece.c,
+                d: piece.d,
+                s: piece.s,
+            })
+            .collect();
+
+        let ranges = self
+            .lanes
+            .sections_with_ranges(self)
+            .map(|(_, start, end)| (start.get::<meter>(), end.get::<meter>()))
+            .collect::<Vec<_>>();
+        for (section, (start, end)) in self.lanes.lane_section.iter_mut().zip(ranges) {
+            if first >= end {
+                continue;
+            }
+            let range = (0.0, end - start);
+            for (id, lane) in lanes_mut(section) {
+                let (widths, borders): (Vec<_>, Vec<_>) =
+                    lane.choice.iter().partition(|choice| match choice {
+                        LaneChoice::Width(_) => true,
+                        LaneChoice::Border(_) => false,
+                    });
+                let cubic = |choice: &LaneChoice| {
+                    let (s_offset, a, b, c, d) = match choice {
+                        LaneChoice::Width(w) => (w.s_offset, w.a, w.b, w.c, w.d),
+                        LaneChoice::Border(b) => (b.s_offset, b.a, b.b, b.c, b.d),
+                    };
+                    Cubic {
+                        s: s_offset.get::<meter>(),
+                        a,
+                        b,
+                        c,
+                        d,
+                    }
+                };
+                let widths = widths.into_iter().map(cubic).collect::<Vec<_>>();
+                let borders = borders.into_iter().map(cubic).collect::<Vec<_>>();
+                // the outer border of the innermost lanes is the offset plus or minus their width,
+                // the borders of all lanes are distances from the center lane
+                let sign = -id.signum() as f64;
+                let widths = if id.abs() == 1 && !widths.is_empty() {
+                    shifted(&widths, true, &shift, sign, start, range)
+                } else {
+                    widths
+                };
+                let borders = if borders.is_empty() {
+                    borders
+                } else {
+                    shifted(&borders, true, &shift, sign, start, range)
+                };
+                lane.choice = widths
+                    .into_iter()
+                    .map(|piece| {
+                        LaneChoice::Width(Width {
+                            a: piece.a,
+                            b: piece.b,
+                            c: piece.c,
+                            d: piece.d,
+                            s_offset: Length::new::<meter>(piece.s),
+                        })
+                    })
+                    .chain(borders.into_iter().map(|piece| {
+                        LaneChoice::Border(Border {
+                            a: piece.a,
+                            b: piece.b,
+                            c: piece.c,
+                            d: piece.d,
+                            s_offset: Length::new::<meter>(piece.s),
+                        })
+                    }))
+                    .collect();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::{parse_revision, TestRoad};
+    use crate::lane::offset::Offset;
+
+    #[test]
+    pub fn test_shift_lane_reference() {
+        let mut drive = parse_revision(
+            8,
+            TestRoad::new("1").lanes(
+                r#"<laneOffset s="10" a="0.5" b="0.01" c="0" d="0"/>
+                        <laneSection s="0">
+                            <left><lane id="1" type="driving" level="false"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane></left>
+                            <center><lane id="0" type="none" level="false"/></center>
+                            <right>
+                                <lane id="-1" type="driving" level="false"><width sOffset="0" a="3.5" b="0.01" c="0" d="0"/><width sOffset="30" a="3.8" b="0" c="0" d="0"/></lane>
+                                <lane id="-2" type="shoulder" level="false"><width sOffset="5" a="1" b="0" c="0" d="0"/></lane>
+                            </right>
+                        </laneSection>
+                        <laneSection s="40">
+                            <center><lane id="0" type="none" level="false"/></center>
+                            <right>
+                                <lane id="-1" type="driving" level="false"><border sOffset="0" a="4" b="0.01" c="0" d="0"/></lane>
+                                <lane id="-2" type="driving" level="false"><border sOffset="0" a="7.5" b="0" c="0" d="0"/></lane>
+                            </right>
+                        </laneSection>"#,
+            ),
+        );
+        let borders = |drive: &OpenDrive| {
+            let road = &drive.road[0];
+            (0..=100)
+                .map(|s| {
+                    let s = s as f64;
+                    let (_, section) = road.lanes.lane_section_at(s);
+                    let offset = road.lanes.offset_at(s);
+                    let borders = section
+                        .lanes()
+                        .filter(|(id, _)| *id != 0)
+                        .map(|(id, _)| offset + section.lane_borders(id, s - section.s).unwrap().1)
+                        .collect::<Vec<_>>();
+                    (offset, borders)
+                })
+                .collect::<Vec<_>>()
+        };
+        let before = borders(&drive);
+
+        drive.road[0].shift_lane_reference(&[
+            Offset {
+                a: -1.0,
+                b: 0.0,
+                c: 0.0,
+                d: 0.0,
+                s: 20.0,
+            },
+            Offset {
+                a: -1.0,
+                b: -0.02,
+                c: 0.001,
+                d: 0.0,
+                s: 60.0,
+            },
+        ]);
+        let after = borders(&drive);
+
+        for (s, (before, after)) in before.iter().zip(&after).enumerate() {
+            let s = s as f64;
+            let shift = match s {
+                s if s < 20.0 => 0.0,
+                s if s < 60.0 => -1.0,
+                s => -1.0 - 0.02 * (s - 60.0) + 0.001 * (s - 60.0).powi(2),
+            };
+            assert!((before.0 + shift - after.0).abs() < 1e-9, "s={s}");
+            assert_eq!(before.1.len(), after.1.len());
+            for (before, after) in before.1.iter().zip(&after.1) {
+                assert!((before - after).abs() < 1e-9, "s={s}");
+            }
+        }
+        assert_eq!(3, drive.road[0].lanes.lane_offset.len());
+    }
+}
@@ -1,32 +1,48 @@
-use crate::road::unit::SpeedUnit;
+use crate::road::unit::{SpeedUnit, SpeedValue};
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Defines the maximum allowed speed on a given lane. Each element is valid in direction of the
 /// increasing s-coordinate until a new element is defined.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Speed {
-    /// Maximum allowed speed. If the attribute unit is not specified, m/s is used as default.
+    /// Maximum allowed speed in the unit of the attribute unit, m/s if the attribute unit is not
+    /// specified
     pub max: f64,
     /// s-coordinate of start position, relative to the position of the preceding `<laneSection>`
     /// element
     pub s_offset: Length,
-    /// Unit of the attribute max
+    /// Unit of the attribute max, written back as read
     pub unit: Option<SpeedUnit>,
 }
 
 impl Speed {
+    /// The maximum allowed speed in its unit
+    pub fn speed(&self) -> SpeedValue {
+        SpeedValue::new(
+            self.max,
+            self.unit.clone().unwrap_or(SpeedUnit::MetersPerSecond),
+        )
+    }
+
+    /// The maximum allowed speed as velocity
+    pub fn velocity(&self) -> Velocity {
+        self.speed().velocity()
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "max" => Some(self.max.to_scientific_string()).as_deref(),
-            "sOffset" => Some(self.s_offset.value.to_scientific_string()).as_deref(),
+            options,
+            "max" => Some(options.format("max", self.max)).as_deref(),
+            "sOffset" => Some(options.format("sOffset", self.s_offset.get::<meter>())).as_deref(),
             "unit" => self.unit.as_ref().map(SpeedUnit::as_str),
         )
     }
@@ -34,15 +50,16 @@ impl Speed {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Speed
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
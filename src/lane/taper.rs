@@ -0,0 +1,454 @@
+use crate::core::additional_data::AdditionalData;
+use crate::lane::access::Access;
+use crate::lane::border::Border;
+use crate::lane::height::Height;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::lane_link::LaneLink;
+use crate::lane::lane_section::LaneSection;
+use crate::lane::lane_type::LaneType;
+use crate::lane::left::Left;
+use crate::lane::left_lane::LeftLane;
+use crate::lane::material::Material;
+use crate::lane::predecessor_successor::PredecessorSuccessor;
+use crate::lane::right::Right;
+use crate::lane::right_lane::RightLane;
+use crate::lane::road_mark::RoadMark;
+use crate::lane::rule::Rule;
+use crate::lane::speed::Speed;
+use crate::lane::width::Width;
+use crate::lane::Lane;
+use crate::road::Road;
+use crate::units::prelude::*;
+use vec1::Vec1;
+
+/// A side of the center lane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LaneSide {
+    /// The lanes with positive ids
+    Left,
+    /// The lanes with negative ids
+    Right,
+}
+
+impl LaneSide {
+    fn sign(&self) -> i64 {
+        match self {
+            LaneSide::Left => 1,
+            LaneSide::Right => -1,
+        }
+    }
+}
+
+/// Why a taper could not be inserted, see [`Road::add_lane_taper`] and
+/// [`Road::drop_lane_taper`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TaperError {
+    #[error("The length of the taper {0} is not positive")]
+    InvalidLength(f64),
+    #[error("The taper from s={start} to s={end} is not within a single lane section")]
+    CrossesLaneSection { start: f64, end: f64 },
+    #[error("There is no lane to drop on the {0:?} side")]
+    NoLane(LaneSide),
+}
+
+impl Road {
+    /// Adds a lane of the given type as new outermost lane on the given side. The lane widens
+    /// from zero at `s` to `width` at `s + length` with a [`Width::taper`] and keeps that width
+    /// up to the end of the lane section containing `s`, which must also contain `s + length`.
+    /// That lane section is split at `s` and `s + length` into lane sections with linked lanes,
+    /// see [`LaneSection::split`]. Returns the id of the new lane.
+    pub fn add_lane_taper(
+        &mut self,
+        side: LaneSide,
+        s: f64,
+        length: f64,
+        width: f64,
+        r#type: LaneType,
+    ) -> Result<i64, TaperError> {
+        let (taper, after) = self.split_for_taper(s, length)?;
+        let sections = &mut self.lanes.lane_section;
+        let id = side.sign() * (outermost(&sections[taper], side) + 1);
+
+        let lane = |width: Width, predecessor: bool, successor: bool| Lane {
+            link: Some(LaneLink {
+                predecessor: predecessor
+                    .then_some(PredecessorSuccessor { id })
+                    .into_iter()
+                    .collect(),
+                successor: successor
+                    .then_some(PredecessorSuccessor { id })
+                    .into_iter()
+                    .collect(),
+                additional_data: AdditionalData::default(),
+            }),
+            choice: vec![LaneChoice::Width(width)],
+            r#type: r#type.clone(),
+            ..Lane::default()
+        };
+        add_outermost(
+            &mut sections[taper],
+            id,
+            lane(
+                Width::taper(0.0, 0.0, width, length),
+                false,
+                after.is_some(),
+            ),
+        );
+        if let Some(after) = after {
+            add_outermost(
+                &mut sections[after],
+                id,
+                lane(Width::constant(0.0, width), true, false),
+            );
+        }
+        Ok(id)
+    }
+
+    /// Drops the outermost lane on the given side. The lane narrows from its width at `s` to
+    /// zero at `s + length` with a [`Width::taper`] and is removed from there on up to the end
+    /// of the lane section containing `s`, which must also contain `s + length`. That lane
+    /// section is split at `s` and `s + length` into lane sections with linked lanes, see
+    /// [`LaneSection::split`].
+    pub fn drop_lane_taper(
+        &mut self,
+        side: LaneSide,
+        s: f64,
+        length: f64,
+    ) -> Result<(), TaperError> {
+        let (index, _) = self.lanes.lane_section_at(s);
+        if outermost(&self.lanes.lane_section[index], side) == 0 {
+            return Err(TaperError::NoLane(side));
+        }
+        let (taper, after) = self.split_for_taper(s, length)?;
+        let sections = &mut self.lanes.lane_section;
+        let id = side.sign() * outermost(&sections[taper], side);
+
+        let section = &mut sections[taper];
+        let from = section
+            .lane_borders(id, 0.0)
+            .map_or(0.0, |(inner, outer)| (outer - inner).abs());
+        if let Some((_, lane)) = lanes_mut(section).find(|(i, _)| *i == id) {
+            lane.choice = vec![LaneChoice::Width(Width::taper(0.0, from, 0.0, length))];
+            if let Some(link) = &mut lane.link {
+                link.successor.clear();
+            }
+        }
+        if let Some(after) = after {
+            let section = &mut sections[after];
+            match side {
+                LaneSide::Left => {
+                    section.left = section.left.take().and_then(|mut left| {
+                        left.lane.retain(|lane| lane.id != id).ok()?;
+                        Some(left)
+                    })
+                }
+                LaneSide::Right => {
+                    section.right = section.right.take().and_then(|mut right| {
+                        right.lane.retain(|lane| lane.id != id).ok()?;
+                        Some(right)
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Splits the lane section containing `s` so that a lane section starts at `s` and, unless
+    /// the lane section ends there, another one at `s + length`. Returns their indices.
+    fn split_for_taper(
+        &mut self,
+        s: f64,
+        length: f64,
+    ) -> Result<(usize, Option<usize>), TaperError> {
+        const EPSILON: f64 = 1e-9;
+        if length.is_nan() || length <= 0.0 {
+            return Err(TaperError::InvalidLength(length));
+        }
+        let end = s + length;
+        let (index, section) = self.lanes.lane_section_at(s);
+        let section_end = self
+            .lanes
+            .lane_section
+            .get(index + 1)
+            .map_or(self.length.get::<meter>(), |next| next.s);
+        if s < section.s - EPSILON || end > section_end + EPSILON {
+            return Err(TaperError::CrossesLaneSection { start: s, end });
+        }
+
+        let mut sections = std::mem::replace(
+            &mut self.lanes.lane_section,
+            Vec1::new(LaneSection::default()),
+        )
+        .into_vec();
+        let section = sections.remove(index);
+        let mut parts = Vec::new();
+        let rest = if s > section.s + EPSILON {
+            let (before, rest) = section.split(s - section.s);
+            parts.push(before);
+            rest
+        } else {
+            section
+        };
+        let taper = index + parts.len();
+        let after = if end < section_end - EPSILON {
+            let (tapered, after) = rest.split(length);
+            parts.extend([tapered, after]);
+            Some(taper + 1)
+        } else {
+            parts.push(rest);
+            None
+        };
+        sections.splice(index..index, parts);
+        self.lanes.lane_section =
+            Vec1::try_from_vec(sections).expect("the parts replace a section");
+        Ok((taper, after))
+    }
+}
+
+impl LaneSection {
+    /// Splits this lane section at `ds`, relative to its start, into two lane sections with
+    /// the same lanes. The records of each lane from `ds` on, like widths, road marks or
+    /// speeds, move to the second lane section, the records in effect at `ds` are repeated at
+    /// its start with the polynomials of widths and borders rebased onto it. The lanes of both
+    /// lane sections are linked to each other, the first lane section keeps the predecessors
+    /// and the second one the successors of the lanes.
+    pub fn split(&self, ds: f64) -> (Self, Self) {
+        let (mut first, mut second) = (self.clone(), self.clone());
+        second.s = self.s + ds;
+        for ((id, first), (_, second)) in lanes_mut(&mut first).zip(lanes_mut(&mut second)) {
+            let lane = self
+                .lane(id)
+                .expect("the lanes of the clones are those of the original");
+            (first.choice, second.choice) = split_records(&lane.choice, ds);
+            (first.road_mark, second.road_mark) = split_records(&lane.road_mark, ds);
+            (first.material, second.material) = split_records(&lane.material, ds);
+            (first.speed, second.speed) = split_records(&lane.speed, ds);
+            (first.access, second.access) = split_records(&lane.access, ds);
+            (first.height, second.height) = split_records(&lane.height, ds);
+            (first.rule, second.rule) = split_records(&lane.rule, ds);
+
+            link_mut(first).successor = vec![PredecessorSuccessor { id }];
+            link_mut(second).predecessor = vec![PredecessorSuccessor { id }];
+        }
+        (first, second)
+    }
+}
+
+fn link_mut(lane: &mut Lane) -> &mut LaneLink {
+    lane.link.get_or_insert_with(|| LaneLink {
+        predecessor: Vec::new(),
+        successor: Vec::new(),
+        additional_data: AdditionalData::default(),
+    })
+}
+
+/// The lanes of the lane section except the center lane, which has no records to split
+pub(crate) fn lanes_mut(section: &mut LaneSection) -> impl Iterator<Item = (i64, &mut Lane)> {
+    let left = section
+        .left
+        .iter_mut()
+        .flat_map(|l| l.lane.iter_mut().map(|l| (l.id, &mut l.base)));
+    let right = section
+        .right
+        .iter_mut()
+        .flat_map(|r| r.lane.iter_mut().map(|l| (l.id, &mut l.base)));
+    left.chain(right)
+}
+
+/// The number of lanes on the given side
+fn outermost(section: &LaneSection, side: LaneSide) -> i64 {
+    section
+        .lanes()
+        .map(|(id, _)| id * side.sign())
+        .filter(|id| *id > 0)
+        .max()
+        .unwrap_or(0)
+}
+
+fn add_outermost(section: &mut LaneSection, id: i64, lane: Lane) {
+    if id > 0 {
+        let lane = LeftLane { id, base: lane };
+        match &mut section.left {
+            // left lanes are ordered by descending id
+            Some(left) => left.lane.insert(0, lane),
+            None => {
+                section.left = Some(Left {
+                    lane: Vec1::new(lane),
+                    additional_data: AdditionalData::default(),
+                })
+            }
+        }
+    } else {
+        let lane = RightLane { id, base: lane };
+        match &mut section.right {
+            Some(right) => right.lane.push(lane),
+            None => {
+                section.right = Some(Right {
+                    lane: Vec1::new(lane),
+                    additional_data: AdditionalData::default(),
+                })
+            }
+        }
+    }
+}
+
+/// A record of a lane starting at an offset relative to its lane section
+trait LaneRecord: Clone {
+    fn s_offset(&self) -> f64;
+
+    /// The record relative to a lane section starting `ds` later, which is in effect from the
+    /// offset `max(0, s_offset - ds)` on
+    fn moved(&self, ds: f64) -> Self;
+}
+
+/// The records before `ds` and, relative to `ds`, the records in effect at `ds` and after it
+fn split_records<T: LaneRecord>(records: &[T], ds: f64) -> (Vec<T>, Vec<T>) {
+    let first = records
+        .iter()
+        .filter(|r| r.s_offset() < ds)
+        .cloned()
+        .collect();
+    let in_effect = records
+        .iter()
+        .map(T::s_offset)
+        .filter(|s| *s <= ds)
+        .fold(None, |max: Option<f64>, s| {
+            Some(max.map_or(s, |m| m.max(s)))
+        });
+    let second = records
+        .iter()
+        .filter(|r| Some(r.s_offset()) == in_effect || r.s_offset() > ds)
+        .map(|r| r.moved(ds))
+        .collect();
+    (first, second)
+}
+
+macro_rules! impl_lane_record {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl LaneRecord for $ty {
+                fn s_offset(&self) -> f64 {
+                    self.s_offset.get::<meter>()
+                }
+
+                fn moved(&self, ds: f64) -> Self {
+                    Self {
+                        s_offset: Length::new::<meter>((self.s_offset() - ds).max(0.0)),
+                        ..self.clone()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_lane_record!(RoadMark, Material, Speed, Access, Height, Rule);
+
+macro_rules! impl_lane_record_cubic {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl LaneRecord for $ty {
+                fn s_offset(&self) -> f64 {
+                    self.s_offset.get::<meter>()
+                }
+
+                fn moved(&self, ds: f64) -> Self {
+                    let shift = (ds - self.s_offset()).max(0.0);
+                    let (b, c, d) = (self.b, self.c, self.d);
+                    Self {
+                        a: self.a + b * shift + c * shift.powi(2) + d * shift.powi(3),
+                        b: b + 2.0 * c * shift + 3.0 * d * shift.powi(2),
+                        c: c + 3.0 * d * shift,
+                        d,
+                        s_offset: Length::new::<meter>((self.s_offset() - ds).max(0.0)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_lane_record_cubic!(Width, Border);
+
+impl LaneRecord for LaneChoice {
+    fn s_offset(&self) -> f64 {
+        match self {
+            LaneChoice::Border(border) => border.s_offset(),
+            LaneChoice::Width(width) => width.s_offset(),
+        }
+    }
+
+    fn moved(&self, ds: f64) -> Self {
+        match self {
+            LaneChoice::Border(border) => LaneChoice::Border(border.moved(ds)),
+            LaneChoice::Width(width) => LaneChoice::Width(width.moved(ds)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::lane::lane_type::LaneType;
+    use crate::lane::taper::{LaneSide, TaperError};
+
+    #[test]
+    pub fn test_lane_tapers() {
+        let drive = parse(TestRoad::new("1").lanes(
+            r#"<laneSection s="0">
+                <center><lane id="0" type="none"/></center>
+                <right><lane id="-1" type="driving">
+                    <width sOffset="0" a="3.5" b="0.01" c="0" d="0"/>
+                    <roadMark sOffset="0" type="solid" color="standard"/>
+                </lane></right>
+            </laneSection>"#,
+        ));
+        let mut road = drive.road[0].clone();
+
+        assert_eq!(
+            Ok(-2),
+            road.add_lane_taper(LaneSide::Right, 20.0, 30.0, 3.0, LaneType::Driving)
+        );
+        let sections = &road.lanes.lane_section;
+        assert_eq!(
+            vec![0.0, 20.0, 50.0],
+            sections.iter().map(|s| s.s).collect::<Vec<_>>()
+        );
+        let width = |section: usize, id: i64, ds: f64| {
+            sections[section].lane(id).unwrap().width_at(ds).unwrap()
+        };
+        assert!((3.7 - width(1, -1, 0.0)).abs() < 1e-9);
+        assert!((4.05 - width(2, -1, 5.0)).abs() < 1e-9);
+        assert_eq!(0.0, width(1, -2, 0.0));
+        assert!((1.5 - width(1, -2, 15.0)).abs() < 1e-9);
+        assert!((3.0 - width(1, -2, 30.0)).abs() < 1e-9);
+        assert_eq!(3.0, width(2, -2, 10.0));
+        assert_eq!(1, sections[2].lane(-1).unwrap().road_mark.len());
+        let link = sections[0].lane(-1).unwrap().link.as_ref().unwrap();
+        assert_eq!(-1, link.successor[0].id);
+
+        road.drop_lane_taper(LaneSide::Right, 60.0, 20.0).unwrap();
+        let sections = &road.lanes.lane_section;
+        assert_eq!(
+            vec![0.0, 20.0, 50.0, 60.0, 80.0],
+            sections.iter().map(|s| s.s).collect::<Vec<_>>()
+        );
+        let lane = sections[3].lane(-2).unwrap();
+        assert_eq!(Some(3.0), lane.width_at(0.0));
+        assert!(lane.width_at(20.0).unwrap().abs() < 1e-9);
+        assert!(sections[4].lane(-2).is_none());
+        assert!(sections[4].lane(-1).is_some());
+
+        assert_eq!(
+            Err(TaperError::CrossesLaneSection {
+                start: 90.0,
+                end: 110.0
+            }),
+            road.drop_lane_taper(LaneSide::Right, 90.0, 20.0)
+        );
+        assert_eq!(
+            Err(TaperError::NoLane(LaneSide::Left)),
+            road.drop_lane_taper(LaneSide::Left, 85.0, 5.0)
+        );
+    }
+}
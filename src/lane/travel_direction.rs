@@ -0,0 +1,18 @@
+/// Direction of travel on a lane relative to the s-direction of the road reference line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TravelDirection {
+    /// In direction of increasing s
+    Forward,
+    /// In direction of decreasing s
+    Backward,
+    /// In both directions
+    Both,
+}
+
+impl TravelDirection {
+    /// Whether traffic in the given (single) direction may use a lane with this travel direction
+    #[inline]
+    pub fn permits(self, direction: TravelDirection) -> bool {
+        self == TravelDirection::Both || self == direction
+    }
+}
@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// The width of a lane is defined along the t-coordinate. The width of a lane may change within a
 /// lane section.
@@ -29,34 +28,70 @@ pub struct Width {
 }
 
 impl Width {
+    /// A width of `width` from `s_offset` on
+    pub fn constant(s_offset: f64, width: f64) -> Self {
+        Self {
+            a: width,
+            b: 0.0,
+            c: 0.0,
+            d: 0.0,
+            s_offset: Length::new::<meter>(s_offset),
+        }
+    }
+
+    /// A width changing from `from` to `to` over `length` from `s_offset` on, with a slope of
+    /// zero at both ends, so that it joins constant widths before and after it smoothly (C1).
+    /// This is the cubic of lanes that are added or dropped, see
+    /// [`crate::road::Road::add_lane_taper`].
+    pub fn taper(s_offset: f64, from: f64, to: f64, length: f64) -> Self {
+        let change = to - from;
+        Self {
+            a: from,
+            b: 0.0,
+            c: 3.0 * change / length.powi(2),
+            d: -2.0 * change / length.powi(3),
+            s_offset: Length::new::<meter>(s_offset),
+        }
+    }
+
+    /// The width at `ds`, relative to the start of the lane section
+    pub fn value(&self, ds: f64) -> f64 {
+        let ds = ds - self.s_offset.get::<meter>();
+        self.a + (self.b * ds) + (self.c * ds * ds) + (self.d * ds * ds * ds)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
-            "sOffset" => &self.s_offset.value.to_scientific_string(),
+            options,
+            "a" => &options.format("a", self.a),
+            "b" => &options.format("b", self.b),
+            "c" => &options.format("c", self.c),
+            "d" => &options.format("d", self.d),
+            "sOffset" => &options.format("sOffset", self.s_offset.get::<meter>()),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Width
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -28,19 +28,66 @@
 //! non-exclusive and unlimited license to use the standard ASAM OpenDRIVE".
 //! ```
 //! where `license terms` refers to https://www.asam.net/license
+//!
+//! # Reading
+//!
+//! Documents are parsed from a string with [`core::OpenDrive::from_xml_str`], from bytes with
+//! [`core::OpenDrive::from_slice`] or from any [`std::io::Read`] with
+//! [`core::OpenDrive::from_xml_read`]. None of them copies the input: readers are streamed
+//! through a fixed-size buffer, so memory is dominated by the parsed model. Bytes and readers
+//! are decoded according to the XML declaration, including Windows-1252, see
+//! [`core::encoding::DecodingReader`].
+//!
+//! For the common cases, [`core::OpenDrive::from_path`] and [`core::OpenDrive::from_str_with`]
+//! take [`parser::ParseOptions`], [`core::OpenDrive::to_path`] takes [`writer::WriteOptions`] and
+//! [`core::OpenDrive::to_string_pretty`] writes an indented document.
+//!
+//! # Tracing
+//!
+//! With the `tracing` feature, parsing emits [`tracing`](https://crates.io/crates/tracing) spans:
+//! `parse` around [`core::OpenDrive::from_str_with`] and the other functions taking
+//! [`parser::ParseOptions`], `read` around reading the element tree into the model, `road` with
+//! the `id` of each road and `rule` with the `name` of each [`core::validation::ValidationRule`]
+//! checked against the parsed document. Violations, skipped elements of a recovering parse and
+//! included files are events.
+//!
+//! # Comparison
+//!
+//! All types of the model implement [`PartialEq`]. Floating point values, including quantities
+//! such as [`units::Length`], are compared exactly, without any tolerance, so two models are
+//! only equal if they describe the very same values. Types without any floating point values
+//! additionally implement [`Eq`] and [`Hash`].
 
 #[macro_use]
 pub mod parser;
 pub mod writer;
 
+pub mod backend;
 pub mod core;
+pub mod edit;
+pub mod export;
+#[cfg(test)]
+mod fixtures;
+pub mod generate;
 pub mod junction;
 pub mod lane;
 pub mod object;
+pub mod prelude;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod query;
 pub mod railroad;
 pub mod road;
+pub mod routing;
 pub mod signal;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+pub mod units;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+#[cfg(feature = "capi")]
+pub mod capi;
 #[cfg(feature = "fuzzing")]
 pub mod fuzzing;
 
@@ -123,4 +170,506 @@ mod tests {
             "#;
         let _ = OpenDrive::from_xml_str(source).unwrap();
     }
+
+    #[test]
+    pub fn test_blank_constructors() {
+        use crate::junction::connection::Connection;
+        use crate::junction::Junction;
+        use crate::road::geometry::geometry_type::GeometryType;
+        use crate::road::geometry::line::Line;
+        use crate::road::geometry::plan_view::PlanView;
+        use crate::road::geometry::Geometry;
+        use crate::road::Road;
+        use crate::units::prelude::*;
+
+        let plan_view = PlanView::new(Geometry {
+            hdg: Angle::new::<radian>(0.0),
+            length: Length::new::<meter>(100.0),
+            s: Length::new::<meter>(0.0),
+            x: Length::new::<meter>(0.0),
+            y: Length::new::<meter>(0.0),
+            r#type: GeometryType::Line(Line::default()),
+            additional_data: Default::default(),
+        });
+        let drive = OpenDrive {
+            road: vec![Road::new("1", plan_view)],
+            junction: vec![Junction::new(
+                "J",
+                Connection {
+                    id: "0".to_string(),
+                    incoming_road: Some("1".to_string()),
+                    ..Connection::default()
+                },
+            )],
+            ..OpenDrive::default()
+        };
+        assert_eq!(100.0, drive.road[0].length.get::<meter>());
+
+        let xml = drive.to_writer().unwrap().into_inner();
+        let parsed = OpenDrive::from_xml_read(xml.as_slice()).unwrap();
+        assert_eq!(drive, parsed);
+    }
+
+    #[test]
+    pub fn test_summary() {
+        use crate::fixtures::TestRoad;
+
+        let drive = OpenDrive::from_xml_str(&format!(
+            r#"<OpenDRIVE>
+                <header revMajor="1" revMinor="7" name="Test"/>
+                {}{}
+                <junction id="J">
+                    <connection id="0" incomingRoad="1" connectingRoad="2" contactPoint="start">
+                        <laneLink from="-1" to="-1"/>
+                    </connection>
+                </junction>
+            </OpenDRIVE>"#,
+            TestRoad::new("1")
+                .attribute("name", "Main")
+                .link(r#"<successor elementType="junction" elementId="J"/>"#)
+                .sides(&[("driving", 3.0)], &[("driving", 3.0)]),
+            TestRoad::new("2")
+                .length(10.0)
+                .at(100.0, 0.0, 0.0)
+                .junction("J")
+                .link(r#"<predecessor elementType="road" elementId="1" contactPoint="end"/>"#)
+                .sides(&[], &[("driving", 3.0)]),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            drive.summary(),
+            [
+                r#"OpenDRIVE 1.7 "Test": 2 roads, 1 junction, 0 controllers"#,
+                r#"  road 1 "Main": 100 m, 1 lane section, 2 lanes, successor junction J"#,
+                "    lane section at s = 0 m: 1 left, 1 right",
+                "  road 2: 10 m, 1 lane section, 1 lane, in junction J, predecessor road 1 (end)",
+                "    lane section at s = 0 m: 0 left, 1 right",
+                "  junction J (default): 1 connection, incoming roads 1",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    pub fn test_speed_units() {
+        use crate::fixtures::{parse, TestRoad};
+        use crate::road::speed::MaxSpeed;
+        use crate::road::unit::{SpeedUnit, SpeedValue};
+
+        let drive = parse(
+            TestRoad::new("1")
+                .child(r#"<type s="0" type="motorway"><speed max="65" unit="mph"/></type>"#)
+                .child(r#"<type s="50" type="motorway"><speed max="no limit" unit="km/h"/></type>"#)
+                .lanes(
+                    r#"<laneSection s="0">
+                        <center><lane id="0" type="none"/></center>
+                        <right><lane id="-1" type="driving">
+                            <width sOffset="0" a="3" b="0" c="0" d="0"/>
+                            <speed sOffset="0" max="90" unit="km/h"/>
+                            <speed sOffset="50" max="20"/>
+                        </lane></right>
+                    </laneSection>"#,
+                ),
+        );
+
+        let road = &drive.road[0];
+        let speed = road.r#type[0].speed.as_ref().unwrap();
+        assert_eq!(MaxSpeed::Limit(65.0), speed.max);
+        assert_eq!(
+            Some(SpeedValue::new(65.0, SpeedUnit::MilesPerHour)),
+            speed.limit()
+        );
+        let lane = road.lanes.lane_section[0].lane(-1).unwrap();
+        assert!((25.0 - lane.speed[0].speed().meters_per_second()).abs() < 1e-9);
+        assert_eq!(
+            SpeedValue::new(20.0, SpeedUnit::MetersPerSecond),
+            lane.speed[1].speed()
+        );
+        assert!(
+            (55.9234 - lane.speed[0].speed().to_unit(SpeedUnit::MilesPerHour).value).abs() < 1e-4
+        );
+        assert_eq!("90 km/h", lane.speed[0].speed().to_string());
+
+        let xml = drive.to_xml_string().unwrap();
+        // speeds are written in the unit they were read in, and without one if read without
+        assert!(xml.contains(r#"unit="mph""#) && xml.contains(r#"unit="km/h""#));
+        assert!(xml.contains(r#"max="no limit" unit="km/h""#));
+        let written = OpenDrive::from_xml_str(&xml).unwrap();
+        assert_eq!(
+            None,
+            written.road[0].lanes.lane_section[0]
+                .lane(-1)
+                .unwrap()
+                .speed[1]
+                .unit
+        );
+        assert_eq!(drive, OpenDrive::from_xml_str(&xml).unwrap());
+    }
+
+    #[test]
+    pub fn test_version_neutral_accessors() {
+        use crate::fixtures::{parse_revision, TestRoad};
+        use crate::units::prelude::*;
+
+        let drive = parse_revision(
+            4,
+            TestRoad::new("1")
+                .child(r#"<lateralProfile><superelevation s="10" a="0.1" b="0.01" c="0" d="0"/></lateralProfile>"#)
+                .lanes(
+                    r#"<laneSection s="0">
+                        <center><lane id="0" type="none"/></center>
+                        <right><lane id="-1" type="driving">
+                            <width sOffset="0" a="3" b="0" c="0" d="0"/>
+                            <material sOffset="0" friction="0.8"/>
+                            <material sOffset="40" friction="0.5"/>
+                        </lane></right>
+                    </laneSection>"#,
+                )
+                .objects(
+                    r#"<object id="o" s="0" t="0" zOffset="0">
+                        <outline><cornerLocal u="0" v="0" z="0" height="1"/></outline>
+                    </object>"#,
+                )
+                .signals(
+                    r#"<signal s="0" t="0" id="s" dynamic="no" orientation="+" zOffset="0" country="Germany" type="206" subtype="-1"/>"#,
+                ),
+        );
+
+        let road = &drive.road[0];
+        assert_eq!(
+            0.0,
+            road.superelevation_at(Length::new::<meter>(5.0))
+                .get::<radian>()
+        );
+        assert!(
+            (0.2 - road
+                .superelevation_at(Length::new::<meter>(20.0))
+                .get::<radian>())
+            .abs()
+                < 1e-9
+        );
+
+        let lane = road.lanes.lane_section[0].lane(-1).unwrap();
+        assert_eq!(Some(0.8), lane.friction_at(10.0));
+        assert_eq!(Some(0.5), lane.friction_at(40.0));
+
+        let object = &road.objects.as_ref().unwrap().object[0];
+        assert_eq!(1, object.all_outlines().count());
+
+        let signal = &road.signals.as_ref().unwrap().signal[0];
+        assert_eq!(
+            Some("DE"),
+            signal.country.as_ref().and_then(|c| c.iso3166alpha2())
+        );
+    }
+
+    #[test]
+    pub fn test_from_slice_and_read() {
+        struct OneByteAtATime<'a>(&'a [u8]);
+
+        impl std::io::Read for OneByteAtATime<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                match (self.0.split_first(), buf.first_mut()) {
+                    (Some((byte, rest)), Some(first)) => {
+                        *first = *byte;
+                        self.0 = rest;
+                        Ok(1)
+                    }
+                    _ => Ok(0),
+                }
+            }
+        }
+
+        let xml = br#"<OpenDRIVE><header revMajor="1" revMinor="7" name="Test"/></OpenDRIVE>"#;
+        let drive = OpenDrive::from_slice(xml).unwrap();
+        assert_eq!(Some("Test"), drive.header.name.as_deref());
+        assert_eq!(
+            drive,
+            OpenDrive::from_xml_read(OneByteAtATime(xml)).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fs")]
+    pub fn test_path_and_options() {
+        use crate::parser::ParseOptions;
+        use crate::writer::WriteOptions;
+
+        let xml = r#"
+            <OpenDRIVE>
+                <header revMajor="1" revMinor="4" name="Test"/>
+                <junction id="J"><connection id="0"><laneLink from="-1"/></connection></junction>
+            </OpenDRIVE>
+        "#;
+        let mut diagnostics = Vec::new();
+        let drive = OpenDrive::from_str_with(
+            xml,
+            ParseOptions {
+                recover: true,
+                diagnostics: Some(&mut diagnostics),
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(drive.junction.is_empty());
+        assert_eq!(Some("J"), diagnostics[0].id.as_deref());
+
+        let path = std::env::temp_dir().join(format!("opendrive-{}.xodr", std::process::id()));
+        drive.to_path(&path, &WriteOptions::shortest()).unwrap();
+        assert_eq!(
+            drive,
+            OpenDrive::from_path(&path, ParseOptions::default()).unwrap()
+        );
+        let outdated = OpenDrive::from_path(
+            &path,
+            ParseOptions {
+                revisions: Some((1, 6)..=(1, 8)),
+                ..ParseOptions::default()
+            },
+        );
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            outdated.map_err(|e| *e),
+            Err(crate::parser::Error::UnsupportedRevision {
+                rev_major: 1,
+                rev_minor: 4
+            })
+        ));
+
+        assert!(drive.to_string_pretty().unwrap().contains("\n  <header"));
+    }
+
+    #[test]
+    pub fn test_tolerant_numbers() {
+        use crate::parser::{ParseOptions, Warning};
+        use crate::units::prelude::*;
+
+        let xml = r#"
+            <OpenDRIVE>
+                <header revMajor="1" revMinor="7"/>
+                <road id="1" junction="-1" length=" 100 ">
+                    <planView><geometry s="0" x="+1" y="0" hdg="0,5" length="100"><line/></geometry></planView>
+                    <lanes><laneSection s="0"><center><lane id="0" type="none"/></center></laneSection></lanes>
+                </road>
+            </OpenDRIVE>
+        "#;
+        assert!(OpenDrive::from_str_with(xml, ParseOptions::default()).is_err());
+
+        let mut warnings = Vec::new();
+        let drive = OpenDrive::from_str_with(
+            xml,
+            ParseOptions {
+                tolerant_numbers: true,
+                warnings: Some(&mut warnings),
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(100.0, drive.road[0].length.get::<meter>());
+        let geometry = &drive.road[0].plan_view.geometry[0];
+        assert_eq!(0.5, geometry.hdg.get::<radian>());
+        assert_eq!(
+            vec![("hdg", "0,5", "0.5"), ("length", " 100 ", "100")],
+            warnings
+                .iter()
+                .filter_map(|w| match w {
+                    Warning::Normalized {
+                        field,
+                        value,
+                        normalized,
+                        ..
+                    } => Some((field.as_str(), value.as_str(), normalized.as_str())),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    pub fn test_duplicates() {
+        use crate::parser::{Duplicates, Error, ParseOptions, Warning};
+        use crate::units::prelude::*;
+
+        let xml = r#"
+            <OpenDRIVE>
+                <header revMajor="1" revMinor="7"/>
+                <road id="1" junction="-1" length="100" LENGTH="50">
+                    <planView><geometry s="0" x="0" y="0" hdg="0" length="100"><line/></geometry></planView>
+                    <planView><geometry s="0" x="5" y="0" hdg="0" length="50"><line/></geometry></planView>
+                    <lanes><laneSection s="0"><center><lane id="0" type="none"/></center></laneSection></lanes>
+                </road>
+            </OpenDRIVE>
+        "#;
+        let parse = |duplicates| {
+            let mut warnings = Vec::new();
+            OpenDrive::from_str_with(
+                xml,
+                ParseOptions {
+                    duplicates: Some(duplicates),
+                    warnings: Some(&mut warnings),
+                    ..ParseOptions::default()
+                },
+            )
+            .map(|drive| (drive, warnings))
+        };
+
+        assert!(matches!(
+            parse(Duplicates::Error).map_err(|e| *e),
+            Err(Error::Duplicate { .. })
+        ));
+
+        let (first, warnings) = parse(Duplicates::KeepFirst).unwrap();
+        let road = &first.road[0];
+        assert_eq!(100.0, road.length.get::<meter>());
+        assert_eq!(0.0, road.plan_view.geometry[0].x.get::<meter>());
+        assert_eq!(2, warnings.len());
+        assert!(warnings.iter().all(|w| matches!(
+            w,
+            Warning::Duplicate {
+                kept: Duplicates::KeepFirst,
+                ..
+            }
+        )));
+
+        let (last, _) = parse(Duplicates::KeepLast).unwrap();
+        let road = &last.road[0];
+        assert_eq!(50.0, road.length.get::<meter>());
+        assert_eq!(5.0, road.plan_view.geometry[0].x.get::<meter>());
+    }
+
+    #[test]
+    pub fn test_tolerant_enums() {
+        use crate::fixtures::{document, TestRoad};
+        use crate::lane::lane_type::LaneType;
+        use crate::parser::{ParseOptions, Warning};
+        use crate::road::unit::Unit;
+        use crate::writer::WriteOptions;
+
+        let xml = &document(
+            TestRoad::new("1")
+                .sides(&[], &[("vendorLane", 3.0)])
+                .signals(
+                    r#"<signal id="s" s="10" t="-2" type="274" subtype="60" value="60" unit="furlong" dynamic="no" orientation="-" zOffset="2" country="DE"/>"#,
+                ),
+        );
+        assert!(OpenDrive::from_str_with(xml, ParseOptions::default()).is_err());
+
+        let mut warnings = Vec::new();
+        let drive = OpenDrive::from_str_with(
+            xml,
+            ParseOptions {
+                tolerant_enums: true,
+                warnings: Some(&mut warnings),
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
+        let road = &drive.road[0];
+        let (_, lane) = road.lanes.lane_section[0].lanes().nth(1).unwrap();
+        assert_eq!(LaneType::Other("vendorLane".to_string()), lane.r#type);
+        let signal = &road.signals.as_ref().unwrap().signal[0];
+        assert_eq!(Some(Unit::Other("furlong".to_string())), signal.unit);
+        assert_eq!(
+            vec![("type", "vendorLane"), ("unit", "furlong")],
+            warnings
+                .iter()
+                .filter_map(|w| match w {
+                    Warning::UnknownValue { field, value, .. } =>
+                        Some((field.as_str(), value.as_str())),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        );
+
+        let written = drive.to_xml_string_with(&WriteOptions::shortest()).unwrap();
+        assert!(written.contains(r#"type="vendorLane""#));
+        assert!(written.contains(r#"unit="furlong""#));
+    }
+
+    #[test]
+    pub fn test_out_of_range() {
+        use crate::fixtures::{document, TestRoad};
+        use crate::parser::{Error, OutOfRange, ParseOptions, Warning};
+        use crate::units::prelude::*;
+
+        let xml = &document(
+            TestRoad::new("1")
+                .length(-100.0)
+                .geometry(r#"<geometry s="0" x="0" y="0" hdg="-1.5707963267948966" length="100"><line/></geometry>"#)
+                .signals(
+                    r#"<signal id="s" s="10" t="-10" zOffset="2" type="274" subtype="60" value="60" unit="km/h" dynamic="no" orientation="-" country="DE"/>"#,
+                ),
+        );
+        let parse = |out_of_range, warn_beyond_lanes| {
+            let mut warnings = Vec::new();
+            OpenDrive::from_str_with(
+                xml,
+                ParseOptions {
+                    out_of_range,
+                    warn_beyond_lanes,
+                    warnings: Some(&mut warnings),
+                    ..ParseOptions::default()
+                },
+            )
+            .map(|drive| (drive, warnings))
+        };
+        let values = |drive: &OpenDrive| {
+            let road = &drive.road[0];
+            (
+                road.length.get::<meter>(),
+                road.plan_view.geometry[0].hdg.get::<radian>(),
+                road.signals.as_ref().unwrap().signal[0].t.get::<meter>(),
+            )
+        };
+
+        fn out_of_range(warnings: &[Warning]) -> Vec<(&str, &str)> {
+            warnings
+                .iter()
+                .filter_map(|w| match w {
+                    Warning::OutOfRange { path, field, .. } => {
+                        Some((path.as_str(), field.as_str()))
+                    }
+                    _ => None,
+                })
+                .collect()
+        }
+
+        let (drive, warnings) = parse(None, false).unwrap();
+        assert_eq!(
+            (-100.0, -std::f64::consts::FRAC_PI_2, -10.0),
+            values(&drive)
+        );
+        assert!(warnings.is_empty());
+
+        assert!(matches!(
+            parse(Some(OutOfRange::Error), false).map_err(|e| *e),
+            Err(Error::OutOfRange { field, .. }) if field == "hdg"
+        ));
+
+        let (accepted, warnings) = parse(Some(OutOfRange::Accept), false).unwrap();
+        assert_eq!(values(&drive), values(&accepted));
+        assert_eq!(2, warnings.len());
+
+        // the roadside sign beyond the lanes is kept as read
+        let (clamped, warnings) = parse(Some(OutOfRange::Clamp), false).unwrap();
+        let (length, hdg, t) = values(&clamped);
+        assert_eq!(0.0, length);
+        assert!((hdg - 3.0 * std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(-10.0, t);
+        assert_eq!(
+            vec![
+                (".OpenDRIVE.road.planView.geometry", "hdg"),
+                (".OpenDRIVE.road", "length")
+            ],
+            out_of_range(&warnings)
+        );
+
+        let (warned, warnings) = parse(None, true).unwrap();
+        assert_eq!(values(&drive), values(&warned));
+        assert_eq!(
+            vec![(".OpenDRIVE.road.signals.signal", "t")],
+            out_of_range(&warnings)
+        );
+    }
 }
@@ -1,5 +1,4 @@
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum Access {
     All,
     Car,
@@ -9,10 +8,13 @@ pub enum Access {
     Truck,
     Electric,
     Residents,
+    /// A value unknown to the schema, see [`crate::parser::ParseOptions::tolerant_enums`]
+    Other(String),
 }
 
 impl_from_str_as_str!(
     Access,
+    other => Other,
     "all" => All,
     "car" => Car,
     "women" => Women,
@@ -22,3 +24,21 @@ impl_from_str_as_str!(
     "electric" => Electric,
     "residents" => Residents,
 );
+
+#[cfg(feature = "fuzzing")]
+impl arbitrary::Arbitrary<'_> for Access {
+    fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
+        use crate::fuzzing::ArbitraryOther;
+        Ok(match u.int_in_range(0..=8)? {
+            0 => Self::All,
+            1 => Self::Car,
+            2 => Self::Women,
+            3 => Self::Handicapped,
+            4 => Self::Bus,
+            5 => Self::Truck,
+            6 => Self::Electric,
+            7 => Self::Residents,
+            _ => Self::Other(u.arbitrary_other()?),
+        })
+    }
+}
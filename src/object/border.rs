@@ -1,9 +1,9 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::boolean::Bool;
 use crate::object::border_type::BorderType;
 use crate::object::corner_reference::CornerReference;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Specifies a border along certain outline points.
 #[derive(Debug, Clone, PartialEq)]
@@ -25,32 +25,38 @@ impl Border {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "outlineId" => Some(self.outline_id.to_string()).as_deref(),
             "type" => Some(self.r#type.as_str()),
-            "useCompleteOutline" => self.use_complete_outline.map(|v| v.to_string()).as_deref(),
-            "width" => Some(self.width.value.to_scientific_string()).as_deref(),
+            "useCompleteOutline" => self.use_complete_outline.map(|v| Bool(v).as_str()),
+            "width" => Some(options.format("width", self.width.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for corner_reference in &self.corner_reference {
-            visit_children!(visitor, "cornerReference" => corner_reference);
+            visit_children!(visitor, options, "cornerReference" => corner_reference);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Border
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -66,11 +72,13 @@ where
 
         Ok(Self {
             outline_id: read.attribute("outlineId")?,
-            r#type: read.attribute("type")?,
-            use_complete_outline: read.attribute_opt("useCompleteOutline")?,
+            r#type: read.enum_attribute("type")?,
+            use_complete_outline: read
+                .attribute_opt::<Bool>("useCompleteOutline")?
+                .map(bool::from),
             width: read.attribute("width").map(Length::new::<meter>)?,
             corner_reference,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
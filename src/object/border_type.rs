@@ -1,12 +1,26 @@
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum BorderType {
     Concrete,
     Curb,
+    /// A value unknown to the schema, see [`crate::parser::ParseOptions::tolerant_enums`]
+    Other(String),
 }
 
 impl_from_str_as_str!(
     BorderType,
+    other => Other,
     "concrete" => Concrete,
     "curb" => Curb,
 );
+
+#[cfg(feature = "fuzzing")]
+impl arbitrary::Arbitrary<'_> for BorderType {
+    fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
+        use crate::fuzzing::ArbitraryOther;
+        Ok(match u.int_in_range(0..=2)? {
+            0 => Self::Concrete,
+            1 => Self::Curb,
+            _ => Self::Other(u.arbitrary_other()?),
+        })
+    }
+}
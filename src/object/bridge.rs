@@ -1,9 +1,8 @@
 use crate::core::additional_data::AdditionalData;
 use crate::object::bridge_type::BridgeType;
 use crate::object::lane_validity::LaneValidity;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Bridge {
@@ -26,33 +25,39 @@ impl Bridge {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "id" => Some(self.id.as_str()),
-            "length" => Some(self.length.value.to_scientific_string()).as_deref(),
+            "length" => Some(options.format("length", self.length.get::<meter>())).as_deref(),
             "name" => self.name.as_deref(),
-            "s" => Some(self.s.value.to_scientific_string()).as_deref(),
+            "s" => Some(options.format("s", self.s.get::<meter>())).as_deref(),
             "type" => Some(self.r#type.as_str()),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for validity in &self.validity {
-            visit_children!(visitor, "validity" => validity);
+            visit_children!(visitor, options, "validity" => validity);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Bridge
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -73,7 +78,7 @@ where
             s: read.attribute("s").map(Length::new::<meter>)?,
             r#type: read.attribute("type")?,
             validity,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
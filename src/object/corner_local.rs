@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Used to describe complex forms of objects. Defines a corner point on the object outline relative
 /// to the object pivot point in local u/v-coordinates. The insertion point and the orientation of
@@ -24,30 +23,34 @@ impl CornerLocal {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "height" => Some(self.height.value.to_scientific_string()).as_deref(),
+            options,
+            "height" => Some(options.format("height", self.height.get::<meter>())).as_deref(),
             "id" => self.id.map(|v| v.to_string()).as_deref(),
-            "u" => Some(self.u.value.to_scientific_string()).as_deref(),
-            "v" => Some(self.v.value.to_scientific_string()).as_deref(),
-            "z" => Some(self.z.value.to_scientific_string()).as_deref(),
+            "u" => Some(options.format("u", self.u.get::<meter>())).as_deref(),
+            "v" => Some(options.format("v", self.v.get::<meter>())).as_deref(),
+            "z" => Some(options.format("z", self.z.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for CornerLocal
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Defines a corner point on the object’s outline in road coordinates.
 #[derive(Debug, Clone, PartialEq)]
@@ -22,30 +21,34 @@ impl CornerRoad {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "dz" => Some(self.dz.value.to_scientific_string()).as_deref(),
-            "height" => Some(self.height.value.to_scientific_string()).as_deref(),
+            options,
+            "dz" => Some(options.format("dz", self.dz.get::<meter>())).as_deref(),
+            "height" => Some(options.format("height", self.height.get::<meter>())).as_deref(),
             "id" => self.id.map(|v| v.to_string()).as_deref(),
-            "s" => Some(self.s.value.to_scientific_string()).as_deref(),
-            "t" => Some(self.t.value.to_scientific_string()).as_deref(),
+            "s" => Some(options.format("s", self.s.get::<meter>())).as_deref(),
+            "t" => Some(options.format("t", self.t.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for CornerRoad
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -1,3 +1,4 @@
+use crate::core::boolean::Bool;
 use std::borrow::Cow;
 
 /// Elevation data described in {GLO_VAR_STA_ASAM_OpenCRG} are represented by the `<CRG>` element
@@ -17,35 +18,41 @@ impl Crg {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "file" => self.file.as_deref(),
-            "hideRoadSurfaceCRG" => self.hide_road_surface_crg.map(|v| v.to_string()).as_deref(),
-            "zScale" => self.z_scale.map(|v| v.to_scientific_string()).as_deref(),
+            "hideRoadSurfaceCRG" => self.hide_road_surface_crg.map(|v| Bool(v).as_str()),
+            "zScale" => self.z_scale.map(|v| options.format("zScale", v)).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Crg
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
     fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
         read.expecting_no_child_elements_for(Self {
             file: read.attribute_opt("file")?,
-            hide_road_surface_crg: read.attribute_opt("hideRoadSurfaceCRG")?,
+            hide_road_surface_crg: read
+                .attribute_opt::<Bool>("hideRoadSurfaceCRG")?
+                .map(bool::from),
             z_scale: read.attribute_opt("zScale")?,
         })
     }
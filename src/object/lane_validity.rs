@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct LaneValidity {
     /// Minimum ID of the lanes for which the object is valid
@@ -10,14 +10,28 @@ pub struct LaneValidity {
 }
 
 impl LaneValidity {
+    /// Whether the given lane is within the range of this validity element
+    #[inline]
+    pub fn contains(&self, lane_id: i64) -> bool {
+        let (min, max) = if self.from_lane <= self.to_lane {
+            (self.from_lane, self.to_lane)
+        } else {
+            (self.to_lane, self.from_lane)
+        };
+        (min..=max).contains(&lane_id)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
+            options,
             "fromLane" => &self.from_lane.to_string(),
             "toLane" => &self.to_lane.to_string(),
         )
@@ -26,15 +40,16 @@ impl LaneValidity {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for LaneValidity
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -3,9 +3,8 @@ use crate::lane::road_mark::weight::Weight;
 use crate::object::corner_reference::CornerReference;
 use crate::object::road_mark_color::RoadMarkColor;
 use crate::object::side_type::SideType;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Specifies a marking that is either attached to one side of the object bounding box or
 /// referencing outline points.
@@ -38,37 +37,43 @@ impl Marking {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "color" => Some(self.color.as_str()),
-            "lineLength" => Some(self.line_length.value.to_scientific_string()).as_deref(),
+            "lineLength" => Some(options.format("lineLength", self.line_length.get::<meter>())).as_deref(),
             "side" => self.side.as_ref().map(SideType::as_str),
-            "spaceLength" => Some(self.space_length.value.to_scientific_string()).as_deref(),
-            "startOffset" => Some(self.start_offset.value.to_scientific_string()).as_deref(),
-            "stopOffset" => Some(self.stop_offset.value.to_scientific_string()).as_deref(),
+            "spaceLength" => Some(options.format("spaceLength", self.space_length.get::<meter>())).as_deref(),
+            "startOffset" => Some(options.format("startOffset", self.start_offset.get::<meter>())).as_deref(),
+            "stopOffset" => Some(options.format("stopOffset", self.stop_offset.get::<meter>())).as_deref(),
             "weight" => self.weight.as_ref().map(Weight::as_str),
-            "width" => self.width.map(|v| v.value.to_scientific_string()).as_deref(),
-            "zOffset" => self.z_offset.map(|v| v.value.to_scientific_string()).as_deref(),
+            "width" => self.width.map(|v| options.format("width", v.get::<meter>())).as_deref(),
+            "zOffset" => self.z_offset.map(|v| options.format("zOffset", v.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for corner_reference in &self.corner_reference {
-            visit_children!(visitor, "cornerReference" => corner_reference);
+            visit_children!(visitor, options, "cornerReference" => corner_reference);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Marking
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -93,7 +98,7 @@ where
             weight: read.attribute_opt("weight")?,
             width: read.attribute_opt("width")?.map(Length::new::<meter>),
             z_offset: read.attribute_opt("zOffset")?.map(Length::new::<meter>),
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -18,12 +18,15 @@ impl Material {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "friction" => self.friction.map(|v| v.to_scientific_string()).as_deref(),
-            "roughness" => self.roughness.map(|v| v.to_scientific_string()).as_deref(),
+            options,
+            "friction" => self.friction.map(|v| options.format("friction", v)).as_deref(),
+            "roughness" => self.roughness.map(|v| options.format("roughness", v)).as_deref(),
             "surface" => self.surface.as_deref(),
         )
     }
@@ -31,15 +34,16 @@ impl Material {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Material
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
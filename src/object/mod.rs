@@ -1,5 +1,8 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::boolean::Bool;
+use crate::lane::travel_direction::TravelDirection;
 use crate::object::borders::Borders;
+use crate::object::corner::Corner;
 use crate::object::lane_validity::LaneValidity;
 use crate::object::markings::Markings;
 use crate::object::material::Material;
@@ -8,12 +11,12 @@ use crate::object::outline::Outline;
 use crate::object::parking_space::ParkingSpace;
 use crate::object::repeat::Repeat;
 use crate::object::surface::Surface;
+use crate::road::geometry::point::Point;
+use crate::road::geometry::pose::Pose;
+use crate::road::Road;
+use crate::units::prelude::*;
 use outlines::Outlines;
 use std::borrow::Cow;
-use uom::si::angle::radian;
-use uom::si::f64::Angle;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 pub mod access;
 pub mod border;
@@ -70,6 +73,7 @@ pub struct Object {
     /// - "+" = valid in positive s-direction
     /// - "-" = valid in negative s-direction
     /// - "none" = valid in both directions
+    ///
     /// (does not affect the heading)
     pub orientation: Option<Orientation>,
     /// Alternative to @pitch and @roll. If true, the object is vertically perpendicular to the road
@@ -110,82 +114,154 @@ pub struct Object {
 }
 
 impl Object {
+    /// The pose of the origin of this object on the given road, which is expected to be the road
+    /// this object belongs to
+    pub fn pose(&self, road: &Road) -> Pose {
+        let mut pose = road.pose_at(self.s, self.t);
+        pose.hdg += self.hdg.unwrap_or_else(|| Angle::new::<radian>(0.0));
+        pose
+    }
+
+    /// Whether this object is valid for the given lane. Objects without `<validity>` elements are
+    /// valid for all lanes.
+    pub fn applies_to_lane(&self, lane_id: i64) -> bool {
+        self.validity.is_empty() || self.validity.iter().any(|v| v.contains(lane_id))
+    }
+
+    /// Whether this object is valid for traffic travelling in the given direction with respect to
+    /// the reference line, see [`Object::orientation`]
+    pub fn applies_to_direction(&self, direction: TravelDirection) -> bool {
+        match self.orientation {
+            None | Some(Orientation::None) => true,
+            Some(Orientation::Plus) => direction.permits(TravelDirection::Forward),
+            Some(Orientation::Minus) => direction.permits(TravelDirection::Backward),
+        }
+    }
+
+    /// All outlines of this object, the single `<outline>` of ASAM OpenDRIVE 1.4 followed by the
+    /// outlines of the `<outlines>` element introduced with 1.5
+    pub fn all_outlines(&self) -> impl Iterator<Item = &Outline> {
+        self.outline
+            .iter()
+            .chain(self.outlines.iter().flat_map(|o| o.outline.iter()))
+    }
+
+    /// The outline of this object in the inertial x/y-plane on the given road. The first
+    /// `<outline>` is used if present, otherwise the bounding box or bounding circle. Objects
+    /// without extent are represented by their origin.
+    pub fn footprint(&self, road: &Road) -> Vec<Point> {
+        let pose = self.pose(road);
+        if let Some(outline) = self.all_outlines().next() {
+            outline
+                .choice
+                .iter()
+                .map(|corner| match corner {
+                    Corner::Road(corner) => road.pose_at(corner.s, corner.t).point(),
+                    Corner::Local(corner) => pose.transform(corner.u, corner.v),
+                })
+                .collect()
+        } else if let Some(radius) = self.radius {
+            (0..16)
+                .map(|i| {
+                    let angle = i as f64 * std::f64::consts::PI / 8.0;
+                    pose.transform(radius * angle.cos(), radius * angle.sin())
+                })
+                .collect()
+        } else if let (Some(width), Some(length)) = (self.width, self.length) {
+            let (u, v) = (width / 2.0, length / 2.0);
+            vec![
+                pose.transform(u, v),
+                pose.transform(-u, v),
+                pose.transform(-u, -v),
+                pose.transform(u, -v),
+            ]
+        } else {
+            vec![pose.point()]
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
-            "dynamic" => self.dynamic.map(|v| if v { "yes" } else {"no"}),
-            "hdg" => self.hdg.map(|v| v.value.to_scientific_string()).as_deref(),
-            "height" => self.height.map(|v| v.value.to_scientific_string()).as_deref(),
+            options,
+            "dynamic" => self.dynamic.map(|v| Bool(v).as_yes_no()),
+            "hdg" => self.hdg.map(|v| options.format("hdg", v.get::<radian>())).as_deref(),
+            "height" => self.height.map(|v| options.format("height", v.get::<meter>())).as_deref(),
             "id" => Some(self.id.as_str()),
-            "length" => self.length.map(|v| v.value.to_scientific_string()).as_deref(),
+            "length" => self.length.map(|v| options.format("length", v.get::<meter>())).as_deref(),
             "name" => self.name.as_deref(),
             "orientation" => self.orientation.as_ref().map(Orientation::as_str),
-            "perpToRoad" => self.perp_to_road.map(|v| v.to_string()).as_deref(),
-            "pitch" => self.pitch.map(|v| v.value.to_scientific_string()).as_deref(),
-            "radius" => self.radius.map(|v| v.value.to_scientific_string()).as_deref(),
-            "roll" => self.roll.map(|v| v.value.to_scientific_string()).as_deref(),
-            "s" => Some(self.s.value.to_scientific_string()).as_deref(),
+            "perpToRoad" => self.perp_to_road.map(|v| Bool(v).as_str()),
+            "pitch" => self.pitch.map(|v| options.format("pitch", v.get::<radian>())).as_deref(),
+            "radius" => self.radius.map(|v| options.format("radius", v.get::<meter>())).as_deref(),
+            "roll" => self.roll.map(|v| options.format("roll", v.get::<radian>())).as_deref(),
+            "s" => Some(options.format("s", self.s.get::<meter>())).as_deref(),
             "subtype" => self.subtype.as_deref(),
-            "t" => Some(self.t.value.to_scientific_string()).as_deref(),
+            "t" => Some(options.format("t", self.t.get::<meter>())).as_deref(),
             "type" => self.r#type.as_ref().map(ObjectType::as_str),
-            "validLength" => self.valid_length.map(|v| v.value.to_scientific_string()).as_deref(),
-            "width" => self.width.map(|v| v.value.to_scientific_string()).as_deref(),
-            "zOffset" => Some(self.z_offset.value.to_scientific_string()).as_deref(),
+            "validLength" => self.valid_length.map(|v| options.format("validLength", v.get::<meter>())).as_deref(),
+            "width" => self.width.map(|v| options.format("width", v.get::<meter>())).as_deref(),
+            "zOffset" => Some(options.format("zOffset", self.z_offset.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for repeat in &self.repeat {
-            visit_children!(visitor, "repeat" => repeat);
+            visit_children!(visitor, options, "repeat" => repeat);
         }
 
         if let Some(outline) = &self.outline {
-            visit_children!(visitor, "outline" => outline);
+            visit_children!(visitor, options, "outline" => outline);
         }
 
         if let Some(outlines) = &self.outlines {
-            visit_children!(visitor, "outlines" => outlines);
+            visit_children!(visitor, options, "outlines" => outlines);
         }
 
         for material in &self.material {
-            visit_children!(visitor, "material" => material);
+            visit_children!(visitor, options, "material" => material);
         }
 
         for validity in &self.validity {
-            visit_children!(visitor, "validity" => validity);
+            visit_children!(visitor, options, "validity" => validity);
         }
 
         if let Some(parking_space) = &self.parking_space {
-            visit_children!(visitor, "parkingSpace" => parking_space);
+            visit_children!(visitor, options, "parkingSpace" => parking_space);
         }
 
         if let Some(markings) = &self.markings {
-            visit_children!(visitor, "markings" => markings);
+            visit_children!(visitor, options, "markings" => markings);
         }
 
         if let Some(borders) = &self.borders {
-            visit_children!(visitor, "borders" => borders);
+            visit_children!(visitor, options, "borders" => borders);
         }
 
         if let Some(surface) = &self.surface {
-            visit_children!(visitor, "surface" => surface);
+            visit_children!(visitor, options, "surface" => surface);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Object
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -216,9 +292,7 @@ where
         );
 
         Ok(Self {
-            dynamic: read
-                .attribute_opt::<String>("dynamic")?
-                .map(|v| v.eq_ignore_ascii_case("yes")),
+            dynamic: read.attribute_opt::<Bool>("dynamic")?.map(bool::from),
             hdg: read.attribute_opt("hdg")?.map(Angle::new::<radian>),
             height: read.attribute_opt("height")?.map(Length::new::<meter>),
             id: read.attribute("id")?,
@@ -227,7 +301,7 @@ where
                 .map(Length::new::<meter>),
             name: read.attribute_opt("name")?,
             orientation: read.attribute_opt("orientation")?,
-            perp_to_road: read.attribute_opt("perpToRoad")?,
+            perp_to_road: read.attribute_opt::<Bool>("perpToRoad")?.map(bool::from),
             pitch: read
                 .attribute_opt::<f64>("pitch")?
                 .map(Angle::new::<radian>),
@@ -251,7 +325,7 @@ where
             markings,
             borders,
             surface,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
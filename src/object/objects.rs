@@ -21,38 +21,43 @@ impl Objects {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for object in &self.object {
-            visit_children!(visitor, "object" => object);
+            visit_children!(visitor, options, "object" => object);
         }
 
         for object_reference in &self.object_reference {
-            visit_children!(visitor, "objectReference" => object_reference);
+            visit_children!(visitor, options, "objectReference" => object_reference);
         }
 
         for tunnel in &self.tunnel {
-            visit_children!(visitor, "tunnel" => tunnel);
+            visit_children!(visitor, options, "tunnel" => tunnel);
         }
 
         for bridge in &self.bridge {
-            visit_children!(visitor, "bridge" => bridge);
+            visit_children!(visitor, options, "bridge" => bridge);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Objects
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -77,7 +82,7 @@ where
             object_reference,
             tunnel,
             bridge,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
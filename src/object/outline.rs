@@ -1,4 +1,5 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::boolean::Bool;
 use crate::lane::lane_type::LaneType;
 use crate::object::corner::Corner;
 use crate::object::corner_local::CornerLocal;
@@ -34,36 +35,42 @@ impl Outline {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
-            "closed" => self.closed.map(|v| v.to_string()).as_deref(),
+            options,
+            "closed" => self.closed.map(|v| Bool(v).as_str()),
             "fillType" => self.fill_type.as_ref().map(OutlineFillType::as_str),
             "id" => self.id.map(|v| v.to_string()).as_deref(),
             "laneType" => self.lane_type.as_ref().map(LaneType::as_str),
-            "outer" => self.outer.map(|v| v.to_string()).as_deref(),
+            "outer" => self.outer.map(|v| Bool(v).as_str()),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for choice in &self.choice {
             match choice {
-                Corner::Road(road) => visit_children!(visitor, "cornerRoad" => road),
-                Corner::Local(local) => visit_children!(visitor, "cornerLocal" => local),
+                Corner::Road(road) => visit_children!(visitor, options, "cornerRoad" => road),
+                Corner::Local(local) => visit_children!(visitor, options, "cornerLocal" => local),
             }
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Outline
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -79,11 +86,11 @@ where
         );
 
         Ok(Self {
-            closed: read.attribute_opt("closed")?,
+            closed: read.attribute_opt::<Bool>("closed")?.map(bool::from),
             fill_type: read.attribute_opt("fillType")?,
             id: read.attribute_opt("id")?,
             lane_type: read.attribute_opt("laneType")?,
-            outer: read.attribute_opt("outer")?,
+            outer: read.attribute_opt::<Bool>("outer")?.map(bool::from),
             choice: Vec1::try_from_vec(choice).map_err(|_| {
                 crate::parser::Error::missing_element(
                     read.path().to_string(),
@@ -91,7 +98,7 @@ where
                     core::any::type_name::<Corner>(),
                 )
             })?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -17,10 +17,13 @@ impl ParkingSpace {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "access" => Some(self.access.as_str()),
             "restrictions" => self.restrictions.as_deref(),
         )
@@ -29,20 +32,21 @@ impl ParkingSpace {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for ParkingSpace
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
     fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
         read.expecting_no_child_elements_for(Self {
-            access: read.attribute("access")?,
+            access: read.enum_attribute("access")?,
             restrictions: read.attribute_opt("restrictions")?,
         })
     }
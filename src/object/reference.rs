@@ -1,9 +1,8 @@
 use crate::core::additional_data::AdditionalData;
 use crate::object::lane_validity::LaneValidity;
 use crate::object::orientation::Orientation;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// It is possible to link an object with one or more roads, signals or other objects using a
 /// `<objectReference>` element. The referenced objects require a unique ID. The object reference
@@ -34,34 +33,40 @@ impl ObjectReference {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "id" => Some(self.id.as_str()),
             "orientation" => Some(self.orientation.as_str()),
-            "s" => Some(self.s.value.to_scientific_string()).as_deref(),
-            "t" => Some(self.t.value.to_scientific_string()).as_deref(),
-            "validLength" => self.valid_length.as_ref().map(|v| v.value.to_scientific_string()).as_deref(),
-            "zOffset" => self.z_offset.as_ref().map(|v| v.value.to_scientific_string()).as_deref(),
+            "s" => Some(options.format("s", self.s.get::<meter>())).as_deref(),
+            "t" => Some(options.format("t", self.t.get::<meter>())).as_deref(),
+            "validLength" => self.valid_length.as_ref().map(|v| options.format("validLength", v.get::<meter>())).as_deref(),
+            "zOffset" => self.z_offset.as_ref().map(|v| options.format("zOffset", v.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for validity in &self.validity {
-            visit_children!(visitor, "validity" => validity);
+            visit_children!(visitor, options, "validity" => validity);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for ObjectReference
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -83,7 +88,7 @@ where
             valid_length: read.attribute_opt("validLength")?.map(Length::new::<meter>),
             z_offset: read.attribute_opt("zOffset")?.map(Length::new::<meter>),
             validity,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
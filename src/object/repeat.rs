@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// To avoid lengthy XML code, objects of the same type may be repeated. Attributes of the repeated
 /// object shall overrule the attributes from the original object. If attributes are omitted in the
@@ -47,40 +46,44 @@ impl Repeat {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "distance" => Some(self.distance.value.to_scientific_string()).as_deref(),
-            "heightEnd" => Some(self.height_end.value.to_scientific_string()).as_deref(),
-            "heightStart" => Some(self.height_start.value.to_scientific_string()).as_deref(),
-            "length" => Some(self.length.value.to_scientific_string()).as_deref(),
-            "lengthEnd" => self.length_end.map(|v| v.value.to_scientific_string()).as_deref(),
-            "lengthStart" => self.length_start.map(|v| v.value.to_scientific_string()).as_deref(),
-            "radiusEnd" => self.radius_end.map(|v| v.value.to_scientific_string()).as_deref(),
-            "radiusStart" => self.radius_start.map(|v| v.value.to_scientific_string()).as_deref(),
-            "s" => Some(self.s.value.to_scientific_string()).as_deref(),
-            "tEnd" => Some(self.t_end.value.to_scientific_string()).as_deref(),
-            "tStart" => Some(self.t_start.value.to_scientific_string()).as_deref(),
-            "widthEnd" => self.width_end.map(|v| v.value.to_scientific_string()).as_deref(),
-            "widthStart" => self.width_start.map(|v| v.value.to_scientific_string()).as_deref(),
-            "zOffsetEnd" => self.z_offset_end.map(|v| v.value.to_scientific_string()).as_deref(),
-            "zOffsetStart" => self.z_offset_start.map(|v| v.value.to_scientific_string()).as_deref(),
+            options,
+            "distance" => Some(options.format("distance", self.distance.get::<meter>())).as_deref(),
+            "heightEnd" => Some(options.format("heightEnd", self.height_end.get::<meter>())).as_deref(),
+            "heightStart" => Some(options.format("heightStart", self.height_start.get::<meter>())).as_deref(),
+            "length" => Some(options.format("length", self.length.get::<meter>())).as_deref(),
+            "lengthEnd" => self.length_end.map(|v| options.format("lengthEnd", v.get::<meter>())).as_deref(),
+            "lengthStart" => self.length_start.map(|v| options.format("lengthStart", v.get::<meter>())).as_deref(),
+            "radiusEnd" => self.radius_end.map(|v| options.format("radiusEnd", v.get::<meter>())).as_deref(),
+            "radiusStart" => self.radius_start.map(|v| options.format("radiusStart", v.get::<meter>())).as_deref(),
+            "s" => Some(options.format("s", self.s.get::<meter>())).as_deref(),
+            "tEnd" => Some(options.format("tEnd", self.t_end.get::<meter>())).as_deref(),
+            "tStart" => Some(options.format("tStart", self.t_start.get::<meter>())).as_deref(),
+            "widthEnd" => self.width_end.map(|v| options.format("widthEnd", v.get::<meter>())).as_deref(),
+            "widthStart" => self.width_start.map(|v| options.format("widthStart", v.get::<meter>())).as_deref(),
+            "zOffsetEnd" => self.z_offset_end.map(|v| options.format("zOffsetEnd", v.get::<meter>())).as_deref(),
+            "zOffsetStart" => self.z_offset_start.map(|v| options.format("zOffsetStart", v.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Repeat
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -1,9 +1,10 @@
+use crate::backend::{Attribute, Event};
+use crate::core::comment::Comment;
 use backtrace::Backtrace;
 use std::convert::Infallible;
 use std::fmt::{Display, Formatter};
 use std::num::{ParseFloatError, ParseIntError};
 use std::str::{FromStr, ParseBoolError};
-use xml::attribute::OwnedAttribute;
 
 pub type Result<T> = std::result::Result<T, Box<Error>>;
 
@@ -25,24 +26,53 @@ impl Display for Path<'_> {
 
 pub struct ReadContext<'a, I>
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     iterator: &'a mut I,
     path: Path<'a>,
-    attributes: Vec<OwnedAttribute>,
+    attributes: Vec<Attribute<'static>>,
     children_done: bool,
-    #[cfg(debug_assertions)]
-    read_attributes: std::cell::RefCell<Vec<String>>,
+    comments: Vec<Comment>,
+    leading_comments: Option<&'a std::cell::Cell<Vec<String>>>,
+    read_attributes: ReadAttributes,
+    skipped: bool,
+    leniency: Option<&'a Leniency>,
+}
+
+/// The indices of the attributes of a [`ReadContext`] that have been requested, as a bitset for
+/// the first 64 attributes and a list for the rare elements with more
+#[derive(Debug, Default)]
+struct ReadAttributes {
+    first: std::cell::Cell<u64>,
+    more: std::cell::RefCell<Vec<usize>>,
+}
+
+impl ReadAttributes {
+    fn mark(&self, index: usize) {
+        if index < 64 {
+            self.first.set(self.first.get() | (1 << index));
+        } else if !self.contains(index) {
+            self.more.borrow_mut().push(index);
+        }
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        if index < 64 {
+            self.first.get() & (1 << index) != 0
+        } else {
+            self.more.borrow().contains(&index)
+        }
+    }
 }
 
 impl<'a, I> ReadContext<'a, I>
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     pub fn from_parent(
         iterator: &'a mut I,
         path: Path<'a>,
-        attributes: Vec<OwnedAttribute>,
+        attributes: Vec<Attribute<'static>>,
     ) -> Self {
         Self::from(iterator)
             .with_path(path)
@@ -54,12 +84,19 @@ where
         self
     }
 
-    pub fn with_attributes(mut self, attributes: Vec<OwnedAttribute>) -> Self {
+    pub fn with_attributes(mut self, attributes: Vec<Attribute<'static>>) -> Self {
         self.attributes = attributes;
         self
     }
 
-    pub fn path(&self) -> Path {
+    /// Accepts the deviations from the schema of the given leniency while reading this element
+    /// and all its children
+    pub fn with_leniency(mut self, leniency: &'a Leniency) -> Self {
+        self.leniency = Some(leniency);
+        self
+    }
+
+    pub fn path(&self) -> Path<'_> {
         self.path
     }
 
@@ -67,25 +104,94 @@ where
         self.path.name
     }
 
-    pub fn attribute<T: FromStr>(&self, name: &str) -> Result<T>
+    /// The value of an enum attribute, which is kept as `Other` value if it is unknown to the
+    /// schema and [`ParseOptions::tolerant_enums`] is set
+    pub fn enum_attribute<T: OtherValue>(&self, name: &str) -> Result<T>
     where
         T::Err: Into<ParseError>,
     {
-        #[cfg(debug_assertions)]
-        self.read_attributes.borrow_mut().push(name.to_string());
-        for attribute in &self.attributes {
-            if attribute.name.local_name.eq_ignore_ascii_case(name) {
-                return match T::from_str(&attribute.value) {
-                    Ok(v) => Ok(v),
-                    Err(e) => Err(Box::new(Error::ParseError {
-                        path: self.path.to_string(),
-                        field: name.to_string(),
-                        ty: core::any::type_name::<T>().to_string(),
-                        error: e.into(),
-                        bt: Box::new(Backtrace::new()),
-                    })),
-                };
+        match self.find_attribute(name)? {
+            Some(value) => self.parse_enum(name, value),
+            None => self.attribute(name),
+        }
+    }
+
+    /// The value of a numeric attribute that the schema restricts to the given range, see
+    /// [`ReadContext::in_range`]
+    pub fn attribute_in(&self, name: &str, range: ValueRange) -> Result<f64> {
+        let value = self.attribute(name)?;
+        self.in_range(name, value, range)
+    }
+
+    /// Whether values outside of the range the schema allows are handled at all, see
+    /// [`ParseOptions::out_of_range`], so that checks which are costly to prepare can be skipped
+    pub fn checks_ranges(&self) -> bool {
+        self.leniency.is_some_and(|l| l.out_of_range.is_some())
+    }
+
+    /// Whether values beyond the outer lane borders are warned about, see
+    /// [`ParseOptions::warn_beyond_lanes`]
+    pub fn warns_beyond_lanes(&self) -> bool {
+        self.leniency.is_some_and(|l| l.warn_beyond_lanes)
+    }
+
+    /// Warns about a value of the given field of the element at `path` that is outside of the
+    /// given range, which is kept as read
+    pub fn warn_out_of_range(&self, path: &str, field: &str, value: f64, range: ValueRange) {
+        if !range.contains(value) {
+            self.warn(Warning::OutOfRange {
+                path: path.to_string(),
+                field: field.to_string(),
+                value: value.to_string(),
+                kept: value.to_string(),
+            });
+        }
+    }
+
+    /// Handles a value of the given field that is outside of the range the schema allows as
+    /// configured by [`ParseOptions::out_of_range`]
+    pub fn in_range(&self, field: &str, value: f64, range: ValueRange) -> Result<f64> {
+        if range.contains(value) {
+            return Ok(value);
+        }
+        let kept = match self.leniency.and_then(|l| l.out_of_range) {
+            None => return Ok(value),
+            Some(OutOfRange::Error) => {
+                return Err(Box::new(Error::OutOfRange {
+                    path: self.path.to_string(),
+                    field: field.to_string(),
+                    value,
+                }))
             }
+            Some(OutOfRange::Clamp) => range.clamp(value),
+            Some(OutOfRange::Accept) => value,
+        };
+        self.warn(Warning::OutOfRange {
+            path: self.path.to_string(),
+            field: field.to_string(),
+            value: value.to_string(),
+            kept: kept.to_string(),
+        });
+        Ok(kept)
+    }
+
+    /// An optional enum attribute, see [`ReadContext::enum_attribute`]
+    pub fn enum_attribute_opt<T: OtherValue>(&self, name: &str) -> Result<Option<T>>
+    where
+        T::Err: Into<ParseError>,
+    {
+        match self.find_attribute(name)? {
+            Some(value) => self.parse_enum(name, value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn attribute<T: FromStr>(&self, name: &str) -> Result<T>
+    where
+        T::Err: Into<ParseError>,
+    {
+        if let Some(value) = self.find_attribute(name)? {
+            return self.parse(name, value);
         }
         Err(Box::new(Error::missing_attribute(
             self.path.to_string(),
@@ -98,36 +204,159 @@ where
     where
         T::Err: Into<ParseError>,
     {
-        #[cfg(debug_assertions)]
-        self.read_attributes.borrow_mut().push(name.to_string());
-        for attribute in &self.attributes {
-            if attribute.name.local_name.eq_ignore_ascii_case(name) {
-                return match T::from_str(&attribute.value) {
-                    Ok(v) => Ok(Some(v)),
-                    Err(e) => Err(Box::new(Error::ParseError {
-                        path: self.path.to_string(),
-                        field: name.to_string(),
-                        ty: core::any::type_name::<T>().to_string(),
-                        error: e.into(),
-                        bt: Box::new(Backtrace::new()),
-                    })),
-                };
+        match self.find_attribute(name)? {
+            Some(value) => self.parse(name, value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// The value of the attribute of the given name, see [`ParseOptions::duplicates`] for
+    /// attributes whose name differs in case only
+    fn find_attribute(&self, name: &str) -> Result<Option<&str>> {
+        let mut matching = self
+            .attributes
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.name.local_name.eq_ignore_ascii_case(name))
+            .map(|(index, a)| {
+                self.read_attributes.mark(index);
+                a
+            });
+        let first = match matching.next() {
+            Some(first) => first,
+            None => return Ok(None),
+        };
+        let last = matching.last();
+        match (self.leniency.and_then(|l| l.duplicates), last) {
+            (Some(Duplicates::Error), Some(_)) => Err(Box::new(Error::Duplicate {
+                path: self.path.to_string(),
+                name: name.to_string(),
+            })),
+            (Some(kept), Some(last)) => {
+                self.warn(Warning::Duplicate {
+                    path: self.path.to_string(),
+                    name: name.to_string(),
+                    kept,
+                });
+                Ok(Some(match kept {
+                    Duplicates::KeepLast => &last.value,
+                    _ => &first.value,
+                }))
+            }
+            _ => Ok(Some(&first.value)),
+        }
+    }
+
+    fn warn(&self, warning: Warning) {
+        if let Some(leniency) = self.leniency {
+            leniency.warnings.borrow_mut().push(warning);
+        }
+    }
+
+    fn parse_enum<T: OtherValue>(&self, name: &str, value: &str) -> Result<T>
+    where
+        T::Err: Into<ParseError>,
+    {
+        match T::from_str(value) {
+            Ok(v) => Ok(v),
+            Err(_) if self.leniency.is_some_and(|l| l.tolerant_enums) => {
+                self.warn(Warning::UnknownValue {
+                    path: self.path.to_string(),
+                    field: name.to_string(),
+                    value: value.to_string(),
+                });
+                Ok(T::other(value))
+            }
+            Err(_) => self.parse(name, value),
+        }
+    }
+
+    fn parse<T: FromStr>(&self, name: &str, value: &str) -> Result<T>
+    where
+        T::Err: Into<ParseError>,
+    {
+        match T::from_str(value) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                if self.leniency.is_some_and(|l| l.tolerant_numbers) {
+                    if let Some((v, normalized)) =
+                        normalize_number(value).and_then(|n| T::from_str(&n).ok().map(|v| (v, n)))
+                    {
+                        self.warn(Warning::Normalized {
+                            path: self.path.to_string(),
+                            field: name.to_string(),
+                            value: value.to_string(),
+                            normalized,
+                        });
+                        return Ok(v);
+                    }
+                }
+                Err(Box::new(Error::ParseError {
+                    path: self.path.to_string(),
+                    field: name.to_string(),
+                    ty: core::any::type_name::<T>().to_string(),
+                    error: e.into(),
+                    bt: Box::new(Backtrace::new()),
+                }))
             }
         }
-        Ok(None)
     }
 
-    pub fn attributes(&self) -> impl Iterator<Item = &OwnedAttribute> {
-        #[allow(clippy::map_identity)] // because of debug assertions cfg flag
-        self.attributes.iter().map(|a| {
-            #[cfg(debug_assertions)]
-            self.read_attributes
-                .borrow_mut()
-                .push(a.name.local_name.clone());
+    pub fn attributes(&self) -> impl Iterator<Item = &Attribute<'static>> {
+        self.attributes.iter().enumerate().map(|(index, a)| {
+            self.read_attributes.mark(index);
             a
         })
     }
 
+    /// The comments between the child elements that have been read so far
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    /// Takes the comments in front of this element, which its parent keeps as
+    /// [`Comment::before`] this element otherwise
+    pub fn take_leading_comments(&self) -> Vec<String> {
+        self.leading_comments.map(|c| c.take()).unwrap_or_default()
+    }
+
+    /// The attributes that have not been requested so far, which are the attributes unknown to
+    /// the model once all known attributes of the element have been read
+    pub fn unread_attributes(&self) -> impl Iterator<Item = &Attribute<'static>> {
+        self.attributes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.read_attributes.contains(*index))
+            .map(|(_, a)| a)
+    }
+
+    /// The [`ReadContext::unread_attributes`], which are marked as read, as the caller keeps them
+    pub fn keep_unread_attributes(&self) -> impl Iterator<Item = &Attribute<'static>> {
+        self.attributes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.read_attributes.contains(*index))
+            .map(|(index, a)| {
+                self.read_attributes.mark(index);
+                a
+            })
+    }
+
+    /// Skips this element, which is unknown to the model, and warns about it
+    pub fn skip_unknown(mut self) {
+        if !self.skipped && self.leniency.is_some() {
+            self.warn(Warning::UnknownElement {
+                path: self
+                    .path
+                    .parent
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+                name: self.element_name().to_string(),
+            });
+        }
+        self.skipped = true;
+    }
+
     #[allow(clippy::type_complexity)] // for now, getting removed later on most properly anyway...
     pub fn elements(
         &mut self,
@@ -137,11 +366,9 @@ where
         )],
     ) -> Result<()> {
         'outer: while let Some(event) = self.iterator.next() {
-            match event.map_err(Error::from).map_err(Box::new)? {
-                xml::reader::XmlEvent::StartElement {
-                    name,
-                    attributes,
-                    namespace: _,
+            match event? {
+                Event::StartElement {
+                    name, attributes, ..
                 } => {
                     let mut context = ReadContext::from_parent(
                         &mut *self.iterator,
@@ -151,16 +378,16 @@ where
                         },
                         attributes,
                     );
+                    context.leniency = self.leniency;
                     for (mapper_name, mapper_fn) in mapper.iter_mut() {
                         if name.local_name.eq_ignore_ascii_case(mapper_name) {
                             mapper_fn(&mut context)?;
                             continue 'outer;
                         }
                     }
-                    context.elements(&mut [])?;
+                    context.skip_unknown();
                 }
-                xml::reader::XmlEvent::EndElement { name } => {
-                    debug_assert_eq!(self.element_name(), &name.local_name);
+                Event::EndElement => {
                     self.children_done = true;
                     break;
                 }
@@ -183,72 +410,184 @@ where
         mut mapper: impl for<'b> FnMut(&'b str, ReadContext<'_, I>) -> Result<()>,
         mut cdata: impl for<'b> FnMut(String) -> Result<()>,
     ) -> Result<()> {
+        let mut counts: Vec<(String, usize)> = Vec::new();
+        let mut leading = Vec::new();
+        let mut single_children = Vec::new();
         while let Some(event) = self.iterator.next() {
-            match event.map_err(Error::from).map_err(Box::new)? {
-                xml::reader::XmlEvent::StartElement {
-                    name,
-                    attributes,
-                    namespace: _,
+            match event? {
+                Event::StartElement {
+                    name, attributes, ..
                 } => {
-                    if let Err(e) = mapper(
-                        &name.local_name,
-                        ReadContext::from_parent(
-                            &mut *self.iterator,
-                            Path {
-                                parent: Some(&self.path),
-                                name: &name.local_name,
-                            },
-                            attributes,
-                        ),
-                    ) {
+                    let index = match counts
+                        .iter_mut()
+                        .find(|(counted, _)| counted.eq_ignore_ascii_case(&name.local_name))
+                    {
+                        Some((_, count)) => {
+                            *count += 1;
+                            *count - 1
+                        }
+                        None => {
+                            counts.push((name.local_name.to_string(), 1));
+                            0
+                        }
+                    };
+                    let duplicates = self.leniency.and_then(|l| l.duplicates);
+                    let single = duplicates.and_then(|_| {
+                        SINGLE_CHILDREN
+                            .iter()
+                            .find(|(parent, child)| {
+                                parent.eq_ignore_ascii_case(self.path.name)
+                                    && child.eq_ignore_ascii_case(&name.local_name)
+                            })
+                            .map(|(_, child)| *child)
+                    });
+                    let skip = match (duplicates, single) {
+                        (Some(kept), Some(single)) if single_children.contains(&single) => {
+                            if kept == Duplicates::Error {
+                                self.children_done = true;
+                                return Err(Box::new(Error::Duplicate {
+                                    path: self.path.to_string(),
+                                    name: name.local_name.into_owned(),
+                                }));
+                            }
+                            self.warn(Warning::Duplicate {
+                                path: self.path.to_string(),
+                                name: name.local_name.to_string(),
+                                kept,
+                            });
+                            kept == Duplicates::KeepFirst
+                        }
+                        (_, Some(single)) => {
+                            single_children.push(single);
+                            false
+                        }
+                        _ => false,
+                    };
+                    let leading = std::cell::Cell::new(std::mem::take(&mut leading));
+                    let mut context = ReadContext::from_parent(
+                        &mut *self.iterator,
+                        Path {
+                            parent: Some(&self.path),
+                            name: &name.local_name,
+                        },
+                        attributes,
+                    );
+                    context.leniency = self.leniency;
+                    context.leading_comments = Some(&leading);
+                    if skip {
+                        // walk the repeated element by dropping it, it has been warned about
+                        context.skipped = true;
+                        drop(context);
+                    } else if let Err(e) = mapper(&name.local_name, context) {
                         // dont walk any more elements on an error, just drop them
                         self.children_done = true;
                         return Err(e);
                     }
+                    // elements without leading comments of their own leave them to the parent
+                    self.comments
+                        .extend(leading.take().into_iter().map(|text| Comment {
+                            before: Some((name.local_name.to_string(), index)),
+                            text,
+                        }));
                 }
-                xml::reader::XmlEvent::EndElement { name } => {
-                    debug_assert_eq!(self.element_name(), &name.local_name);
+                Event::EndElement => {
                     self.children_done = true;
                     break;
                 }
-                xml::reader::XmlEvent::EndDocument => {
+                Event::EndDocument => {
                     debug_assert!(self.path.parent.is_none());
                     debug_assert!(self.path.name.is_empty());
                     self.children_done = true;
                     break;
                 }
-                xml::reader::XmlEvent::CData(data) => {
-                    cdata(data)?;
+                Event::CData(data) | Event::Characters(data) => {
+                    cdata(data.into_owned())?;
                 }
+                Event::Comment(text) => leading.push(text.into_owned()),
                 other => {
                     drop(other);
                 }
             }
         }
+        self.comments.extend(
+            leading
+                .into_iter()
+                .map(|text| Comment { before: None, text }),
+        );
         Ok(())
     }
 
+    /// Reads this element as `T`. If that fails, the rest of the element is skipped, so that
+    /// reading can continue with the next sibling of the element.
+    pub fn read_or_skip<T>(mut self) -> Result<T>
+    where
+        T: for<'b, 'c> TryFrom<ReadContext<'b, SubtreeEvents<'c, I>>, Error = Box<Error>>,
+    {
+        let attributes = std::mem::take(&mut self.attributes);
+        let mut events = SubtreeEvents {
+            iterator: &mut *self.iterator,
+            depth: 1,
+        };
+        let mut context = ReadContext::from_parent(&mut events, self.path, attributes);
+        context.leniency = self.leniency;
+        context.leading_comments = self.leading_comments;
+        let warnings = self.leniency.map(|l| l.warnings.borrow().len());
+        let result = T::try_from(context);
+        if let (Some(leniency), Some(len), Err(_)) = (self.leniency, warnings, &result) {
+            // the element is skipped as a whole, so what has been dropped of it is of no interest
+            leniency.warnings.borrow_mut().truncate(len);
+        }
+        while events.depth > 0 {
+            if !matches!(events.next(), Some(Ok(_))) {
+                break;
+            }
+        }
+        self.children_done = true;
+        result
+    }
+
     #[inline]
     pub fn expecting_no_child_elements(&mut self) -> Result<()> {
-        self.children(|name, mut read| {
-            dbg!(name);
-            read.expecting_no_child_elements()
+        self.children(|_name, read| {
+            read.skip_unknown();
+            Ok(())
         })
     }
 
     #[inline]
     pub fn expecting_no_child_elements_for<T>(&mut self, value: T) -> Result<T> {
-        self.children(|_name, mut read| {
-            dbg!(read.path().to_string());
-            read.expecting_no_child_elements()
-        })?;
+        self.expecting_no_child_elements()?;
         Ok(value)
     }
 }
 
+/// The events of an element that is read by [`ReadContext::read_or_skip`], which tracks how
+/// deep within the element the events have been read
+pub struct SubtreeEvents<'a, I> {
+    iterator: &'a mut I,
+    depth: usize,
+}
+
+impl<I> Iterator for SubtreeEvents<'_, I>
+where
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
+{
+    type Item = Result<Event<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let event = self.iterator.next();
+        match &event {
+            Some(Ok(Event::StartElement { .. })) => self.depth += 1,
+            Some(Ok(Event::EndElement)) => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+        event
+    }
+}
+
 impl<'a, I> From<&'a mut I> for ReadContext<'a, I>
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     fn from(iterator: &'a mut I) -> Self {
         Self {
@@ -259,45 +598,318 @@ where
             },
             attributes: Vec::new(),
             children_done: false,
-            #[cfg(debug_assertions)]
-            read_attributes: std::cell::RefCell::new(Vec::new()),
+            comments: Vec::new(),
+            leading_comments: None,
+            read_attributes: ReadAttributes::default(),
+            skipped: false,
+            leniency: None,
         }
     }
 }
 
 impl<'a, I> Drop for ReadContext<'a, I>
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     fn drop(&mut self) {
         if !self.children_done {
-            let _ = self.children(|name, ctx| {
+            let skipped = self.skipped;
+            let _ = self.children(|_name, mut ctx| {
                 // walk it by dropping it
-                let _ = (name, ctx);
+                if skipped {
+                    ctx.skipped = true;
+                } else {
+                    ctx.skip_unknown();
+                }
                 Ok(())
             });
         }
 
-        #[cfg(debug_assertions)]
-        {
-            dbg!(self.path().to_string());
-            let attributes = self
-                .attributes
-                .iter()
-                .filter(|a| !self.read_attributes.borrow().contains(&a.name.local_name))
+        if !self.skipped && self.leniency.is_some() {
+            let unknown = self
+                .unread_attributes()
+                .filter(|a| a.name.prefix.is_none())
+                .map(|a| Warning::UnknownAttribute {
+                    path: self.path.to_string(),
+                    name: a.name.local_name.to_string(),
+                    value: a.value.to_string(),
+                })
                 .collect::<Vec<_>>();
-            if !attributes.is_empty() {
-                dbg!(self.path().to_string());
-                dbg!(attributes);
+            for warning in unknown {
+                self.warn(warning);
+            }
+        }
+    }
+}
+
+/// Controls how [`crate::core::OpenDrive::from_str_with`] and
+/// [`crate::core::OpenDrive::from_path`] parse a document. The default parses strictly and accepts every revision.
+#[derive(Debug, Default)]
+pub struct ParseOptions<'a> {
+    /// Skips malformed roads and junctions instead of failing, see
+    /// [`crate::core::OpenDrive::from_xml_str_recovering`]
+    pub recover: bool,
+    /// Fails on documents whose header declares a revision outside of this range, like
+    /// `(1, 4)..=(1, 7)`
+    pub revisions: Option<std::ops::RangeInclusive<(u16, u16)>>,
+    /// Receives the diagnostics of the skipped elements when recovering
+    pub diagnostics: Option<&'a mut Vec<crate::core::recovery::Diagnostic>>,
+    /// Accepts numbers with a decimal comma, surrounding whitespace or a leading `+`, as some
+    /// exports contain them, instead of failing on them
+    pub tolerant_numbers: bool,
+    /// How attributes whose names differ in case only and repeated child elements that the
+    /// schema allows once, such as a second `<planView>`, are handled. Without, the first of
+    /// such attributes and the last of such elements is kept silently.
+    pub duplicates: Option<Duplicates>,
+    /// Receives a warning for each deviation from the schema that was accepted and for each
+    /// attribute and child element unknown to the model that was dropped, as only some elements
+    /// keep unknown data, see [`crate::core::additional_data::AdditionalData`]
+    pub warnings: Option<&'a mut Vec<Warning>>,
+    /// Checks the rules of the validator while parsing
+    pub validator: Option<&'a mut crate::core::validation::Validator>,
+    /// Keeps values of enums such as lane types that are unknown to the schema, like those of
+    /// future revisions or of vendors, as their `Other` variant instead of failing on them, see
+    /// [`OtherValue`]. They are written back as read.
+    pub tolerant_enums: bool,
+    /// How values outside of the range the schema allows, such as negative lengths and headings
+    /// outside of [0, 2π), are handled. Without, they are kept silently.
+    pub out_of_range: Option<OutOfRange>,
+    /// Warns about objects and signals whose t-coordinate is beyond the outer lane borders,
+    /// which are kept as read, as roadside objects and signals are placed there on purpose. The
+    /// warnings are received by [`ParseOptions::warnings`].
+    pub warn_beyond_lanes: bool,
+}
+
+impl ParseOptions<'_> {
+    /// The leniency of these options, if any deviation from the schema is accepted
+    pub(crate) fn leniency(&self) -> Option<Leniency> {
+        (self.tolerant_numbers
+            || self.tolerant_enums
+            || self.duplicates.is_some()
+            || self.out_of_range.is_some()
+            || self.warn_beyond_lanes
+            || self.warnings.is_some())
+        .then(|| Leniency {
+            tolerant_numbers: self.tolerant_numbers,
+            tolerant_enums: self.tolerant_enums,
+            duplicates: self.duplicates,
+            out_of_range: self.out_of_range,
+            warn_beyond_lanes: self.warn_beyond_lanes,
+            warnings: std::cell::RefCell::default(),
+        })
+    }
+}
+
+/// How repeated attributes and child elements are handled, see [`ParseOptions::duplicates`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Duplicates {
+    /// Fails the parsing
+    Error,
+    /// Keeps the first and warns about the others
+    KeepFirst,
+    /// Keeps the last and warns about the others
+    KeepLast,
+}
+
+/// How values outside of the range the schema allows are handled, see
+/// [`ParseOptions::out_of_range`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutOfRange {
+    /// Fails the parsing
+    Error,
+    /// Clamps the value into the range, or wraps it for headings, and warns about it
+    Clamp,
+    /// Keeps the value and warns about it
+    Accept,
+}
+
+/// The range of values the schema allows for a numeric value, see [`ReadContext::in_range`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueRange {
+    /// Between both bounds, inclusive
+    Between(f64, f64),
+    /// An angle in radian within [0, 2π), out of range values are wrapped into it
+    Heading,
+}
+
+impl ValueRange {
+    /// Non-negative values, like lengths
+    pub const NON_NEGATIVE: ValueRange = ValueRange::Between(0.0, f64::INFINITY);
+
+    pub fn contains(&self, value: f64) -> bool {
+        match *self {
+            Self::Between(min, max) => (min..=max).contains(&value),
+            Self::Heading => (0.0..core::f64::consts::TAU).contains(&value),
+        }
+    }
+
+    /// The value of the range closest to the given value
+    pub fn clamp(&self, value: f64) -> f64 {
+        match *self {
+            Self::Between(min, max) => value.max(min).min(max),
+            Self::Heading => {
+                // rounding may yield 2π itself for tiny negative values
+                let wrapped = value.rem_euclid(core::f64::consts::TAU);
+                if wrapped < core::f64::consts::TAU {
+                    wrapped
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// The deviations from the schema that are accepted by a [`ReadContext`] together with the
+/// warnings about the accepted deviations
+#[derive(Debug, Default)]
+pub struct Leniency {
+    pub tolerant_numbers: bool,
+    pub tolerant_enums: bool,
+    pub duplicates: Option<Duplicates>,
+    pub out_of_range: Option<OutOfRange>,
+    pub warn_beyond_lanes: bool,
+    pub warnings: std::cell::RefCell<Vec<Warning>>,
+}
+
+/// A deviation from the schema that was accepted while parsing
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Warning {
+    /// A numeric attribute value that was normalized, see [`ParseOptions::tolerant_numbers`]
+    Normalized {
+        path: String,
+        field: String,
+        /// The value as read
+        value: String,
+        /// The value as parsed
+        normalized: String,
+    },
+    /// An enum value unknown to the schema that was kept, see [`ParseOptions::tolerant_enums`]
+    UnknownValue {
+        path: String,
+        field: String,
+        value: String,
+    },
+    /// A repeated attribute or child element, see [`ParseOptions::duplicates`]
+    Duplicate {
+        path: String,
+        name: String,
+        kept: Duplicates,
+    },
+    /// A value outside of the range the schema allows, see [`ParseOptions::out_of_range`]
+    OutOfRange {
+        path: String,
+        field: String,
+        /// The value as read
+        value: String,
+        /// The value as parsed, which differs from the value as read if it was clamped
+        kept: String,
+    },
+    /// An attribute unknown to the model of an element that does not keep unknown attributes,
+    /// which was dropped
+    UnknownAttribute {
+        path: String,
+        name: String,
+        value: String,
+    },
+    /// A child element unknown to the model of an element that does not keep unknown child
+    /// elements, which was dropped
+    UnknownElement { path: String, name: String },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::Normalized {
+                path,
+                field,
+                value,
+                normalized,
+            } => write!(
+                f,
+                "Normalized `{path}`.`{field}` from `{value}` to `{normalized}`"
+            ),
+            Warning::UnknownValue { path, field, value } => {
+                write!(f, "Kept the unknown value `{value}` of `{path}`.`{field}`")
+            }
+            Warning::Duplicate { path, name, kept } => {
+                write!(f, "Repeated `{name}` in `{path}`, kept {kept:?}")
+            }
+            Warning::OutOfRange {
+                path,
+                field,
+                value,
+                kept,
+            } => write!(
+                f,
+                "`{path}`.`{field}` is out of range with `{value}`, kept `{kept}`"
+            ),
+            Warning::UnknownAttribute { path, name, value } => {
+                write!(
+                    f,
+                    "Dropped the unknown attribute `{path}`.`{name}` of `{value}`"
+                )
+            }
+            Warning::UnknownElement { path, name } => {
+                write!(f, "Dropped the unknown child element `{name}` of `{path}`")
             }
         }
     }
 }
 
+/// The child elements that the schema allows at most once within their parent, by the names of
+/// the parent and the child
+const SINGLE_CHILDREN: &[(&str, &str)] = &[
+    ("OpenDRIVE", "header"),
+    ("header", "geoReference"),
+    ("header", "offset"),
+    ("road", "link"),
+    ("road", "planView"),
+    ("road", "elevationProfile"),
+    ("road", "lateralProfile"),
+    ("road", "lanes"),
+    ("road", "objects"),
+    ("road", "signals"),
+    ("road", "surface"),
+    ("road", "railroad"),
+    ("laneSection", "left"),
+    ("laneSection", "center"),
+    ("laneSection", "right"),
+    ("object", "parkingSpace"),
+    ("object", "markings"),
+    ("object", "borders"),
+    ("object", "outlines"),
+];
+
+/// The number with surrounding whitespace and a leading `+` removed and a decimal comma replaced
+/// by a point, if the value looks like a number that is not in its canonical form
+fn normalize_number(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    let trimmed = trimmed.strip_prefix('+').unwrap_or(trimmed);
+    if trimmed.is_empty()
+        || !trimmed
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | ',' | 'e' | 'E'))
+    {
+        return None;
+    }
+    let normalized = if !trimmed.contains('.') && trimmed.matches(',').count() == 1 {
+        trimmed.replacen(',', ".", 1)
+    } else {
+        trimmed.to_string()
+    };
+    (normalized != value).then_some(normalized)
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("XML parsing failed: {0}")]
     XmlError(#[from] xml::reader::Error),
+    #[error("XML writing failed: {0}")]
+    WriteError(#[from] xml::writer::Error),
+    #[cfg(feature = "quick-xml")]
+    #[error("XML parsing failed: {0}")]
+    QuickXmlError(#[from] quick_xml::Error),
     #[error("Missing element at `{path}`.`{field}` of type `{ty}`")]
     ElementMissing {
         path: String,
@@ -323,6 +935,18 @@ pub enum Error {
     },
     #[error("Invalid value for `{name}`: {value}")]
     InvalidValueFor { name: String, value: String },
+    #[error("`{name}` is repeated in `{path}`")]
+    Duplicate { path: String, name: String },
+    #[error("`{path}`.`{field}` is out of range with `{value}`")]
+    OutOfRange {
+        path: String,
+        field: String,
+        value: f64,
+    },
+    #[error("Unsupported revision {rev_major}.{rev_minor}")]
+    UnsupportedRevision { rev_major: u16, rev_minor: u16 },
+    #[error("Validation aborted the parsing: {0}")]
+    Violation(crate::core::validation::Violation),
 }
 
 impl Error {
@@ -426,6 +1050,13 @@ impl ToScientificString for f64 {
     }
 }
 
+/// An enum with a variant for values unknown to the schema, which only strings of the schema
+/// parse from but which keeps other values while reading with [`ParseOptions::tolerant_enums`]
+pub trait OtherValue: FromStr {
+    /// The `Other` variant of the given value
+    fn other(value: &str) -> Self;
+}
+
 #[derive(Debug, thiserror::Error)]
 pub struct InvalidEnumValue {
     pub r#type: String,
@@ -573,14 +1204,7 @@ macro_rules! match_child_eq_ignore_ascii_case {
                         Ok(())
                     },
                 )*
-                _ => {
-                    let v: $crate::parser::Result<()> = Ok(());
-                    $(
-                        let mut a = $alt;
-                        let v = v.and_then(|_| a(name, context));
-                    )?
-                    v
-                }
+                _ => $crate::match_child_default!(name, context $(, $alt)?),
             }
         })?;
 
@@ -602,12 +1226,26 @@ macro_rules! match_child_eq_ignore_ascii_case {
     }
 }
 
+/// The default branch of [`match_child_eq_ignore_ascii_case`], which skips unknown child
+/// elements without an alternative
+#[doc(hidden)]
+#[macro_export]
+macro_rules! match_child_default {
+    ($name:ident, $context:ident) => {{
+        $context.skip_unknown();
+        Ok(())
+    }};
+    ($name:ident, $context:ident, $alt:expr) => {{
+        let mut a = $alt;
+        a($name, $context)
+    }};
+}
+
 #[macro_export]
 macro_rules! visit_attributes {
-    ($visitor:ident$(, $name:literal => $attr:expr)* $(,)?) => {
+    ($visitor:ident, $options:ident$(, $name:literal => $attr:expr)* $(,)?) => {
         $visitor(Cow::Borrowed({
-            #[allow(unused)]
-            use $crate::parser::ToScientificString as _;
+            let _: &$crate::writer::WriteOptions = $options;
             &[
                 $(
                     xml::attribute::Attribute::new(
@@ -616,16 +1254,15 @@ macro_rules! visit_attributes {
                     ),
                 )*
             ]
-        }))
+        }), &[])
     }
 }
 
 #[macro_export]
 macro_rules! visit_attributes_flatten {
-    ($visitor:ident$(, $name:literal => $attr:expr)* $(,)?) => {
+    ($visitor:ident, $options:ident$(, $name:literal => $attr:expr)* $(,)?) => {
         $visitor(Cow::Borrowed({
-            #[allow(unused)]
-            use $crate::parser::ToScientificString as _;
+            let _: &$crate::writer::WriteOptions = $options;
             &[
                 $(
                     $attr.map(|attr| ($name, attr)),
@@ -636,24 +1273,28 @@ macro_rules! visit_attributes_flatten {
                     attr
                 )
             }).collect::<Vec<_>>()
-        }))
+        }), &[])
     }
 }
 
 #[macro_export]
 macro_rules! visit_children {
-    ($visitor:ident $(, $name:literal => $child:expr)* $(,)?) => {
+    ($visitor:ident, $options:ident $(, $name:literal => $child:expr)* $(,)?) => {
         {
             let _ = &mut $visitor;
+            let _: &$crate::writer::WriteOptions = $options;
             $(
-                $child.visit_attributes(|attributes| {
+                $child.visit_attributes(|attributes, comments| {
+                    for comment in comments {
+                        $visitor(xml::writer::XmlEvent::Comment(comment))?;
+                    }
                     $visitor(xml::writer::XmlEvent::StartElement {
                         name: xml::name::Name::local($name),
                         attributes,
                         namespace: std::borrow::Cow::Owned(xml::namespace::Namespace::empty()),
                     })
-                })?;
-                $child.visit_children(&mut $visitor)?;
+                }, $options)?;
+                $child.visit_children(&mut $visitor, $options)?;
                 $visitor(xml::writer::XmlEvent::EndElement { name: None })?;
             )*
         }
@@ -662,6 +1303,37 @@ macro_rules! visit_children {
 
 #[macro_export]
 macro_rules! impl_from_str_as_str {
+    ($ty:ty, other => $other:ident $(, $name:literal => $value:ident)* $(,)?) => {
+        impl $ty {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(<$ty>::$value => $name,)*
+                    Self::$other(value) => value,
+                }
+            }
+        }
+
+        impl core::str::FromStr for $ty {
+            type Err = $crate::parser::InvalidEnumValue;
+
+            #[allow(deprecated)]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(_ if s.eq_ignore_ascii_case(Self::$value.as_str()) => Ok(Self::$value),)*
+                    _ => Err($crate::parser::InvalidEnumValue {
+                        r#type: stringify!(Self).to_string(),
+                        value: s.to_string(),
+                    }),
+                }
+            }
+        }
+
+        impl $crate::parser::OtherValue for $ty {
+            fn other(value: &str) -> Self {
+                Self::$other(value.to_string())
+            }
+        }
+    };
     ($ty:ty $(, $name:literal => $value:ident)* $(,)?) => {
         impl $ty {
             pub fn as_str(&self) -> &'static str {
@@ -0,0 +1,42 @@
+//! Re-exports of the document type, the common element types, the results of the queries and
+//! routing as well as the quantities and units, for use with `use opendrive::prelude::*;`.
+
+pub use crate::core::header::Header;
+pub use crate::core::validated::{Positive, SCoordinate, SortedByS, StartS};
+pub use crate::core::xml_element::XmlElement;
+pub use crate::core::OpenDrive;
+pub use crate::junction::connection::Connection;
+pub use crate::junction::contact_point::ContactPoint;
+pub use crate::junction::lane_link::LaneLink;
+pub use crate::junction::Junction;
+pub use crate::lane::lane_section::LaneSection;
+pub use crate::lane::lane_type::LaneType;
+pub use crate::lane::lanes::Lanes;
+pub use crate::lane::travel_direction::TravelDirection;
+pub use crate::lane::Lane;
+pub use crate::object::Object;
+pub use crate::query::drivable_lanes::DrivableLane;
+pub use crate::query::junction_features::{FeatureSource, JunctionApproach, JunctionFeature};
+pub use crate::query::lane_signals::LaneSignal;
+pub use crate::query::neighbors::{CenterLineNeighbor, LanePosition};
+pub use crate::query::right_of_way::{ApproachRegulation, RightOfWay, RightOfWayMatrix};
+pub use crate::query::speed_limit::{SpeedLimit, SpeedLimitSource};
+pub use crate::query::successor_lanes::SuccessorLane;
+pub use crate::query::traffic_lights::{TrafficLightControl, TrafficLightMap};
+pub use crate::query::turns::{TurnDirection, TurnRelation};
+pub use crate::road::geometry::geometry_type::GeometryType;
+pub use crate::road::geometry::plan_view::PlanView;
+pub use crate::road::geometry::point::Point;
+pub use crate::road::geometry::pose::Pose;
+pub use crate::road::geometry::Geometry;
+pub use crate::road::linked_element::LinkedElement;
+pub use crate::road::profile::lateral_profile::LateralProfile;
+pub use crate::road::profile::ElevationProfile;
+pub use crate::road::Road;
+pub use crate::routing::lane_graph::{LaneEdgeKind, LaneGraph, LaneRef};
+pub use crate::routing::road_graph::RoadGraph;
+pub use crate::routing::route::{Route, RouteSegment};
+pub use crate::routing::router::{LanePath, Router};
+pub use crate::signal::controller::Controller;
+pub use crate::signal::Signal;
+pub use crate::units::prelude::*;
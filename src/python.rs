@@ -0,0 +1,253 @@
+//! A [`pyo3`](https://crates.io/crates/pyo3) module `opendrive` to parse, query, validate and
+//! write documents from Python. Build the extension module with `maturin` and the features
+//! `python` and `pyo3/extension-module`.
+
+// the `pymethods` and `pyfunction` macros of pyo3 0.22 convert `PyErr` into itself
+#![allow(clippy::useless_conversion)]
+
+use crate::core::validation::Validator;
+use crate::core::OpenDrive;
+use crate::junction::Junction;
+use crate::parser::ParseOptions;
+use crate::road::Road;
+use crate::units::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+fn value_error(error: impl ToString) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// A parsed document
+#[pyclass(name = "Document", module = "opendrive")]
+pub struct PyDocument {
+    drive: OpenDrive,
+}
+
+#[pymethods]
+impl PyDocument {
+    /// Parses the XML of an ASAM OpenDRIVE document
+    #[staticmethod]
+    fn parse(xml: &str) -> PyResult<Self> {
+        OpenDrive::from_xml_str(xml)
+            .map(|drive| Self { drive })
+            .map_err(value_error)
+    }
+
+    /// Parses the file at the given path
+    #[cfg(feature = "fs")]
+    #[staticmethod]
+    fn read(path: std::path::PathBuf) -> PyResult<Self> {
+        OpenDrive::from_path(path, ParseOptions::default())
+            .map(|drive| Self { drive })
+            .map_err(value_error)
+    }
+
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.drive.header.name.clone()
+    }
+
+    #[getter]
+    fn roads(&self) -> Vec<PyRoad> {
+        self.drive.road.iter().cloned().map(PyRoad::from).collect()
+    }
+
+    #[getter]
+    fn junctions(&self) -> Vec<PyJunction> {
+        self.drive
+            .junction
+            .iter()
+            .cloned()
+            .map(PyJunction::from)
+            .collect()
+    }
+
+    /// The road with the given id, `None` if there is no such road
+    fn road(&self, id: &str) -> Option<PyRoad> {
+        self.drive
+            .road
+            .iter()
+            .find(|road| road.id == id)
+            .cloned()
+            .map(PyRoad::from)
+    }
+
+    /// The document as XML
+    fn to_xml(&self) -> PyResult<String> {
+        self.drive.to_xml_string().map_err(value_error)
+    }
+
+    /// The roads and objects as GeoJSON
+    fn to_geojson(&self) -> String {
+        self.drive.to_geojson()
+    }
+
+    fn __repr__(&self) -> String {
+        self.drive.to_string()
+    }
+}
+
+/// A road of a [`PyDocument`]
+#[pyclass(name = "Road", module = "opendrive")]
+#[derive(Clone)]
+pub struct PyRoad {
+    road: Road,
+}
+
+impl From<Road> for PyRoad {
+    fn from(road: Road) -> Self {
+        Self { road }
+    }
+}
+
+#[pymethods]
+impl PyRoad {
+    #[getter]
+    fn id(&self) -> String {
+        self.road.id.clone()
+    }
+
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.road.name.clone()
+    }
+
+    /// The id of the junction of this connecting road, `None` if the road is no connecting road
+    #[getter]
+    fn junction(&self) -> Option<String> {
+        Some(self.road.junction.clone()).filter(|junction| junction != "-1")
+    }
+
+    /// The length in meter
+    #[getter]
+    fn length(&self) -> f64 {
+        self.road.length.get::<meter>()
+    }
+
+    /// The ids of the lanes of each lane section
+    #[getter]
+    fn lane_sections(&self) -> Vec<Vec<i64>> {
+        self.road
+            .lanes
+            .lane_section
+            .iter()
+            .map(|section| section.lanes().map(|(id, _)| id).collect())
+            .collect()
+    }
+
+    /// The inertial `(x, y, hdg)` at `s` along and `t` perpendicular to the reference line
+    #[pyo3(signature = (s, t = 0.0))]
+    fn pose_at(&self, s: f64, t: f64) -> (f64, f64, f64) {
+        let pose = self
+            .road
+            .pose_at(Length::new::<meter>(s), Length::new::<meter>(t));
+        (
+            pose.x.get::<meter>(),
+            pose.y.get::<meter>(),
+            pose.hdg.get::<radian>(),
+        )
+    }
+
+    /// The `(s, t)` of the position on the reference line closest to the inertial position
+    fn project(&self, x: f64, y: f64) -> (f64, f64) {
+        let (s, t) = self
+            .road
+            .project(Length::new::<meter>(x), Length::new::<meter>(y));
+        (s.get::<meter>(), t.get::<meter>())
+    }
+
+    fn __repr__(&self) -> String {
+        self.road.to_string()
+    }
+}
+
+/// A junction of a [`PyDocument`]
+#[pyclass(name = "Junction", module = "opendrive")]
+#[derive(Clone)]
+pub struct PyJunction {
+    junction: Junction,
+}
+
+impl From<Junction> for PyJunction {
+    fn from(junction: Junction) -> Self {
+        Self { junction }
+    }
+}
+
+#[pymethods]
+impl PyJunction {
+    #[getter]
+    fn id(&self) -> String {
+        self.junction.id.clone()
+    }
+
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.junction.name.clone()
+    }
+
+    /// The `(incoming road, connecting road)` of each connection
+    #[getter]
+    fn connections(&self) -> Vec<(Option<String>, Option<String>)> {
+        self.junction
+            .connection
+            .iter()
+            .map(|c| (c.incoming_road.clone(), c.connecting_road.clone()))
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        self.junction.to_string()
+    }
+}
+
+/// Parses the XML of an ASAM OpenDRIVE document and returns the violations of the rules of
+/// [`Validator::new`], raises a `ValueError` if the document cannot be parsed
+#[pyfunction]
+fn validate(xml: &str) -> PyResult<Vec<String>> {
+    let mut validator = Validator::new();
+    OpenDrive::from_str_with(
+        xml,
+        ParseOptions {
+            validator: Some(&mut validator),
+            ..ParseOptions::default()
+        },
+    )
+    .map_err(value_error)?;
+    Ok(validator
+        .violations()
+        .iter()
+        .map(ToString::to_string)
+        .collect())
+}
+
+#[pymodule]
+fn opendrive(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyDocument>()?;
+    module.add_class::<PyRoad>()?;
+    module.add_class::<PyJunction>()?;
+    module.add_function(wrap_pyfunction!(validate, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::TestRoad;
+    use crate::python::{validate, PyDocument};
+
+    #[test]
+    pub fn test_python() {
+        let road = TestRoad::new("1").length(10.0).sides(&[], &[]);
+        let document = PyDocument::parse(&format!(
+            r#"<OpenDRIVE><header revMajor="1" revMinor="7" name="Test"/>{road}</OpenDRIVE>"#
+        ))
+        .unwrap();
+        assert_eq!(Some("Test".to_string()), document.name());
+        let road = document.road("1").unwrap();
+        assert_eq!(None, road.junction());
+        assert_eq!(vec![vec![0]], road.lane_sections());
+        assert_eq!((5.0, 2.0, 0.0), road.pose_at(5.0, 2.0));
+        assert!(validate(&document.to_xml().unwrap()).unwrap().is_empty());
+    }
+}
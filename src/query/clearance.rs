@@ -0,0 +1,209 @@
+use crate::core::OpenDrive;
+use crate::object::bridge::Bridge;
+use crate::object::corner::Corner;
+use crate::object::Object;
+use crate::road::geometry::polygon;
+use crate::road::Road;
+use crate::units::prelude::*;
+
+/// Anything less than this above the road surface does not limit the clearance, so that objects
+/// standing on the road, like poles, are not taken for overhead ones
+const MIN_HEIGHT: f64 = 0.01;
+
+/// Why the clearance at a position is not known, see [`OpenDrive::clearance_at`]
+#[derive(Debug, thiserror::Error)]
+pub enum ClearanceError {
+    #[error("There is no road with the id {0}")]
+    UnknownRoad(String),
+    /// Tunnels have no height, so the clearance within them is only known from the objects
+    /// modelling their ceiling
+    #[error("The position is within the tunnel {0} which has no objects above the road")]
+    TunnelHeight(String),
+}
+
+/// The free height above a position of a road, see [`OpenDrive::clearance_at`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clearance<'a> {
+    /// The height between the road surface and the lowest thing above it
+    pub height: Length,
+    pub limit: ClearanceLimit<'a>,
+}
+
+/// What limits the [`Clearance`] above a road
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClearanceLimit<'a> {
+    /// The underside of an object, like a bridge deck, a gantry or the ceiling of a tunnel
+    Object { road: &'a Road, object: &'a Object },
+    /// The surface of a road on a bridge. The thickness of the deck is not part of the model, so
+    /// the actual clearance is lower, unless it is modelled as object.
+    Bridge { road: &'a Road, bridge: &'a Bridge },
+}
+
+impl OpenDrive {
+    /// The free height above the road surface at `s` and `t` of the given road, for routing tall
+    /// vehicles, or `None` if there is nothing above it. The height is limited by the underside
+    /// of objects of any road whose footprint covers the position and by the surface of other
+    /// roads whose `<bridge>` passes over it, both with their elevation. Within a `<tunnel>`
+    /// there must be such an object, as tunnels have no height. Repeated objects and the lateral
+    /// shape are ignored.
+    pub fn clearance_at(
+        &self,
+        road: &str,
+        s: Length,
+        t: Length,
+    ) -> Result<Option<Clearance<'_>>, ClearanceError> {
+        let base = self
+            .road
+            .iter()
+            .find(|r| r.id == road)
+            .ok_or_else(|| ClearanceError::UnknownRoad(road.to_string()))?;
+        let point = base.pose_at(s, t).point();
+        let z = base.surface_z_at(s, t).get::<meter>();
+
+        let objects = self.road.iter().flat_map(|road| {
+            road.objects
+                .iter()
+                .flat_map(|objects| &objects.object)
+                .filter(|object| {
+                    let footprint = object.footprint(road);
+                    footprint.len() > 2 && polygon::contains(&footprint, &point)
+                })
+                .map(move |object| Clearance {
+                    height: Length::new::<meter>(underside(road, object) - z),
+                    limit: ClearanceLimit::Object { road, object },
+                })
+        });
+        let bridges = self
+            .road
+            .iter()
+            .filter(|road| road.id != base.id)
+            .flat_map(|road| {
+                let bridges = road.objects.iter().flat_map(|objects| &objects.bridge);
+                let (s, t) = road.project(point.x, point.y);
+                let surface = covers(road, s.get::<meter>(), t.get::<meter>())
+                    .then(|| road.surface_z_at(s, t).get::<meter>());
+                bridges
+                    .filter(move |bridge| bridge.s <= s && s <= bridge.s + bridge.length)
+                    .filter_map(move |bridge| {
+                        Some(Clearance {
+                            height: Length::new::<meter>(surface? - z),
+                            limit: ClearanceLimit::Bridge { road, bridge },
+                        })
+                    })
+            });
+        let clearance = objects
+            .chain(bridges)
+            .filter(|clearance| clearance.height.get::<meter>() >= MIN_HEIGHT)
+            .min_by(|a, b| a.height.get::<meter>().total_cmp(&b.height.get::<meter>()));
+
+        let tunnel = base
+            .objects
+            .iter()
+            .flat_map(|objects| &objects.tunnel)
+            .find(|tunnel| tunnel.s <= s && s <= tunnel.s + tunnel.length);
+        match (clearance, tunnel) {
+            (None, Some(tunnel)) => Err(ClearanceError::TunnelHeight(tunnel.id.clone())),
+            (clearance, _) => Ok(clearance),
+        }
+    }
+}
+
+/// The elevation of the reference line of the road at `s`
+fn elevation(road: &Road, s: Length) -> f64 {
+    road.elevation_profile
+        .as_ref()
+        .map(|profile| profile.elevation_at(s.get::<meter>()))
+        .unwrap_or(0.0)
+}
+
+/// The lowest inertial z-coordinate of the object, given by the corners of its first outline or
+/// otherwise by its origin
+fn underside(road: &Road, object: &Object) -> f64 {
+    let origin = elevation(road, object.s) + object.z_offset.get::<meter>();
+    object
+        .all_outlines()
+        .next()
+        .map(|outline| {
+            outline
+                .choice
+                .iter()
+                .map(|corner| match corner {
+                    Corner::Road(corner) => elevation(road, corner.s) + corner.dz.get::<meter>(),
+                    Corner::Local(corner) => origin + corner.z.get::<meter>(),
+                })
+                .fold(f64::INFINITY, f64::min)
+        })
+        .filter(|z| z.is_finite())
+        .unwrap_or(origin)
+}
+
+/// Whether `s` and `t` are within the length and between the outer borders of the outermost
+/// lanes of the road
+fn covers(road: &Road, s: f64, t: f64) -> bool {
+    if !(0.0..=road.length.get::<meter>()).contains(&s) {
+        return false;
+    }
+    let (min, max) = road.lanes.extent_at(s);
+    (min..=max).contains(&t)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse_revision, TestRoad};
+    use crate::query::clearance::{ClearanceError, ClearanceLimit};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_clearance_at() {
+        // road 1 rises by 1 % and passes a gantry at s=20, below road 2 on a bridge at s=50 and
+        // into a tunnel from s=80
+        let drive = parse_revision(
+            8,
+            format!(
+                "{}{}",
+                TestRoad::new("1")
+                    .child(r#"<elevationProfile><elevation s="0" a="0" b="0.01" c="0" d="0"/></elevationProfile>"#)
+                    .objects(
+                        r#"<object id="gantry" s="20" t="0" zOffset="5" height="1" width="10" length="10"/>
+                        <object id="pole" s="30" t="-1" zOffset="0" height="3" radius="0.5"/>
+                        <tunnel id="tunnel" s="80" length="20" type="standard"/>"#
+                    ),
+                TestRoad::new("2")
+                    .at(50.0, -50.0, std::f64::consts::FRAC_PI_2)
+                    .sides(&[("driving", 3.5)], &[("driving", 3.5)])
+                    .child(r#"<elevationProfile><elevation s="0" a="6" b="0" c="0" d="0"/></elevationProfile>"#)
+                    .objects(r#"<bridge id="bridge" s="40" length="20" type="concrete"/>"#),
+            ),
+        );
+        let length = Length::new::<meter>;
+        let height = |s: f64, t: f64| {
+            drive
+                .clearance_at("1", length(s), length(t))
+                .unwrap()
+                .map(|clearance| clearance.height.get::<meter>())
+        };
+
+        // the road surface is at 0.2 m, the underside of the gantry at 5.2 m
+        let gantry = drive.clearance_at("1", length(20.0), length(-1.0)).unwrap();
+        let gantry = gantry.unwrap();
+        assert!((gantry.height.get::<meter>() - 5.0).abs() < 1e-9);
+        assert!(
+            matches!(gantry.limit, ClearanceLimit::Object { object, .. } if object.id == "gantry")
+        );
+        // the road surface is at 0.5 m, that of the bridge at 6 m
+        let bridge = drive.clearance_at("1", length(50.0), length(-2.0)).unwrap();
+        assert!((bridge.unwrap().height.get::<meter>() - 5.5).abs() < 1e-6);
+        // next to the bridge and at a pole standing on the road
+        assert_eq!(None, height(56.0, -2.0));
+        assert_eq!(None, height(30.0, -1.0));
+
+        assert!(matches!(
+            drive.clearance_at("1", length(90.0), length(-2.0)),
+            Err(ClearanceError::TunnelHeight(id)) if id == "tunnel"
+        ));
+        assert!(matches!(
+            drive.clearance_at("3", length(0.0), length(0.0)),
+            Err(ClearanceError::UnknownRoad(_))
+        ));
+    }
+}
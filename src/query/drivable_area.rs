@@ -0,0 +1,193 @@
+use crate::core::OpenDrive;
+use crate::export::mesh::lane_surfaces;
+use crate::road::geometry::point::Point;
+use crate::road::geometry::polygon;
+use crate::units::prelude::*;
+
+/// Points closer than this to a lane count as on it, so that points on the shared border of two
+/// lanes are not missed by rounding
+const TOLERANCE: f64 = 1e-6;
+
+/// The distance in meter of the samples along the edges of a polygon tested by
+/// [`DrivableArea::contains_polygon`]
+const EDGE_STEP: f64 = 0.1;
+
+/// The distance in meter of the samples within a polygon tested by
+/// [`DrivableArea::contains_polygon`]
+const INTERIOR_STEP: f64 = 0.5;
+
+/// The surfaces of the drivable lanes of a document as polygons of the inertial x/y-plane, see
+/// [`OpenDrive::drivable_area`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrivableArea {
+    pub lanes: Vec<DrivableLaneSurface>,
+}
+
+/// The surface of a drivable lane of a single lane section, see [`LaneType::is_drivable`]
+///
+/// [`LaneType::is_drivable`]: crate::lane::lane_type::LaneType::is_drivable
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrivableLaneSurface {
+    pub road: String,
+    pub lane: i64,
+    /// The inner border followed by the outer border in reverse, sampled at most every meter
+    /// along the reference line
+    pub polygon: Vec<Point>,
+    min: Point,
+    max: Point,
+}
+
+impl DrivableLaneSurface {
+    fn contains(&self, point: &Point) -> bool {
+        let (x, y) = (point.x.get::<meter>(), point.y.get::<meter>());
+        let within = |value: f64, min: Length, max: Length| {
+            value >= min.get::<meter>() - TOLERANCE && value <= max.get::<meter>() + TOLERANCE
+        };
+        within(x, self.min.x, self.max.x)
+            && within(y, self.min.y, self.max.y)
+            && (polygon::contains(&self.polygon, point)
+                || polygon::distance(&self.polygon, point) <= TOLERANCE)
+    }
+}
+
+impl DrivableArea {
+    /// Whether the point lies on a drivable lane, including its borders
+    pub fn contains(&self, point: &Point) -> bool {
+        self.lanes.iter().any(|lane| lane.contains(point))
+    }
+
+    /// Whether the polygon lies entirely on drivable lanes, which may be several adjacent ones.
+    /// This is tested with samples every 0.1 m along the edges and every 0.5 m within the
+    /// polygon, so gaps of the drivable area that are narrower than that may be missed.
+    pub fn contains_polygon(&self, polygon: &[Point]) -> bool {
+        let Some((min, max)) = polygon::bounds(polygon) else {
+            return true;
+        };
+        let point = |x: f64, y: f64| Point {
+            x: Length::new::<meter>(x),
+            y: Length::new::<meter>(y),
+        };
+        let edges = (0..polygon.len()).flat_map(|index| {
+            let (from, to) = (&polygon[index], &polygon[(index + 1) % polygon.len()]);
+            let (dx, dy) = (
+                (to.x - from.x).get::<meter>(),
+                (to.y - from.y).get::<meter>(),
+            );
+            let samples = (dx.hypot(dy) / EDGE_STEP).ceil().max(1.0) as usize;
+            (0..samples).map(move |sample| {
+                let f = sample as f64 / samples as f64;
+                point(
+                    from.x.get::<meter>() + f * dx,
+                    from.y.get::<meter>() + f * dy,
+                )
+            })
+        });
+        let (min_x, min_y) = (min.x.get::<meter>(), min.y.get::<meter>());
+        let columns = ((max.x.get::<meter>() - min_x) / INTERIOR_STEP).ceil() as usize;
+        let rows = ((max.y.get::<meter>() - min_y) / INTERIOR_STEP).ceil() as usize;
+        let interior = (0..=columns)
+            .flat_map(|column| (0..=rows).map(move |row| (column, row)))
+            .map(|(column, row)| {
+                point(
+                    min_x + column as f64 * INTERIOR_STEP,
+                    min_y + row as f64 * INTERIOR_STEP,
+                )
+            })
+            .filter(|sample| polygon.len() > 2 && polygon::contains(polygon, sample));
+        edges.chain(interior).all(|sample| self.contains(&sample))
+    }
+}
+
+impl OpenDrive {
+    /// The surfaces of the drivable lanes of all roads, see [`LaneType::is_drivable`]. Lane
+    /// heights, the elevation and the lateral shape are ignored.
+    ///
+    /// [`LaneType::is_drivable`]: crate::lane::lane_type::LaneType::is_drivable
+    pub fn drivable_area(&self) -> DrivableArea {
+        let lanes = self
+            .road
+            .iter()
+            .flat_map(|road| {
+                lane_surfaces(road)
+                    .into_iter()
+                    .filter(|surface| surface.lane.r#type.is_drivable())
+                    .filter_map(|surface| {
+                        let polygon = surface
+                            .inner
+                            .iter()
+                            .chain(surface.outer.iter().rev())
+                            .map(|[x, y, _]| Point {
+                                x: Length::new::<meter>(*x),
+                                y: Length::new::<meter>(*y),
+                            })
+                            .collect::<Vec<_>>();
+                        let (min, max) = polygon::bounds(&polygon)?;
+                        Some(DrivableLaneSurface {
+                            road: road.id.clone(),
+                            lane: surface.lane_id,
+                            polygon,
+                            min,
+                            max,
+                        })
+                    })
+            })
+            .collect();
+        DrivableArea { lanes }
+    }
+
+    /// Whether the point lies on a drivable lane, for example to validate spawn positions. This
+    /// tessellates all lanes for a single query, for many queries use [`OpenDrive::drivable_area`]
+    /// and [`DrivableArea::contains`].
+    pub fn is_on_drivable_area(&self, point: &Point) -> bool {
+        self.drivable_area().contains(point)
+    }
+
+    /// Whether the polygon, like the footprint of a vehicle, lies entirely on drivable lanes,
+    /// see [`DrivableArea::contains_polygon`] and [`OpenDrive::is_on_drivable_area`]
+    pub fn polygon_within_drivable_area(&self, polygon: &[Point]) -> bool {
+        self.drivable_area().contains_polygon(polygon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::generators::StandardMap;
+    use crate::road::geometry::point::Point;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_drivable_area() {
+        let point = |x: f64, y: f64| Point {
+            x: Length::new::<meter>(x),
+            y: Length::new::<meter>(y),
+        };
+        // one 3.5 m wide driving lane on each side of the reference line from x=0 to x=100
+        let drive = StandardMap::Highway {
+            lanes: 1,
+            length: Length::new::<meter>(100.0),
+        }
+        .generate();
+        let area = drive.drivable_area();
+        assert_eq!(2, area.lanes.len());
+
+        assert!(drive.is_on_drivable_area(&point(50.0, 0.0)));
+        assert!(area.contains(&point(50.0, -3.5)));
+        assert!(area.contains(&point(0.0, 3.0)));
+        assert!(!area.contains(&point(50.0, 3.6)));
+        assert!(!area.contains(&point(100.5, 0.0)));
+
+        let vehicle = |x: f64, y: f64| {
+            vec![
+                point(x - 2.0, y - 1.0),
+                point(x + 2.0, y - 1.0),
+                point(x + 2.0, y + 1.0),
+                point(x - 2.0, y + 1.0),
+            ]
+        };
+        // across both lanes
+        assert!(drive.polygon_within_drivable_area(&vehicle(50.0, 0.0)));
+        assert!(area.contains_polygon(&vehicle(50.0, -2.5)));
+        assert!(!area.contains_polygon(&vehicle(50.0, -3.0)));
+        assert!(!area.contains_polygon(&vehicle(99.0, 0.0)));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::query::drivable_area::{DrivableArea, DrivableLaneSurface};
+use crate::units::prelude::*;
+use geo::orient::{Direction, Orient};
+use geo::{unary_union, Coord, LineString, MultiPolygon, Polygon, Simplify};
+
+impl DrivableArea {
+    /// The union of all drivable lane surfaces as multipolygon of the inertial x/y-plane in
+    /// meter, simplified by Ramer-Douglas-Peucker with the given tolerance, for occupancy grids
+    /// and coverage tools. Surfaces of lanes that touch or overlap, like those of adjacent lanes,
+    /// lane sections, roads and junctions, are merged into a single polygon.
+    pub fn union(&self, tolerance: Length) -> MultiPolygon<f64> {
+        union(self.lanes.iter(), tolerance)
+    }
+
+    /// The union of the drivable lane surfaces of each road, see [`DrivableArea::union`], in the
+    /// order of the roads of the document. Roads without drivable lanes are skipped.
+    pub fn union_by_road(&self, tolerance: Length) -> Vec<(String, MultiPolygon<f64>)> {
+        let mut roads: Vec<(String, Vec<&DrivableLaneSurface>)> = Vec::new();
+        for lane in &self.lanes {
+            match roads.last_mut() {
+                Some((road, lanes)) if *road == lane.road => lanes.push(lane),
+                _ => roads.push((lane.road.clone(), vec![lane])),
+            }
+        }
+        roads
+            .into_iter()
+            .map(|(road, lanes)| (road, union(lanes.into_iter(), tolerance)))
+            .collect()
+    }
+}
+
+fn union<'a>(
+    lanes: impl Iterator<Item = &'a DrivableLaneSurface>,
+    tolerance: Length,
+) -> MultiPolygon<f64> {
+    // the union expects all exteriors in the same winding order, but that of the surfaces
+    // depends on the side of their lanes
+    let polygons = lanes
+        .filter(|lane| lane.polygon.len() > 2)
+        .map(|lane| {
+            let exterior = lane
+                .polygon
+                .iter()
+                .map(|point| Coord {
+                    x: point.x.get::<meter>(),
+                    y: point.y.get::<meter>(),
+                })
+                .collect::<LineString<f64>>();
+            Polygon::new(exterior, Vec::new()).orient(Direction::Default)
+        })
+        .collect::<Vec<_>>();
+    unary_union(&polygons).simplify(tolerance.get::<meter>().max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::generators::StandardMap;
+    use crate::units::prelude::*;
+    use geo::Area;
+
+    #[test]
+    pub fn test_drivable_area_union() {
+        // two 3.5 m wide driving lanes on each side of the reference line from x=0 to x=100
+        let drive = StandardMap::Highway {
+            lanes: 2,
+            length: Length::new::<meter>(100.0),
+        }
+        .generate();
+        let area = drive.drivable_area();
+        assert_eq!(4, area.lanes.len());
+
+        let union = area.union(Length::new::<meter>(0.01));
+        assert_eq!(1, union.0.len());
+        assert!((union.unsigned_area() - 1400.0).abs() < 1e-6);
+        // the straight borders are simplified to the four corners
+        assert_eq!(5, union.0[0].exterior().0.len());
+        assert!(union.0[0].interiors().is_empty());
+
+        let by_road = area.union_by_road(Length::new::<meter>(0.01));
+        assert_eq!(1, by_road.len());
+        assert_eq!(drive.road[0].id, by_road[0].0);
+        assert_eq!(union, by_road[0].1);
+    }
+}
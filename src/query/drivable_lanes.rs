@@ -0,0 +1,101 @@
+use crate::core::OpenDrive;
+use crate::lane::lane_type::LaneType;
+use crate::lane::travel_direction::TravelDirection;
+use crate::lane::Lane;
+use crate::units::prelude::*;
+
+/// A lane that may be driven on at a position of a road, see [`OpenDrive::drivable_lanes_at`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrivableLane<'a> {
+    pub id: i64,
+    pub lane: &'a Lane,
+    /// Type of the lane, see [`LaneType::is_drivable`]
+    pub r#type: LaneType,
+    /// Width of the lane at the queried position
+    pub width: Length,
+    /// Derived from the sign of the lane id and the traffic rule of the road
+    pub direction: TravelDirection,
+}
+
+impl OpenDrive {
+    /// The drivable lanes at the s-coordinate `s` of the given road from left to right, or `None`
+    /// if there is no such road. Lanes without a positive width at `s` are skipped.
+    pub fn drivable_lanes_at(&self, road_id: &str, s: Length) -> Option<Vec<DrivableLane<'_>>> {
+        let road = self.road.iter().find(|r| r.id == road_id)?;
+        let s = s.get::<meter>();
+        let (_, section) = road.lanes.lane_section_at(s);
+        let ds = s - section.s;
+
+        Some(
+            section
+                .lanes()
+                .filter(|(_, lane)| lane.r#type.is_drivable())
+                .filter_map(|(id, lane)| {
+                    let (inner, outer) = section.lane_borders(id, ds)?;
+                    let width = (outer - inner) * id.signum() as f64;
+                    Some(DrivableLane {
+                        id,
+                        lane,
+                        r#type: lane.r#type.clone(),
+                        width: Length::new::<meter>(width),
+                        direction: road.lane_direction(id, lane)?,
+                    })
+                    .filter(|_| width > 0.0)
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::lane::lane_type::LaneType;
+    use crate::lane::travel_direction::TravelDirection;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_drivable_lanes_at() {
+        let drive = parse(TestRoad::new("1").attribute("rule", "LHT").lanes(
+            r#"<laneSection s="0">
+                            <center><lane id="0" type="none"/></center>
+                            <right><lane id="-1" type="driving"><width sOffset="0" a="3" b="0" c="0" d="0"/></lane></right>
+                        </laneSection>
+                        <laneSection s="50">
+                            <left>
+                                <lane id="2" type="sidewalk"><width sOffset="0" a="2" b="0" c="0" d="0"/></lane>
+                                <lane id="1" type="driving"><width sOffset="0" a="3" b="0" c="0" d="0"/></lane>
+                            </left>
+                            <center><lane id="0" type="none"/></center>
+                            <right>
+                                <lane id="-1" type="driving"><width sOffset="0" a="3" b="0.1" c="0" d="0"/></lane>
+                                <lane id="-2" type="exit"><width sOffset="10" a="0" b="0.5" c="0" d="0"/></lane>
+                            </right>
+                        </laneSection>"#,
+        ));
+
+        let lanes = drive
+            .drivable_lanes_at("1", Length::new::<meter>(70.0))
+            .unwrap()
+            .into_iter()
+            .map(|l| (l.id, l.r#type, l.width.get::<meter>(), l.direction))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lanes,
+            [
+                (1, LaneType::Driving, 3.0, TravelDirection::Forward),
+                (-1, LaneType::Driving, 5.0, TravelDirection::Backward),
+                (-2, LaneType::Exit, 5.0, TravelDirection::Backward),
+            ]
+        );
+
+        // the exit lane has no width before the start of its width element
+        let lanes = drive
+            .drivable_lanes_at("1", Length::new::<meter>(55.0))
+            .unwrap();
+        assert_eq!(2, lanes.len());
+        assert!(drive
+            .drivable_lanes_at("2", Length::new::<meter>(0.0))
+            .is_none());
+    }
+}
@@ -0,0 +1,317 @@
+use crate::core::OpenDrive;
+use crate::junction::contact_point::ContactPoint;
+use crate::junction::Junction;
+use crate::lane::travel_direction::TravelDirection;
+use crate::object::orientation::ObjectType;
+use crate::object::Object;
+use crate::road::geometry::point::Point;
+use crate::road::Road;
+use crate::signal::semantics::SignalCategory;
+use crate::signal::Signal;
+use crate::units::prelude::*;
+use std::collections::HashSet;
+
+/// The roads entering a junction together with the stop lines and crosswalks that apply to them,
+/// see [`OpenDrive::junction_approaches`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct JunctionApproach<'a> {
+    pub junction: &'a Junction,
+    /// The incoming road
+    pub road: &'a Road,
+    /// The end of the incoming road that is linked to the junction
+    pub contact_point: ContactPoint,
+    pub stop_lines: Vec<JunctionFeature<'a>>,
+    pub crosswalks: Vec<JunctionFeature<'a>>,
+}
+
+/// A stop line or crosswalk on the incoming road or on a connecting road of an approach.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JunctionFeature<'a> {
+    pub source: FeatureSource<'a>,
+    /// The road the feature is placed on
+    pub road: &'a Road,
+    /// The outline of the feature in the inertial x/y-plane, a line across the lanes for stop
+    /// line signals
+    pub geometry: Vec<Point>,
+    /// The lanes of [`JunctionFeature::road`] the feature applies to
+    pub lanes: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeatureSource<'a> {
+    Signal(&'a Signal),
+    Object(&'a Object),
+}
+
+impl<'a> JunctionFeature<'a> {
+    /// Identifies the feature by its road and the id of its signal or object
+    fn key(&self) -> (&'a str, bool, &'a str) {
+        match self.source {
+            FeatureSource::Signal(signal) => (&self.road.id, true, &signal.id),
+            FeatureSource::Object(object) => (&self.road.id, false, &object.id),
+        }
+    }
+}
+
+impl OpenDrive {
+    /// The approaches of the given junction, one for each incoming road that is linked to the
+    /// junction. Stop lines are signals of the [`SignalCategory::Stop`], like stop signs and
+    /// lines, and road mark objects named or subtyped as stop lines. Crosswalks are crosswalk
+    /// objects and signals of the [`SignalCategory::PedestrianCrossing`]. Signals are classified
+    /// in the country of the road type at their position. Returns `None` if there is no such
+    /// junction.
+    pub fn junction_approaches(&self, junction_id: &str) -> Option<Vec<JunctionApproach<'_>>> {
+        let junction = self.junction.iter().find(|j| j.id == junction_id)?;
+        let mut approaches: Vec<JunctionApproach> = Vec::new();
+        // the features of each approach by their key
+        let mut seen = HashSet::new();
+
+        for connection in &junction.connection {
+            let road = match connection
+                .incoming_road
+                .as_deref()
+                .and_then(|id| self.road.iter().find(|r| r.id == id))
+            {
+                Some(road) => road,
+                None => continue,
+            };
+
+            let contact_point = match road.junction_contact_point(&junction.id) {
+                Some(contact_point) => contact_point,
+                None => continue,
+            };
+
+            let index = match approaches.iter().position(|a| a.road.id == road.id) {
+                Some(index) => index,
+                None => {
+                    let direction = match contact_point {
+                        ContactPoint::End => TravelDirection::Forward,
+                        ContactPoint::Start => TravelDirection::Backward,
+                    };
+                    let (stop_lines, crosswalks) = Self::features(road, direction);
+                    let index = approaches.len();
+                    for feature in stop_lines.iter().chain(&crosswalks) {
+                        seen.insert((index, feature.key()));
+                    }
+                    approaches.push(JunctionApproach {
+                        junction,
+                        road,
+                        contact_point,
+                        stop_lines,
+                        crosswalks,
+                    });
+                    approaches.len() - 1
+                }
+            };
+
+            let connecting_road = connection
+                .connecting_road
+                .as_deref()
+                .and_then(|id| self.road.iter().find(|r| r.id == id));
+            if let Some(connecting_road) = connecting_road {
+                let direction = match connection.contact_point {
+                    Some(ContactPoint::End) => TravelDirection::Backward,
+                    _ => TravelDirection::Forward,
+                };
+                let (stop_lines, crosswalks) = Self::features(connecting_road, direction);
+                let approach = &mut approaches[index];
+                for (features, new) in [
+                    (&mut approach.stop_lines, stop_lines),
+                    (&mut approach.crosswalks, crosswalks),
+                ] {
+                    for feature in new {
+                        if seen.insert((index, feature.key())) {
+                            features.push(feature);
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(approaches)
+    }
+
+    /// Stop lines and crosswalks of the given road that apply to traffic in `direction`
+    fn features(
+        road: &Road,
+        direction: TravelDirection,
+    ) -> (Vec<JunctionFeature<'_>>, Vec<JunctionFeature<'_>>) {
+        let mut stop_lines = Vec::new();
+        let mut crosswalks = Vec::new();
+
+        for signal in road.signals.iter().flat_map(|s| &s.signal) {
+            let country = road
+                .r#type
+                .iter()
+                .rev()
+                .find(|road_type| road_type.s <= signal.s)
+                .and_then(|road_type| road_type.country.as_ref());
+            let category = signal.semantics(country).category;
+            if category == SignalCategory::Stop && signal.applies_to_direction(direction) {
+                let lanes = Self::lanes_at(road, signal.s, Some(direction))
+                    .into_iter()
+                    .filter(|lane| signal.applies_to_lane(*lane))
+                    .collect::<Vec<_>>();
+                stop_lines.push(JunctionFeature {
+                    source: FeatureSource::Signal(signal),
+                    road,
+                    geometry: Self::line_across(road, signal.s, &lanes),
+                    lanes,
+                });
+            } else if category == SignalCategory::PedestrianCrossing {
+                let lanes = Self::lanes_at(road, signal.s, None)
+                    .into_iter()
+                    .filter(|lane| signal.applies_to_lane(*lane))
+                    .collect::<Vec<_>>();
+                crosswalks.push(JunctionFeature {
+                    source: FeatureSource::Signal(signal),
+                    road,
+                    geometry: Self::line_across(road, signal.s, &lanes),
+                    lanes,
+                });
+            }
+        }
+
+        for object in road.objects.iter().flat_map(|o| &o.object) {
+            let applies = |lane: &i64| {
+                object.validity.is_empty() || object.validity.iter().any(|v| v.contains(*lane))
+            };
+            match object.r#type {
+                Some(ObjectType::Crosswalk) => crosswalks.push(JunctionFeature {
+                    source: FeatureSource::Object(object),
+                    road,
+                    geometry: object.footprint(road),
+                    lanes: Self::lanes_at(road, object.s, None)
+                        .into_iter()
+                        .filter(applies)
+                        .collect(),
+                }),
+                Some(ObjectType::RoadMark) if Self::is_stop_line(object) => {
+                    stop_lines.push(JunctionFeature {
+                        source: FeatureSource::Object(object),
+                        road,
+                        geometry: object.footprint(road),
+                        lanes: Self::lanes_at(road, object.s, Some(direction))
+                            .into_iter()
+                            .filter(applies)
+                            .collect(),
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        (stop_lines, crosswalks)
+    }
+
+    fn is_stop_line(object: &Object) -> bool {
+        [&object.name, &object.subtype]
+            .into_iter()
+            .flatten()
+            .any(|name| {
+                let name = name.to_ascii_lowercase().replace([' ', '_', '-'], "");
+                name == "stopline" || name == "haltlinie"
+            })
+    }
+
+    /// The lanes of the lane section at `s` that permit travelling in `direction`, all lanes
+    /// except the center lane if `direction` is `None`
+    fn lanes_at(road: &Road, s: Length, direction: Option<TravelDirection>) -> Vec<i64> {
+        let (_, section) = road.lanes.lane_section_at(s.get::<meter>());
+        section
+            .lanes()
+            .filter(|(id, _)| *id != 0)
+            .filter(|(id, lane)| match direction {
+                Some(direction) => road
+                    .lane_direction(*id, lane)
+                    .is_some_and(|d| d.permits(direction)),
+                None => true,
+            })
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// A line at `s` from the rightmost to the leftmost border of the given lanes
+    fn line_across(road: &Road, s: Length, lanes: &[i64]) -> Vec<Point> {
+        let (_, section) = road.lanes.lane_section_at(s.get::<meter>());
+        let ds = s.get::<meter>() - section.s;
+        let offset = road.lanes.offset_at(s.get::<meter>());
+        let borders = lanes
+            .iter()
+            .filter_map(|lane| section.lane_borders(*lane, ds))
+            .flat_map(|(inner, outer)| [inner, outer])
+            .collect::<Vec<_>>();
+        let min = borders.iter().copied().reduce(f64::min);
+        let max = borders.iter().copied().reduce(f64::max);
+        match (min, max) {
+            (Some(min), Some(max)) => [min, max]
+                .into_iter()
+                .map(|t| road.pose_at(s, Length::new::<meter>(offset + t)).point())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::junction::contact_point::ContactPoint;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_junction_approaches() {
+        let drive = parse(format!(
+            r#"{}{}
+            <junction id="J">
+                <connection id="0" incomingRoad="1" connectingRoad="2" contactPoint="start">
+                    <laneLink from="-1" to="-1"/>
+                </connection>
+                <connection id="1" incomingRoad="1" connectingRoad="2" contactPoint="start">
+                    <laneLink from="-2" to="-1"/>
+                </connection>
+            </junction>"#,
+            TestRoad::new("1")
+                .link(r#"<successor elementType="junction" elementId="J"/>"#)
+                .sides(&[("driving", 3.0)], &[("driving", 3.0), ("driving", 3.0)])
+                .objects(
+                    r#"<object id="c" s="95" t="0" zOffset="0" type="crosswalk" width="12" length="4"/>"#
+                )
+                .signals(
+                    r#"<signal s="90" t="-6" id="stop" dynamic="no" orientation="+" zOffset="0" country="DE" type="294" subtype="-1"/>"#
+                ),
+            TestRoad::new("2")
+                .length(10.0)
+                .at(100.0, 0.0, 0.0)
+                .junction("J")
+                .link(r#"<predecessor elementType="road" elementId="1" contactPoint="end"/>"#)
+                .sides(&[], &[("driving", 3.0)])
+                .signals(
+                    r#"<signal s="5" t="0" id="zebra" dynamic="no" orientation="none" zOffset="0" country="DE" type="293" subtype="-1"/>"#
+                ),
+        ));
+
+        let approaches = drive.junction_approaches("J").unwrap();
+        assert_eq!(1, approaches.len());
+        let approach = &approaches[0];
+        assert_eq!("1", approach.road.id);
+        assert_eq!(ContactPoint::End, approach.contact_point);
+
+        assert_eq!(1, approach.stop_lines.len());
+        let stop_line = &approach.stop_lines[0];
+        assert_eq!(vec![-1, -2], stop_line.lanes);
+        let ends = stop_line
+            .geometry
+            .iter()
+            .map(|p| (p.x.get::<meter>(), p.y.get::<meter>()))
+            .collect::<Vec<_>>();
+        assert_eq!(ends, [(90.0, -6.0), (90.0, 0.0)]);
+
+        // the crosswalk of the connecting road is shared by both connections
+        assert_eq!(2, approach.crosswalks.len());
+        assert_eq!(vec![1, -1, -2], approach.crosswalks[0].lanes);
+        assert_eq!(4, approach.crosswalks[0].geometry.len());
+        assert_eq!("2", approach.crosswalks[1].road.id);
+        assert_eq!(vec![-1], approach.crosswalks[1].lanes);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::core::OpenDrive;
+use crate::lane::travel_direction::TravelDirection;
+use crate::signal::signal_reference::SignalReference;
+use crate::signal::Signal;
+use crate::units::prelude::*;
+
+/// A signal ahead on a lane, see [`OpenDrive::upcoming_signals_for_lane`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LaneSignal<'a> {
+    /// Distance along the reference line from the queried position to the signal
+    pub distance: Length,
+    pub signal: &'a Signal,
+    /// The `<signalReference>` of the queried road, if the signal is placed on another road
+    pub reference: Option<&'a SignalReference>,
+}
+
+impl OpenDrive {
+    /// The signals ahead of the s-coordinate `s` on the given lane when travelling in `direction`
+    /// with respect to the reference line, ordered by distance. Only signals whose validity and
+    /// orientation apply to the lane are returned, including signals of other roads referenced by
+    /// a `<signalReference>`. [`TravelDirection::Both`] is treated as travelling in the direction
+    /// of the reference line. Returns `None` if there is no such road.
+    pub fn upcoming_signals_for_lane(
+        &self,
+        road_id: &str,
+        lane_id: i64,
+        s: Length,
+        direction: TravelDirection,
+    ) -> Option<Vec<LaneSignal<'_>>> {
+        let road = self.road.iter().find(|r| r.id == road_id)?;
+        let direction = match direction {
+            TravelDirection::Both => TravelDirection::Forward,
+            direction => direction,
+        };
+        let distance = |position: Length| match direction {
+            TravelDirection::Backward => s - position,
+            _ => position - s,
+        };
+
+        let signals = road.signals.iter().flat_map(|signals| &signals.signal);
+        let references = road
+            .signals
+            .iter()
+            .flat_map(|signals| &signals.signal_reference);
+
+        let mut upcoming = signals
+            .filter(|signal| signal.applies_to_direction(direction))
+            .filter(|signal| signal.applies_to_lane(lane_id))
+            .map(|signal| LaneSignal {
+                distance: distance(signal.s),
+                signal,
+                reference: None,
+            })
+            .chain(
+                references
+                    .filter(|reference| reference.applies_to_direction(direction))
+                    .filter(|reference| reference.applies_to_lane(lane_id))
+                    .filter_map(|reference| {
+                        Some(LaneSignal {
+                            distance: distance(reference.s),
+                            signal: self.signal(&reference.id)?,
+                            reference: Some(reference),
+                        })
+                    }),
+            )
+            .filter(|signal| signal.distance.get::<meter>() >= 0.0)
+            .collect::<Vec<_>>();
+
+        upcoming.sort_by(|a, b| {
+            a.distance
+                .get::<meter>()
+                .total_cmp(&b.distance.get::<meter>())
+        });
+        Some(upcoming)
+    }
+
+    /// The signal with the given id on any road
+    pub fn signal(&self, id: &str) -> Option<&Signal> {
+        self.road
+            .iter()
+            .flat_map(|road| &road.signals)
+            .flat_map(|signals| &signals.signal)
+            .find(|signal| signal.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::lane::travel_direction::TravelDirection;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_upcoming_signals_for_lane() {
+        let drive = parse(format!(
+            "{}{}",
+            TestRoad::new("1").sides(&[], &[("driving", 3.0)]).signals(
+                r#"<signal s="10" t="-4" id="behind" dynamic="no" orientation="+" zOffset="0" type="206" subtype="-1"/>
+                        <signal s="80" t="-4" id="stop" dynamic="no" orientation="+" zOffset="0" type="206" subtype="-1"/>
+                        <signal s="60" t="4" id="opposite" dynamic="no" orientation="-" zOffset="0" type="206" subtype="-1"/>
+                        <signal s="40" t="-4" id="other-lane" dynamic="no" orientation="+" zOffset="0" type="206" subtype="-1">
+                            <validity fromLane="-3" toLane="-2"/>
+                        </signal>
+                        <signalReference s="50" t="-4" id="light" orientation="+"/>"#
+            ),
+            TestRoad::new("2").length(10.0).sides(&[], &[]).signals(
+                r#"<signal s="5" t="0" id="light" dynamic="yes" orientation="none" zOffset="0" type="1000001" subtype="-1"/>"#
+            ),
+        ));
+
+        let ids = drive
+            .upcoming_signals_for_lane(
+                "1",
+                -1,
+                Length::new::<meter>(20.0),
+                TravelDirection::Forward,
+            )
+            .unwrap()
+            .into_iter()
+            .map(|s| {
+                (
+                    s.distance.get::<meter>(),
+                    s.signal.id.as_str(),
+                    s.reference.is_some(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ids, [(30.0, "light", true), (60.0, "stop", false)]);
+
+        let ids = drive
+            .upcoming_signals_for_lane(
+                "1",
+                1,
+                Length::new::<meter>(100.0),
+                TravelDirection::Backward,
+            )
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.distance.get::<meter>(), s.signal.id.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, [(40.0, "opposite")]);
+    }
+}
@@ -0,0 +1,14 @@
+pub mod clearance;
+pub mod drivable_area;
+#[cfg(feature = "geo")]
+pub mod drivable_area_union;
+pub mod drivable_lanes;
+pub mod junction_features;
+pub mod lane_signals;
+pub mod neighbors;
+pub mod object_index;
+pub mod right_of_way;
+pub mod speed_limit;
+pub mod successor_lanes;
+pub mod traffic_lights;
+pub mod turns;
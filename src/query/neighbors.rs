@@ -0,0 +1,144 @@
+use crate::core::OpenDrive;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::f64::consts::PI;
+
+/// Maximum distance between the center lanes of two roads that share a center line
+const CENTER_LINE_TOLERANCE: f64 = 0.1;
+/// Maximum heading deviation of two roads that share a center line
+const HEADING_TOLERANCE: f64 = 0.1;
+
+/// A lane at a position along the reference line of a road.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LanePosition<'a> {
+    pub road: &'a Road,
+    pub s: Length,
+    pub lane_id: i64,
+}
+
+/// Result of [`OpenDrive::center_line_neighbor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CenterLineNeighbor<'a> {
+    pub road: &'a Road,
+    /// s-coordinate on the neighboring road
+    pub s: Length,
+    /// Whether the reference line of the neighboring road runs in the opposite direction
+    pub reversed: bool,
+}
+
+impl OpenDrive {
+    /// The road whose center lane coincides with the center lane of the given road at the
+    /// s-coordinate `s`, as found in maps that model each direction of travel as a separate road
+    /// split down the center line.
+    pub fn center_line_neighbor(&self, road_id: &str, s: Length) -> Option<CenterLineNeighbor<'_>> {
+        let road = self.road.iter().find(|r| r.id == road_id)?;
+        let center = |road: &Road, s: Length| {
+            let offset = Length::new::<meter>(road.lanes.offset_at(s.get::<meter>()));
+            road.pose_at(s, offset)
+        };
+        let pose = center(road, s);
+
+        self.road
+            .iter()
+            .filter(|other| other.id != road.id)
+            .find_map(|other| {
+                let (other_s, _) = other.project(pose.x, pose.y);
+                let other_pose = center(other, other_s);
+                let distance = (other_pose.x - pose.x)
+                    .get::<meter>()
+                    .hypot((other_pose.y - pose.y).get::<meter>());
+                let heading = (other_pose.hdg - pose.hdg)
+                    .get::<radian>()
+                    .rem_euclid(2.0 * PI);
+                let reversed = if heading.min(2.0 * PI - heading) < HEADING_TOLERANCE {
+                    false
+                } else if (heading - PI).abs() < HEADING_TOLERANCE {
+                    true
+                } else {
+                    return None;
+                };
+
+                Some(CenterLineNeighbor {
+                    road: other,
+                    s: other_s,
+                    reversed,
+                })
+                .filter(|_| distance < CENTER_LINE_TOLERANCE)
+            })
+    }
+
+    /// The adjacent lane on the other side of the center line of the given lane, see
+    /// [`crate::lane::lane_section::LaneSection::opposite_direction_neighbor`]. If the lane
+    /// section of the road has no lanes on the other side, the lane is looked up on the
+    /// [`OpenDrive::center_line_neighbor`].
+    pub fn opposite_direction_neighbor(
+        &self,
+        road_id: &str,
+        lane_id: i64,
+        s: Length,
+    ) -> Option<LanePosition<'_>> {
+        let road = self.road.iter().find(|r| r.id == road_id)?;
+        let (_, section) = road.lanes.lane_section_at(s.get::<meter>());
+        section.lane(lane_id)?;
+
+        if let Some((neighbor, _)) = section.opposite_direction_neighbor(lane_id) {
+            return Some(LanePosition {
+                road,
+                s,
+                lane_id: neighbor,
+            });
+        }
+
+        if lane_id.abs() != 1 {
+            return None;
+        }
+
+        let neighbor = self.center_line_neighbor(road_id, s)?;
+        // the sides of a reversed road are swapped
+        let lane_id = if neighbor.reversed { lane_id } else { -lane_id };
+        let (_, section) = neighbor
+            .road
+            .lanes
+            .lane_section_at(neighbor.s.get::<meter>());
+        section.lane(lane_id)?;
+        Some(LanePosition {
+            road: neighbor.road,
+            s: neighbor.s,
+            lane_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_neighbors() {
+        let drive = parse(format!(
+            "{}{}",
+            TestRoad::new("1").sides(&[], &[("driving", 3.0), ("driving", 3.0)]),
+            TestRoad::new("2")
+                .at(100.0, 0.0, std::f64::consts::PI)
+                .sides(&[], &[("driving", 3.0)]),
+        ));
+
+        let section = &drive.road[0].lanes.lane_section[0];
+        assert_eq!(Some(-1), section.inner_neighbor(-2).map(|(id, _)| id));
+        assert_eq!(Some(-2), section.outer_neighbor(-1).map(|(id, _)| id));
+        assert!(section.inner_neighbor(-1).is_none());
+        assert!(section.outer_neighbor(-2).is_none());
+        assert!(section.opposite_direction_neighbor(-1).is_none());
+
+        let neighbor = drive
+            .opposite_direction_neighbor("1", -1, Length::new::<meter>(30.0))
+            .unwrap();
+        assert_eq!("2", neighbor.road.id);
+        assert_eq!(-1, neighbor.lane_id);
+        assert!((70.0 - neighbor.s.get::<meter>()).abs() < 1e-6);
+        assert!(drive
+            .opposite_direction_neighbor("1", -2, Length::new::<meter>(30.0))
+            .is_none());
+    }
+}
@@ -0,0 +1,232 @@
+use crate::core::OpenDrive;
+use crate::object::Object;
+use crate::road::geometry::point::Point;
+use crate::road::geometry::polygon;
+use crate::road::geometry::pose::Pose;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::collections::HashMap;
+
+/// An object together with its road and its position in the inertial x/y-plane, see
+/// [`OpenDrive::objects_within`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacedObject<'a> {
+    pub road: &'a Road,
+    pub object: &'a Object,
+    /// The pose of the origin of the object, see [`Object::pose`]
+    pub pose: Pose,
+    /// The outline of the object, see [`Object::footprint`]
+    pub footprint: Vec<Point>,
+}
+
+/// A grid of the footprints of the objects of a document, for spatial queries like
+/// [`ObjectIndex::within`]. The index refers to roads and objects by their position in the
+/// document, so it must only be used with the document it was built for, as long as that is
+/// not changed. Repeated objects are indexed by their origin only.
+#[derive(Debug, Clone)]
+pub struct ObjectIndex {
+    cell_size: f64,
+    objects: Vec<IndexedObject>,
+    /// The indices of the objects whose footprint overlaps each grid cell
+    grid: HashMap<(i64, i64), Vec<usize>>,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedObject {
+    road: usize,
+    object: usize,
+    pose: Pose,
+    footprint: Vec<Point>,
+}
+
+impl ObjectIndex {
+    /// Indexes all objects of the document in a grid of cells with the given edge length
+    pub fn new(drive: &OpenDrive, cell_size: Length) -> Self {
+        let mut index = Self {
+            cell_size: cell_size.get::<meter>().max(1e-3),
+            objects: Vec::new(),
+            grid: HashMap::new(),
+        };
+        for (road_index, road) in drive.road.iter().enumerate() {
+            let objects = road.objects.iter().flat_map(|o| o.object.iter());
+            for (object_index, object) in objects.enumerate() {
+                let footprint = object.footprint(road);
+                if let Some((min, max)) = polygon::bounds(&footprint) {
+                    for cell in index.cells(&min, &max) {
+                        index
+                            .grid
+                            .entry(cell)
+                            .or_default()
+                            .push(index.objects.len());
+                    }
+                }
+                index.objects.push(IndexedObject {
+                    road: road_index,
+                    object: object_index,
+                    pose: object.pose(road),
+                    footprint,
+                });
+            }
+        }
+        index
+    }
+
+    /// The objects whose footprint is at most `radius` away from `center`, in the order of the
+    /// document
+    pub fn within<'a>(
+        &self,
+        drive: &'a OpenDrive,
+        center: Point,
+        radius: Length,
+    ) -> Vec<PlacedObject<'a>> {
+        let radius = radius.get::<meter>().max(0.0);
+        let offset = |point: &Point, sign: f64| Point {
+            x: point.x + Length::new::<meter>(sign * radius),
+            y: point.y + Length::new::<meter>(sign * radius),
+        };
+        self.query(
+            drive,
+            &offset(&center, -1.0),
+            &offset(&center, 1.0),
+            |object| polygon::distance(&object.footprint, &center) <= radius,
+        )
+    }
+
+    /// The objects whose footprint overlaps the axis-aligned box from `min` to `max`, in the
+    /// order of the document
+    pub fn in_aabb<'a>(
+        &self,
+        drive: &'a OpenDrive,
+        min: Point,
+        max: Point,
+    ) -> Vec<PlacedObject<'a>> {
+        self.query(drive, &min, &max, |object| {
+            polygon::intersects_aabb(&object.footprint, &min, &max)
+        })
+    }
+
+    fn cells(&self, min: &Point, max: &Point) -> impl Iterator<Item = (i64, i64)> {
+        let cell = |point: &Point| {
+            (
+                (point.x.get::<meter>() / self.cell_size).floor() as i64,
+                (point.y.get::<meter>() / self.cell_size).floor() as i64,
+            )
+        };
+        let (min, max) = (cell(min), cell(max));
+        (min.0..=max.0).flat_map(move |x| (min.1..=max.1).map(move |y| (x, y)))
+    }
+
+    fn query<'a>(
+        &self,
+        drive: &'a OpenDrive,
+        min: &Point,
+        max: &Point,
+        matches: impl Fn(&IndexedObject) -> bool,
+    ) -> Vec<PlacedObject<'a>> {
+        let mut candidates = self
+            .cells(min, max)
+            .filter_map(|cell| self.grid.get(&cell))
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+            .into_iter()
+            .map(|index| &self.objects[index])
+            .filter(|object| matches(object))
+            .filter_map(|indexed| {
+                let road = drive.road.get(indexed.road)?;
+                Some(PlacedObject {
+                    road,
+                    object: road.objects.as_ref()?.object.get(indexed.object)?,
+                    pose: indexed.pose,
+                    footprint: indexed.footprint.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl OpenDrive {
+    /// The objects whose footprint is at most `radius` away from `center`, in the order of the
+    /// document. This builds an [`ObjectIndex`] for a single query, for many queries build one
+    /// index and use [`ObjectIndex::within`] or [`crate::core::shared::SharedMap`].
+    pub fn objects_within(&self, center: Point, radius: Length) -> Vec<PlacedObject<'_>> {
+        ObjectIndex::new(self, Length::new::<meter>(50.0)).within(self, center, radius)
+    }
+
+    /// The objects whose footprint overlaps the axis-aligned box from `min` to `max`, in the
+    /// order of the document, see [`OpenDrive::objects_within`]
+    pub fn objects_in_aabb(&self, min: Point, max: Point) -> Vec<PlacedObject<'_>> {
+        ObjectIndex::new(self, Length::new::<meter>(50.0)).in_aabb(self, min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::shared::SharedMap;
+    use crate::core::validated::SCoordinate;
+    use crate::generate::generators::StandardMap;
+    use crate::generate::parking_lot::ParkingLayout;
+    use crate::lane::taper::LaneSide;
+    use crate::road::geometry::point::Point;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_objects_within() {
+        let length = Length::new::<meter>;
+        let point = |x, y| Point {
+            x: length(x),
+            y: length(y),
+        };
+        let mut drive = StandardMap::Highway {
+            lanes: 1,
+            length: length(500.0),
+        }
+        .generate();
+        // 2.5 m wide stalls from s=100 to s=200 with their centers at t=-6
+        let road = drive.road[0].id.clone();
+        drive
+            .add_parking_row(
+                &road,
+                LaneSide::Right,
+                SCoordinate::new(length(100.0)).unwrap(),
+                SCoordinate::new(length(200.0)).unwrap(),
+                &ParkingLayout::default(),
+            )
+            .unwrap();
+
+        let ids = |objects: Vec<crate::query::object_index::PlacedObject>| {
+            objects
+                .into_iter()
+                .map(|o| (o.road.id.clone(), o.object.id.clone()))
+                .collect::<Vec<_>>()
+        };
+        // the stall centered at s=101.25 reaches up to s=102.5 and t=-3.5
+        assert_eq!(
+            vec![(road, "1".to_string())],
+            ids(drive.objects_within(point(101.0, 0.0), length(3.6)))
+        );
+        assert!(drive
+            .objects_within(point(101.0, 0.0), length(3.4))
+            .is_empty());
+        assert_eq!(
+            3,
+            drive
+                .objects_in_aabb(point(103.0, -9.0), point(109.0, -8.0))
+                .len()
+        );
+        assert!(drive
+            .objects_in_aabb(point(0.0, 0.0), point(99.0, 10.0))
+            .is_empty());
+
+        let within = drive.objects_within(point(150.0, -6.0), length(10.0));
+        let map = SharedMap::new(drive.clone());
+        let shared = map.objects_within(point(150.0, -6.0), length(10.0));
+        assert_eq!(within, shared);
+        let stall = &within[0];
+        assert_eq!(4, stall.footprint.len());
+        assert!((stall.pose.y.get::<meter>() + 6.0).abs() < 1e-9);
+    }
+}
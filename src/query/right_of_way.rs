@@ -0,0 +1,374 @@
+use crate::core::OpenDrive;
+use crate::junction::connection::Connection;
+use crate::junction::contact_point::ContactPoint;
+use crate::junction::Junction;
+use crate::lane::travel_direction::TravelDirection;
+use crate::road::Road;
+use crate::signal::semantics::SignalCategory;
+use crate::units::prelude::*;
+
+/// How traffic entering a junction through a connection is regulated, ordered from the highest to
+/// the lowest priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ApproachRegulation {
+    /// Controlled by traffic lights
+    Signalized,
+    /// On the priority road
+    Priority,
+    /// Neither signs nor traffic lights, the default traffic rules apply
+    Unregulated,
+    Yield,
+    Stop,
+}
+
+/// The relation between two conflicting connections of a junction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RightOfWay {
+    /// The connection has the right of way over the other connection
+    Has,
+    /// The connection has to yield to the other connection
+    Yields,
+    /// Both connections are controlled by traffic lights
+    Signalized,
+    /// The relation cannot be derived, for example because both connections are unregulated
+    Undetermined,
+}
+
+/// Right-of-way relations between all connections of a junction, see
+/// [`OpenDrive::right_of_way`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RightOfWayMatrix<'a> {
+    pub junction: &'a Junction,
+    pub connections: Vec<&'a Connection>,
+    /// The regulation of each connection in the order of [`RightOfWayMatrix::connections`]
+    pub regulations: Vec<ApproachRegulation>,
+    /// `relations[a][b]` is the relation of connection `a` towards connection `b`, `None` if both
+    /// connections do not conflict
+    pub relations: Vec<Vec<Option<RightOfWay>>>,
+}
+
+impl<'a> RightOfWayMatrix<'a> {
+    /// The relation of the connection with id `a` towards the connection with id `b`, `None` if
+    /// they do not conflict or do not exist
+    pub fn relation(&self, a: &str, b: &str) -> Option<RightOfWay> {
+        let index = |id: &str| self.connections.iter().position(|c| c.id == id);
+        self.relations[index(a)?][index(b)?]
+    }
+}
+
+impl OpenDrive {
+    /// Derives the right-of-way relations between the connections of the given junction. Two
+    /// connections conflict if they come from different incoming roads and lead onto the same lane
+    /// of a connecting road or, in direct junctions, of a linked road, or if their connecting
+    /// roads cross or end at the same point. Junction `<priority>` records take precedence,
+    /// otherwise the relation is derived from the [`ApproachRegulation`] of both connections,
+    /// which is based on the traffic lights and the priority, yield and stop signs on the incoming
+    /// lanes.
+    /// Returns `None` if there is no such junction.
+    pub fn right_of_way(&self, junction_id: &str) -> Option<RightOfWayMatrix<'_>> {
+        let junction = self.junction.iter().find(|j| j.id == junction_id)?;
+        let connections = junction.connection.iter().collect::<Vec<_>>();
+        let regulations = connections
+            .iter()
+            .map(|c| self.regulation(junction, c))
+            .collect::<Vec<_>>();
+        let paths = connections
+            .iter()
+            .map(|c| self.connection_path(c))
+            .collect::<Vec<_>>();
+
+        let relations = (0..connections.len())
+            .map(|a| {
+                (0..connections.len())
+                    .map(|b| {
+                        if a == b
+                            || !Self::conflict(connections[a], connections[b], &paths[a], &paths[b])
+                        {
+                            return None;
+                        }
+                        Some(Self::relation(
+                            junction,
+                            (connections[a], regulations[a]),
+                            (connections[b], regulations[b]),
+                        ))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Some(RightOfWayMatrix {
+            junction,
+            connections,
+            regulations,
+            relations,
+        })
+    }
+
+    fn regulation(&self, junction: &Junction, connection: &Connection) -> ApproachRegulation {
+        let road = connection
+            .incoming_road
+            .as_deref()
+            .and_then(|id| self.road.iter().find(|r| r.id == id));
+        let (road, direction) = match road.map(|r| (r, r.junction_contact_point(&junction.id))) {
+            Some((road, Some(ContactPoint::End))) => (road, TravelDirection::Forward),
+            Some((road, Some(ContactPoint::Start))) => (road, TravelDirection::Backward),
+            _ => return ApproachRegulation::Unregulated,
+        };
+        let start = match direction {
+            TravelDirection::Backward => road.length,
+            _ => Length::new::<meter>(0.0),
+        };
+
+        connection
+            .lane_link
+            .iter()
+            .filter_map(|l| self.upcoming_signals_for_lane(&road.id, l.from, start, direction))
+            .flatten()
+            .filter_map(|s| {
+                if s.signal.dynamic {
+                    return Some(ApproachRegulation::Signalized);
+                }
+                // the country of the road type at the signal, or at its reference
+                let position = s.reference.map_or(s.signal.s, |reference| reference.s);
+                let country = road
+                    .r#type
+                    .iter()
+                    .rev()
+                    .find(|road_type| road_type.s <= position)
+                    .and_then(|road_type| road_type.country.as_ref());
+                match s.signal.semantics(country).category {
+                    SignalCategory::Stop => Some(ApproachRegulation::Stop),
+                    SignalCategory::Yield => Some(ApproachRegulation::Yield),
+                    SignalCategory::Priority => Some(ApproachRegulation::Priority),
+                    _ => None,
+                }
+            })
+            .min_by_key(|r| match r {
+                // traffic lights override signs, stop signs override yield signs and so on
+                ApproachRegulation::Signalized => 0,
+                ApproachRegulation::Stop => 1,
+                ApproachRegulation::Yield => 2,
+                ApproachRegulation::Priority => 3,
+                ApproachRegulation::Unregulated => 4,
+            })
+            .unwrap_or(ApproachRegulation::Unregulated)
+    }
+
+    fn relation(
+        junction: &Junction,
+        (a, a_regulation): (&Connection, ApproachRegulation),
+        (b, b_regulation): (&Connection, ApproachRegulation),
+    ) -> RightOfWay {
+        let explicit = |high: &Connection, low: &Connection| {
+            junction.priority.iter().any(|p| {
+                p.high.is_some()
+                    && p.high == high.connecting_road
+                    && p.low.is_some()
+                    && p.low == low.connecting_road
+            })
+        };
+
+        if explicit(a, b) {
+            RightOfWay::Has
+        } else if explicit(b, a) {
+            RightOfWay::Yields
+        } else if a_regulation == ApproachRegulation::Signalized
+            && b_regulation == ApproachRegulation::Signalized
+        {
+            RightOfWay::Signalized
+        } else if a_regulation == ApproachRegulation::Signalized
+            || b_regulation == ApproachRegulation::Signalized
+        {
+            RightOfWay::Undetermined
+        } else if a_regulation < b_regulation {
+            RightOfWay::Has
+        } else if a_regulation > b_regulation {
+            RightOfWay::Yields
+        } else {
+            RightOfWay::Undetermined
+        }
+    }
+
+    /// The reference line of the connecting road sampled every meter
+    fn connection_path(&self, connection: &Connection) -> Vec<(f64, f64)> {
+        let road = connection
+            .connecting_road
+            .as_deref()
+            .and_then(|id| self.road.iter().find(|r| r.id == id));
+        match road {
+            Some(road) => Self::sample(road),
+            None => Vec::new(),
+        }
+    }
+
+    fn sample(road: &Road) -> Vec<(f64, f64)> {
+        let length = road.length.get::<meter>().max(0.0);
+        let samples = (length.ceil() as usize).max(1);
+        (0..=samples)
+            .map(|i| {
+                let pose =
+                    road.reference_pose(Length::new::<meter>(length * i as f64 / samples as f64));
+                (pose.x.get::<meter>(), pose.y.get::<meter>())
+            })
+            .collect()
+    }
+
+    fn conflict(
+        a: &Connection,
+        b: &Connection,
+        a_path: &[(f64, f64)],
+        b_path: &[(f64, f64)],
+    ) -> bool {
+        if a.incoming_road.is_some() && a.incoming_road == b.incoming_road {
+            return false;
+        }
+
+        if Self::target_lanes(a).any(|lane| Self::target_lanes(b).any(|other| other == lane)) {
+            return true;
+        }
+        if Self::target_road(a).is_some() && Self::target_road(a) == Self::target_road(b) {
+            // side by side on different lanes of the same road
+            return false;
+        }
+
+        // merging onto the same road
+        let end = |path: &[(f64, f64)]| path.last().copied();
+        let merging = match (end(a_path), end(b_path)) {
+            (Some(a), Some(b)) => (a.0 - b.0).hypot(a.1 - b.1) < 0.5,
+            _ => false,
+        };
+
+        merging
+            || a_path.windows(2).any(|a| {
+                b_path
+                    .windows(2)
+                    .any(|b| Self::segments_intersect(a[0], a[1], b[0], b[1]))
+            })
+    }
+
+    /// The connecting road or, for direct junctions, the linked road of the connection
+    fn target_road(connection: &Connection) -> Option<&str> {
+        connection
+            .connecting_road
+            .as_deref()
+            .or(connection.linked_road.as_deref())
+    }
+
+    /// The lanes of the [`Self::target_road`] the connection leads onto
+    fn target_lanes(connection: &Connection) -> impl Iterator<Item = (&str, i64)> {
+        let road = Self::target_road(connection);
+        connection
+            .lane_link
+            .iter()
+            .filter_map(move |link| Some((road?, link.to)))
+    }
+
+    fn segments_intersect(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> bool {
+        let cross = |o: (f64, f64), p: (f64, f64), q: (f64, f64)| {
+            (p.0 - o.0) * (q.1 - o.1) - (p.1 - o.1) * (q.0 - o.0)
+        };
+        let d1 = cross(c, d, a);
+        let d2 = cross(c, d, b);
+        let d3 = cross(a, b, c);
+        let d4 = cross(a, b, d);
+        d1 * d2 < 0.0 && d3 * d4 < 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::query::right_of_way::{ApproachRegulation, RightOfWay};
+
+    #[test]
+    pub fn test_right_of_way() {
+        let road = |id: &str, x: f64, y: f64, hdg: f64| {
+            TestRoad::new(id)
+                .length(20.0)
+                .at(x, y, hdg)
+                .sides(&[], &[("driving", 3.0)])
+        };
+        let incoming = |id: &str, x: f64, y: f64, hdg: f64| {
+            road(id, x, y, hdg).link(r#"<successor elementType="junction" elementId="J"/>"#)
+        };
+        let half_pi = std::f64::consts::FRAC_PI_2;
+        // the connecting roads cross each other in the middle of the junction
+        let diagonal = std::f64::consts::PI + 0.5f64.atan();
+        let drive = parse(format!(
+            r#"{}{}{}{}{}{}
+            <junction id="J">
+                <connection id="west" incomingRoad="1" connectingRoad="10" contactPoint="start"><laneLink from="-1" to="-1"/></connection>
+                <connection id="south" incomingRoad="2" connectingRoad="11" contactPoint="start"><laneLink from="-1" to="-1"/></connection>
+                <connection id="east" incomingRoad="3" connectingRoad="12" contactPoint="start"><laneLink from="-1" to="-1"/></connection>
+                <priority high="12" low="10"/>
+            </junction>"#,
+            incoming("1", -30.25, 0.3, 0.0),
+            incoming("2", 0.5, -30.2, half_pi).signals(
+                r#"<signal s="18" t="-4" id="stop" dynamic="no" orientation="+" zOffset="0" country="DE" type="206" subtype="-1"/>"#
+            ),
+            incoming("3", 30.0, 15.3, diagonal),
+            road("10", -10.25, 0.3, 0.0).junction("J"),
+            road("11", 0.5, -10.2, half_pi).junction("J"),
+            road("12", 10.0, 5.3, diagonal).junction("J"),
+        ));
+        let matrix = drive.right_of_way("J").unwrap();
+
+        assert_eq!(
+            matrix.regulations,
+            [
+                ApproachRegulation::Unregulated,
+                ApproachRegulation::Stop,
+                ApproachRegulation::Unregulated,
+            ]
+        );
+        assert_eq!(Some(RightOfWay::Has), matrix.relation("west", "south"));
+        assert_eq!(Some(RightOfWay::Yields), matrix.relation("south", "west"));
+        assert_eq!(Some(RightOfWay::Has), matrix.relation("east", "west"));
+        assert_eq!(Some(RightOfWay::Yields), matrix.relation("west", "east"));
+        assert_eq!(Some(RightOfWay::Has), matrix.relation("east", "south"));
+        assert_eq!(None, matrix.relation("west", "west"));
+    }
+
+    #[test]
+    pub fn test_right_of_way_direct_junction() {
+        let incoming = |id: &str, y: f64| {
+            TestRoad::new(id)
+                .at(0.0, y, 0.0)
+                .sides(&[], &[("driving", 3.0)])
+                .link(r#"<successor elementType="junction" elementId="D"/>"#)
+        };
+        let drive = parse(format!(
+            r#"{}{}{}
+            <junction id="D" type="direct">
+                <connection id="0" incomingRoad="1" linkedRoad="3" contactPoint="start"><laneLink from="-1" to="-1"/></connection>
+                <connection id="1" incomingRoad="2" linkedRoad="3" contactPoint="start"><laneLink from="-1" to="-1"/></connection>
+                <connection id="2" incomingRoad="2" linkedRoad="3" contactPoint="start"><laneLink from="-1" to="-2"/></connection>
+                <connection id="3" incomingRoad="1" linkedRoad="3" contactPoint="start"><laneLink from="-1" to="-3"/></connection>
+            </junction>"#,
+            // the countries of the signs are given by the road types, the number of a German stop
+            // sign means nothing on a road in the US
+            incoming("1", 0.0)
+                .child(r#"<type s="0" type="rural" country="US"/>"#)
+                .signals(
+                    r#"<signal s="90" t="-2" id="stop" dynamic="no" orientation="+" zOffset="0" type="206" subtype="-1"/>"#
+                ),
+            incoming("2", -3.0)
+                .child(r#"<type s="0" type="rural" country="DE"/>"#)
+                .signals(
+                    r#"<signal s="90" t="-2" id="yield" dynamic="no" orientation="+" zOffset="0" type="205" subtype="-1"/>"#
+                ),
+            TestRoad::new("3")
+                .at(100.0, 0.0, 0.0)
+                .sides(&[], &[("driving", 3.0), ("driving", 3.0), ("driving", 3.0)]),
+        ));
+        let matrix = drive.right_of_way("D").unwrap();
+
+        // both incoming roads merge onto the first lane of the linked road
+        assert_eq!(Some(RightOfWay::Has), matrix.relation("0", "1"));
+        assert_eq!(Some(RightOfWay::Yields), matrix.relation("1", "0"));
+        // side by side on different lanes or from the same incoming road
+        assert_eq!(None, matrix.relation("0", "2"));
+        assert_eq!(None, matrix.relation("2", "3"));
+        assert_eq!(None, matrix.relation("0", "3"));
+    }
+}
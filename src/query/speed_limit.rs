@@ -0,0 +1,161 @@
+use crate::core::OpenDrive;
+use crate::lane::speed::Speed;
+use crate::lane::travel_direction::TravelDirection;
+use crate::road::road_type::RoadType;
+use crate::signal::semantics::{SignalCategory, SignalValue};
+use crate::signal::Signal;
+use crate::units::prelude::*;
+
+/// Result of [`OpenDrive::speed_limit_at`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeedLimit<'a> {
+    /// The maximum allowed speed, infinite if there is explicitly no limit
+    pub value: Velocity,
+    pub source: SpeedLimitSource<'a>,
+}
+
+/// The element a [`SpeedLimit`] is derived from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpeedLimitSource<'a> {
+    /// A `<speed>` element of the lane
+    Lane(&'a Speed),
+    /// The last speed limit signal passed in the direction of travel, including the town entrance
+    /// and exit signs of countries where they imply a speed limit
+    Signal(&'a Signal),
+    /// The `<speed>` element of the road type
+    RoadType(&'a RoadType),
+}
+
+impl OpenDrive {
+    /// The speed limit on the given lane at the s-coordinate `s` of the given road. The
+    /// `<speed>` elements of the lane take precedence over speed limit signals, which in turn take
+    /// precedence over the `<speed>` elements of the road type. Signals are considered if they
+    /// have been passed in the direction of travel of the lane and are valid for the lane, and
+    /// classified by [`Signal::semantics`] in the country of the road type. If the last signal
+    /// passed ends the speed limit, the road type applies again. Minimum and advisory speeds are
+    /// never speed limits.
+    pub fn speed_limit_at(&self, road_id: &str, lane_id: i64, s: Length) -> Option<SpeedLimit<'_>> {
+        let road = self.road.iter().find(|r| r.id == road_id)?;
+        let (_, section) = road.lanes.lane_section_at(s.get::<meter>());
+        let lane = section.lane(lane_id)?;
+        let ds = Length::new::<meter>(s.get::<meter>() - section.s);
+
+        let lane_speed = lane.speed.iter().rev().find(|speed| speed.s_offset <= ds);
+        if let Some(speed) = lane_speed {
+            return Some(SpeedLimit {
+                value: speed.velocity(),
+                source: SpeedLimitSource::Lane(speed),
+            });
+        }
+
+        // bidirectional lanes are treated as travelled in the direction of the reference line
+        let direction = match road.lane_direction(lane_id, lane) {
+            Some(TravelDirection::Backward) => TravelDirection::Backward,
+            _ => TravelDirection::Forward,
+        };
+        let road_type = road.r#type.iter().rev().find(|road_type| road_type.s <= s);
+        let country = road_type.and_then(|road_type| road_type.country.as_ref());
+        let signal = road
+            .signals
+            .iter()
+            .flat_map(|signals| &signals.signal)
+            .filter(|signal| match direction {
+                TravelDirection::Backward => signal.s >= s,
+                _ => signal.s <= s,
+            })
+            .filter(|signal| signal.applies_to_direction(direction))
+            .filter(|signal| signal.applies_to_lane(lane_id))
+            .filter_map(|signal| {
+                let semantics = signal.semantics(country);
+                let speed = match semantics.value {
+                    Some(SignalValue::Speed(speed)) => Some(speed),
+                    _ => None,
+                };
+                match semantics.category {
+                    SignalCategory::SpeedLimit
+                    | SignalCategory::TownEntrance
+                    | SignalCategory::TownExit => speed.map(|speed| (signal, Some(speed))),
+                    SignalCategory::SpeedLimitEnd | SignalCategory::EndOfRestrictions => {
+                        Some((signal, None))
+                    }
+                    _ => None,
+                }
+            })
+            .min_by(|(a, _), (b, _)| {
+                let a = (a.s - s).abs();
+                let b = (b.s - s).abs();
+                a.get::<meter>().total_cmp(&b.get::<meter>())
+            });
+        if let Some((signal, Some(value))) = signal {
+            return Some(SpeedLimit {
+                value,
+                source: SpeedLimitSource::Signal(signal),
+            });
+        }
+
+        road_type.and_then(|road_type| {
+            Some(SpeedLimit {
+                value: road_type.speed.as_ref()?.velocity()?,
+                source: SpeedLimitSource::RoadType(road_type),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::query::speed_limit::SpeedLimitSource;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_speed_limit_priority() {
+        let drive = parse(
+            TestRoad::new("1")
+                .child(r#"<type s="0" type="rural"><speed max="100" unit="km/h"/></type>"#)
+                .lanes(
+                    r#"<laneSection s="0">
+                        <left><lane id="1" type="driving"><width sOffset="0" a="3" b="0" c="0" d="0"/></lane></left>
+                        <center><lane id="0" type="none"/></center>
+                        <right>
+                            <lane id="-1" type="driving"><width sOffset="0" a="3" b="0" c="0" d="0"/></lane>
+                            <lane id="-2" type="driving">
+                                <width sOffset="0" a="3" b="0" c="0" d="0"/>
+                                <speed sOffset="60" max="30" unit="km/h"/>
+                            </lane>
+                        </right>
+                    </laneSection>"#,
+                )
+                .signals(
+                    r#"<signal s="50" t="-3" id="s" dynamic="no" orientation="+" zOffset="0" country="DE" type="274" subtype="-1" value="70" unit="km/h"/>
+                    <signal s="60" t="-3" id="min" dynamic="no" orientation="+" zOffset="0" country="DE" type="275" subtype="-1" value="30" unit="km/h"/>
+                    <signal s="80" t="-3" id="end" dynamic="no" orientation="+" zOffset="0" country="DE" type="278" subtype="-1" value="70" unit="km/h"/>"#,
+                ),
+        );
+
+        let limit = |lane: i64, s: f64| {
+            let limit = drive
+                .speed_limit_at("1", lane, Length::new::<meter>(s))
+                .unwrap();
+            let source = match limit.source {
+                SpeedLimitSource::Lane(_) => "lane",
+                SpeedLimitSource::Signal(_) => "signal",
+                SpeedLimitSource::RoadType(_) => "road",
+            };
+            (limit.value.get::<kilometer_per_hour>().round(), source)
+        };
+
+        assert_eq!((100.0, "road"), limit(-1, 40.0));
+        assert_eq!((70.0, "signal"), limit(-1, 55.0));
+        assert_eq!((70.0, "signal"), limit(-2, 55.0));
+        assert_eq!((30.0, "lane"), limit(-2, 65.0));
+        // a minimum speed is no limit, and the end of the limit falls back to the road type
+        assert_eq!((70.0, "signal"), limit(-1, 65.0));
+        assert_eq!((100.0, "road"), limit(-1, 85.0));
+        // the signal faces the other direction of travel
+        assert_eq!((100.0, "road"), limit(1, 55.0));
+        assert!(drive
+            .speed_limit_at("1", -3, Length::new::<meter>(0.0))
+            .is_none());
+    }
+}
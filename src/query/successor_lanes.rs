@@ -0,0 +1,297 @@
+use crate::core::OpenDrive;
+use crate::junction::connection_type::ConnectionType;
+use crate::junction::contact_point::ContactPoint;
+use crate::junction::element_dir::ElementDir;
+use crate::junction::Junction;
+use crate::lane::travel_direction::TravelDirection;
+use crate::lane::Lane;
+use crate::road::linked_element::LinkedElement;
+use crate::road::Road;
+use crate::units::prelude::*;
+
+/// A lane that may be entered after leaving a road, see [`OpenDrive::successor_lanes`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuccessorLane<'a> {
+    pub road: &'a Road,
+    /// Index of the entered lane section within [`crate::lane::lanes::Lanes::lane_section`]
+    pub section: usize,
+    pub lane_id: i64,
+    /// Direction in which the entered lane is travelled with respect to its reference line
+    pub direction: TravelDirection,
+    /// The junction the transition belongs to, `None` for direct road links
+    pub junction: Option<&'a Junction>,
+}
+
+impl OpenDrive {
+    /// All lanes a vehicle may continue into when leaving the given lane of the given road at
+    /// `contact_point`, that is, through the successor of the road at [`ContactPoint::End`] and
+    /// through its predecessor at [`ContactPoint::Start`]. Road links are followed using the lane
+    /// links of the lane, junctions using the `<laneLink>` elements of their connections. Direct
+    /// junctions are followed through `@linkedRoad`, connecting roads of virtual junctions leave
+    /// through the `<predecessor>` or `<successor>` of their connection if they are not linked
+    /// otherwise.
+    pub fn successor_lanes(
+        &self,
+        road_id: &str,
+        lane_id: i64,
+        contact_point: ContactPoint,
+    ) -> Vec<SuccessorLane<'_>> {
+        let road = match self.road.iter().find(|r| r.id == road_id) {
+            Some(road) => road,
+            None => return Vec::new(),
+        };
+        let sections = &road.lanes.lane_section;
+        let section = match contact_point {
+            ContactPoint::Start => sections.first(),
+            ContactPoint::End => sections.last(),
+        };
+        match section.lane(lane_id) {
+            Some(lane) => self.successor_lanes_of(road, lane_id, lane, contact_point),
+            None => Vec::new(),
+        }
+    }
+
+    pub(crate) fn successor_lanes_of<'a>(
+        &'a self,
+        road: &'a Road,
+        lane_id: i64,
+        lane: &'a Lane,
+        contact_point: ContactPoint,
+    ) -> Vec<SuccessorLane<'a>> {
+        let lane_links = lane
+            .link
+            .iter()
+            .flat_map(|l| match contact_point {
+                ContactPoint::End => &l.successor,
+                ContactPoint::Start => &l.predecessor,
+            })
+            .map(|l| l.id)
+            .collect::<Vec<_>>();
+
+        let road_link = road.link.as_ref().and_then(|l| match contact_point {
+            ContactPoint::End => l.successor.as_ref(),
+            ContactPoint::Start => l.predecessor.as_ref(),
+        });
+
+        match road_link.map(|link| link.resolve(self)) {
+            Some(Some(LinkedElement::Junction(junction))) => {
+                self.junction_successor_lanes(road, lane_id, junction)
+            }
+            Some(Some(LinkedElement::Road {
+                road: target,
+                s,
+                direction,
+            })) => {
+                let entries = match direction {
+                    TravelDirection::Both => Self::entries(target, None).collect(),
+                    direction => {
+                        let (section, _) = target.lanes.lane_section_at(s.get::<meter>());
+                        vec![(section, direction)]
+                    }
+                };
+                entries
+                    .into_iter()
+                    .flat_map(|(section, direction)| {
+                        lane_links.iter().map(move |lane_id| SuccessorLane {
+                            road: target,
+                            section,
+                            lane_id: *lane_id,
+                            direction,
+                            junction: None,
+                        })
+                    })
+                    .filter(SuccessorLane::exists)
+                    .collect()
+            }
+            Some(None) => Vec::new(),
+            None => self.virtual_successor_lanes(road, &lane_links, contact_point),
+        }
+    }
+
+    fn junction_successor_lanes<'a>(
+        &'a self,
+        road: &'a Road,
+        lane_id: i64,
+        junction: &'a Junction,
+    ) -> Vec<SuccessorLane<'a>> {
+        let mut successors = Vec::new();
+        for connection in &junction.connection {
+            if connection.incoming_road.as_deref() != Some(road.id.as_str()) {
+                continue;
+            }
+
+            let target = connection
+                .connecting_road
+                .as_deref()
+                .or(connection.linked_road.as_deref())
+                .and_then(|id| self.road.iter().find(|r| r.id == id));
+
+            if let Some(target) = target {
+                for lane_link in connection.lane_link.iter().filter(|l| l.from == lane_id) {
+                    for (section, direction) in
+                        Self::entries(target, connection.contact_point.as_ref())
+                    {
+                        successors.push(SuccessorLane {
+                            road: target,
+                            section,
+                            lane_id: lane_link.to,
+                            direction,
+                            junction: Some(junction),
+                        });
+                    }
+                }
+            }
+        }
+
+        successors.retain(SuccessorLane::exists);
+        successors
+    }
+
+    /// Connecting roads of virtual junctions may branch off and rejoin their main road anywhere
+    /// along it, which is described by the `<predecessor>` and `<successor>` of the connection.
+    fn virtual_successor_lanes<'a>(
+        &'a self,
+        road: &'a Road,
+        lane_links: &[i64],
+        contact_point: ContactPoint,
+    ) -> Vec<SuccessorLane<'a>> {
+        let junction = match self.junction.iter().find(|j| j.id == road.junction) {
+            Some(junction) => junction,
+            None => return Vec::new(),
+        };
+
+        junction
+            .connection
+            .iter()
+            .filter(|c| c.r#type == Some(ConnectionType::Virtual))
+            .filter(|c| c.connecting_road.as_deref() == Some(road.id.as_str()))
+            .filter_map(|c| match contact_point {
+                ContactPoint::End => c.successor.as_ref(),
+                ContactPoint::Start => c.predecessor.as_ref(),
+            })
+            .filter_map(|element| {
+                let target = self.road.iter().find(|r| r.id == element.element_id)?;
+                let (section, _) = target
+                    .lanes
+                    .lane_section_at(element.element_s.get::<meter>());
+                let direction = match element.element_dir {
+                    ElementDir::Plus => TravelDirection::Forward,
+                    ElementDir::Minus => TravelDirection::Backward,
+                };
+                Some((target, section, direction))
+            })
+            .flat_map(|(target, section, direction)| {
+                lane_links.iter().map(move |lane_id| SuccessorLane {
+                    road: target,
+                    section,
+                    lane_id: *lane_id,
+                    direction,
+                    junction: Some(junction),
+                })
+            })
+            .filter(SuccessorLane::exists)
+            .collect()
+    }
+
+    /// The lane section and travel direction when entering the given road at the given contact
+    /// point. Without a contact point, both ends are considered.
+    fn entries(
+        road: &Road,
+        contact_point: Option<&ContactPoint>,
+    ) -> impl Iterator<Item = (usize, TravelDirection)> {
+        let start = (0, TravelDirection::Forward);
+        let end = (road.lanes.lane_section.len() - 1, TravelDirection::Backward);
+        let (start, end) = match contact_point {
+            Some(ContactPoint::Start) => (Some(start), None),
+            Some(ContactPoint::End) => (None, Some(end)),
+            None => (Some(start), Some(end)),
+        };
+        start.into_iter().chain(end)
+    }
+}
+
+impl SuccessorLane<'_> {
+    fn exists(&self) -> bool {
+        self.road.lanes.lane_section[self.section]
+            .lane(self.lane_id)
+            .is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::junction::contact_point::ContactPoint;
+    use crate::lane::travel_direction::TravelDirection;
+
+    #[test]
+    pub fn test_successor_lanes() {
+        let road = |id: &str| {
+            TestRoad::new(id).length(10.0).lanes(
+                r#"<laneSection s="0">
+                    <center><lane id="0" type="none"/></center>
+                    <right><lane id="-1" type="driving">
+                        <link><predecessor id="-1"/><successor id="-1"/></link>
+                        <width sOffset="0" a="3.5" b="0" c="0" d="0"/>
+                    </lane></right>
+                </laneSection>"#,
+            )
+        };
+        let drive = parse(format!(
+            r#"{}{}{}{}{}
+            <junction id="J">
+                <connection id="0" incomingRoad="1" connectingRoad="2" contactPoint="start">
+                    <laneLink from="-1" to="-1"/>
+                </connection>
+                <connection id="1" incomingRoad="1" connectingRoad="3" contactPoint="end">
+                    <laneLink from="-1" to="-1"/>
+                </connection>
+            </junction>
+            <junction id="D" type="direct">
+                <connection id="0" incomingRoad="4" linkedRoad="5" contactPoint="start">
+                    <laneLink from="-1" to="-1"/>
+                </connection>
+            </junction>"#,
+            road("1").link(r#"<successor elementType="junction" elementId="J"/>"#),
+            road("2").junction("J"),
+            road("3").junction("J"),
+            road("4").link(
+                r#"<predecessor elementType="road" elementId="1" contactPoint="start"/>
+                   <successor elementType="junction" elementId="D"/>"#
+            ),
+            road("5"),
+        ));
+
+        let successors = |road: &str, contact_point: ContactPoint| {
+            drive
+                .successor_lanes(road, -1, contact_point)
+                .into_iter()
+                .map(|l| {
+                    (
+                        l.road.id.as_str(),
+                        l.lane_id,
+                        l.direction,
+                        l.junction.is_some(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            successors("1", ContactPoint::End),
+            [
+                ("2", -1, TravelDirection::Forward, true),
+                ("3", -1, TravelDirection::Backward, true),
+            ]
+        );
+        assert_eq!(
+            successors("4", ContactPoint::End),
+            [("5", -1, TravelDirection::Forward, true)]
+        );
+        assert_eq!(
+            successors("4", ContactPoint::Start),
+            [("1", -1, TravelDirection::Forward, false)]
+        );
+        assert!(successors("5", ContactPoint::End).is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+use crate::core::OpenDrive;
+use crate::junction::connection::Connection;
+use crate::junction::contact_point::ContactPoint;
+use crate::junction::Junction;
+use crate::lane::travel_direction::TravelDirection;
+use crate::road::Road;
+use crate::signal::controller::Controller;
+use crate::signal::Signal;
+use crate::units::prelude::*;
+use std::collections::HashSet;
+
+/// Which dynamic signal controls which connecting lane of which junction, see
+/// [`OpenDrive::traffic_light_map`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrafficLightMap<'a> {
+    pub entries: Vec<TrafficLightControl<'a>>,
+}
+
+/// A dynamic signal controlling the traffic from a lane of an incoming road onto a lane of a
+/// connecting road.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrafficLightControl<'a> {
+    pub junction: &'a Junction,
+    pub connection: &'a Connection,
+    pub incoming_road: &'a Road,
+    /// Lane of the incoming road, [`crate::junction::lane_link::LaneLink::from`]
+    pub incoming_lane: i64,
+    /// The connecting road or, for direct junctions, the linked road
+    pub connecting_road: &'a Road,
+    /// Lane of the connecting road, [`crate::junction::lane_link::LaneLink::to`]
+    pub connecting_lane: i64,
+    pub signal: &'a Signal,
+    /// The controller the signal is controlled by, if any
+    pub controller: Option<&'a Controller>,
+}
+
+impl<'a> TrafficLightMap<'a> {
+    /// The controls of the given lane of a connecting road
+    pub fn controls_of_lane<'b>(
+        &'b self,
+        connecting_road: &'b str,
+        lane_id: i64,
+    ) -> impl Iterator<Item = &'b TrafficLightControl<'a>> {
+        self.entries.iter().filter(move |e| {
+            e.connecting_road.id == connecting_road && e.connecting_lane == lane_id
+        })
+    }
+
+    /// The controls of the signal with the given id
+    pub fn controls_of_signal<'b>(
+        &'b self,
+        signal_id: &'b str,
+    ) -> impl Iterator<Item = &'b TrafficLightControl<'a>> {
+        self.entries
+            .iter()
+            .filter(move |e| e.signal.id == signal_id)
+    }
+
+    /// The controls within the junction with the given id
+    pub fn controls_of_junction<'b>(
+        &'b self,
+        junction_id: &'b str,
+    ) -> impl Iterator<Item = &'b TrafficLightControl<'a>> {
+        self.entries
+            .iter()
+            .filter(move |e| e.junction.id == junction_id)
+    }
+}
+
+impl OpenDrive {
+    /// Maps the dynamic signals to the junction connections they control. A signal controls a
+    /// `<laneLink>` of a connection if it applies to the incoming lane when approaching the
+    /// junction, or to the connecting lane when travelling through the junction, considering the
+    /// `<validity>`, `@orientation` and `<signalReference>`s of the signal.
+    pub fn traffic_light_map(&self) -> TrafficLightMap<'_> {
+        let mut entries = Vec::new();
+        // a signal controlling a lane link by the ids of the junction, connection and signal
+        let mut seen = HashSet::new();
+
+        for junction in &self.junction {
+            for connection in &junction.connection {
+                let find = |id: Option<&str>| self.road.iter().find(|r| Some(r.id.as_str()) == id);
+                let incoming_road = find(connection.incoming_road.as_deref());
+                let connecting_road = find(
+                    connection
+                        .connecting_road
+                        .as_deref()
+                        .or(connection.linked_road.as_deref()),
+                );
+                let (incoming_road, connecting_road) = match (incoming_road, connecting_road) {
+                    (Some(incoming), Some(connecting)) => (incoming, connecting),
+                    _ => continue,
+                };
+
+                let approach = match incoming_road.junction_contact_point(&junction.id) {
+                    Some(ContactPoint::End) => TravelDirection::Forward,
+                    Some(ContactPoint::Start) => TravelDirection::Backward,
+                    None => continue,
+                };
+                let through = match connection.contact_point {
+                    Some(ContactPoint::End) => TravelDirection::Backward,
+                    _ => TravelDirection::Forward,
+                };
+
+                for lane_link in &connection.lane_link {
+                    let signals = self
+                        .dynamic_signals(incoming_road, lane_link.from, approach)
+                        .chain(self.dynamic_signals(connecting_road, lane_link.to, through));
+                    for signal in signals {
+                        let key = (
+                            junction.id.as_str(),
+                            connection.id.as_str(),
+                            lane_link.from,
+                            lane_link.to,
+                            signal.id.as_str(),
+                        );
+                        if !seen.insert(key) {
+                            continue;
+                        }
+                        entries.push(TrafficLightControl {
+                            junction,
+                            connection,
+                            incoming_road,
+                            incoming_lane: lane_link.from,
+                            connecting_road,
+                            connecting_lane: lane_link.to,
+                            signal,
+                            controller: self.controller_of(&signal.id),
+                        });
+                    }
+                }
+            }
+        }
+
+        TrafficLightMap { entries }
+    }
+
+    /// All dynamic signals applying to the given lane of the given road when travelling it in
+    /// `direction` from one end to the other
+    fn dynamic_signals<'a>(
+        &'a self,
+        road: &'a Road,
+        lane_id: i64,
+        direction: TravelDirection,
+    ) -> impl Iterator<Item = &'a Signal> + 'a {
+        let start = match direction {
+            TravelDirection::Backward => road.length,
+            _ => Length::new::<meter>(0.0),
+        };
+        self.upcoming_signals_for_lane(&road.id, lane_id, start, direction)
+            .into_iter()
+            .flatten()
+            .map(|s| s.signal)
+            .filter(|signal| signal.dynamic)
+    }
+
+    /// The controller that controls the signal with the given id
+    pub fn controller_of(&self, signal_id: &str) -> Option<&Controller> {
+        self.controller
+            .iter()
+            .find(|c| c.control.iter().any(|c| c.signal_id == signal_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+
+    #[test]
+    pub fn test_traffic_light_map() {
+        let connecting = |id: &str| {
+            TestRoad::new(id)
+                .length(10.0)
+                .at(100.0, 0.0, 0.0)
+                .junction("J")
+                .link(r#"<predecessor elementType="road" elementId="1" contactPoint="end"/>"#)
+                .sides(&[], &[("driving", 3.0)])
+        };
+        let drive = parse(format!(
+            r#"{}{}{}
+                <controller id="C">
+                    <control signalId="light"/>
+                </controller>
+                <junction id="J">
+                    <connection id="0" incomingRoad="1" connectingRoad="2" contactPoint="start">
+                        <laneLink from="-1" to="-1"/>
+                    </connection>
+                    <connection id="1" incomingRoad="1" connectingRoad="3" contactPoint="start">
+                        <laneLink from="-2" to="-1"/>
+                    </connection>
+                    <controller id="C"/>
+                </junction>"#,
+            TestRoad::new("1")
+                .link(r#"<successor elementType="junction" elementId="J"/>"#)
+                .sides(&[], &[("driving", 3.0), ("driving", 3.0)])
+                .signals(
+                    r#"<signal s="98" t="-7" id="light" dynamic="yes" orientation="+" zOffset="0" type="1000001" subtype="-1">
+                        <validity fromLane="-1" toLane="-1"/>
+                    </signal>
+                    <signal s="50" t="-7" id="sign" dynamic="no" orientation="+" zOffset="0" type="205" subtype="-1"/>"#
+                ),
+            connecting("2"),
+            connecting("3"),
+        ));
+
+        let map = drive.traffic_light_map();
+        assert_eq!(1, map.entries.len());
+        let control = map.controls_of_lane("2", -1).next().unwrap();
+        assert_eq!("light", control.signal.id);
+        assert_eq!(-1, control.incoming_lane);
+        assert_eq!(Some("C"), control.controller.map(|c| c.id.as_str()));
+        assert!(map.controls_of_lane("3", -1).next().is_none());
+        assert_eq!(1, map.controls_of_signal("light").count());
+        assert_eq!(1, map.controls_of_junction("J").count());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::core::OpenDrive;
+use crate::junction::connection::Connection;
+use crate::junction::contact_point::ContactPoint;
+use crate::junction::Junction;
+use crate::road::Road;
+use crate::units::prelude::*;
+use std::f64::consts::PI;
+
+/// Turn angles up to this value are considered to go straight
+const STRAIGHT_THRESHOLD_DEGREE: f64 = 30.0;
+/// Turn angles beyond this value are considered to be U-turns
+const U_TURN_THRESHOLD_DEGREE: f64 = 150.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TurnDirection {
+    Straight,
+    Left,
+    Right,
+    UTurn,
+}
+
+/// Classification of a single connection of a junction, see [`OpenDrive::turn_table`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TurnRelation<'a> {
+    pub connection: &'a Connection,
+    pub incoming_road: &'a Road,
+    /// The connecting road or, for direct junctions, the linked road
+    pub outgoing_road: &'a Road,
+    /// Change of heading from entering to leaving the junction, positive for left turns
+    pub angle: Angle,
+    pub direction: TurnDirection,
+}
+
+impl OpenDrive {
+    /// Classifies each connection of the given junction by the change of heading between the end
+    /// of the incoming road and the exit of the connection. Connections that refer to unknown
+    /// roads or whose incoming road is not linked to the junction are skipped. Returns `None` if
+    /// there is no such junction.
+    pub fn turn_table(&self, junction_id: &str) -> Option<Vec<TurnRelation<'_>>> {
+        let junction = self.junction.iter().find(|j| j.id == junction_id)?;
+        Some(
+            junction
+                .connection
+                .iter()
+                .filter_map(|connection| self.turn_relation(junction, connection))
+                .collect(),
+        )
+    }
+
+    fn turn_relation<'a>(
+        &'a self,
+        junction: &Junction,
+        connection: &'a Connection,
+    ) -> Option<TurnRelation<'a>> {
+        let find = |id: &str| self.road.iter().find(|r| r.id == id);
+        let incoming_road = find(connection.incoming_road.as_deref()?)?;
+        let outgoing_road = find(
+            connection
+                .connecting_road
+                .as_deref()
+                .or(connection.linked_road.as_deref())?,
+        )?;
+
+        // heading when arriving at the junction
+        let incoming = match incoming_road.junction_contact_point(&junction.id)? {
+            ContactPoint::End => Self::heading_at(incoming_road, ContactPoint::End, false),
+            ContactPoint::Start => Self::heading_at(incoming_road, ContactPoint::Start, true),
+        };
+
+        // heading when leaving the connecting road or entering the linked road
+        let contact_point = connection
+            .contact_point
+            .clone()
+            .unwrap_or(ContactPoint::Start);
+        let outgoing = match (&connection.connecting_road, contact_point) {
+            (Some(_), ContactPoint::Start) => {
+                Self::heading_at(outgoing_road, ContactPoint::End, false)
+            }
+            (Some(_), ContactPoint::End) => {
+                Self::heading_at(outgoing_road, ContactPoint::Start, true)
+            }
+            (None, ContactPoint::Start) => {
+                Self::heading_at(outgoing_road, ContactPoint::Start, false)
+            }
+            (None, ContactPoint::End) => Self::heading_at(outgoing_road, ContactPoint::End, true),
+        };
+
+        let angle = (outgoing - incoming + PI).rem_euclid(2.0 * PI) - PI;
+        let degrees = angle.to_degrees();
+        let direction = if degrees.abs() <= STRAIGHT_THRESHOLD_DEGREE {
+            TurnDirection::Straight
+        } else if degrees.abs() >= U_TURN_THRESHOLD_DEGREE {
+            TurnDirection::UTurn
+        } else if degrees > 0.0 {
+            TurnDirection::Left
+        } else {
+            TurnDirection::Right
+        };
+
+        Some(TurnRelation {
+            connection,
+            incoming_road,
+            outgoing_road,
+            angle: Angle::new::<degree>(degrees),
+            direction,
+        })
+    }
+
+    fn heading_at(road: &Road, contact_point: ContactPoint, reversed: bool) -> f64 {
+        let s = match contact_point {
+            ContactPoint::Start => Length::new::<meter>(0.0),
+            ContactPoint::End => road.length,
+        };
+        let heading = road.reference_pose(s).hdg.get::<radian>();
+        if reversed {
+            heading + PI
+        } else {
+            heading
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::query::turns::TurnDirection;
+
+    #[test]
+    pub fn test_turn_table() {
+        let road = |id: &str| TestRoad::new(id).length(10.0);
+        let connecting = |id: &str| road(id).junction("J");
+        let quarter = std::f64::consts::FRAC_PI_2 / 10.0;
+        let drive = parse(format!(
+            r#"{}{}{}{}{}{}
+            <junction id="J">
+                <connection id="0" incomingRoad="1" connectingRoad="10" contactPoint="start"/>
+                <connection id="1" incomingRoad="1" connectingRoad="11" contactPoint="start"/>
+                <connection id="2" incomingRoad="1" connectingRoad="12" contactPoint="start"/>
+                <connection id="3" incomingRoad="2" connectingRoad="10" contactPoint="start"/>
+                <connection id="4" incomingRoad="1" connectingRoad="13" contactPoint="end"/>
+            </junction>"#,
+            road("1")
+                .at(-10.0, 0.0, 0.0)
+                .link(r#"<successor elementType="junction" elementId="J"/>"#),
+            road("2").link(r#"<predecessor elementType="junction" elementId="J"/>"#),
+            connecting("10"),
+            connecting("11").arc(quarter),
+            connecting("12").arc(-quarter),
+            // travelled against its reference line
+            connecting("13").at(10.0, 0.0, std::f64::consts::PI),
+        ));
+
+        let turns = drive
+            .turn_table("J")
+            .unwrap()
+            .into_iter()
+            .map(|t| (t.connection.id.as_str(), t.direction))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            turns,
+            [
+                ("0", TurnDirection::Straight),
+                ("1", TurnDirection::Left),
+                ("2", TurnDirection::Right),
+                ("3", TurnDirection::UTurn),
+                ("4", TurnDirection::Straight),
+            ]
+        );
+        assert!(drive.turn_table("K").is_none());
+    }
+}
@@ -1,7 +1,6 @@
 use crate::railroad::segment_side::SegmentSide;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Each `<platform>` element is valid on one or more track segments. The `<segment>` element must
 /// be specified.
@@ -22,29 +21,33 @@ impl Segment {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
+            options,
             "roadId" => self.road_id.as_str(),
-            "sEnd" => self.s_end.value.to_scientific_string().as_str(),
+            "sEnd" => options.format("sEnd", self.s_end.get::<meter>()).as_str(),
             "side" => self.side.as_str(),
-            "sStart" => self.s_start.value.to_scientific_string().as_str(),
+            "sStart" => options.format("sStart", self.s_start.get::<meter>()).as_str(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Segment
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
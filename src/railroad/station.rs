@@ -24,10 +24,14 @@ impl Station {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "id" => Some(self.id.as_str()),
             "name" => Some(self.name.as_str()),
             "type" => self.r#type.as_ref().map(StationType::as_str),
@@ -36,19 +40,21 @@ impl Station {
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for platform in &self.platform {
-            visit_children!(visitor, "platform" => platform);
+            visit_children!(visitor, options, "platform" => platform);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Station
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -67,7 +73,7 @@ where
             id: read.attribute("id")?,
             name: read.attribute("name")?,
             r#type: read.attribute_opt("type")?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
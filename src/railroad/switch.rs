@@ -25,10 +25,14 @@ impl Switch {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes!(
             visitor,
+            options,
             "id" => self.id.as_str(),
             "name" => self.name.as_str(),
             "position" => self.position.as_str(),
@@ -37,25 +41,28 @@ impl Switch {
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         visit_children!(
             visitor,
+            options,
             "mainTrack" => self.main_track,
             "sideTrack" => self.side_track,
         );
 
         if let Some(partner) = &self.partner {
-            visit_children!(visitor, "partner" => partner);
+            visit_children!(visitor, options, "partner" => partner);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Switch
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -80,7 +87,7 @@ where
             id: read.attribute("id")?,
             name: read.attribute("name")?,
             position: read.attribute("position")?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
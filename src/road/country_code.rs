@@ -15,6 +15,26 @@ impl CountryCode {
             Self::Iso3166alpha3(v) => v,
         }
     }
+
+    /// The ISO 3166-1 alpha-2 code of the country, also for the country names of ASAM OpenDRIVE
+    /// 1.4, or `None` for `OpenDRIVE` and alpha-3 codes
+    #[allow(deprecated)]
+    pub fn iso3166alpha2(&self) -> Option<&str> {
+        match self {
+            Self::CountryCodeDeprecated(code) => match code {
+                CountryCodeDeprecated::OpenDRIVE => None,
+                CountryCodeDeprecated::Austria => Some("AT"),
+                CountryCodeDeprecated::Brazil => Some("BR"),
+                CountryCodeDeprecated::China => Some("CN"),
+                CountryCodeDeprecated::France => Some("FR"),
+                CountryCodeDeprecated::Germany => Some("DE"),
+                CountryCodeDeprecated::Italy => Some("IT"),
+                CountryCodeDeprecated::Switzerland => Some("CH"),
+            },
+            Self::Iso3166alpha2(v) => Some(v),
+            Self::Iso3166alpha3(_) => None,
+        }
+    }
 }
 
 impl core::str::FromStr for CountryCode {
@@ -1,11 +1,8 @@
 use crate::junction::crg_mode::CrgMode;
 use crate::junction::crg_purpose::CrgPurpose;
 use crate::object::orientation::Orientation;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::angle::radian;
-use uom::si::f64::Angle;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Data described in OpenCRG is represented by the `<CRG>` element within the `<surface>` element.
 #[derive(Debug, Clone, PartialEq)]
@@ -43,36 +40,40 @@ impl Crg {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "file" => Some(self.file.as_str()),
-            "hOffset" => self.h_offset.map(|v| v.value.to_scientific_string()).as_deref(),
+            "hOffset" => self.h_offset.map(|v| options.format("hOffset", v.get::<radian>())).as_deref(),
             "mode" => Some(self.mode.as_str()),
             "orientation" => Some(self.orientation.as_str()),
             "purpose" => self.purpose.as_ref().map(CrgPurpose::as_str),
-            "sEnd" => Some(self.s_end.value.to_scientific_string()).as_deref(),
-            "sOffset" => self.s_offset.map(|v| v.value.to_scientific_string()).as_deref(),
-            "sStart" => Some(self.s_start.value.to_scientific_string()).as_deref(),
-            "tOffset" => self.t_offset.map(|v| v.value.to_scientific_string()).as_deref(),
-            "zOffset" => self.z_offset.map(|v| v.value.to_scientific_string()).as_deref(),
-            "zScale" => self.z_scale.map(|v| v.to_scientific_string()).as_deref(),
+            "sEnd" => Some(options.format("sEnd", self.s_end.get::<meter>())).as_deref(),
+            "sOffset" => self.s_offset.map(|v| options.format("sOffset", v.get::<meter>())).as_deref(),
+            "sStart" => Some(options.format("sStart", self.s_start.get::<meter>())).as_deref(),
+            "tOffset" => self.t_offset.map(|v| options.format("tOffset", v.get::<meter>())).as_deref(),
+            "zOffset" => self.z_offset.map(|v| options.format("zOffset", v.get::<meter>())).as_deref(),
+            "zScale" => self.z_scale.map(|v| options.format("zScale", v)).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Crg
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
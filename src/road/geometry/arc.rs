@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::curvature::radian_per_meter;
-use uom::si::f64::Curvature;
 
 /// An arc describes a road reference line with constant curvature. In ASAM OpenDRIVE, an arc is
 /// represented by an `<arc>` element within the `<geometry>` element.
@@ -11,30 +10,46 @@ pub struct Arc {
 }
 
 impl Arc {
+    /// Position `(u, v)` and heading in the local coordinate system of the geometry element at
+    /// `ds` along the arc
+    pub fn local(&self, ds: f64) -> (f64, f64, f64) {
+        let curvature = self.curvature.get::<radian_per_meter>();
+        if curvature.abs() < f64::EPSILON {
+            (ds, 0.0, 0.0)
+        } else {
+            let hdg = curvature * ds;
+            (hdg.sin() / curvature, (1.0 - hdg.cos()) / curvature, hdg)
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
-            "curvature" => &self.curvature.value.to_scientific_string(),
+            options,
+            "curvature" => &options.format("curvature", self.curvature.get::<radian_per_meter>()),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Arc
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -1,14 +1,14 @@
 use crate::core::additional_data::AdditionalData;
+use crate::parser::ValueRange;
+use crate::units::prelude::*;
 use arc::Arc;
 use geometry_type::GeometryType;
 use line::Line;
 use param_poly_3::ParamPoly3;
 use poly_3::Poly3;
+use pose::Pose;
 use spiral::Spiral;
 use std::borrow::Cow;
-use uom::si::angle::radian;
-use uom::si::f64::{Angle, Length};
-use uom::si::length::meter;
 
 pub mod arc;
 pub mod geometry_type;
@@ -16,8 +16,12 @@ pub mod line;
 pub mod param_poly_3;
 pub mod param_poly_3_p_range;
 pub mod plan_view;
+pub mod point;
 pub mod poly_3;
+pub mod polygon;
+pub mod pose;
 pub mod spiral;
+pub mod synthesis;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Geometry {
@@ -36,42 +40,68 @@ pub struct Geometry {
 }
 
 impl Geometry {
+    /// The pose on the reference line at `ds` from the start of this element, `ds` is clamped to
+    /// the length of the element.
+    pub fn pose_at(&self, ds: Length) -> Pose {
+        let length = self.length.get::<meter>();
+        let ds = ds.get::<meter>().clamp(0.0, length.max(0.0));
+        let (u, v, hdg) = match &self.r#type {
+            GeometryType::Line(_) => (ds, 0.0, 0.0),
+            GeometryType::Spiral(spiral) => spiral.local(ds, length),
+            GeometryType::Arc(arc) => arc.local(ds),
+            GeometryType::Poly3(poly3) => poly3.local(ds),
+            GeometryType::ParamPoly3(poly3) => poly3.local(ds, length),
+        };
+        let (sin, cos) = self.hdg.get::<radian>().sin_cos();
+        Pose {
+            x: self.x + Length::new::<meter>(u * cos - v * sin),
+            y: self.y + Length::new::<meter>(u * sin + v * cos),
+            hdg: self.hdg + Angle::new::<radian>(hdg),
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes!(
             visitor,
-            "hdg" => &self.hdg.value.to_scientific_string(),
-            "length" => &self.length.value.to_scientific_string(),
-            "s" => &self.s.value.to_scientific_string(),
-            "x" => &self.x.value.to_scientific_string(),
-            "y" => &self.y.value.to_scientific_string(),
+            options,
+            "hdg" => &options.format("hdg", self.hdg.get::<radian>()),
+            "length" => &options.format("length", self.length.get::<meter>()),
+            "s" => &options.format("s", self.s.get::<meter>()),
+            "x" => &options.format("x", self.x.get::<meter>()),
+            "y" => &options.format("y", self.y.get::<meter>()),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         match &self.r#type {
-            GeometryType::Line(value) => visit_children!(visitor, "line" => value),
-            GeometryType::Spiral(value) => visit_children!(visitor, "spiral" => value),
-            GeometryType::Arc(value) => visit_children!(visitor, "arc" => value),
-            GeometryType::Poly3(value) => visit_children!(visitor, "poly3" => value),
+            GeometryType::Line(value) => visit_children!(visitor, options, "line" => value),
+            GeometryType::Spiral(value) => visit_children!(visitor, options, "spiral" => value),
+            GeometryType::Arc(value) => visit_children!(visitor, options, "arc" => value),
+            GeometryType::Poly3(value) => visit_children!(visitor, options, "poly3" => value),
             GeometryType::ParamPoly3(value) => {
-                visit_children!(visitor, "paramPoly3" => value)
+                visit_children!(visitor, options, "paramPoly3" => value)
             }
         }
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Geometry
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -90,8 +120,12 @@ where
         );
 
         Ok(Self {
-            hdg: read.attribute("hdg").map(Angle::new::<radian>)?,
-            length: read.attribute("length").map(Length::new::<meter>)?,
+            hdg: read
+                .attribute_in("hdg", ValueRange::Heading)
+                .map(Angle::new::<radian>)?,
+            length: read
+                .attribute_in("length", ValueRange::NON_NEGATIVE)
+                .map(Length::new::<meter>)?,
             s: read.attribute("s").map(Length::new::<meter>)?,
             x: read.attribute("x").map(Length::new::<meter>)?,
             y: read.attribute("y").map(Length::new::<meter>)?,
@@ -102,7 +136,7 @@ where
                     core::any::type_name::<GeometryType>(),
                 )
             })?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
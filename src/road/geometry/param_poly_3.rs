@@ -46,22 +46,49 @@ impl ParamPoly3 {
         self.a_v + (self.b_v * p) + (self.c_v * p * p) + (self.d_v * p * p * p)
     }
 
+    /// First derivative of [`ParamPoly3::u`]
+    #[inline]
+    pub fn du(&self, p: f64) -> f64 {
+        self.b_u + (2.0 * self.c_u * p) + (3.0 * self.d_u * p * p)
+    }
+
+    /// First derivative of [`ParamPoly3::v`]
+    #[inline]
+    pub fn dv(&self, p: f64) -> f64 {
+        self.b_v + (2.0 * self.c_v * p) + (3.0 * self.d_v * p * p)
+    }
+
+    /// Position `(u, v)` and heading in the local coordinate system of the geometry element at
+    /// `ds` along the curve with the given total `length`. For [`ParamPoly3pRange::ArcLength`],
+    /// `p` is assumed to be equal to the arc length.
+    pub fn local(&self, ds: f64, length: f64) -> (f64, f64, f64) {
+        let p = match self.p_range {
+            ParamPoly3pRange::ArcLength => ds,
+            ParamPoly3pRange::Normalized if length > 0.0 => ds / length,
+            ParamPoly3pRange::Normalized => 0.0,
+        };
+        (self.u(p), self.v(p), self.dv(p).atan2(self.du(p)))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
-            "aU" => &self.a_u.to_scientific_string(),
-            "aV" => &self.a_v.to_scientific_string(),
-            "bU" => &self.b_u.to_scientific_string(),
-            "bV" => &self.b_v.to_scientific_string(),
-            "cU" => &self.c_u.to_scientific_string(),
-            "cV" => &self.c_v.to_scientific_string(),
-            "dU" => &self.d_u.to_scientific_string(),
-            "dV" => &self.d_v.to_scientific_string(),
+            options,
+            "aU" => &options.format("aU", self.a_u),
+            "aV" => &options.format("aV", self.a_v),
+            "bU" => &options.format("bU", self.b_u),
+            "bV" => &options.format("bV", self.b_v),
+            "cU" => &options.format("cU", self.c_u),
+            "cV" => &options.format("cV", self.c_v),
+            "dU" => &options.format("dU", self.d_u),
+            "dV" => &options.format("dV", self.d_v),
             "pRange" => self.p_range.as_str(),
         )
     }
@@ -69,15 +96,16 @@ impl ParamPoly3 {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for ParamPoly3
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
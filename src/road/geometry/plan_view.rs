@@ -1,5 +1,8 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::validated::SortedByS;
+use crate::road::geometry::pose::Pose;
 use crate::road::geometry::Geometry;
+use crate::units::prelude::*;
 use std::borrow::Cow;
 use vec1::Vec1;
 
@@ -12,30 +15,67 @@ pub struct PlanView {
 }
 
 impl PlanView {
+    /// A plan view consisting of the given geometries, `None` if there are none
+    pub fn from_geometries(geometries: SortedByS<Geometry>) -> Option<Self> {
+        Some(Self {
+            geometry: Vec1::try_from_vec(geometries.into_inner()).ok()?,
+            additional_data: AdditionalData::default(),
+        })
+    }
+
+    /// A plan view consisting of the given geometry only
+    pub fn new(geometry: Geometry) -> Self {
+        Self {
+            geometry: Vec1::new(geometry),
+            additional_data: AdditionalData::default(),
+        }
+    }
+
+    /// The geometry element that contains the given s-coordinate. Positions before the first
+    /// element resolve to the first element.
+    pub fn geometry_at(&self, s: Length) -> &Geometry {
+        self.geometry
+            .iter()
+            .rev()
+            .find(|g| g.s <= s)
+            .unwrap_or_else(|| self.geometry.first())
+    }
+
+    /// The pose on the reference line at the given s-coordinate
+    pub fn pose_at(&self, s: Length) -> Pose {
+        let geometry = self.geometry_at(s);
+        geometry.pose_at(s - geometry.s)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for geometry in &self.geometry {
-            visit_children!(visitor, "geometry" => geometry);
+            visit_children!(visitor, options, "geometry" => geometry);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for PlanView
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -51,7 +91,7 @@ where
 
         Ok(Self {
             geometry: Vec1::try_from_vec(geometry).unwrap(),
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -0,0 +1,10 @@
+use crate::units::prelude::*;
+
+/// Position in the inertial x/y-plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    /// Position (x inertial)
+    pub x: Length,
+    /// Position (y inertial)
+    pub y: Length,
+}
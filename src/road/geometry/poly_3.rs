@@ -23,33 +23,61 @@ impl Poly3 {
         self.a + (self.b * u) + (self.c * u * u) + (self.d * u * u * u)
     }
 
+    /// First derivative of [`Poly3::v`]
+    pub fn dv(&self, u: f64) -> f64 {
+        self.b + (2.0 * self.c * u) + (3.0 * self.d * u * u)
+    }
+
+    /// Position `(u, v)` and heading in the local coordinate system of the geometry element at
+    /// `ds` along the curve. Because `u` is not the arc length of the curve, the matching `u` is
+    /// found by numerically integrating the arc length.
+    pub fn local(&self, ds: f64) -> (f64, f64, f64) {
+        let step = (ds / 1000.0).clamp(1e-4, 0.1);
+        let arc = |u: f64| (1.0 + self.dv(u).powi(2)).sqrt();
+        let (mut u, mut s) = (0.0, 0.0);
+        while s < ds {
+            let delta = step * (arc(u) + 4.0 * arc(u + step / 2.0) + arc(u + step)) / 6.0;
+            if s + delta >= ds {
+                u += step * (ds - s) / delta;
+                break;
+            }
+            s += delta;
+            u += step;
+        }
+        (u, self.v(u) - self.a, self.dv(u).atan())
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
+            options,
+            "a" => &options.format("a", self.a),
+            "b" => &options.format("b", self.b),
+            "c" => &options.format("c", self.c),
+            "d" => &options.format("d", self.d),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Poly3
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
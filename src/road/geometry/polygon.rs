@@ -0,0 +1,94 @@
+//! Tests of polygons of the inertial x/y-plane, given by their corners in order. A polygon with a
+//! single corner is a point, one with two corners a line segment.
+
+use crate::road::geometry::point::Point;
+use crate::units::prelude::*;
+
+fn xy(point: &Point) -> (f64, f64) {
+    (point.x.get::<meter>(), point.y.get::<meter>())
+}
+
+/// The edges of the polygon, including the one closing it
+fn edges(polygon: &[Point]) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+    (0..polygon.len()).map(|i| (xy(&polygon[i]), xy(&polygon[(i + 1) % polygon.len()])))
+}
+
+/// Whether the point lies within the polygon by the even-odd rule
+pub(crate) fn contains(polygon: &[Point], point: &Point) -> bool {
+    let (x, y) = xy(point);
+    let mut inside = false;
+    for ((x0, y0), (x1, y1)) in edges(polygon) {
+        if (y0 > y) != (y1 > y) && x < x0 + (y - y0) / (y1 - y0) * (x1 - x0) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// The distance of the point to the polygon, zero within it, infinite for an empty polygon
+pub(crate) fn distance(polygon: &[Point], point: &Point) -> f64 {
+    if polygon.len() > 2 && contains(polygon, point) {
+        return 0.0;
+    }
+    let (x, y) = xy(point);
+    edges(polygon)
+        .map(|((x0, y0), (x1, y1))| {
+            let (dx, dy) = (x1 - x0, y1 - y0);
+            let length = dx * dx + dy * dy;
+            let f = if length > 0.0 {
+                (((x - x0) * dx + (y - y0) * dy) / length).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            (x0 + f * dx - x).hypot(y0 + f * dy - y)
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// The smallest axis-aligned box containing the polygon, `None` for an empty polygon
+pub(crate) fn bounds(polygon: &[Point]) -> Option<(Point, Point)> {
+    let (first, rest) = polygon.split_first()?;
+    Some(rest.iter().fold((*first, *first), |(min, max), p| {
+        (
+            Point {
+                x: Length::new::<meter>(min.x.get::<meter>().min(p.x.get::<meter>())),
+                y: Length::new::<meter>(min.y.get::<meter>().min(p.y.get::<meter>())),
+            },
+            Point {
+                x: Length::new::<meter>(max.x.get::<meter>().max(p.x.get::<meter>())),
+                y: Length::new::<meter>(max.y.get::<meter>().max(p.y.get::<meter>())),
+            },
+        )
+    }))
+}
+
+/// Whether the polygon and the axis-aligned box from `min` to `max` overlap
+pub(crate) fn intersects_aabb(polygon: &[Point], min: &Point, max: &Point) -> bool {
+    let ((min_x, min_y), (max_x, max_y)) = (xy(min), xy(max));
+    let inside = |(x, y): (f64, f64)| (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y);
+    // an edge crosses the box, clipped to it by Liang-Barsky
+    let crosses = |((x0, y0), (x1, y1)): ((f64, f64), (f64, f64))| {
+        let (dx, dy) = (x1 - x0, y1 - y0);
+        let (mut from, mut to) = (0.0_f64, 1.0_f64);
+        for (p, q) in [
+            (-dx, x0 - min_x),
+            (dx, max_x - x0),
+            (-dy, y0 - min_y),
+            (dy, max_y - y0),
+        ] {
+            if p == 0.0 {
+                if q < 0.0 {
+                    return false;
+                }
+            } else if p < 0.0 {
+                from = from.max(q / p);
+            } else {
+                to = to.min(q / p);
+            }
+        }
+        from <= to
+    };
+    polygon.iter().any(|p| inside(xy(p)))
+        || edges(polygon).any(crosses)
+        || (polygon.len() > 2 && contains(polygon, min))
+}
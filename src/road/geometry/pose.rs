@@ -0,0 +1,33 @@
+use crate::road::geometry::point::Point;
+use crate::units::prelude::*;
+
+/// Position and heading in the inertial x/y-plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pose {
+    /// Position (x inertial)
+    pub x: Length,
+    /// Position (y inertial)
+    pub y: Length,
+    /// Orientation (inertial heading)
+    pub hdg: Angle,
+}
+
+impl Pose {
+    #[inline]
+    pub fn point(&self) -> Point {
+        Point {
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    /// Transforms the position `(u, v)` of the local coordinate system defined by this pose into
+    /// the inertial system
+    pub fn transform(&self, u: Length, v: Length) -> Point {
+        let (sin, cos) = self.hdg.get::<radian>().sin_cos();
+        Point {
+            x: self.x + u * cos - v * sin,
+            y: self.y + u * sin + v * cos,
+        }
+    }
+}
@@ -1,6 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::curvature::radian_per_meter;
-use uom::si::f64::Curvature;
 
 /// In ASAM OpenDRIVE, a spiral is represented by a `<spiral>` element within the `<geometry>`
 /// element.
@@ -15,31 +14,67 @@ pub struct Spiral {
 }
 
 impl Spiral {
+    /// Position `(u, v)` and heading in the local coordinate system of the geometry element at
+    /// `ds` along the spiral of the given total `length`. The Fresnel integrals are solved
+    /// numerically.
+    pub fn local(&self, ds: f64, length: f64) -> (f64, f64, f64) {
+        let start = self.curvature_start.get::<radian_per_meter>();
+        let end = self.curvature_end.get::<radian_per_meter>();
+        let change = if length > 0.0 {
+            (end - start) / length
+        } else {
+            0.0
+        };
+        let hdg = |s: f64| start * s + change * s * s / 2.0;
+
+        // composite simpson rule with an even number of steps of at most half a meter
+        let steps = ((ds.abs() * 2.0).ceil() as usize).max(8) * 2;
+        let step = ds / steps as f64;
+        let (mut u, mut v) = (0.0, 0.0);
+        for i in 0..=steps {
+            let weight = match i {
+                0 => 1.0,
+                _ if i == steps => 1.0,
+                _ if i % 2 == 1 => 4.0,
+                _ => 2.0,
+            };
+            let (sin, cos) = hdg(i as f64 * step).sin_cos();
+            u += weight * cos;
+            v += weight * sin;
+        }
+
+        (u * step / 3.0, v * step / 3.0, hdg(ds))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
-            "curvStart" => &self.curvature_start.value.to_scientific_string(),
-            "curvEnd" => &self.curvature_end.value.to_scientific_string(),
+            options,
+            "curvStart" => &options.format("curvStart", self.curvature_start.get::<radian_per_meter>()),
+            "curvEnd" => &options.format("curvEnd", self.curvature_end.get::<radian_per_meter>()),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Spiral
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -0,0 +1,352 @@
+use crate::core::additional_data::AdditionalData;
+use crate::road::geometry::arc::Arc;
+use crate::road::geometry::geometry_type::GeometryType;
+use crate::road::geometry::param_poly_3::ParamPoly3;
+use crate::road::geometry::param_poly_3_p_range::ParamPoly3pRange;
+use crate::road::geometry::plan_view::PlanView;
+use crate::road::geometry::pose::Pose;
+use crate::road::geometry::spiral::Spiral;
+use crate::road::geometry::Geometry;
+use crate::units::prelude::*;
+use std::f64::consts::PI;
+
+/// The length of the clothoids that are measured to derive the shape of a turn
+const SAMPLE_LENGTH: f64 = 1000.0;
+/// How often a connection that is not a single turn is halved into turns
+const SPLITS: usize = 3;
+
+/// A pose with the curvature of the reference line, an end of [`PlanView::connect`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveState {
+    pub pose: Pose,
+    pub curvature: Curvature,
+}
+
+impl From<Pose> for CurveState {
+    fn from(pose: Pose) -> Self {
+        Self {
+            pose,
+            curvature: Curvature::new::<radian_per_meter>(0.0),
+        }
+    }
+}
+
+/// The settings of [`PlanView::connect`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectOptions {
+    /// The length of the clothoids that take the curvature at the ends to zero and back, 10 m by
+    /// default. With zero, the curvature jumps at ends with a curvature.
+    pub transition: Length,
+    /// The share of each turn that is made on an arc between its clothoids, from 0 for pure
+    /// clothoids, the default, to 1 for a single arc with a jump of the curvature at its ends
+    pub arc_share: f64,
+}
+
+impl Default for ConnectOptions {
+    fn default() -> Self {
+        Self {
+            transition: Length::new::<meter>(10.0),
+            arc_share: 0.0,
+        }
+    }
+}
+
+impl PlanView {
+    /// Plan view records leading from the `start` to the `end` state, for ramps, lane merges and
+    /// connecting roads in junctions. Clothoids of [`ConnectOptions::transition`] take the
+    /// curvatures of the states to zero and back. In between, the connection is a turn of lines
+    /// and a symmetric clothoid-arc-clothoid curve if the heading lines meet ahead of the one and
+    /// behind the other pose, or otherwise is halved into such turns, like an S-curve. Both
+    /// make the curvature continuous. Connections that cannot be made of turns, like
+    /// reversing ones, are a cubic curve with continuous headings only. The records start at
+    /// `s = 0`.
+    pub fn connect(start: CurveState, end: CurveState, options: ConnectOptions) -> PlanView {
+        let transition = options.transition.get::<meter>().max(0.0);
+        let arc_share = options.arc_share.clamp(0.0, 1.0);
+        let (from, to) = (
+            start.curvature.get::<radian_per_meter>(),
+            end.curvature.get::<radian_per_meter>(),
+        );
+
+        let mut pieces = Vec::new();
+        let mut turn_start = start.pose;
+        if from != 0.0 && transition > 0.0 {
+            let piece = (transition, spiral(from, 0.0));
+            turn_start = geometry(0.0, start.pose, piece.clone()).pose_at(options.transition);
+            pieces.push(piece);
+        }
+        let mut turn_end = end.pose;
+        let mut last = None;
+        if to != 0.0 && transition > 0.0 {
+            let back = geometry(0.0, reversed(end.pose), (transition, spiral(-to, 0.0)))
+                .pose_at(options.transition);
+            turn_end = reversed(back);
+            last = Some((transition, spiral(0.0, to)));
+        }
+        pieces.extend(
+            turns(turn_start, turn_end, arc_share, SPLITS)
+                .unwrap_or_else(|| vec![cubic(turn_start, turn_end)]),
+        );
+        pieces.extend(last);
+
+        let mut pieces = pieces.into_iter().filter(|(length, _)| *length > 1e-9);
+        let first = pieces
+            .next()
+            .unwrap_or((0.0, GeometryType::Line(Default::default())));
+        let mut plan_view = PlanView::new(geometry(0.0, start.pose, first));
+        for piece in pieces {
+            let previous = plan_view.geometry.last();
+            let geometry = geometry(
+                (previous.s + previous.length).get::<meter>(),
+                previous.pose_at(previous.length),
+                piece,
+            );
+            plan_view.geometry.push(geometry);
+        }
+        plan_view
+    }
+}
+
+fn geometry(s: f64, start: Pose, (length, r#type): (f64, GeometryType)) -> Geometry {
+    Geometry {
+        hdg: start.hdg,
+        length: Length::new::<meter>(length),
+        s: Length::new::<meter>(s),
+        x: start.x,
+        y: start.y,
+        r#type,
+        additional_data: AdditionalData::default(),
+    }
+}
+
+fn spiral(from: f64, to: f64) -> GeometryType {
+    GeometryType::Spiral(Spiral {
+        curvature_start: Curvature::new::<radian_per_meter>(from),
+        curvature_end: Curvature::new::<radian_per_meter>(to),
+    })
+}
+
+fn reversed(pose: Pose) -> Pose {
+    Pose {
+        hdg: pose.hdg + Angle::new::<radian>(PI),
+        ..pose
+    }
+}
+
+/// The position of `end` in the coordinates of `start` and the change of the heading within
+/// `[-π, π)`
+fn local(start: Pose, end: Pose) -> (f64, f64, f64) {
+    let (sin, cos) = start.hdg.get::<radian>().sin_cos();
+    let (dx, dy) = (
+        (end.x - start.x).get::<meter>(),
+        (end.y - start.y).get::<meter>(),
+    );
+    let turn = ((end.hdg - start.hdg).get::<radian>() + PI).rem_euclid(2.0 * PI) - PI;
+    (dx * cos + dy * sin, dy * cos - dx * sin, turn)
+}
+
+/// Lines and turns without curvature at their ends from `start` to `end`, halving the connection
+/// up to `splits` times, `None` if that is not enough
+fn turns(
+    start: Pose,
+    end: Pose,
+    arc_share: f64,
+    splits: usize,
+) -> Option<Vec<(f64, GeometryType)>> {
+    let (u, v, turn) = local(start, end);
+    if turn.abs() < 1e-9 && v.abs() < 1e-9 && u >= 0.0 {
+        return Some(vec![(u, GeometryType::Line(Default::default()))]);
+    }
+    if let Some(pieces) = single_turn(u, v, turn, arc_share) {
+        return Some(pieces);
+    }
+    if splits == 0 {
+        return None;
+    }
+    // the middle of a symmetric S-curve, heading as far beyond the chord as the ends are before
+    let chord = start.hdg.get::<radian>() + v.atan2(u);
+    let middle = Pose {
+        x: (start.x + end.x) / 2.0,
+        y: (start.y + end.y) / 2.0,
+        hdg: Angle::new::<radian>(2.0 * chord - start.hdg.get::<radian>() - turn / 2.0),
+    };
+    let mut pieces = turns(start, middle, arc_share, splits - 1)?;
+    pieces.extend(turns(middle, end, arc_share, splits - 1)?);
+    Some(pieces)
+}
+
+/// A line, a clothoid-arc-clothoid curve and a line, if the heading lines meet ahead of the
+/// start and behind the end in the local coordinates of the start
+fn single_turn(u: f64, v: f64, turn: f64, arc_share: f64) -> Option<Vec<(f64, GeometryType)>> {
+    if turn.abs() < 1e-6 || turn.abs() > 0.9 * PI {
+        return None;
+    }
+    let (turn_sin, turn_cos) = turn.sin_cos();
+    let behind = v / turn_sin;
+    let ahead = u - behind * turn_cos;
+    if ahead <= 1e-9 || behind <= 1e-9 {
+        return None;
+    }
+    let tangent = ahead.min(behind);
+
+    // the shape of the curve only depends on the turn and the share of the arc, its size grows
+    // with its length, which is measured on a long sample for an accurate numeric integration
+    let curvature = turn / SAMPLE_LENGTH;
+    let (clothoid, arc) = (SAMPLE_LENGTH * (1.0 - arc_share), SAMPLE_LENGTH * arc_share);
+    let curve = |scale: f64| {
+        let curvature = curvature / scale;
+        [
+            (clothoid * scale, spiral(0.0, curvature)),
+            (
+                arc * scale,
+                GeometryType::Arc(Arc {
+                    curvature: Curvature::new::<radian_per_meter>(curvature),
+                }),
+            ),
+            (clothoid * scale, spiral(curvature, 0.0)),
+        ]
+    };
+    let mut sample_end = Pose {
+        x: Length::new::<meter>(0.0),
+        y: Length::new::<meter>(0.0),
+        hdg: Angle::new::<radian>(0.0),
+    };
+    for piece in curve(1.0).into_iter().filter(|(length, _)| *length > 0.0) {
+        let length = Length::new::<meter>(piece.0);
+        sample_end = geometry(0.0, sample_end, piece).pose_at(length);
+    }
+    let sample_tangent =
+        sample_end.x.get::<meter>() - sample_end.y.get::<meter>() * turn_cos / turn_sin;
+
+    let line = |length: f64| (length, GeometryType::Line(Default::default()));
+    let mut pieces = vec![line(ahead - tangent)];
+    pieces.extend(curve(tangent / sample_tangent));
+    pieces.push(line(behind - tangent));
+    Some(pieces)
+}
+
+/// The cubic curve of a normalized parametric polynomial between the poses. The tangents at the
+/// ends are as long as the arc of a circle through both ends would be.
+fn cubic(start: Pose, end: Pose) -> (f64, GeometryType) {
+    let (u, v, turn) = local(start, end);
+    let chord = u.hypot(v);
+    let tangent = if turn.abs() < 1e-9 {
+        chord
+    } else {
+        chord * (turn / 2.0) / (turn / 2.0).sin()
+    };
+    let (turn_sin, turn_cos) = turn.sin_cos();
+    let poly = ParamPoly3 {
+        a_u: 0.0,
+        a_v: 0.0,
+        b_u: tangent,
+        b_v: 0.0,
+        c_u: 3.0 * u - tangent * (2.0 + turn_cos),
+        c_v: 3.0 * v - tangent * turn_sin,
+        d_u: -2.0 * u + tangent * (1.0 + turn_cos),
+        d_v: -2.0 * v + tangent * turn_sin,
+        p_range: ParamPoly3pRange::Normalized,
+    };
+    // Simpson's rule over the speed of the curve
+    let steps = 64;
+    let speed = |i: usize| {
+        let p = i as f64 / steps as f64;
+        poly.du(p).hypot(poly.dv(p))
+    };
+    let length = (0..=steps)
+        .map(|i| match i {
+            0 => speed(i),
+            i if i == steps => speed(i),
+            i if i % 2 == 1 => 4.0 * speed(i),
+            i => 2.0 * speed(i),
+        })
+        .sum::<f64>()
+        / (3.0 * steps as f64);
+    (length, GeometryType::ParamPoly3(poly))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::road::geometry::geometry_type::GeometryType;
+    use crate::road::geometry::plan_view::PlanView;
+    use crate::road::geometry::pose::Pose;
+    use crate::road::geometry::synthesis::{ConnectOptions, CurveState};
+    use crate::units::prelude::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    pub fn test_connect() {
+        let pose = |x: f64, y: f64, hdg: f64| Pose {
+            x: Length::new::<meter>(x),
+            y: Length::new::<meter>(y),
+            hdg: Angle::new::<radian>(hdg),
+        };
+        let ends_at = |plan_view: &PlanView, end: Pose| {
+            let last = plan_view.geometry.last();
+            let reached = last.pose_at(last.length);
+            (reached.x - end.x)
+                .get::<meter>()
+                .hypot((reached.y - end.y).get::<meter>())
+                < 1e-6
+                && (reached.hdg - end.hdg).get::<radian>().sin().abs() < 1e-6
+        };
+        let types = |plan_view: &PlanView| {
+            plan_view
+                .geometry
+                .iter()
+                .map(|g| match g.r#type {
+                    GeometryType::Line(_) => "line",
+                    GeometryType::Spiral(_) => "spiral",
+                    GeometryType::Arc(_) => "arc",
+                    _ => "other",
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let (start, end) = (pose(0.0, 0.0, 0.0), pose(30.0, 20.0, FRAC_PI_2));
+        let turn = PlanView::connect(start.into(), end.into(), ConnectOptions::default());
+        assert!(ends_at(&turn, end));
+        assert_eq!(vec!["line", "spiral", "spiral"], types(&turn));
+
+        let options = ConnectOptions {
+            arc_share: 0.5,
+            ..ConnectOptions::default()
+        };
+        let with_arc = PlanView::connect(start.into(), end.into(), options);
+        assert!(ends_at(&with_arc, end));
+        assert_eq!(vec!["line", "spiral", "arc", "spiral"], types(&with_arc));
+
+        let lane_change = pose(60.0, 3.5, 0.0);
+        let s_curve =
+            PlanView::connect(start.into(), lane_change.into(), ConnectOptions::default());
+        assert!(ends_at(&s_curve, lane_change));
+        assert!(types(&s_curve)
+            .iter()
+            .all(|t| *t == "line" || *t == "spiral"));
+
+        let curved = |pose: Pose, curvature: f64| CurveState {
+            pose,
+            curvature: Curvature::new::<radian_per_meter>(curvature),
+        };
+        let ramp = PlanView::connect(
+            curved(start, 0.02),
+            curved(pose(100.0, 60.0, 1.0), -0.01),
+            ConnectOptions::default(),
+        );
+        assert!(ends_at(&ramp, pose(100.0, 60.0, 1.0)));
+        let (first, last) = (ramp.geometry.first(), ramp.geometry.last());
+        let GeometryType::Spiral(first) = &first.r#type else {
+            panic!("{first:?}");
+        };
+        let GeometryType::Spiral(last) = &last.r#type else {
+            panic!("{last:?}");
+        };
+        assert_eq!(0.02, first.curvature_start.get::<radian_per_meter>());
+        assert_eq!(-0.01, last.curvature_end.get::<radian_per_meter>());
+
+        let backwards = pose(-20.0, 0.0, 0.0);
+        let cubic = PlanView::connect(start.into(), backwards.into(), ConnectOptions::default());
+        assert!(ends_at(&cubic, backwards));
+        assert_eq!(vec!["other"], types(&cubic));
+    }
+}
@@ -4,7 +4,7 @@ use std::borrow::Cow;
 
 /// Follows the road header if the road is linked to a successor or a predecessor. Isolated roads
 /// may omit this element.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Link {
     pub predecessor: Option<PredecessorSuccessor>,
@@ -17,30 +17,35 @@ impl Link {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         if let Some(predecessor) = &self.predecessor {
-            visit_children!(visitor, "predecessor" => predecessor);
+            visit_children!(visitor, options, "predecessor" => predecessor);
         }
 
         if let Some(successor) = &self.successor {
-            visit_children!(visitor, "successor" => successor);
+            visit_children!(visitor, options, "successor" => successor);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Link
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -59,7 +64,7 @@ where
         Ok(Self {
             predecessor,
             successor,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
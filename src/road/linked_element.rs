@@ -0,0 +1,19 @@
+use crate::junction::Junction;
+use crate::lane::travel_direction::TravelDirection;
+use crate::road::Road;
+use crate::units::prelude::*;
+
+/// The element a road link refers to, see
+/// [`crate::road::predecessor_successor::PredecessorSuccessor::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LinkedElement<'a> {
+    Road {
+        road: &'a Road,
+        /// s-coordinate at which the linked road is entered
+        s: Length,
+        /// Direction in which the linked road is travelled after entering it,
+        /// [`TravelDirection::Both`] if the link has neither `@contactPoint` nor `@elementDir`
+        direction: TravelDirection,
+    },
+    Junction(&'a Junction),
+}
@@ -1,18 +1,28 @@
 use crate::core::additional_data::AdditionalData;
+use crate::junction::contact_point::ContactPoint;
+use crate::lane::lane_type::LaneType;
 use crate::lane::lanes::Lanes;
+use crate::lane::taper::LaneSide;
+use crate::lane::travel_direction::TravelDirection;
+use crate::lane::Lane;
 use crate::object::objects::Objects;
+use crate::parser::ValueRange;
 use crate::railroad::Railroad;
+use crate::road::element_type::ElementType;
+use crate::road::predecessor_successor::PredecessorSuccessor;
+use crate::road::profile::crossfall::CrossfallSide;
 use crate::road::profile::ElevationProfile;
 use crate::road::road_type::RoadType;
 use crate::road::surface::Surface;
 use crate::signal::signals::Signals;
+use crate::units::prelude::*;
 use geometry::plan_view::PlanView;
+use geometry::pose::Pose;
 use link::Link;
 use profile::lateral_profile::LateralProfile;
 use rule::Rule;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
+use std::fmt::{Display, Formatter};
 
 #[allow(deprecated)]
 pub mod country_code;
@@ -20,6 +30,7 @@ pub mod crg;
 pub mod element_type;
 pub mod geometry;
 pub mod link;
+pub mod linked_element;
 pub mod predecessor_successor;
 pub mod profile;
 pub mod road_type;
@@ -69,17 +80,226 @@ pub struct Road {
 }
 
 impl Road {
+    /// A road that belongs to no junction with the given id and reference line. The length of the
+    /// road is derived from the end of the last geometry and the road has a single lane section
+    /// with only the center lane, see [`Lanes::default`].
+    pub fn new(id: impl Into<String>, plan_view: PlanView) -> Self {
+        let last = plan_view.geometry.last();
+        Self {
+            id: id.into(),
+            junction: "-1".to_string(),
+            length: last.s + last.length,
+            name: None,
+            rule: None,
+            link: None,
+            r#type: Vec::new(),
+            plan_view,
+            elevation_profile: None,
+            lateral_profile: None,
+            lanes: Lanes::default(),
+            objects: None,
+            signals: None,
+            surface: None,
+            railroad: None,
+            additional_data: AdditionalData::default(),
+        }
+    }
+
+    /// The direction in which vehicles travel on the given lane of this road, derived from the
+    /// sign of the lane id and [`Road::rule`]. Lanes of type [`LaneType::Bidirectional`] may be
+    /// used in both directions. The center lane has no travel direction.
+    pub fn lane_direction(&self, lane_id: i64, lane: &Lane) -> Option<TravelDirection> {
+        if lane_id == 0 {
+            None
+        } else if lane.r#type == LaneType::Bidirectional {
+            Some(TravelDirection::Both)
+        } else {
+            let right_lane = lane_id < 0;
+            let right_hand_traffic = !matches!(self.rule, Some(Rule::LeftHandTraffic));
+            Some(if right_lane == right_hand_traffic {
+                TravelDirection::Forward
+            } else {
+                TravelDirection::Backward
+            })
+        }
+    }
+
+    /// The end of this road that is linked to the junction with the given id, if any
+    pub fn junction_contact_point(&self, junction_id: &str) -> Option<ContactPoint> {
+        let link = self.link.as_ref()?;
+        let links_to = |l: &Option<PredecessorSuccessor>| {
+            l.as_ref().is_some_and(|l| {
+                l.element_type == Some(ElementType::Junction) && l.element_id == junction_id
+            })
+        };
+        if links_to(&link.successor) {
+            Some(ContactPoint::End)
+        } else if links_to(&link.predecessor) {
+            Some(ContactPoint::Start)
+        } else {
+            None
+        }
+    }
+
+    /// The pose on the reference line at the s-coordinate `s`
+    pub fn reference_pose(&self, s: Length) -> Pose {
+        self.plan_view.pose_at(s)
+    }
+
+    /// The pose at the s-coordinate `s` shifted by `t` perpendicular to the reference line
+    pub fn pose_at(&self, s: Length, t: Length) -> Pose {
+        let pose = self.reference_pose(s);
+        let (sin, cos) = pose.hdg.get::<radian>().sin_cos();
+        Pose {
+            x: pose.x - t * sin,
+            y: pose.y + t * cos,
+            hdg: pose.hdg,
+        }
+    }
+
+    /// The z-coordinate of the road surface at `s` and `t`: the elevation of the reference line,
+    /// tilted by the superelevation and raised by the height of the lane at `t`, which changes
+    /// linearly from its inner to its outer border, see [`Lane::height_at`]. The lateral shape
+    /// is ignored.
+    pub fn surface_z_at(&self, s: Length, t: Length) -> Length {
+        let (s, t) = (s.get::<meter>(), t.get::<meter>());
+        let elevation = self
+            .elevation_profile
+            .as_ref()
+            .map(|profile| profile.elevation_at(s))
+            .unwrap_or(0.0);
+        let roll = self.superelevation_at(Length::new::<meter>(s));
+        let (_, section) = self.lanes.lane_section_at(s);
+        let (ds, lateral) = (s - section.s, t - self.lanes.offset_at(s));
+        let height = section
+            .lanes()
+            .filter(|(id, _)| *id != 0)
+            .find_map(|(id, lane)| {
+                let (inner, outer) = section.lane_borders(id, ds)?;
+                let f = (lateral - inner) / (outer - inner);
+                (0.0..=1.0).contains(&f).then(|| {
+                    let (inner, outer) = lane.height_at(ds);
+                    inner + f * (outer - inner)
+                })
+            })
+            .unwrap_or(0.0);
+        Length::new::<meter>(elevation + t * roll.get::<radian>().tan() + height)
+    }
+
+    /// The length of the reference line in three dimensions, including the elevation profile.
+    /// Other than [`Road::length`], which is measured in the x/y-plane, this is the distance
+    /// actually travelled along the reference line.
+    pub fn length_3d(&self) -> Length {
+        let length = self.length.get::<meter>();
+        match &self.elevation_profile {
+            Some(profile) => Length::new::<meter>(profile.length_3d(0.0, length)),
+            None => self.length,
+        }
+    }
+
+    /// The roll angle of the road around the reference line at the s-coordinate `s`, zero if the
+    /// road has no `<superelevation>` elements. A positive roll angle lowers the right side of
+    /// the road. For the slope of one side that includes the `<crossfall>` of ASAM OpenDRIVE 1.4,
+    /// see [`Road::crossfall_at`].
+    pub fn superelevation_at(&self, s: Length) -> Angle {
+        let s = s.get::<meter>();
+        Angle::new::<radian>(
+            self.lateral_profile
+                .iter()
+                .flat_map(|p| p.super_elevation.iter().rev())
+                .find(|e| e.s <= s)
+                .map(|e| e.value(s))
+                .unwrap_or(0.0),
+        )
+    }
+
+    /// The angle by which the road surface on the given side falls from the reference line to the
+    /// outer border at the s-coordinate `s`, regardless of the revision: the superelevation of
+    /// the road, which lowers the right side and raises the left side, plus the `<crossfall>` of
+    /// ASAM OpenDRIVE 1.4 that applies to the side.
+    pub fn crossfall_at(&self, s: Length, side: LaneSide) -> Angle {
+        let superelevation = match side {
+            LaneSide::Left => -self.superelevation_at(s),
+            LaneSide::Right => self.superelevation_at(s),
+        };
+        let s = s.get::<meter>();
+        let crossfall = self
+            .lateral_profile
+            .iter()
+            .flat_map(|p| p.crossfall.iter().rev())
+            .filter(|c| match c.side {
+                CrossfallSide::Both => true,
+                CrossfallSide::Left => side == LaneSide::Left,
+                CrossfallSide::Right => side == LaneSide::Right,
+            })
+            .find(|c| c.s <= s)
+            .map(|c| c.value(s))
+            .unwrap_or(0.0);
+        superelevation + Angle::new::<radian>(crossfall)
+    }
+
+    /// The s- and t-coordinate of the point on the reference line closest to the given inertial
+    /// position. The reference line is sampled and the closest sample is refined numerically.
+    pub fn project(&self, x: Length, y: Length) -> (Length, Length) {
+        let length = self.length.get::<meter>().max(0.0);
+        let (x, y) = (x.get::<meter>(), y.get::<meter>());
+        let distance = |s: f64| {
+            let pose = self.reference_pose(Length::new::<meter>(s));
+            (pose.x.get::<meter>() - x).powi(2) + (pose.y.get::<meter>() - y).powi(2)
+        };
+
+        let samples = ((length / 0.5).ceil() as usize).max(1);
+        let step = length / samples as f64;
+        let closest = (0..=samples)
+            .map(|i| i as f64 * step)
+            .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+            .unwrap_or(0.0);
+
+        // ternary search around the closest sample
+        let (mut low, mut high) = ((closest - step).max(0.0), (closest + step).min(length));
+        for _ in 0..50 {
+            let a = low + (high - low) / 3.0;
+            let b = high - (high - low) / 3.0;
+            if distance(a) < distance(b) {
+                high = b;
+            } else {
+                low = a;
+            }
+        }
+
+        let s = Length::new::<meter>((low + high) / 2.0);
+        let pose = self.reference_pose(s);
+        let (sin, cos) = pose.hdg.get::<radian>().sin_cos();
+        let t = (y - pose.y.get::<meter>()) * cos - (x - pose.x.get::<meter>()) * sin;
+        (s, Length::new::<meter>(t))
+    }
+
+    /// The t-coordinate of the center of the given lane at the s-coordinate `s`, including the
+    /// lane offset, or `None` if the lane section at `s` has no such lane
+    pub fn lane_center_t(&self, lane_id: i64, s: Length) -> Option<Length> {
+        let s = s.get::<meter>();
+        let (_, section) = self.lanes.lane_section_at(s);
+        let (inner, outer) = section.lane_borders(lane_id, s - section.s)?;
+        Some(Length::new::<meter>(
+            self.lanes.offset_at(s) + (inner + outer) / 2.0,
+        ))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "id" => Some(self.id.as_str()),
             "junction" => Some(self.junction.as_str()),
-            "length" => Some(self.length.value.to_scientific_string()).as_deref(),
+            "length" => Some(options.format("length", self.length.get::<meter>())).as_deref(),
             "name" => self.name.as_deref(),
             "rule" => self.rule.as_ref().map(Rule::as_str),
         )
@@ -87,57 +307,108 @@ impl Road {
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         if let Some(link) = &self.link {
-            visit_children!(visitor, "link" => link);
+            visit_children!(visitor, options, "link" => link);
         }
 
         for r#type in &self.r#type {
-            visit_children!(visitor, "type" => r#type);
+            visit_children!(visitor, options, "type" => r#type);
         }
 
         if let Some(elevation) = &self.elevation_profile {
-            visit_children!(visitor, "elevationProfile" => elevation);
+            visit_children!(visitor, options, "elevationProfile" => elevation);
         }
 
         if let Some(lateral) = &self.lateral_profile {
-            visit_children!(visitor, "lateralProfile" => lateral);
+            visit_children!(visitor, options, "lateralProfile" => lateral);
         }
 
         if let Some(objects) = &self.objects {
-            visit_children!(visitor, "objects" => objects);
+            visit_children!(visitor, options, "objects" => objects);
         }
 
         if let Some(signals) = &self.signals {
-            visit_children!(visitor, "signals" => signals);
+            visit_children!(visitor, options, "signals" => signals);
         }
 
         if let Some(surface) = &self.surface {
-            visit_children!(visitor, "surface" => surface);
+            visit_children!(visitor, options, "surface" => surface);
         }
 
         if let Some(railroad) = &self.railroad {
-            visit_children!(visitor, "railroad" => railroad);
+            visit_children!(visitor, options, "railroad" => railroad);
         }
 
         visit_children!(
             visitor,
+            options,
             "planView" => self.plan_view,
             "lanes" => self.lanes,
         );
 
-        self.additional_data.append_children(&mut visitor)
+        self.additional_data.append_children(&mut visitor, options)
+    }
+}
+
+impl Display for Road {
+    /// A one-line summary of the road, like
+    /// `road 1 "Main": 100 m, 1 lane section, 3 lanes, successor junction J`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "road {}", self.id)?;
+        if let Some(name) = self.name.as_deref().filter(|n| !n.is_empty()) {
+            write!(f, " {name:?}")?;
+        }
+        let sections = self.lanes.lane_section.len();
+        let lanes = self
+            .lanes
+            .lane_section
+            .iter()
+            .map(|s| s.lanes().filter(|(id, _)| *id != 0).count())
+            .max()
+            .unwrap_or(0);
+        write!(
+            f,
+            ": {} m, {sections} lane section{}, {lanes} lane{}",
+            self.length.get::<meter>(),
+            if sections == 1 { "" } else { "s" },
+            if lanes == 1 { "" } else { "s" },
+        )?;
+        if self.junction != "-1" {
+            write!(f, ", in junction {}", self.junction)?;
+        }
+        let links = self
+            .link
+            .iter()
+            .flat_map(|l| [("predecessor", &l.predecessor), ("successor", &l.successor)])
+            .filter_map(|(name, link)| link.as_ref().map(|link| (name, link)));
+        for (name, link) in links {
+            let element_type = link.element_type.as_ref().map_or("element", |t| t.as_str());
+            write!(f, ", {name} {element_type} {}", link.element_id)?;
+            if let Some(contact_point) = &link.contact_point {
+                write!(f, " ({})", contact_point.as_str())?;
+            }
+        }
+        Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Road
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
     fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "road",
+            id = read.attribute_opt::<String>("id").ok().flatten()
+        )
+        .entered();
         let mut link = None;
         let mut r#type = Vec::new();
         let mut plan_view = None;
@@ -165,10 +436,26 @@ where
             _ => |_name, context| additional_data.fill(context),
         );
 
+        // the lateral position of objects and signals is only known to be within the lanes
+        // once these are read
+        let lanes = lanes.unwrap();
+        if read.warns_beyond_lanes() {
+            let path = format!("{}.objects.object", read.path());
+            for object in objects.iter().flat_map(|o| &o.object) {
+                warn_beyond_lanes(&read, &lanes, &path, object.s, object.t);
+            }
+            let path = format!("{}.signals.signal", read.path());
+            for signal in signals.iter().flat_map(|s| &s.signal) {
+                warn_beyond_lanes(&read, &lanes, &path, signal.s, signal.t);
+            }
+        }
+
         Ok(Self {
             id: read.attribute("id")?,
             junction: read.attribute("junction")?,
-            length: read.attribute("length").map(Length::new::<meter>)?,
+            length: read
+                .attribute_in("length", ValueRange::NON_NEGATIVE)
+                .map(Length::new::<meter>)?,
             name: read.attribute_opt("name")?,
             rule: read.attribute_opt("rule")?,
             link,
@@ -176,16 +463,31 @@ where
             plan_view: plan_view.unwrap(),
             elevation_profile,
             lateral_profile,
-            lanes: lanes.unwrap(),
+            lanes,
             objects,
             signals,
             surface,
             railroad,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
 
+/// Warns about the t-coordinate at `s` of the element at `path` if it is beyond the outer lane
+/// borders, see [`crate::parser::ParseOptions::warn_beyond_lanes`]
+fn warn_beyond_lanes<I>(
+    read: &crate::parser::ReadContext<'_, I>,
+    lanes: &Lanes,
+    path: &str,
+    s: Length,
+    t: Length,
+) where
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
+{
+    let (min, max) = lanes.extent_at(s.get::<meter>());
+    read.warn_out_of_range(path, "t", t.get::<meter>(), ValueRange::Between(min, max));
+}
+
 #[cfg(feature = "fuzzing")]
 impl arbitrary::Arbitrary<'_> for Road {
     fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
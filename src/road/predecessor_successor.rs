@@ -1,9 +1,11 @@
+use crate::core::OpenDrive;
 use crate::junction::contact_point::ContactPoint;
 use crate::junction::element_dir::ElementDir;
+use crate::lane::travel_direction::TravelDirection;
 use crate::road::element_type::ElementType;
+use crate::road::linked_element::LinkedElement;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Successors and predecessors can be junctions or roads. For each, different attribute sets shall
 /// be used.
@@ -25,18 +27,52 @@ pub struct PredecessorSuccessor {
 }
 
 impl PredecessorSuccessor {
+    /// The road or junction this link refers to, or `None` if there is no such element in the
+    /// given document. The entry of a linked road is derived from `@contactPoint` or, for
+    /// connections within the road, from `@elementS` and `@elementDir`.
+    pub fn resolve<'a>(&self, drive: &'a OpenDrive) -> Option<LinkedElement<'a>> {
+        match self.element_type {
+            Some(ElementType::Junction) => drive
+                .junction
+                .iter()
+                .find(|j| j.id == self.element_id)
+                .map(LinkedElement::Junction),
+            Some(ElementType::Road) | None => {
+                let road = drive.road.iter().find(|r| r.id == self.element_id)?;
+                let (s, direction) = match (&self.contact_point, self.element_s) {
+                    (Some(ContactPoint::Start), _) => {
+                        (Length::new::<meter>(0.0), TravelDirection::Forward)
+                    }
+                    (Some(ContactPoint::End), _) => (road.length, TravelDirection::Backward),
+                    (None, element_s) => (
+                        element_s.unwrap_or_else(|| Length::new::<meter>(0.0)),
+                        match self.element_dir {
+                            Some(ElementDir::Plus) => TravelDirection::Forward,
+                            Some(ElementDir::Minus) => TravelDirection::Backward,
+                            None => TravelDirection::Both,
+                        },
+                    ),
+                };
+                Some(LinkedElement::Road { road, s, direction })
+            }
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "contactPoint" => self.contact_point.as_ref().map(ContactPoint::as_str),
             "elementDir" => self.element_dir.as_ref().map(ElementDir::as_str),
             "elementId" => Some(self.element_id.as_str()),
-            "elementS" => self.element_s.map(|v| v.value.to_scientific_string()).as_deref(),
+            "elementS" => self.element_s.map(|v| options.format("elementS", v.get::<meter>())).as_deref(),
             "elementType" => self.element_type.as_ref().map(ElementType::as_str),
         )
     }
@@ -44,15 +80,16 @@ impl PredecessorSuccessor {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for PredecessorSuccessor
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
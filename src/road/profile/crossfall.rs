@@ -0,0 +1,146 @@
+use std::borrow::Cow;
+
+/// The angle of the road surface relative to the t-axis on one or both sides of the reference
+/// line, as of ASAM OpenDRIVE 1.4. A positive crossfall falls from the reference line to the
+/// outer border. Later revisions describe the road surface by superelevation and shapes instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crossfall {
+    /// Polynom parameter a, crossfall at @s (ds=0)
+    pub a: f64,
+    /// Polynom parameter b
+    pub b: f64,
+    /// Polynom parameter c
+    pub c: f64,
+    /// Polynom parameter d
+    pub d: f64,
+    /// s-coordinate of start position
+    pub s: f64,
+    /// The side of the road the crossfall applies to
+    pub side: CrossfallSide,
+}
+
+impl Crossfall {
+    /// The crossfall in radian at the s-coordinate `s`
+    pub fn value(&self, s: f64) -> f64 {
+        let ds = s - self.s;
+        self.a + (self.b * ds) + (self.c * ds * ds) + (self.d * ds * ds * ds)
+    }
+
+    pub fn visit_attributes(
+        &self,
+        visitor: impl for<'b> FnOnce(
+            Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
+        ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
+    ) -> xml::writer::Result<()> {
+        visit_attributes!(
+            visitor,
+            options,
+            "side" => self.side.as_str(),
+            "s" => &options.format("s", self.s),
+            "a" => &options.format("a", self.a),
+            "b" => &options.format("b", self.b),
+            "c" => &options.format("c", self.c),
+            "d" => &options.format("d", self.d),
+        )
+    }
+
+    pub fn visit_children(
+        &self,
+        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
+    ) -> xml::writer::Result<()> {
+        visit_children!(visitor, options);
+        Ok(())
+    }
+}
+
+impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Crossfall
+where
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
+{
+    type Error = Box<crate::parser::Error>;
+
+    fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
+        read.expecting_no_child_elements_for(Self {
+            a: read.attribute("a")?,
+            b: read.attribute("b")?,
+            c: read.attribute("c")?,
+            d: read.attribute("d")?,
+            s: read.attribute("s")?,
+            side: read.attribute("side")?,
+        })
+    }
+}
+
+#[cfg(feature = "fuzzing")]
+impl arbitrary::Arbitrary<'_> for Crossfall {
+    fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
+        use crate::fuzzing::NotNan;
+        Ok(Self {
+            a: u.not_nan_f64()?,
+            b: u.not_nan_f64()?,
+            c: u.not_nan_f64()?,
+            d: u.not_nan_f64()?,
+            s: u.not_nan_f64()?,
+            side: u.arbitrary()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub enum CrossfallSide {
+    Left,
+    Right,
+    Both,
+}
+
+impl_from_str_as_str!(
+    CrossfallSide,
+    "left" => Left,
+    "right" => Right,
+    "both" => Both,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse_revision, TestRoad};
+    use crate::lane::taper::LaneSide;
+    use crate::road::profile::crossfall::CrossfallSide;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_crossfall() {
+        let drive = parse_revision(
+            4,
+            TestRoad::new("1").child(
+                r#"<lateralProfile>
+                    <superelevation s="0" a="0.01" b="0" c="0" d="0"/>
+                    <crossfall side="both" s="0" a="0.02" b="0" c="0" d="0"/>
+                    <crossfall side="left" s="50" a="0.03" b="0.001" c="0" d="0"/>
+                </lateralProfile>"#,
+            ),
+        );
+        let road = &drive.road[0];
+        let profile = road.lateral_profile.as_ref().unwrap();
+        assert_eq!(CrossfallSide::Left, profile.crossfall[1].side);
+
+        let crossfall = |s: f64, side| {
+            road.crossfall_at(Length::new::<meter>(s), side)
+                .get::<radian>()
+        };
+        assert!((0.03 - crossfall(10.0, LaneSide::Right)).abs() < 1e-12);
+        assert!((0.01 - crossfall(10.0, LaneSide::Left)).abs() < 1e-12);
+        // the left side changes at 50, the right side keeps the crossfall of both sides
+        assert!((0.03 - crossfall(60.0, LaneSide::Right)).abs() < 1e-12);
+        assert!((0.03 - crossfall(60.0, LaneSide::Left)).abs() < 1e-12);
+
+        let written = drive.to_xml_string().unwrap();
+        assert_eq!(
+            drive,
+            crate::core::OpenDrive::from_xml_str(&written).unwrap()
+        );
+    }
+}
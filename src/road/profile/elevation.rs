@@ -18,34 +18,51 @@ pub struct Elevation {
 }
 
 impl Elevation {
+    /// The elevation in meter at the s-coordinate `s`
+    pub fn value(&self, s: f64) -> f64 {
+        let ds = s - self.s;
+        self.a + (self.b * ds) + (self.c * ds * ds) + (self.d * ds * ds * ds)
+    }
+
+    /// The slope of the elevation, that is, its derivative with respect to s, at the s-coordinate
+    /// `s`
+    pub fn slope(&self, s: f64) -> f64 {
+        let ds = s - self.s;
+        self.b + (2.0 * self.c * ds) + (3.0 * self.d * ds * ds)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
-            "s" => &self.s.to_scientific_string(),
+            options,
+            "a" => &options.format("a", self.a),
+            "b" => &options.format("b", self.b),
+            "c" => &options.format("c", self.c),
+            "d" => &options.format("d", self.d),
+            "s" => &options.format("s", self.s),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Elevation
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -1,14 +1,17 @@
 use crate::core::additional_data::AdditionalData;
+use crate::road::profile::crossfall::Crossfall;
 use crate::road::profile::shape::Shape;
 use crate::road::profile::super_elevation::SuperElevation;
 use std::borrow::Cow;
 
 /// Contains a series of superelevation elements that define the characteristics of the road
 /// surface's banking along the reference line.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct LateralProfile {
     pub super_elevation: Vec<SuperElevation>,
+    /// The crossfall of ASAM OpenDRIVE 1.4, see [`crate::road::Road::crossfall_at`]
+    pub crossfall: Vec<Crossfall>,
     pub shape: Vec<Shape>,
     pub additional_data: AdditionalData,
 }
@@ -18,49 +21,61 @@ impl LateralProfile {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for elevation in &self.super_elevation {
-            visit_children!(visitor, "superelevation" => elevation);
+            visit_children!(visitor, options, "superelevation" => elevation);
+        }
+
+        for crossfall in &self.crossfall {
+            visit_children!(visitor, options, "crossfall" => crossfall);
         }
 
         for shape in &self.shape {
-            visit_children!(visitor, "shape" => shape);
+            visit_children!(visitor, options, "shape" => shape);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for LateralProfile
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
     fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
         let mut super_elevation = Vec::new();
+        let mut crossfall = Vec::new();
         let mut shape = Vec::new();
         let mut additional_data = AdditionalData::default();
 
         match_child_eq_ignore_ascii_case!(
             read,
             "superelevation" => SuperElevation => |v| super_elevation.push(v),
+            "crossfall" => Crossfall => |v| crossfall.push(v),
             "shape" => Shape => |v| shape.push(v),
             _ => |_name, context| additional_data.fill(context),
         );
 
         Ok(Self {
             super_elevation,
+            crossfall,
             shape,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
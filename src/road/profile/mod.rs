@@ -1,14 +1,16 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::validated::SortedByS;
 use elevation::Elevation;
 use std::borrow::Cow;
 
+pub mod crossfall;
 pub mod elevation;
 pub mod lateral_profile;
 pub mod shape;
 pub mod super_elevation;
 
 /// Defines the characteristics of the road elevation along the reference line.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct ElevationProfile {
     pub elevation: Vec<Elevation>,
@@ -16,30 +18,170 @@ pub struct ElevationProfile {
 }
 
 impl ElevationProfile {
+    /// An elevation profile consisting of the given elevations
+    pub fn from_elevations(elevation: SortedByS<Elevation>) -> Self {
+        Self {
+            elevation: elevation.into_inner(),
+            additional_data: AdditionalData::default(),
+        }
+    }
+
+    /// The `<elevation>` that applies at the s-coordinate `s`, or `None` if there is none
+    pub fn elevation_element_at(&self, s: f64) -> Option<&Elevation> {
+        self.elevation.iter().rev().find(|e| e.s <= s)
+    }
+
+    /// The elevation of the reference line at the s-coordinate `s`, zero where no `<elevation>`
+    /// applies
+    pub fn elevation_at(&self, s: f64) -> f64 {
+        self.elevation_element_at(s)
+            .map(|e| e.value(s))
+            .unwrap_or(0.0)
+    }
+
+    /// The length of the reference line between the s-coordinates `start` and `end` in three
+    /// dimensions, given the 2D length `end - start` in the x/y-plane. The arc length is
+    /// integrated numerically over each `<elevation>` element.
+    pub fn length_3d(&self, start: f64, end: f64) -> f64 {
+        const STEPS: usize = 64;
+        let mut bounds = vec![start];
+        bounds.extend(
+            self.elevation
+                .iter()
+                .map(|e| e.s)
+                .filter(|s| *s > start && *s < end),
+        );
+        bounds.push(end);
+
+        bounds
+            .windows(2)
+            .map(|w| {
+                let (from, to) = (w[0], w[1]);
+                let element = self.elevation_element_at(from);
+                let slope = |s: f64| element.map(|e| e.slope(s)).unwrap_or(0.0);
+                let integrand = |s: f64| (1.0 + slope(s).powi(2)).sqrt();
+                // composite Simpson's rule
+                let h = (to - from) / STEPS as f64;
+                let sum = (1..STEPS)
+                    .map(|i| {
+                        let weight = if i % 2 == 0 { 2.0 } else { 4.0 };
+                        weight * integrand(from + i as f64 * h)
+                    })
+                    .sum::<f64>();
+                h / 3.0 * (integrand(from) + sum + integrand(to))
+            })
+            .sum()
+    }
+
+    /// Fits elevation records to sampled `(s, z)` heights, for example of LiDAR or GNSS traces,
+    /// so that every sample is within `tolerance` of the profile. The records run from sample to
+    /// sample, matching their heights and the slopes of the parabolas through each sample and its
+    /// neighbours, so consecutive records join with equal height and slope (C1 continuity).
+    /// Starting at the first sample, each record is extended over as many samples as the
+    /// tolerance allows, which yields few records. The samples need not be sorted, heights
+    /// sampled at the same s-coordinate are averaged and non-finite samples are ignored. Noise
+    /// above the tolerance leads to records between neighbouring samples instead of a failure.
+    pub fn fit(samples: impl IntoIterator<Item = (f64, f64)>, tolerance: f64) -> Self {
+        let mut sorted = samples
+            .into_iter()
+            .filter(|(s, z)| s.is_finite() && z.is_finite())
+            .collect::<Vec<_>>();
+        sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut samples = Vec::<(f64, f64)>::with_capacity(sorted.len());
+        let mut count = 0.0;
+        for (s, z) in sorted {
+            match samples.last_mut() {
+                Some(last) if last.0 == s => {
+                    count += 1.0;
+                    last.1 += (z - last.1) / count;
+                }
+                _ => {
+                    samples.push((s, z));
+                    count = 1.0;
+                }
+            }
+        }
+
+        let mut elevation = Vec::new();
+        if let [(s, z)] = samples[..] {
+            elevation.push(Elevation {
+                a: z,
+                b: 0.0,
+                c: 0.0,
+                d: 0.0,
+                s,
+            });
+        }
+        let slopes = slopes(&samples);
+        let knot = |i: usize| (samples[i].0, samples[i].1, slopes[i]);
+        let mut start = 0;
+        while start + 1 < samples.len() {
+            let fits = |end: usize| {
+                let record = hermite(knot(start), knot(end));
+                samples[start + 1..end]
+                    .iter()
+                    .all(|(s, z)| (record.value(*s) - z).abs() <= tolerance)
+            };
+            // gallop to a range that does not fit, then bisect for the last one that does
+            let (mut fitting, mut step) = (start + 1, 1);
+            let mut failing = loop {
+                step *= 2;
+                let end = start + step;
+                if end >= samples.len() {
+                    break samples.len();
+                } else if !fits(end) {
+                    break end;
+                }
+                fitting = end;
+            };
+            while failing - fitting > 1 {
+                let middle = fitting + (failing - fitting) / 2;
+                if fits(middle) {
+                    fitting = middle;
+                } else {
+                    failing = middle;
+                }
+            }
+
+            elevation.push(hermite(knot(start), knot(fitting)));
+            start = fitting;
+        }
+
+        Self {
+            elevation,
+            additional_data: AdditionalData::default(),
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(visitor)
+        let visitor = self.additional_data.append_attributes(visitor);
+        visit_attributes!(visitor, options)
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for elevation in &self.elevation {
-            visit_children!(visitor, "elevation" => elevation);
+            visit_children!(visitor, options, "elevation" => elevation);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for ElevationProfile
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -55,7 +197,111 @@ where
 
         Ok(Self {
             elevation,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
+        })
+    }
+}
+
+/// The cubic from the height `z0` with slope `m0` at `s0` to the height `z1` with slope `m1` at
+/// `s1`
+fn hermite((s0, z0, m0): (f64, f64, f64), (s1, z1, m1): (f64, f64, f64)) -> Elevation {
+    let h = s1 - s0;
+    let secant = (z1 - z0) / h;
+    Elevation {
+        a: z0,
+        b: m0,
+        c: (3.0 * secant - 2.0 * m0 - m1) / h,
+        d: (m0 + m1 - 2.0 * secant) / (h * h),
+        s: s0,
+    }
+}
+
+/// The slope at each sample, that of the parabola through the sample and its neighbours
+fn slopes(samples: &[(f64, f64)]) -> Vec<f64> {
+    if let [(s0, z0), (s1, z1)] = samples[..] {
+        let slope = (z1 - z0) / (s1 - s0);
+        return vec![slope, slope];
+    }
+    (0..samples.len())
+        .map(|i| {
+            let first = i.saturating_sub(1).min(samples.len().saturating_sub(3));
+            let nodes = &samples[first..(first + 3).min(samples.len())];
+            let s = samples[i].0;
+            nodes
+                .iter()
+                .enumerate()
+                .map(|(k, (sk, zk))| {
+                    let others = nodes
+                        .iter()
+                        .enumerate()
+                        .filter(|(l, _)| *l != k)
+                        .map(|(_, (sl, _))| *sl)
+                        .collect::<Vec<_>>();
+                    let numerator = others.iter().map(|sl| s - sl).sum::<f64>();
+                    let denominator = others.iter().map(|sl| sk - sl).product::<f64>();
+                    zk * numerator / denominator
+                })
+                .sum()
         })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::road::profile::elevation::Elevation;
+    use crate::road::profile::ElevationProfile;
+
+    #[test]
+    pub fn test_length_3d() {
+        let elevation = |s: f64, a: f64, b: f64| Elevation {
+            a,
+            b,
+            c: 0.0,
+            d: 0.0,
+            s,
+        };
+        let profile = ElevationProfile {
+            elevation: vec![elevation(0.0, 0.0, 0.0), elevation(40.0, 0.0, 0.75)],
+            ..ElevationProfile::default()
+        };
+
+        assert_eq!(30.0, profile.elevation_at(80.0));
+        assert!((40.0 + 50.0 - profile.length_3d(0.0, 80.0)).abs() < 1e-9);
+        assert!((10.0 - profile.length_3d(10.0, 20.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn test_fit() {
+        let parabola = (0..=100).map(|s| (s as f64, 0.001 * (s * s) as f64));
+        let profile = ElevationProfile::fit(parabola, 1e-6);
+        assert_eq!(1, profile.elevation.len());
+        assert!((0.001 - profile.elevation[0].c).abs() < 1e-9);
+
+        let samples = (0..=400)
+            .map(|i| {
+                let s = i as f64 * 0.5;
+                (s, 2.0 * (s / 20.0).sin())
+            })
+            .collect::<Vec<_>>();
+        let profile = ElevationProfile::fit(samples.iter().rev().copied(), 0.01);
+        assert!(profile.elevation.len() > 1 && profile.elevation.len() < 20);
+        for (s, z) in &samples {
+            assert!((profile.elevation_at(*s) - z).abs() <= 0.01);
+        }
+        for pair in profile.elevation.windows(2) {
+            let s = pair[1].s;
+            assert!((pair[0].value(s) - pair[1].value(s)).abs() < 1e-9);
+            assert!((pair[0].slope(s) - pair[1].slope(s)).abs() < 1e-9);
+        }
+
+        assert_eq!(
+            vec![(5.0, 2.0)],
+            ElevationProfile::fit([(5.0, 1.0), (5.0, 3.0)], 0.1)
+                .elevation
+                .iter()
+                .map(|e| (e.s, e.a))
+                .collect::<Vec<_>>()
+        );
+        assert!(ElevationProfile::fit([], 0.1).elevation.is_empty());
     }
 }
@@ -2,9 +2,8 @@ use crate::core::additional_data::AdditionalData;
 use crate::road::country_code::CountryCode;
 use crate::road::road_type_e::RoadTypeE;
 use crate::road::speed::Speed;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// A road type element is valid for the entire cross section of a road. It is valid until a new
 /// road type element is provided or until the road ends.
@@ -25,31 +24,37 @@ impl RoadType {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "country" => self.country.as_ref().map(CountryCode::as_str),
-            "s" => Some(self.s.value.to_scientific_string()).as_deref(),
+            "s" => Some(options.format("s", self.s.get::<meter>())).as_deref(),
             "type" => Some(self.r#type.as_str()),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         if let Some(speed) = &self.speed {
-            visit_children!(visitor, "speed" => speed);
+            visit_children!(visitor, options, "speed" => speed);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for RoadType
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -68,7 +73,7 @@ where
             country: read.attribute_opt("country")?,
             s: read.attribute("s").map(Length::new::<meter>)?,
             r#type: read.attribute("type")?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
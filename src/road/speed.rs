@@ -1,4 +1,5 @@
-use crate::road::unit::SpeedUnit;
+use crate::road::unit::{SpeedUnit, SpeedValue};
+use crate::units::prelude::*;
 use std::borrow::Cow;
 
 /// Defines the default maximum speed allowed in conjunction with the specified road type.
@@ -9,19 +10,44 @@ pub struct Speed {
     /// the respective unit (see attribute unit). If the attribute unit is not specified, m/s is
     /// used as default.
     pub max: MaxSpeed,
-    /// Unit of the attribute max. For values, see chapter “units”.
+    /// Unit of the attribute max, written back as read
     pub unit: Option<SpeedUnit>,
 }
 
 impl Speed {
+    /// The numerical maximum allowed speed in its unit, `None` if there is no limit or the speed
+    /// is undefined
+    pub fn limit(&self) -> Option<SpeedValue> {
+        match self.max {
+            MaxSpeed::Limit(limit) => Some(SpeedValue::new(
+                limit,
+                self.unit.clone().unwrap_or(SpeedUnit::MetersPerSecond),
+            )),
+            MaxSpeed::NoLimit | MaxSpeed::Undefined => None,
+        }
+    }
+
+    /// The maximum allowed speed as velocity. No limit is represented by an infinite velocity,
+    /// `None` if the speed is undefined.
+    pub fn velocity(&self) -> Option<Velocity> {
+        match &self.max {
+            MaxSpeed::Limit(_) => self.limit().map(|limit| limit.velocity()),
+            MaxSpeed::NoLimit => Some(Velocity::new::<meter_per_second>(f64::INFINITY)),
+            MaxSpeed::Undefined => None,
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            options,
             "max" => Some(&*self.max.as_str()),
             "unit" => self.unit.as_ref().map(SpeedUnit::as_str),
         )
@@ -30,15 +56,16 @@ impl Speed {
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Speed
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -1,23 +1,33 @@
+use crate::units::prelude::*;
+
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum Unit {
     Distance(DistanceUnit),
     Speed(SpeedUnit),
     Mass(MassUnit),
     Slope(SlopeUnit),
+    /// A unit unknown to the schema, see [`crate::parser::ParseOptions::tolerant_enums`]
+    Other(String),
 }
 
 impl Unit {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Self::Distance(v) => v.as_str(),
             Self::Speed(v) => v.as_str(),
             Self::Mass(v) => v.as_str(),
             Self::Slope(v) => v.as_str(),
+            Self::Other(v) => v,
         }
     }
 }
 
+impl crate::parser::OtherValue for Unit {
+    fn other(value: &str) -> Self {
+        Self::Other(value.to_string())
+    }
+}
+
 impl core::str::FromStr for Unit {
     type Err = crate::parser::InvalidEnumValue;
 
@@ -39,6 +49,20 @@ impl core::str::FromStr for Unit {
     }
 }
 
+#[cfg(feature = "fuzzing")]
+impl arbitrary::Arbitrary<'_> for Unit {
+    fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
+        use crate::fuzzing::ArbitraryOther;
+        Ok(match u.int_in_range(0..=4)? {
+            0 => Self::Distance(u.arbitrary()?),
+            1 => Self::Speed(u.arbitrary()?),
+            2 => Self::Mass(u.arbitrary()?),
+            3 => Self::Slope(u.arbitrary()?),
+            _ => Self::Other(u.arbitrary_other()?),
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum DistanceUnit {
@@ -48,6 +72,18 @@ pub enum DistanceUnit {
     Mile,
 }
 
+impl DistanceUnit {
+    /// The length of the given value in this unit
+    pub fn length(&self, value: f64) -> Length {
+        match self {
+            Self::Meter => Length::new::<meter>(value),
+            Self::KiloMeter => Length::new::<meter>(value * 1000.0),
+            Self::Feet => Length::new::<meter>(value * 0.3048),
+            Self::Mile => Length::new::<meter>(value * 1609.344),
+        }
+    }
+}
+
 impl_from_str_as_str!(
     DistanceUnit,
     "m" => Meter,
@@ -64,6 +100,92 @@ pub enum SpeedUnit {
     MilesPerHour,
 }
 
+impl SpeedUnit {
+    /// The velocity of the given value in this unit
+    pub fn velocity(&self, value: f64) -> Velocity {
+        match self {
+            Self::KilometersPerHour => Velocity::new::<kilometer_per_hour>(value),
+            Self::MetersPerSecond => Velocity::new::<meter_per_second>(value),
+            Self::MilesPerHour => Velocity::new::<mile_per_hour>(value),
+        }
+    }
+
+    /// The value of the given velocity in this unit
+    pub fn value(&self, velocity: Velocity) -> f64 {
+        match self {
+            Self::KilometersPerHour => velocity.get::<kilometer_per_hour>(),
+            Self::MetersPerSecond => velocity.get::<meter_per_second>(),
+            Self::MilesPerHour => velocity.get::<mile_per_hour>(),
+        }
+    }
+}
+
+/// A speed as given in a document, the value in its unit. Speeds without a `@unit` are given in
+/// m/s, see [`crate::lane::speed::Speed::speed`] and [`crate::road::speed::Speed::limit`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeedValue {
+    pub value: f64,
+    pub unit: SpeedUnit,
+}
+
+impl SpeedValue {
+    pub fn new(value: f64, unit: SpeedUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// The velocity in the given unit
+    pub fn from_velocity(velocity: Velocity, unit: SpeedUnit) -> Self {
+        Self {
+            value: unit.value(velocity),
+            unit,
+        }
+    }
+
+    pub fn velocity(&self) -> Velocity {
+        self.unit.velocity(self.value)
+    }
+
+    /// The same speed in the given unit
+    pub fn to_unit(&self, unit: SpeedUnit) -> Self {
+        Self::from_velocity(self.velocity(), unit)
+    }
+
+    pub fn kilometers_per_hour(&self) -> f64 {
+        self.velocity().get::<kilometer_per_hour>()
+    }
+
+    pub fn meters_per_second(&self) -> f64 {
+        self.velocity().get::<meter_per_second>()
+    }
+
+    pub fn miles_per_hour(&self) -> f64 {
+        self.velocity().get::<mile_per_hour>()
+    }
+}
+
+impl From<SpeedValue> for Velocity {
+    fn from(speed: SpeedValue) -> Self {
+        speed.velocity()
+    }
+}
+
+impl core::fmt::Display for SpeedValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {}", self.value, self.unit.as_str())
+    }
+}
+
+#[cfg(feature = "fuzzing")]
+impl arbitrary::Arbitrary<'_> for SpeedValue {
+    fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
+        use crate::fuzzing::NotNan;
+        Ok(Self {
+            value: u.not_nan_f64()?,
+            unit: u.arbitrary()?,
+        })
+    }
+}
+
 impl_from_str_as_str!(
     SpeedUnit,
     "km/h" => KilometersPerHour,
@@ -78,6 +200,16 @@ pub enum MassUnit {
     Ton,
 }
 
+impl MassUnit {
+    /// The mass of the given value in this unit in kilograms
+    pub fn kilograms(&self, value: f64) -> f64 {
+        match self {
+            Self::KiloGram => value,
+            Self::Ton => value * 1000.0,
+        }
+    }
+}
+
 impl_from_str_as_str!(
     MassUnit,
     "kg" => KiloGram,
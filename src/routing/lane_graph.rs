@@ -0,0 +1,275 @@
+use crate::core::OpenDrive;
+use crate::junction::contact_point::ContactPoint;
+use crate::lane::travel_direction::TravelDirection;
+use crate::lane::Lane;
+use crate::road::Road;
+use crate::units::prelude::*;
+use petgraph::graph::{DiGraph, NodeIndex};
+use std::collections::HashMap;
+
+/// Identifies a lane within a lane section of a road.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LaneRef<'a> {
+    /// ID of the road
+    pub road: &'a str,
+    /// Index of the lane section within [`crate::lane::lanes::Lanes::lane_section`]
+    pub section: usize,
+    /// ID of the lane within the lane section
+    pub lane: i64,
+}
+
+/// A lane travelled in one direction. Lanes that may be used in both directions are represented
+/// by two nodes.
+#[derive(Debug, Clone, Copy)]
+pub struct LaneNode<'a> {
+    pub road: &'a Road,
+    pub section: usize,
+    pub lane_id: i64,
+    pub lane: &'a Lane,
+    /// Either [`TravelDirection::Forward`] or [`TravelDirection::Backward`]
+    pub direction: TravelDirection,
+    /// Length of the lane section along the reference line
+    pub length: Length,
+}
+
+impl<'a> LaneNode<'a> {
+    #[inline]
+    pub fn lane_ref(&self) -> LaneRef<'a> {
+        LaneRef {
+            road: &self.road.id,
+            section: self.section,
+            lane: self.lane_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LaneEdgeKind {
+    /// Continuing onto a linked lane of the next lane section or of a linked road
+    Longitudinal,
+    /// Continuing onto a lane of a connecting road according to a junction `<laneLink>`
+    Junction,
+    /// Changing onto an adjacent lane of the same lane section
+    LaneChange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LaneEdge {
+    pub kind: LaneEdgeKind,
+    /// Distance travelled on the source lane before the target lane is entered, zero for lane
+    /// changes
+    pub length: Length,
+}
+
+/// Directed lane-level graph of the road network. Nodes are lanes in a lane section travelled in
+/// one direction, edges are derived from lane links, junction lane links and lane changes that
+/// are permitted by the road marks in between.
+#[derive(Debug, Clone)]
+pub struct LaneGraph<'a> {
+    graph: DiGraph<LaneNode<'a>, LaneEdge>,
+    nodes: HashMap<&'a str, HashMap<(usize, i64, TravelDirection), NodeIndex>>,
+}
+
+impl OpenDrive {
+    /// Builds the lane-level graph of this document, see [`LaneGraph`].
+    pub fn lane_graph(&self) -> LaneGraph<'_> {
+        LaneGraph::new(self)
+    }
+}
+
+impl<'a> LaneGraph<'a> {
+    pub fn new(drive: &'a OpenDrive) -> Self {
+        let mut this = Self {
+            graph: DiGraph::new(),
+            nodes: HashMap::new(),
+        };
+
+        for road in &drive.road {
+            let sections = road.lanes.sections_with_ranges(road);
+            for (index, (section, start, end)) in sections.enumerate() {
+                let length = end - start;
+
+                for (lane_id, lane) in section.lanes() {
+                    let directions: &[TravelDirection] = match road.lane_direction(lane_id, lane) {
+                        None => &[],
+                        Some(TravelDirection::Both) => {
+                            &[TravelDirection::Forward, TravelDirection::Backward]
+                        }
+                        Some(TravelDirection::Forward) => &[TravelDirection::Forward],
+                        Some(TravelDirection::Backward) => &[TravelDirection::Backward],
+                    };
+                    for direction in directions {
+                        let node = this.graph.add_node(LaneNode {
+                            road,
+                            section: index,
+                            lane_id,
+                            lane,
+                            direction: *direction,
+                            length,
+                        });
+                        this.nodes
+                            .entry(road.id.as_str())
+                            .or_default()
+                            .insert((index, lane_id, *direction), node);
+                    }
+                }
+            }
+        }
+
+        for index in this.graph.node_indices().collect::<Vec<_>>() {
+            let node = this.graph[index];
+            this.add_longitudinal_edges(drive, index, node);
+            this.add_lane_change_edges(index, node);
+        }
+
+        this
+    }
+
+    fn add_longitudinal_edges(
+        &mut self,
+        drive: &'a OpenDrive,
+        index: NodeIndex,
+        node: LaneNode<'a>,
+    ) {
+        let forward = node.direction == TravelDirection::Forward;
+        let last_section = node.road.lanes.lane_section.len() - 1;
+        let lane_links = node
+            .lane
+            .link
+            .iter()
+            .flat_map(|l| {
+                if forward {
+                    &l.successor
+                } else {
+                    &l.predecessor
+                }
+            })
+            .map(|l| l.id)
+            .collect::<Vec<_>>();
+
+        let within_road = if forward {
+            Some(node.section + 1).filter(|s| *s <= last_section)
+        } else {
+            node.section.checked_sub(1)
+        };
+
+        if let Some(section) = within_road {
+            for lane in lane_links {
+                self.add_edge(
+                    index,
+                    node.road,
+                    section,
+                    lane,
+                    node.direction,
+                    LaneEdgeKind::Longitudinal,
+                    node.length,
+                );
+            }
+            return;
+        }
+
+        let contact_point = if forward {
+            ContactPoint::End
+        } else {
+            ContactPoint::Start
+        };
+        for successor in drive.successor_lanes_of(node.road, node.lane_id, node.lane, contact_point)
+        {
+            let kind = if successor.junction.is_some() {
+                LaneEdgeKind::Junction
+            } else {
+                LaneEdgeKind::Longitudinal
+            };
+            self.add_edge(
+                index,
+                successor.road,
+                successor.section,
+                successor.lane_id,
+                successor.direction,
+                kind,
+                node.length,
+            );
+        }
+    }
+
+    fn add_lane_change_edges(&mut self, index: NodeIndex, node: LaneNode<'a>) {
+        let section = &node.road.lanes.lane_section[node.section];
+        for neighbor in [node.lane_id - 1, node.lane_id + 1] {
+            if neighbor == 0 || neighbor.signum() != node.lane_id.signum() {
+                continue;
+            }
+
+            // the road mark at the outer border of the inner lane separates both lanes
+            let inner = if neighbor.abs() < node.lane_id.abs() {
+                section.lane(neighbor)
+            } else {
+                Some(node.lane)
+            };
+
+            let permitted = inner
+                .map(|lane| {
+                    lane.road_mark.is_empty()
+                        || lane
+                            .road_mark
+                            .iter()
+                            .any(|m| m.allows_lane_change(node.lane_id, neighbor))
+                })
+                .unwrap_or(false);
+
+            if permitted {
+                self.add_edge(
+                    index,
+                    node.road,
+                    node.section,
+                    neighbor,
+                    node.direction,
+                    LaneEdgeKind::LaneChange,
+                    Length::new::<meter>(0.0),
+                );
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn add_edge(
+        &mut self,
+        from: NodeIndex,
+        road: &'a Road,
+        section: usize,
+        lane: i64,
+        direction: TravelDirection,
+        kind: LaneEdgeKind,
+        length: Length,
+    ) {
+        let key = LaneRef {
+            road: &road.id,
+            section,
+            lane,
+        };
+        if let Some(to) = self.node(key, direction) {
+            self.graph.update_edge(from, to, LaneEdge { kind, length });
+        }
+    }
+
+    /// The underlying [`petgraph`] graph for queries not covered by this type
+    #[inline]
+    pub fn graph(&self) -> &DiGraph<LaneNode<'a>, LaneEdge> {
+        &self.graph
+    }
+
+    /// The node for travelling the referenced lane in the given direction
+    #[inline]
+    pub fn node(&self, lane: LaneRef<'_>, direction: TravelDirection) -> Option<NodeIndex> {
+        self.nodes
+            .get(lane.road)?
+            .get(&(lane.section, lane.lane, direction))
+            .copied()
+    }
+
+    /// All nodes of the referenced lane, two for lanes that may be travelled in both directions
+    pub fn nodes_of<'b>(&'b self, lane: LaneRef<'b>) -> impl Iterator<Item = NodeIndex> + 'b {
+        [TravelDirection::Forward, TravelDirection::Backward]
+            .into_iter()
+            .filter_map(move |d| self.node(lane, d))
+    }
+}
@@ -0,0 +1,4 @@
+pub mod lane_graph;
+pub mod road_graph;
+pub mod route;
+pub mod router;
@@ -0,0 +1,264 @@
+use crate::core::OpenDrive;
+use crate::junction::contact_point::ContactPoint;
+use crate::junction::Junction;
+use crate::road::element_type::ElementType;
+use crate::road::linked_element::LinkedElement;
+use crate::road::predecessor_successor::PredecessorSuccessor;
+use crate::road::Road;
+use crate::units::prelude::*;
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::{Bfs, EdgeRef, Walker};
+use std::collections::HashMap;
+
+/// Directed graph of the road network on road level. Each `<road>` is a node, each edge describes
+/// that a vehicle leaving the source road may enter the target road, either through a direct road
+/// link or through a connection of a `<junction>`.
+#[derive(Debug, Clone)]
+pub struct RoadGraph<'a> {
+    graph: DiGraph<&'a Road, RoadEdge<'a>>,
+    nodes: HashMap<&'a str, NodeIndex>,
+}
+
+/// Transition from one road onto another road.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoadEdge<'a> {
+    /// Distance travelled on the source road before the target road is entered, which is the
+    /// length of the source road
+    pub length: Length,
+    /// ID of the junction this transition belongs to, `None` for direct road links
+    pub junction: Option<&'a str>,
+}
+
+/// Result of a path query on a [`RoadGraph`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoadPath<'a> {
+    /// Roads in the order in which they are travelled, including the start and the target road
+    pub roads: Vec<&'a Road>,
+    /// Sum of all edge lengths, that is, the distance from the start of the first road to the
+    /// entry of the last road
+    pub length: Length,
+}
+
+impl OpenDrive {
+    /// Builds the road-level topology graph of this document, see [`RoadGraph`].
+    pub fn road_graph(&self) -> RoadGraph<'_> {
+        RoadGraph::new(self)
+    }
+}
+
+impl<'a> RoadGraph<'a> {
+    pub fn new(drive: &'a OpenDrive) -> Self {
+        let mut graph = DiGraph::with_capacity(drive.road.len(), drive.road.len() * 2);
+        let nodes = drive
+            .road
+            .iter()
+            .map(|road| (road.id.as_str(), graph.add_node(road)))
+            .collect::<HashMap<_, _>>();
+
+        let mut this = Self { graph, nodes };
+
+        for road in &drive.road {
+            if let Some(junction) = Self::junction_of_connecting_road(drive, road) {
+                this.add_connecting_road_edges(junction, road);
+            } else if let Some(link) = &road.link {
+                for link in [&link.predecessor, &link.successor].into_iter().flatten() {
+                    this.add_link_edges(drive, road, link);
+                }
+            }
+        }
+
+        this
+    }
+
+    fn junction_of_connecting_road(drive: &'a OpenDrive, road: &Road) -> Option<&'a Junction> {
+        if road.junction == "-1" {
+            None
+        } else {
+            drive.junction.iter().find(|j| j.id == road.junction)
+        }
+    }
+
+    fn add_link_edges(
+        &mut self,
+        drive: &'a OpenDrive,
+        road: &'a Road,
+        link: &'a PredecessorSuccessor,
+    ) {
+        match link.resolve(drive) {
+            Some(LinkedElement::Junction(junction)) => {
+                for connection in &junction.connection {
+                    if connection.incoming_road.as_deref() == Some(road.id.as_str()) {
+                        let target = connection
+                            .connecting_road
+                            .as_deref()
+                            .or(connection.linked_road.as_deref());
+                        if let Some(target) = target {
+                            self.add_edge(road, target, Some(junction.id.as_str()));
+                        }
+                    }
+                }
+            }
+            Some(LinkedElement::Road { road: target, .. }) => self.add_edge(road, &target.id, None),
+            None => {}
+        }
+    }
+
+    /// Connecting roads are only travelled in the direction given by the `@contactPoint` of the
+    /// connections referring to them, entering a connecting road at its start leaves it through
+    /// its successor and vice versa.
+    fn add_connecting_road_edges(&mut self, junction: &'a Junction, road: &'a Road) {
+        let link = match &road.link {
+            Some(link) => link,
+            None => return,
+        };
+
+        let mut contact_points = junction
+            .connection
+            .iter()
+            .filter(|c| c.connecting_road.as_deref() == Some(road.id.as_str()))
+            .filter_map(|c| c.contact_point.as_ref())
+            .peekable();
+
+        let exits = if contact_points.peek().is_none() {
+            vec![&link.predecessor, &link.successor]
+        } else {
+            contact_points
+                .map(|contact_point| match contact_point {
+                    ContactPoint::Start => &link.successor,
+                    ContactPoint::End => &link.predecessor,
+                })
+                .collect()
+        };
+
+        for exit in exits.into_iter().flatten() {
+            if !matches!(exit.element_type, Some(ElementType::Junction)) {
+                self.add_edge(road, &exit.element_id, Some(junction.id.as_str()));
+            }
+        }
+    }
+
+    fn add_edge(&mut self, from: &'a Road, to: &str, junction: Option<&'a str>) {
+        if let (Some(a), Some(b)) = (self.node(&from.id), self.node(to)) {
+            self.graph.update_edge(
+                a,
+                b,
+                RoadEdge {
+                    length: from.length,
+                    junction,
+                },
+            );
+        }
+    }
+
+    /// The underlying [`petgraph`] graph for queries not covered by this type
+    #[inline]
+    pub fn graph(&self) -> &DiGraph<&'a Road, RoadEdge<'a>> {
+        &self.graph
+    }
+
+    /// The node of the road with the given id
+    #[inline]
+    pub fn node(&self, road_id: &str) -> Option<NodeIndex> {
+        self.nodes.get(road_id).copied()
+    }
+
+    /// The road with the given id
+    #[inline]
+    pub fn road(&self, road_id: &str) -> Option<&'a Road> {
+        self.node(road_id).map(|n| self.graph[n])
+    }
+
+    /// All roads that can be entered directly after leaving the given road
+    pub fn successors(&self, road_id: &str) -> impl Iterator<Item = &'a Road> + '_ {
+        self.node(road_id)
+            .into_iter()
+            .flat_map(move |n| self.graph.neighbors(n))
+            .map(move |n| self.graph[n])
+    }
+
+    /// The shortest path from the road `from` to the road `to` with respect to [`RoadEdge::length`]
+    /// or `None` if `to` is not reachable from `from`.
+    pub fn shortest_path(&self, from: &str, to: &str) -> Option<RoadPath<'a>> {
+        let start = self.node(from)?;
+        let goal = self.node(to)?;
+        let (length, path) = petgraph::algo::astar(
+            &self.graph,
+            start,
+            |n| n == goal,
+            |e| e.weight().length.get::<meter>(),
+            |_| 0.0,
+        )?;
+        Some(RoadPath {
+            roads: path.into_iter().map(|n| self.graph[n]).collect(),
+            length: Length::new::<meter>(length),
+        })
+    }
+
+    /// All roads reachable from the given road, including the road itself
+    pub fn reachable_from(&self, road_id: &str) -> Vec<&'a Road> {
+        self.node(road_id)
+            .map(|start| {
+                Bfs::new(&self.graph, start)
+                    .iter(&self.graph)
+                    .map(|n| self.graph[n])
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether the road `to` can be reached from the road `from`
+    pub fn is_reachable(&self, from: &str, to: &str) -> bool {
+        match (self.node(from), self.node(to)) {
+            (Some(from), Some(to)) => {
+                petgraph::algo::has_path_connecting(&self.graph, from, to, None)
+            }
+            _ => false,
+        }
+    }
+
+    /// All transitions leaving the given road together with the entered road
+    pub fn edges(&self, road_id: &str) -> impl Iterator<Item = (&'a Road, &RoadEdge<'a>)> + '_ {
+        self.node(road_id)
+            .into_iter()
+            .flat_map(move |n| self.graph.edges(n))
+            .map(move |e| (self.graph[e.target()], e.weight()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_road_graph_through_junction() {
+        let drive = parse(format!(
+            r#"{}{}{}{}
+            <junction id="J">
+                <connection id="0" incomingRoad="1" connectingRoad="2" contactPoint="start">
+                    <laneLink from="-1" to="-1"/>
+                </connection>
+            </junction>"#,
+            TestRoad::new("1").link(r#"<successor elementType="junction" elementId="J"/>"#),
+            TestRoad::new("2").junction("J").length(10.0).link(
+                r#"<predecessor elementType="road" elementId="1" contactPoint="end"/>
+                   <successor elementType="road" elementId="3" contactPoint="start"/>"#
+            ),
+            TestRoad::new("3")
+                .length(50.0)
+                .link(r#"<predecessor elementType="junction" elementId="J"/>"#),
+            TestRoad::new("4").length(5.0),
+        ));
+        let graph = drive.road_graph();
+
+        let path = graph.shortest_path("1", "3").unwrap();
+        let ids = path.roads.iter().map(|r| r.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, ["1", "2", "3"]);
+        assert_eq!(110.0, path.length.get::<meter>());
+
+        // connecting roads are one-way
+        assert!(!graph.is_reachable("3", "1"));
+        assert!(!graph.is_reachable("1", "4"));
+        assert_eq!(3, graph.reachable_from("1").len());
+    }
+}
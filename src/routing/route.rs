@@ -0,0 +1,318 @@
+use crate::lane::travel_direction::TravelDirection;
+use crate::object::Object;
+use crate::road::geometry::pose::Pose;
+use crate::road::Road;
+use crate::routing::router::LanePath;
+use crate::signal::Signal;
+use crate::units::prelude::*;
+use std::f64::consts::PI;
+
+/// A part of a [`Route`] that travels a single lane between two s-coordinates of a road.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RouteSegment<'a> {
+    pub road: &'a Road,
+    pub lane_id: i64,
+    /// s-coordinate at which the lane is entered
+    pub s_start: Length,
+    /// s-coordinate at which the lane is left, smaller than [`RouteSegment::s_start`] if the lane
+    /// is travelled against the direction of the reference line
+    pub s_end: Length,
+}
+
+impl<'a> RouteSegment<'a> {
+    /// Distance travelled on this segment
+    #[inline]
+    pub fn length(&self) -> Length {
+        (self.s_end - self.s_start).abs()
+    }
+
+    /// Either [`TravelDirection::Forward`] or [`TravelDirection::Backward`] with respect to the
+    /// reference line of the road
+    #[inline]
+    pub fn direction(&self) -> TravelDirection {
+        if self.s_end < self.s_start {
+            TravelDirection::Backward
+        } else {
+            TravelDirection::Forward
+        }
+    }
+
+    /// The s-coordinate reached after travelling `distance` on this segment
+    pub fn s_at(&self, distance: Length) -> Length {
+        match self.direction() {
+            TravelDirection::Backward => self.s_start - distance,
+            _ => self.s_start + distance,
+        }
+    }
+
+    /// The distance travelled on this segment until the s-coordinate `s` is reached, or `None`
+    /// if `s` is not within this segment
+    pub fn distance_to(&self, s: Length) -> Option<Length> {
+        let (min, max) = if self.s_start < self.s_end {
+            (self.s_start, self.s_end)
+        } else {
+            (self.s_end, self.s_start)
+        };
+        Some((s - self.s_start).abs()).filter(|_| min <= s && s <= max)
+    }
+}
+
+/// An ordered list of lane segments travelled one after another.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route<'a> {
+    pub segments: Vec<RouteSegment<'a>>,
+}
+
+impl<'a> Route<'a> {
+    pub fn new(segments: Vec<RouteSegment<'a>>) -> Self {
+        Self { segments }
+    }
+
+    /// Total distance travelled along this route
+    pub fn length(&self) -> Length {
+        self.segments
+            .iter()
+            .map(RouteSegment::length)
+            .fold(Length::new::<meter>(0.0), |sum, length| sum + length)
+    }
+
+    /// Segments together with the distance along the route at which they are entered
+    pub fn segments_with_distance(&self) -> impl Iterator<Item = (Length, &RouteSegment<'a>)> + '_ {
+        self.segments
+            .iter()
+            .scan(Length::new::<meter>(0.0), |distance, segment| {
+                let start = *distance;
+                *distance += segment.length();
+                Some((start, segment))
+            })
+    }
+
+    /// The segment and s-coordinate reached after travelling `distance` along the route, or `None`
+    /// if `distance` is negative or beyond the end of the route
+    pub fn position_at(&self, distance: Length) -> Option<(&RouteSegment<'a>, Length)> {
+        if distance.get::<meter>() < 0.0 {
+            return None;
+        }
+        self.segments_with_distance()
+            .find(|(start, segment)| distance <= *start + segment.length())
+            .map(|(start, segment)| (segment, segment.s_at(distance - start)))
+    }
+
+    /// The pose at the center of the travelled lane after travelling `distance` along the route.
+    /// The heading points in the direction of travel.
+    pub fn pose_at(&self, distance: Length) -> Option<Pose> {
+        let (segment, s) = self.position_at(distance)?;
+        let t = segment.road.lane_center_t(segment.lane_id, s)?;
+        let mut pose = segment.road.pose_at(s, t);
+        if segment.direction() == TravelDirection::Backward {
+            pose.hdg = (pose.hdg + Angle::new::<radian>(PI)) % Angle::new::<radian>(2.0 * PI);
+        }
+        Some(pose)
+    }
+
+    /// The distance along the route at which the s-coordinate `s` of the given road is reached
+    /// for the first time, or `None` if the route does not pass it
+    pub fn distance_to(&self, road_id: &str, s: Length) -> Option<Length> {
+        self.segments_with_distance()
+            .filter(|(_, segment)| segment.road.id == road_id)
+            .find_map(|(start, segment)| segment.distance_to(s).map(|d| start + d))
+    }
+
+    /// The remaining distance from `distance` along the route to the next time the s-coordinate
+    /// `s` of the given road is reached
+    pub fn remaining_distance(&self, distance: Length, road_id: &str, s: Length) -> Option<Length> {
+        self.segments_with_distance()
+            .filter(|(_, segment)| segment.road.id == road_id)
+            .filter_map(|(start, segment)| segment.distance_to(s).map(|d| start + d))
+            .find(|target| *target >= distance)
+            .map(|target| target - distance)
+    }
+
+    /// Signals of the travelled roads that are passed at or after `distance`, ordered by the
+    /// remaining distance to them. Only signals whose orientation and validity apply to the
+    /// travelled lane in the direction of travel are returned.
+    pub fn upcoming_signals(&self, distance: Length) -> impl Iterator<Item = (Length, &'a Signal)> {
+        self.upcoming(
+            distance,
+            |segment| {
+                segment
+                    .road
+                    .signals
+                    .iter()
+                    .flat_map(|s| &s.signal)
+                    .filter(|signal| signal.applies_to_direction(segment.direction()))
+                    .filter(|signal| signal.applies_to_lane(segment.lane_id))
+                    .collect()
+            },
+            |signal| signal.s,
+        )
+        .into_iter()
+    }
+
+    /// Objects of the travelled roads that are passed at or after `distance`, ordered by the
+    /// remaining distance to them. Only objects whose orientation and validity apply to the
+    /// travelled lane in the direction of travel are returned.
+    pub fn upcoming_objects(&self, distance: Length) -> impl Iterator<Item = (Length, &'a Object)> {
+        self.upcoming(
+            distance,
+            |segment| {
+                segment
+                    .road
+                    .objects
+                    .iter()
+                    .flat_map(|o| &o.object)
+                    .filter(|object| object.applies_to_direction(segment.direction()))
+                    .filter(|object| object.applies_to_lane(segment.lane_id))
+                    .collect()
+            },
+            |object| object.s,
+        )
+        .into_iter()
+    }
+
+    fn upcoming<T>(
+        &self,
+        distance: Length,
+        items: impl Fn(&RouteSegment<'a>) -> Vec<&'a T>,
+        s: impl Fn(&T) -> Length,
+    ) -> Vec<(Length, &'a T)> {
+        let (items, s) = (&items, &s);
+        let last = self.segments.len().saturating_sub(1);
+        let mut upcoming = self
+            .segments_with_distance()
+            .enumerate()
+            .flat_map(move |(index, (start, segment))| {
+                items(segment).into_iter().filter_map(move |item| {
+                    let offset = segment.distance_to(s(item))?;
+                    // the exit of a segment is the entry of the next one, do not report twice
+                    if offset == segment.length() && index != last {
+                        None
+                    } else {
+                        Some((start + offset - distance, item))
+                    }
+                })
+            })
+            .filter(|(remaining, _)| remaining.get::<meter>() >= 0.0)
+            .collect::<Vec<_>>();
+        upcoming.sort_by(|(a, _), (b, _)| a.get::<meter>().total_cmp(&b.get::<meter>()));
+        upcoming
+    }
+}
+
+impl<'a> From<&LanePath<'a>> for Route<'a> {
+    /// Lanes that are left by a lane change right after being entered are represented as segments
+    /// without length.
+    fn from(path: &LanePath<'a>) -> Self {
+        let segments = path
+            .lanes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let sections = &node.road.lanes.lane_section;
+                let start = Length::new::<meter>(sections[node.section].s);
+                let end = start + node.length;
+                let (s_start, s_end) = match node.direction {
+                    TravelDirection::Backward => (end, start),
+                    _ => (start, end),
+                };
+                let lane_change = path.lanes.get(index + 1).is_some_and(|next| {
+                    next.road.id == node.road.id && next.section == node.section
+                });
+                RouteSegment {
+                    road: node.road,
+                    lane_id: node.lane_id,
+                    s_start,
+                    s_end: if lane_change { s_start } else { s_end },
+                }
+            })
+            .collect();
+        Self { segments }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse, TestRoad};
+    use crate::road::geometry::pose::Pose;
+    use crate::routing::route::{Route, RouteSegment};
+    use crate::units::prelude::*;
+
+    fn m(value: f64) -> Length {
+        Length::new::<meter>(value)
+    }
+
+    fn assert_pose(expected: (f64, f64, f64), pose: Pose) {
+        assert!(
+            (expected.0 - pose.x.get::<meter>()).abs() < 1e-6,
+            "{pose:?}"
+        );
+        assert!(
+            (expected.1 - pose.y.get::<meter>()).abs() < 1e-6,
+            "{pose:?}"
+        );
+        assert!(
+            (expected.2 - pose.hdg.get::<radian>()).abs() < 1e-6,
+            "{pose:?}"
+        );
+    }
+
+    #[test]
+    pub fn test_route_queries() {
+        let drive = parse(
+            TestRoad::new("1")
+                .geometry(
+                    r#"<geometry s="0" x="0" y="0" hdg="0" length="50"><line/></geometry>
+                    <geometry s="50" x="50" y="0" hdg="0" length="50"><arc curvature="0.0628318530717959"/></geometry>"#,
+                )
+                .sides(&[("driving", 4.0)], &[("driving", 4.0)])
+                .signals(
+                    r#"<signal s="20" t="-3" id="a" dynamic="no" orientation="+" zOffset="0" country="DE" type="274" subtype="-1" value="50" unit="km/h"/>
+                    <signal s="80" t="-3" id="b" dynamic="no" orientation="+" zOffset="0" country="DE" type="274" subtype="-1" value="30" unit="km/h"/>
+                    <signal s="60" t="3" id="c" dynamic="no" orientation="-" zOffset="0" country="DE" type="274" subtype="-1" value="70" unit="km/h"/>"#,
+                ),
+        );
+        let road = &drive.road[0];
+        let route = Route::new(vec![
+            RouteSegment {
+                road,
+                lane_id: -1,
+                s_start: m(0.0),
+                s_end: m(100.0),
+            },
+            RouteSegment {
+                road,
+                lane_id: 1,
+                s_start: m(100.0),
+                s_end: m(0.0),
+            },
+        ]);
+
+        assert_eq!(200.0, route.length().get::<meter>());
+        assert_pose((10.0, -2.0, 0.0), route.pose_at(m(10.0)).unwrap());
+        // half circle with a radius of 50 / pi
+        let radius = 50.0 / std::f64::consts::PI;
+        assert_pose(
+            (50.0, 2.0 * radius + 2.0, std::f64::consts::PI),
+            route.pose_at(m(100.0)).unwrap(),
+        );
+        assert_pose(
+            (10.0, 2.0, std::f64::consts::PI),
+            route.pose_at(m(190.0)).unwrap(),
+        );
+        assert!(route.pose_at(m(201.0)).is_none());
+
+        assert_eq!(Some(m(20.0)), route.distance_to("1", m(20.0)));
+        assert_eq!(
+            Some(m(150.0)),
+            route.remaining_distance(m(30.0), "1", m(20.0))
+        );
+        assert_eq!(None, route.distance_to("2", m(20.0)));
+
+        let signals = route
+            .upcoming_signals(m(30.0))
+            .map(|(d, s)| (d.get::<meter>(), s.id.as_str()))
+            .collect::<Vec<_>>();
+        // the signals with orientation + apply on the way there, the one with - on the way back
+        assert_eq!(signals, [(50.0, "b"), (110.0, "c")]);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::core::OpenDrive;
+use crate::lane::lane_type::LaneType;
+use crate::routing::lane_graph::{LaneEdgeKind, LaneGraph, LaneNode, LaneRef};
+use crate::units::prelude::*;
+use petgraph::visit::{EdgeFiltered, EdgeRef};
+
+/// Finds routes on the [`LaneGraph`] of a document, only using lanes of the permitted
+/// [`LaneType`]s and travelling them in their [`crate::lane::travel_direction::TravelDirection`].
+#[derive(Debug, Clone)]
+pub struct Router<'a> {
+    graph: LaneGraph<'a>,
+    lane_types: Vec<LaneType>,
+    lane_change_penalty: Length,
+}
+
+/// Result of [`Router::shortest_route`].
+#[derive(Debug, Clone)]
+pub struct LanePath<'a> {
+    /// Lanes in the order in which they are travelled, including the start and the target lane
+    pub lanes: Vec<LaneNode<'a>>,
+    /// Distance travelled from the start of the first lane to the entry of the last lane,
+    /// including lane change penalties
+    pub length: Length,
+}
+
+impl<'a> Router<'a> {
+    pub fn new(drive: &'a OpenDrive) -> Self {
+        Self::from(drive.lane_graph())
+    }
+
+    /// Lane types that may be used by routes, defaults to [`Router::default_lane_types`]
+    pub fn with_lane_types(mut self, lane_types: Vec<LaneType>) -> Self {
+        self.lane_types = lane_types;
+        self
+    }
+
+    /// Additional cost of each lane change, defaults to zero
+    pub fn with_lane_change_penalty(mut self, penalty: Length) -> Self {
+        self.lane_change_penalty = penalty;
+        self
+    }
+
+    /// All [`LaneType::is_drivable`] lane types
+    pub fn default_lane_types() -> Vec<LaneType> {
+        LaneType::ALL
+            .iter()
+            .filter(|t| t.is_drivable())
+            .cloned()
+            .collect()
+    }
+
+    #[inline]
+    pub fn lane_graph(&self) -> &LaneGraph<'a> {
+        &self.graph
+    }
+
+    /// The shortest route from the lane `from` to the lane `to`, or `None` if either lane is not
+    /// of a permitted type or `to` cannot be reached from `from`.
+    pub fn shortest_route(&self, from: LaneRef<'_>, to: LaneRef<'_>) -> Option<LanePath<'a>> {
+        let graph = self.graph.graph();
+        let permitted = |lane: &LaneNode| self.lane_types.contains(&lane.lane.r#type);
+        let filtered = EdgeFiltered::from_fn(graph, |e| {
+            permitted(&graph[e.source()]) && permitted(&graph[e.target()])
+        });
+        let goals = self.graph.nodes_of(to).collect::<Vec<_>>();
+
+        self.graph
+            .nodes_of(from)
+            .filter(|n| permitted(&graph[*n]))
+            .filter_map(|start| {
+                petgraph::algo::astar(
+                    &filtered,
+                    start,
+                    |n| goals.contains(&n) && permitted(&graph[n]),
+                    |e| match e.weight().kind {
+                        LaneEdgeKind::LaneChange => self.lane_change_penalty.get::<meter>(),
+                        _ => e.weight().length.get::<meter>(),
+                    },
+                    |_| 0.0,
+                )
+            })
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(length, path)| LanePath {
+                lanes: path.into_iter().map(|n| graph[n]).collect(),
+                length: Length::new::<meter>(length),
+            })
+    }
+}
+
+impl<'a> From<LaneGraph<'a>> for Router<'a> {
+    fn from(graph: LaneGraph<'a>) -> Self {
+        Self {
+            graph,
+            lane_types: Self::default_lane_types(),
+            lane_change_penalty: Length::new::<meter>(0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::{parse, TestRoad};
+    use crate::routing::lane_graph::{LaneEdgeKind, LaneRef};
+    use crate::routing::router::Router;
+    use crate::units::prelude::*;
+
+    fn drive() -> OpenDrive {
+        parse(format!(
+            r#"{}{}{}
+            <junction id="J">
+                <connection id="0" incomingRoad="1" connectingRoad="2" contactPoint="start">
+                    <laneLink from="-2" to="-1"/>
+                </connection>
+            </junction>"#,
+            TestRoad::new("1")
+                .link(r#"<successor elementType="junction" elementId="J"/>"#)
+                .lanes(
+                    r#"<laneSection s="0">
+                        <center><lane id="0" type="none"/></center>
+                        <right>
+                            <lane id="-1" type="driving">
+                                <width sOffset="0" a="3.5" b="0" c="0" d="0"/>
+                                <roadMark sOffset="0" type="broken" color="standard"/>
+                            </lane>
+                            <lane id="-2" type="driving"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane>
+                            <lane id="-3" type="sidewalk"><width sOffset="0" a="2" b="0" c="0" d="0"/></lane>
+                        </right>
+                    </laneSection>"#
+                ),
+            TestRoad::new("2")
+                .length(10.0)
+                .at(100.0, 0.0, 0.0)
+                .junction("J")
+                .link(
+                    r#"<predecessor elementType="road" elementId="1" contactPoint="end"/>
+                    <successor elementType="road" elementId="3" contactPoint="start"/>"#
+                )
+                .lanes(
+                    r#"<laneSection s="0">
+                        <center><lane id="0" type="none"/></center>
+                        <right><lane id="-1" type="driving">
+                            <link><predecessor id="-2"/><successor id="-1"/></link>
+                            <width sOffset="0" a="3.5" b="0" c="0" d="0"/>
+                        </lane></right>
+                    </laneSection>"#
+                ),
+            TestRoad::new("3")
+                .length(50.0)
+                .at(110.0, 0.0, 0.0)
+                .link(r#"<predecessor elementType="junction" elementId="J"/>"#)
+                .sides(&[("driving", 3.5)], &[("driving", 3.5)]),
+        ))
+    }
+
+    fn lane(road: &str, lane: i64) -> LaneRef<'_> {
+        LaneRef {
+            road,
+            section: 0,
+            lane,
+        }
+    }
+
+    #[test]
+    pub fn test_shortest_route_with_lane_change() {
+        let drive = drive();
+        let router = Router::new(&drive);
+
+        let route = router.shortest_route(lane("1", -1), lane("3", -1)).unwrap();
+        let lanes = route
+            .lanes
+            .iter()
+            .map(|l| (l.road.id.as_str(), l.lane_id))
+            .collect::<Vec<_>>();
+        assert_eq!(lanes, [("1", -1), ("1", -2), ("2", -1), ("3", -1)]);
+        assert_eq!(110.0, route.length.get::<meter>());
+
+        let graph = router.lane_graph().graph();
+        assert!(graph
+            .edge_weights()
+            .any(|e| e.kind == LaneEdgeKind::Junction));
+
+        // lanes are travelled in their direction only, sidewalks are not permitted
+        assert!(router.shortest_route(lane("3", 1), lane("1", -1)).is_none());
+        assert!(router
+            .shortest_route(lane("1", -3), lane("3", -1))
+            .is_none());
+    }
+}
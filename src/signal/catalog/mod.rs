@@ -0,0 +1,57 @@
+//! Typed catalogs of the signal codes of countries, which map the `@type` and `@subtype` of a
+//! `<signal>` to the sign they stand for. Codes that a catalog does not know are kept as they are.
+
+pub mod mutcd;
+pub mod stvo;
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse_revision, TestRoad};
+    use crate::signal::catalog::mutcd::MutcdSign;
+    use crate::signal::catalog::stvo::StvoSign;
+    use crate::units::prelude::*;
+
+    #[test]
+    pub fn test_signal_catalogs() {
+        assert_eq!(StvoSign::Stop, StvoSign::from_codes("206", "-1", None));
+        assert_eq!(
+            StvoSign::SpeedLimit(50.0),
+            StvoSign::from_codes("274", "55", None)
+        );
+        assert_eq!(
+            StvoSign::SpeedLimit(70.0),
+            StvoSign::from_codes("274", "70", None)
+        );
+        assert_eq!(
+            StvoSign::SpeedLimitZone(30.0),
+            StvoSign::from_codes("274.1", "-1", None)
+        );
+        assert_eq!(
+            StvoSign::Unknown {
+                r#type: "274".to_string(),
+                subtype: "-1".to_string()
+            },
+            StvoSign::from_codes("274", "-1", None)
+        );
+        assert_eq!(
+            MutcdSign::Stop { all_way: true },
+            MutcdSign::from_codes("R1-1", "R1-3P", None)
+        );
+        assert_eq!("R1-1", MutcdSign::Stop { all_way: false }.r#type());
+
+        let drive = parse_revision(
+            8,
+            TestRoad::new("1").sides(&[], &[]).signals(
+                r#"<signal s="20" t="-3" id="a" dynamic="no" orientation="+" zOffset="0" country="DE" type="274" subtype="-1" value="50" unit="km/h"/>
+                    <signal s="40" t="-3" id="b" dynamic="no" orientation="+" zOffset="0" country="US" type="R2-1" subtype="-1" value="72.42048" unit="km/h"/>"#,
+            ),
+        );
+        let signals = &drive.road[0].signals.as_ref().unwrap().signal;
+        let stvo = StvoSign::from_signal(&signals[0]);
+        assert_eq!(StvoSign::SpeedLimit(50.0), stvo);
+        assert_eq!("274", stvo.r#type());
+        let mutcd = MutcdSign::from_signal(&signals[1]);
+        let speed = mutcd.speed().unwrap().get::<mile_per_hour>();
+        assert!((speed - 45.0).abs() < 1e-9, "{speed}");
+    }
+}
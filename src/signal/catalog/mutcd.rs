@@ -0,0 +1,171 @@
+use crate::signal::Signal;
+use crate::units::prelude::*;
+
+/// A sign of the US Manual on Uniform Traffic Control Devices, identified by the `@type` and
+/// `@subtype` of a `<signal>` with the country `US`. Speeds are given in mph.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MutcdSign {
+    /// R1-1 "Stop", an all-way stop with the subtype R1-3P
+    Stop { all_way: bool },
+    /// R1-2 "Yield"
+    Yield,
+    /// R2-1 "Speed Limit"
+    SpeedLimit(f64),
+    /// R2-3P "Night" speed limit
+    NightSpeedLimit(f64),
+    /// R2-4P "Minimum Speed"
+    MinimumSpeed(f64),
+    /// R3-1 "No Right Turn"
+    NoRightTurn,
+    /// R3-2 "No Left Turn"
+    NoLeftTurn,
+    /// R3-4 "No U-Turn"
+    NoUTurn,
+    /// R4-1 "Do Not Pass"
+    DoNotPass,
+    /// R4-2 "Pass With Care"
+    PassWithCare,
+    /// R4-7 "Keep Right"
+    KeepRight,
+    /// R5-1 "Do Not Enter"
+    DoNotEnter,
+    /// R6-1 "One Way"
+    OneWay,
+    /// R7-1 "No Parking Any Time"
+    NoParking,
+    /// R8-3 "No Parking" symbol
+    NoParkingSymbol,
+    /// R10-6 "Stop Here On Red"
+    StopHereOnRed,
+    /// W3-1 "Stop Ahead"
+    StopAhead,
+    /// W3-2 "Yield Ahead"
+    YieldAhead,
+    /// W3-3 "Signal Ahead"
+    SignalAhead,
+    /// W11-2 "Pedestrian Crossing"
+    PedestrianCrossing,
+    /// W13-1P "Advisory Speed"
+    AdvisorySpeed(f64),
+    /// A sign that is not part of this catalog or a speed sign without a speed
+    Unknown { r#type: String, subtype: String },
+}
+
+impl MutcdSign {
+    /// The sign with the given codes. Speeds are taken from `value` in mph or, if that is not
+    /// given, from a subtype like `45`.
+    pub fn from_codes(r#type: &str, subtype: &str, value: Option<f64>) -> Self {
+        let speed = value.or_else(|| {
+            subtype
+                .parse::<u32>()
+                .ok()
+                .filter(|speed| *speed > 0 && speed % 5 == 0)
+                .map(f64::from)
+        });
+        let unknown = || Self::Unknown {
+            r#type: r#type.to_string(),
+            subtype: subtype.to_string(),
+        };
+        match r#type {
+            "R1-1" => Self::Stop {
+                all_way: subtype.starts_with("R1-3"),
+            },
+            "R1-2" => Self::Yield,
+            "R2-1" => speed.map_or_else(unknown, Self::SpeedLimit),
+            "R2-3P" => speed.map_or_else(unknown, Self::NightSpeedLimit),
+            "R2-4P" => speed.map_or_else(unknown, Self::MinimumSpeed),
+            "R3-1" => Self::NoRightTurn,
+            "R3-2" => Self::NoLeftTurn,
+            "R3-4" => Self::NoUTurn,
+            "R4-1" => Self::DoNotPass,
+            "R4-2" => Self::PassWithCare,
+            "R4-7" => Self::KeepRight,
+            "R5-1" => Self::DoNotEnter,
+            "R6-1" => Self::OneWay,
+            "R7-1" => Self::NoParking,
+            "R8-3" => Self::NoParkingSymbol,
+            "R10-6" => Self::StopHereOnRed,
+            "W3-1" => Self::StopAhead,
+            "W3-2" => Self::YieldAhead,
+            "W3-3" => Self::SignalAhead,
+            "W11-2" => Self::PedestrianCrossing,
+            "W13-1P" => speed.map_or_else(unknown, Self::AdvisorySpeed),
+            _ => unknown(),
+        }
+    }
+
+    /// The sign of the signal, regardless of its country. The value of the signal is converted
+    /// to mph if it is given in a speed unit.
+    pub fn from_signal(signal: &Signal) -> Self {
+        let value = signal
+            .speed_limit()
+            .map(|speed| speed.get::<mile_per_hour>())
+            .or(signal.value);
+        Self::from_codes(&signal.r#type, &signal.subtype, value)
+    }
+
+    /// The `@type` of this sign
+    pub fn r#type(&self) -> &str {
+        match self {
+            Self::Stop { .. } => "R1-1",
+            Self::Yield => "R1-2",
+            Self::SpeedLimit(_) => "R2-1",
+            Self::NightSpeedLimit(_) => "R2-3P",
+            Self::MinimumSpeed(_) => "R2-4P",
+            Self::NoRightTurn => "R3-1",
+            Self::NoLeftTurn => "R3-2",
+            Self::NoUTurn => "R3-4",
+            Self::DoNotPass => "R4-1",
+            Self::PassWithCare => "R4-2",
+            Self::KeepRight => "R4-7",
+            Self::DoNotEnter => "R5-1",
+            Self::OneWay => "R6-1",
+            Self::NoParking => "R7-1",
+            Self::NoParkingSymbol => "R8-3",
+            Self::StopHereOnRed => "R10-6",
+            Self::StopAhead => "W3-1",
+            Self::YieldAhead => "W3-2",
+            Self::SignalAhead => "W3-3",
+            Self::PedestrianCrossing => "W11-2",
+            Self::AdvisorySpeed(_) => "W13-1P",
+            Self::Unknown { r#type, .. } => r#type,
+        }
+    }
+
+    /// The speed of a speed sign
+    pub fn speed(&self) -> Option<Velocity> {
+        match self {
+            Self::SpeedLimit(speed)
+            | Self::NightSpeedLimit(speed)
+            | Self::MinimumSpeed(speed)
+            | Self::AdvisorySpeed(speed) => Some(Velocity::new::<mile_per_hour>(*speed)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::signal::catalog::mutcd::MutcdSign;
+
+    #[test]
+    pub fn test_mutcd_sign_speeds() {
+        assert_eq!(
+            MutcdSign::SpeedLimit(45.0),
+            MutcdSign::from_codes("R2-1", "45", None)
+        );
+        assert_eq!(
+            MutcdSign::NightSpeedLimit(35.0),
+            MutcdSign::from_codes("R2-3P", "-1", Some(35.0))
+        );
+        assert_eq!(
+            MutcdSign::Unknown {
+                r#type: "R2-1".to_string(),
+                subtype: "47".to_string()
+            },
+            MutcdSign::from_codes("R2-1", "47", None)
+        );
+        assert_eq!(MutcdSign::Yield, MutcdSign::from_codes("R1-2", "-1", None));
+        assert_eq!("W13-1P", MutcdSign::AdvisorySpeed(25.0).r#type());
+    }
+}
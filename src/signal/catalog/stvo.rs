@@ -0,0 +1,214 @@
+use crate::signal::Signal;
+use crate::units::prelude::*;
+
+/// A sign of the German Straßenverkehrs-Ordnung, identified by the `@type` and `@subtype` of a
+/// `<signal>` with the country `DE`. Speeds are given in km/h.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StvoSign {
+    /// 205 "Vorfahrt gewähren"
+    Yield,
+    /// 206 "Halt. Vorfahrt gewähren"
+    Stop,
+    /// 208 "Vorrang des Gegenverkehrs"
+    YieldToOncoming,
+    /// 215 "Kreisverkehr"
+    Roundabout,
+    /// 220 "Einbahnstraße"
+    OneWay,
+    /// 250 "Verbot für Fahrzeuge aller Art"
+    NoVehicles,
+    /// 267 "Verbot der Einfahrt"
+    NoEntry,
+    /// 274 "Zulässige Höchstgeschwindigkeit"
+    SpeedLimit(f64),
+    /// 274.1 "Beginn einer Tempo 30-Zone", 30 km/h unless given otherwise
+    SpeedLimitZone(f64),
+    /// 274.2 "Ende einer Tempo 30-Zone"
+    SpeedLimitZoneEnd(f64),
+    /// 275 "Vorgeschriebene Mindestgeschwindigkeit"
+    MinimumSpeed(f64),
+    /// 276 "Überholverbot für Kraftfahrzeuge aller Art"
+    NoOvertaking,
+    /// 277 "Überholverbot für Kraftfahrzeuge über 3,5 t"
+    NoOvertakingTrucks,
+    /// 278 "Ende der zulässigen Höchstgeschwindigkeit"
+    SpeedLimitEnd(f64),
+    /// 280 "Ende des Überholverbots für Kraftfahrzeuge aller Art"
+    NoOvertakingEnd,
+    /// 282 "Ende sämtlicher Streckenverbote"
+    EndOfRestrictions,
+    /// 283 "Absolutes Haltverbot"
+    NoStopping,
+    /// 286 "Eingeschränktes Haltverbot"
+    NoParking,
+    /// 293 "Fußgängerüberweg", the marking of a crosswalk
+    CrosswalkMarking,
+    /// 294 "Haltlinie"
+    StopLine,
+    /// 301 "Vorfahrt" at the next intersection
+    PriorityAtNextIntersection,
+    /// 306 "Vorfahrtstraße"
+    PriorityRoad,
+    /// 307 "Ende der Vorfahrtstraße"
+    PriorityRoadEnd,
+    /// 310 "Ortstafel" at the entrance of a town
+    TownEntrance,
+    /// 311 "Ortstafel" at the exit of a town
+    TownExit,
+    /// 325.1 "Beginn eines verkehrsberuhigten Bereichs"
+    LivingStreet,
+    /// 325.2 "Ende eines verkehrsberuhigten Bereichs"
+    LivingStreetEnd,
+    /// 350 "Fußgängerüberweg"
+    PedestrianCrossing,
+    /// 1000001, a traffic light for vehicles
+    TrafficLight,
+    /// 1000002, a traffic light for pedestrians
+    PedestrianTrafficLight,
+    /// A sign that is not part of this catalog or a speed limit without a speed
+    Unknown { r#type: String, subtype: String },
+}
+
+impl StvoSign {
+    /// The sign with the given codes. Speeds are taken from `value` in km/h or, if that is not
+    /// given, from the subtype: either a speed like `50` or a number of the catalog of traffic
+    /// signs (VzKat) like `55` for 274-55, which is 50 km/h.
+    ///
+    /// The VzKat numbers 51 to 63 overlap with speeds. Multiples of 10 are read as speeds, so
+    /// that `60` is 60 km/h, and the other numbers of that range as VzKat numbers. A sign like
+    /// 274-60, which is 100 km/h, is only read as such if its speed is given by `value`.
+    pub fn from_codes(r#type: &str, subtype: &str, value: Option<f64>) -> Self {
+        let speed = value.or_else(|| match subtype.parse::<u32>().ok()? {
+            speed if speed > 0 && speed % 10 == 0 => Some(f64::from(speed)),
+            vzkat @ 51..=63 => Some(f64::from(vzkat - 50) * 10.0),
+            speed if speed > 0 && speed % 5 == 0 => Some(f64::from(speed)),
+            _ => None,
+        });
+        let unknown = || Self::Unknown {
+            r#type: r#type.to_string(),
+            subtype: subtype.to_string(),
+        };
+        match r#type {
+            "205" => Self::Yield,
+            "206" => Self::Stop,
+            "208" => Self::YieldToOncoming,
+            "215" => Self::Roundabout,
+            "220" => Self::OneWay,
+            "250" => Self::NoVehicles,
+            "267" => Self::NoEntry,
+            "274" => speed.map_or_else(unknown, Self::SpeedLimit),
+            "274.1" => Self::SpeedLimitZone(speed.unwrap_or(30.0)),
+            "274.2" => Self::SpeedLimitZoneEnd(speed.unwrap_or(30.0)),
+            "275" => speed.map_or_else(unknown, Self::MinimumSpeed),
+            "276" => Self::NoOvertaking,
+            "277" => Self::NoOvertakingTrucks,
+            "278" => speed.map_or_else(unknown, Self::SpeedLimitEnd),
+            "280" => Self::NoOvertakingEnd,
+            "282" => Self::EndOfRestrictions,
+            "283" => Self::NoStopping,
+            "286" => Self::NoParking,
+            "293" => Self::CrosswalkMarking,
+            "294" => Self::StopLine,
+            "301" => Self::PriorityAtNextIntersection,
+            "306" => Self::PriorityRoad,
+            "307" => Self::PriorityRoadEnd,
+            "310" => Self::TownEntrance,
+            "311" => Self::TownExit,
+            "325.1" => Self::LivingStreet,
+            "325.2" => Self::LivingStreetEnd,
+            "350" => Self::PedestrianCrossing,
+            "1000001" => Self::TrafficLight,
+            "1000002" => Self::PedestrianTrafficLight,
+            _ => unknown(),
+        }
+    }
+
+    /// The sign of the signal, regardless of its country. The value of the signal is converted
+    /// to km/h if it is given in a speed unit.
+    pub fn from_signal(signal: &Signal) -> Self {
+        let value = signal
+            .speed_limit()
+            .map(|speed| speed.get::<kilometer_per_hour>())
+            .or(signal.value);
+        Self::from_codes(&signal.r#type, &signal.subtype, value)
+    }
+
+    /// The `@type` of this sign
+    pub fn r#type(&self) -> &str {
+        match self {
+            Self::Yield => "205",
+            Self::Stop => "206",
+            Self::YieldToOncoming => "208",
+            Self::Roundabout => "215",
+            Self::OneWay => "220",
+            Self::NoVehicles => "250",
+            Self::NoEntry => "267",
+            Self::SpeedLimit(_) => "274",
+            Self::SpeedLimitZone(_) => "274.1",
+            Self::SpeedLimitZoneEnd(_) => "274.2",
+            Self::MinimumSpeed(_) => "275",
+            Self::NoOvertaking => "276",
+            Self::NoOvertakingTrucks => "277",
+            Self::SpeedLimitEnd(_) => "278",
+            Self::NoOvertakingEnd => "280",
+            Self::EndOfRestrictions => "282",
+            Self::NoStopping => "283",
+            Self::NoParking => "286",
+            Self::CrosswalkMarking => "293",
+            Self::StopLine => "294",
+            Self::PriorityAtNextIntersection => "301",
+            Self::PriorityRoad => "306",
+            Self::PriorityRoadEnd => "307",
+            Self::TownEntrance => "310",
+            Self::TownExit => "311",
+            Self::LivingStreet => "325.1",
+            Self::LivingStreetEnd => "325.2",
+            Self::PedestrianCrossing => "350",
+            Self::TrafficLight => "1000001",
+            Self::PedestrianTrafficLight => "1000002",
+            Self::Unknown { r#type, .. } => r#type,
+        }
+    }
+
+    /// The speed of a speed limit or of the end of one, or the minimum speed
+    pub fn speed(&self) -> Option<Velocity> {
+        match self {
+            Self::SpeedLimit(speed)
+            | Self::SpeedLimitZone(speed)
+            | Self::SpeedLimitZoneEnd(speed)
+            | Self::MinimumSpeed(speed)
+            | Self::SpeedLimitEnd(speed) => Some(Velocity::new::<kilometer_per_hour>(*speed)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::signal::catalog::stvo::StvoSign;
+
+    #[test]
+    pub fn test_stvo_sign_speeds() {
+        let speed_limit =
+            |subtype: &str, value: Option<f64>| match StvoSign::from_codes("274", subtype, value) {
+                StvoSign::SpeedLimit(speed) => Some(speed),
+                _ => None,
+            };
+        // VzKat numbers
+        assert_eq!(Some(50.0), speed_limit("55", None));
+        assert_eq!(Some(130.0), speed_limit("63", None));
+        // speeds, including those that overlap with VzKat numbers
+        assert_eq!(Some(60.0), speed_limit("60", None));
+        assert_eq!(Some(30.0), speed_limit("30", None));
+        assert_eq!(Some(15.0), speed_limit("15", None));
+        assert_eq!(Some(100.0), speed_limit("60", Some(100.0)));
+        assert_eq!(None, speed_limit("-1", None));
+        assert_eq!(None, speed_limit("0", None));
+        assert_eq!(None, speed_limit("57.5", None));
+        assert_eq!(
+            StvoSign::SpeedLimitZone(20.0),
+            StvoSign::from_codes("274.1", "20", None)
+        );
+        assert_eq!("274.2", StvoSign::SpeedLimitZoneEnd(30.0).r#type());
+    }
+}
@@ -22,10 +22,14 @@ impl Controller {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "id" => Some(self.id.as_str()),
             "name" => self.name.as_deref(),
             "sequence" => self.sequence.map(|s| s.to_string()).as_deref(),
@@ -34,19 +38,21 @@ impl Controller {
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for control in &self.control {
-            visit_children!(visitor, "control" => control);
+            visit_children!(visitor, options, "control" => control);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Controller
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -65,7 +71,7 @@ where
             id: read.attribute("id")?,
             name: read.attribute_opt("name")?,
             sequence: read.attribute_opt("sequence")?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -1,23 +1,25 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::boolean::Bool;
+use crate::lane::travel_direction::TravelDirection;
 use crate::object::lane_validity::LaneValidity;
 use crate::object::orientation::Orientation;
 use crate::road::country_code::CountryCode;
-use crate::road::unit::Unit;
+use crate::road::unit::{SpeedValue, Unit};
 use crate::signal::dependency::Dependency;
 use crate::signal::position::inertial::PositionInertial;
 use crate::signal::position::road::PositionRoad;
 use crate::signal::position::Position;
 use crate::signal::reference::Reference;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::angle::radian;
-use uom::si::f64::{Angle, Length};
-use uom::si::length::meter;
 
+pub mod catalog;
 pub mod control;
 pub mod controller;
 pub mod dependency;
 pub mod position;
 pub mod reference;
+pub mod semantics;
 pub mod signal_reference;
 pub mod signals;
 
@@ -39,7 +41,7 @@ pub struct Signal {
     pub height: Option<Length>,
     /// Heading offset of the signal (relative to @orientation, if orientation is equal to “+” or “-“)
     /// Heading offset of the signal (relative to reference line, if orientation is equal to “none” )
-    pub h_offset: Option<Length>,
+    pub h_offset: Option<Angle>,
     /// Unique ID of the signal within the OpenDRIVE file
     pub id: String,
     /// Name of the signal. May be chosen freely.
@@ -77,65 +79,102 @@ pub struct Signal {
 }
 
 impl Signal {
+    /// Whether this signal is valid for the given lane. Signals without `<validity>` elements are
+    /// valid for all lanes.
+    pub fn applies_to_lane(&self, lane_id: i64) -> bool {
+        self.validity.is_empty() || self.validity.iter().any(|v| v.contains(lane_id))
+    }
+
+    /// Whether this signal is valid for traffic travelling in the given direction with respect to
+    /// the reference line, see [`Signal::orientation`]
+    pub fn applies_to_direction(&self, direction: TravelDirection) -> bool {
+        match self.orientation {
+            Orientation::None => true,
+            Orientation::Plus => direction.permits(TravelDirection::Forward),
+            Orientation::Minus => direction.permits(TravelDirection::Backward),
+        }
+    }
+
+    /// The [`Signal::value`] of this signal if it is given in a [`crate::road::unit::SpeedUnit`]
+    pub fn speed_value(&self) -> Option<SpeedValue> {
+        match (&self.unit, self.value) {
+            (Some(Unit::Speed(unit)), Some(value)) => Some(SpeedValue::new(value, unit.clone())),
+            _ => None,
+        }
+    }
+
+    /// The speed limit of this signal, that is, its [`Signal::speed_value`] as velocity
+    pub fn speed_limit(&self) -> Option<Velocity> {
+        self.speed_value().map(|speed| speed.velocity())
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes_flatten!(
             visitor,
+            options,
             "country" => self.country.as_ref().map(CountryCode::as_str),
             "countryRevision" => self.country_revision.as_deref(),
-            "dynamic" => Some(if self.dynamic { "yes" } else { "no" }),
-            "height" => self.height.map(|v| v.value.to_scientific_string()).as_deref(),
-            "hOffset" => self.h_offset.map(|v| v.value.to_scientific_string()).as_deref(),
+            "dynamic" => Some(Bool(self.dynamic).as_yes_no()),
+            "height" => self.height.map(|v| options.format("height", v.get::<meter>())).as_deref(),
+            "hOffset" => self.h_offset.map(|v| options.format("hOffset", v.get::<radian>())).as_deref(),
             "id" => Some(self.id.as_str()),
             "name" => self.name.as_deref(),
             "orientation" => Some(self.orientation.as_str()),
-            "pitch" => self.pitch.map(|v| v.value.to_scientific_string()).as_deref(),
-            "roll" => self.roll.map(|v| v.value.to_scientific_string()).as_deref(),
-            "s" => Some(self.s.value.to_scientific_string()).as_deref(),
+            "pitch" => self.pitch.map(|v| options.format("pitch", v.get::<radian>())).as_deref(),
+            "roll" => self.roll.map(|v| options.format("roll", v.get::<radian>())).as_deref(),
+            "s" => Some(options.format("s", self.s.get::<meter>())).as_deref(),
             "subtype" => Some(self.subtype.as_str()),
-            "t" => Some(self.t.value.to_scientific_string()).as_deref(),
+            "t" => Some(options.format("t", self.t.get::<meter>())).as_deref(),
             "text" => self.text.as_deref(),
             "type" => Some(self.r#type.as_str()),
             "unit" => self.unit.as_ref().map(Unit::as_str),
-            "value" => self.value.map(|v| v.to_scientific_string()).as_deref(),
-            "width" => self.width.map(|v| v.value.to_scientific_string()).as_deref(),
-            "zOffset" => Some(self.z_offset.value.to_scientific_string()).as_deref(),
+            "value" => self.value.map(|v| options.format("value", v)).as_deref(),
+            "width" => self.width.map(|v| options.format("width", v.get::<meter>())).as_deref(),
+            "zOffset" => Some(options.format("zOffset", self.z_offset.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for validity in &self.validity {
-            visit_children!(visitor, "validity" => validity);
+            visit_children!(visitor, options, "validity" => validity);
         }
 
         for dependency in &self.dependency {
-            visit_children!(visitor, "dependency" => dependency);
+            visit_children!(visitor, options, "dependency" => dependency);
         }
 
         for reference in &self.reference {
-            visit_children!(visitor, "reference" => reference);
+            visit_children!(visitor, options, "reference" => reference);
         }
 
         match &self.choice {
-            Some(Position::Inertial(v)) => visit_children!(visitor, "positionInertial" => v),
-            Some(Position::Road(v)) => visit_children!(visitor, "positionRoad" => v),
+            Some(Position::Inertial(v)) => {
+                visit_children!(visitor, options, "positionInertial" => v)
+            }
+            Some(Position::Road(v)) => visit_children!(visitor, options, "positionRoad" => v),
             None => {}
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Signal
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -163,11 +202,9 @@ where
             choice,
             country: read.attribute_opt("country")?,
             country_revision: read.attribute_opt("countryRevision")?,
-            dynamic: read
-                .attribute::<String>("dynamic")
-                .map(|v| v.eq_ignore_ascii_case("yes"))?,
+            dynamic: read.attribute::<Bool>("dynamic")?.into(),
             height: read.attribute_opt("height")?.map(Length::new::<meter>),
-            h_offset: read.attribute_opt("hOffset")?.map(Length::new::<meter>),
+            h_offset: read.attribute_opt("hOffset")?.map(Angle::new::<radian>),
             id: read.attribute("id")?,
             name: read.attribute_opt("name")?,
             orientation: read.attribute("orientation")?,
@@ -178,11 +215,11 @@ where
             t: read.attribute("t").map(Length::new::<meter>)?,
             text: read.attribute_opt("text")?,
             r#type: read.attribute("type")?,
-            unit: read.attribute_opt("unit")?,
+            unit: read.enum_attribute_opt("unit")?,
             value: read.attribute_opt("value")?,
             width: read.attribute_opt("width")?.map(Length::new::<meter>),
             z_offset: read.attribute("zOffset").map(Length::new::<meter>)?,
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -205,7 +242,7 @@ impl arbitrary::Arbitrary<'_> for Signal {
                 .transpose()?,
             h_offset: u
                 .arbitrary::<Option<()>>()?
-                .map(|_| u.not_nan_f64().map(Length::new::<meter>))
+                .map(|_| u.not_nan_f64().map(Angle::new::<radian>))
                 .transpose()?,
             id: u.arbitrary()?,
             name: u.arbitrary()?,
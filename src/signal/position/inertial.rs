@@ -1,7 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::angle::radian;
-use uom::si::f64::{Angle, Length};
-use uom::si::length::meter;
 
 /// Describes the reference point of the physical position in inertial coordinates in cases where it
 /// deviates from the logical position. Defines the inertial position.
@@ -28,31 +26,35 @@ impl PositionInertial {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "hdg" => Some(self.hdg.value.to_scientific_string()).as_deref(),
-            "pitch" => self.pitch.map(|v| v.value.to_scientific_string()).as_deref(),
-            "roll" => self.roll.map(|v| v.value.to_scientific_string()).as_deref(),
-            "x" => Some(self.x.value.to_scientific_string()).as_deref(),
-            "y" => Some(self.y.value.to_scientific_string()).as_deref(),
-            "z" => Some(self.z.value.to_scientific_string()).as_deref(),
+            options,
+            "hdg" => Some(options.format("hdg", self.hdg.get::<radian>())).as_deref(),
+            "pitch" => self.pitch.map(|v| options.format("pitch", v.get::<radian>())).as_deref(),
+            "roll" => self.roll.map(|v| options.format("roll", v.get::<radian>())).as_deref(),
+            "x" => Some(options.format("x", self.x.get::<meter>())).as_deref(),
+            "y" => Some(options.format("y", self.y.get::<meter>())).as_deref(),
+            "z" => Some(options.format("z", self.z.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for PositionInertial
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -1,7 +1,5 @@
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::angle::radian;
-use uom::si::f64::{Angle, Length};
-use uom::si::length::meter;
 
 /// Describes the reference point of the physical position road coordinates in cases where it
 /// deviates from the logical position. Defines the position on the road.
@@ -30,32 +28,36 @@ impl PositionRoad {
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "hOffset" => Some(self.h_offset.value.to_scientific_string()).as_deref(),
-            "pitch" => self.pitch.map(|v| v.value.to_scientific_string()).as_deref(),
+            options,
+            "hOffset" => Some(options.format("hOffset", self.h_offset.get::<radian>())).as_deref(),
+            "pitch" => self.pitch.map(|v| options.format("pitch", v.get::<radian>())).as_deref(),
             "roadId" => Some(self.road_id.as_str()),
-            "roll" => self.roll.map(|v| v.value.to_scientific_string()).as_deref(),
-            "s" => Some(self.s.value.to_scientific_string()).as_deref(),
-            "t" => Some(self.t.value.to_scientific_string()).as_deref(),
-            "zOffset" => Some(self.z_offset.value.to_scientific_string()).as_deref(),
+            "roll" => self.roll.map(|v| options.format("roll", v.get::<radian>())).as_deref(),
+            "s" => Some(options.format("s", self.s.get::<meter>())).as_deref(),
+            "t" => Some(options.format("t", self.t.get::<meter>())).as_deref(),
+            "zOffset" => Some(options.format("zOffset", self.z_offset.get::<meter>())).as_deref(),
         )
     }
 
     pub fn visit_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        visit_children!(visitor, options);
         Ok(())
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for PositionRoad
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -0,0 +1,212 @@
+use crate::road::country_code::CountryCode;
+use crate::road::unit::Unit;
+use crate::signal::catalog::mutcd::MutcdSign;
+use crate::signal::catalog::stvo::StvoSign;
+use crate::signal::Signal;
+use crate::units::prelude::*;
+
+/// What a signal means, independent of the catalog of its country, see [`Signal::semantics`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalSemantics {
+    pub category: SignalCategory,
+    /// The speed of speed signs, otherwise the value of the signal in normalized units
+    pub value: Option<SignalValue>,
+}
+
+/// The meaning of a signal shared by the catalogs of all countries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignalCategory {
+    SpeedLimit,
+    SpeedLimitEnd,
+    MinimumSpeed,
+    AdvisorySpeed,
+    Stop,
+    Yield,
+    /// Right of way at the next intersection or along the road
+    Priority,
+    PriorityEnd,
+    NoOvertaking,
+    NoOvertakingEnd,
+    /// The end of all speed limits and overtaking bans
+    EndOfRestrictions,
+    NoEntry,
+    OneWay,
+    /// A prohibited turn or U-turn
+    TurnRestriction,
+    /// A direction to be followed, like around a roundabout or keeping right
+    MandatoryDirection,
+    NoStopping,
+    NoParking,
+    PedestrianCrossing,
+    TownEntrance,
+    TownExit,
+    LivingStreet,
+    LivingStreetEnd,
+    TrafficLight,
+    /// A sign warning of something ahead
+    Warning,
+    Unknown,
+}
+
+/// The value of a signal in normalized units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SignalValue {
+    Speed(Velocity),
+    Distance(Length),
+    /// A mass in kilograms
+    Mass(f64),
+    /// A slope in percent
+    Slope(f64),
+    /// A value without a unit
+    Number(f64),
+}
+
+impl Signal {
+    /// What this signal means by the catalog of its country, like [`StvoSign`] for Germany and
+    /// [`MutcdSign`] for the United States. The given country applies to signals without a
+    /// `@country`, for example the country of the road type. Signals of neither are looked up in
+    /// both catalogs, as their codes do not overlap, and signals of other countries are of the
+    /// category [`SignalCategory::Unknown`]. As in Germany the town entrance implies a speed
+    /// limit of 50 km/h and the town exit one of 100 km/h, these are their values there.
+    pub fn semantics(&self, country: Option<&CountryCode>) -> SignalSemantics {
+        let country = self
+            .country
+            .as_ref()
+            .or(country)
+            .map(|country| country.iso3166alpha2().unwrap_or(country.as_str()));
+        let stvo = || stvo_semantics(StvoSign::from_signal(self));
+        let mutcd = || mutcd_semantics(MutcdSign::from_signal(self));
+        let (category, speed) = match country {
+            Some("DE" | "DEU") => stvo(),
+            Some("US" | "USA") => mutcd(),
+            None | Some("OpenDRIVE") => match stvo() {
+                (SignalCategory::Unknown, _) => mutcd(),
+                semantics => semantics,
+            },
+            Some(_) => (SignalCategory::Unknown, None),
+        };
+        SignalSemantics {
+            category,
+            value: speed
+                .map(SignalValue::Speed)
+                .or_else(|| self.normalized_value()),
+        }
+    }
+
+    /// The value of this signal in normalized units
+    fn normalized_value(&self) -> Option<SignalValue> {
+        let value = self.value?;
+        Some(match &self.unit {
+            Some(Unit::Speed(unit)) => SignalValue::Speed(unit.velocity(value)),
+            Some(Unit::Distance(unit)) => SignalValue::Distance(unit.length(value)),
+            Some(Unit::Mass(unit)) => SignalValue::Mass(unit.kilograms(value)),
+            Some(Unit::Slope(_)) => SignalValue::Slope(value),
+            Some(Unit::Other(_)) | None => SignalValue::Number(value),
+        })
+    }
+}
+
+fn stvo_semantics(sign: StvoSign) -> (SignalCategory, Option<Velocity>) {
+    use SignalCategory as C;
+    let category = match &sign {
+        StvoSign::Yield | StvoSign::YieldToOncoming => C::Yield,
+        StvoSign::Stop | StvoSign::StopLine => C::Stop,
+        StvoSign::Roundabout => C::MandatoryDirection,
+        StvoSign::OneWay => C::OneWay,
+        StvoSign::NoVehicles | StvoSign::NoEntry => C::NoEntry,
+        StvoSign::SpeedLimit(_) | StvoSign::SpeedLimitZone(_) => C::SpeedLimit,
+        StvoSign::SpeedLimitEnd(_) | StvoSign::SpeedLimitZoneEnd(_) => C::SpeedLimitEnd,
+        StvoSign::MinimumSpeed(_) => C::MinimumSpeed,
+        StvoSign::NoOvertaking | StvoSign::NoOvertakingTrucks => C::NoOvertaking,
+        StvoSign::NoOvertakingEnd => C::NoOvertakingEnd,
+        StvoSign::EndOfRestrictions => C::EndOfRestrictions,
+        StvoSign::NoStopping => C::NoStopping,
+        StvoSign::NoParking => C::NoParking,
+        StvoSign::PriorityAtNextIntersection | StvoSign::PriorityRoad => C::Priority,
+        StvoSign::PriorityRoadEnd => C::PriorityEnd,
+        StvoSign::TownEntrance => C::TownEntrance,
+        StvoSign::TownExit => C::TownExit,
+        StvoSign::LivingStreet => C::LivingStreet,
+        StvoSign::LivingStreetEnd => C::LivingStreetEnd,
+        StvoSign::PedestrianCrossing | StvoSign::CrosswalkMarking => C::PedestrianCrossing,
+        StvoSign::TrafficLight | StvoSign::PedestrianTrafficLight => C::TrafficLight,
+        StvoSign::Unknown { .. } => C::Unknown,
+    };
+    let speed = match sign {
+        StvoSign::TownEntrance => Some(Velocity::new::<kilometer_per_hour>(50.0)),
+        StvoSign::TownExit => Some(Velocity::new::<kilometer_per_hour>(100.0)),
+        sign => sign.speed(),
+    };
+    (category, speed)
+}
+
+fn mutcd_semantics(sign: MutcdSign) -> (SignalCategory, Option<Velocity>) {
+    use SignalCategory as C;
+    let category = match &sign {
+        MutcdSign::Stop { .. } => C::Stop,
+        MutcdSign::Yield => C::Yield,
+        MutcdSign::SpeedLimit(_) | MutcdSign::NightSpeedLimit(_) => C::SpeedLimit,
+        MutcdSign::MinimumSpeed(_) => C::MinimumSpeed,
+        MutcdSign::AdvisorySpeed(_) => C::AdvisorySpeed,
+        MutcdSign::NoRightTurn | MutcdSign::NoLeftTurn | MutcdSign::NoUTurn => C::TurnRestriction,
+        MutcdSign::DoNotPass => C::NoOvertaking,
+        MutcdSign::PassWithCare => C::NoOvertakingEnd,
+        MutcdSign::KeepRight => C::MandatoryDirection,
+        MutcdSign::DoNotEnter => C::NoEntry,
+        MutcdSign::OneWay => C::OneWay,
+        MutcdSign::NoParking | MutcdSign::NoParkingSymbol => C::NoParking,
+        MutcdSign::StopHereOnRed => C::TrafficLight,
+        MutcdSign::StopAhead | MutcdSign::YieldAhead | MutcdSign::SignalAhead => C::Warning,
+        MutcdSign::PedestrianCrossing => C::PedestrianCrossing,
+        MutcdSign::Unknown { .. } => C::Unknown,
+    };
+    (category, sign.speed())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{parse_revision, TestRoad};
+    use crate::road::country_code::CountryCode;
+    use crate::signal::semantics::{SignalCategory, SignalValue};
+    use crate::units::prelude::*;
+    use std::str::FromStr;
+
+    #[test]
+    pub fn test_signal_semantics() {
+        let drive = parse_revision(
+            8,
+            TestRoad::new("1").sides(&[], &[]).signals(
+                r#"<signal s="10" t="-3" id="a" dynamic="no" orientation="+" zOffset="0" country="DE" type="274" subtype="53"/>
+                    <signal s="20" t="-3" id="b" dynamic="no" orientation="+" zOffset="0" country="US" type="R2-1" subtype="-1" value="45" unit="mph"/>
+                    <signal s="30" t="-3" id="c" dynamic="no" orientation="+" zOffset="0" type="R1-1" subtype="-1"/>
+                    <signal s="40" t="-3" id="d" dynamic="no" orientation="+" zOffset="0" type="310" subtype="-1"/>
+                    <signal s="50" t="-3" id="e" dynamic="no" orientation="+" zOffset="0" country="FR" type="206" subtype="-1" value="3.5" unit="t"/>
+                    <signal s="60" t="-3" id="f" dynamic="no" orientation="+" zOffset="0" type="262" subtype="-1" value="5.5" unit="t"/>"#,
+            ),
+        );
+        let signals = &drive.road[0].signals.as_ref().unwrap().signal;
+        let de = CountryCode::from_str("DE").unwrap();
+        let speed = |index: usize, country: Option<&CountryCode>| {
+            let semantics = signals[index].semantics(country);
+            match semantics.value {
+                Some(SignalValue::Speed(speed)) => (
+                    semantics.category,
+                    speed.get::<kilometer_per_hour>().round(),
+                ),
+                value => panic!("{value:?}"),
+            }
+        };
+        assert_eq!((SignalCategory::SpeedLimit, 30.0), speed(0, None));
+        assert_eq!((SignalCategory::SpeedLimit, 72.0), speed(1, Some(&de)));
+        assert_eq!(SignalCategory::Stop, signals[2].semantics(None).category);
+        assert_eq!((SignalCategory::TownEntrance, 50.0), speed(3, Some(&de)));
+
+        let unknown = signals[4].semantics(Some(&de));
+        assert_eq!(SignalCategory::Unknown, unknown.category);
+        assert_eq!(Some(SignalValue::Mass(3500.0)), unknown.value);
+        assert_eq!(
+            SignalCategory::Unknown,
+            signals[5].semantics(Some(&de)).category
+        );
+    }
+}
@@ -1,9 +1,9 @@
 use crate::core::additional_data::AdditionalData;
+use crate::lane::travel_direction::TravelDirection;
 use crate::object::lane_validity::LaneValidity;
 use crate::object::orientation::Orientation;
+use crate::units::prelude::*;
 use std::borrow::Cow;
-use uom::si::f64::Length;
-use uom::si::length::meter;
 
 /// Refers to the same, that is, identical signal from multiple roads. The referenced signals
 /// require a unique ID. The `<signalReference>` element consists of a main element and an optional
@@ -25,36 +25,58 @@ pub struct SignalReference {
 }
 
 impl SignalReference {
+    /// Whether the referenced signal is valid for the given lane of the referencing road, see
+    /// [`crate::signal::Signal::applies_to_lane`]
+    pub fn applies_to_lane(&self, lane_id: i64) -> bool {
+        self.validity.is_empty() || self.validity.iter().any(|v| v.contains(lane_id))
+    }
+
+    /// Whether the referenced signal is valid for traffic travelling in the given direction with
+    /// respect to the reference line of the referencing road
+    pub fn applies_to_direction(&self, direction: TravelDirection) -> bool {
+        match self.orientation {
+            Orientation::None => true,
+            Orientation::Plus => direction.permits(TravelDirection::Forward),
+            Orientation::Minus => direction.permits(TravelDirection::Backward),
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
+            &[String],
         ) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let visitor = self.additional_data.append_attributes(visitor);
         visit_attributes!(
             visitor,
+            options,
             "id" => self.id.as_str(),
             "orientation" => self.orientation.as_str(),
-            "s" => self.s.value.to_scientific_string().as_str(),
-            "t" => self.t.value.to_scientific_string().as_str(),
+            "s" => options.format("s", self.s.get::<meter>()).as_str(),
+            "t" => options.format("t", self.t.get::<meter>()).as_str(),
         )
     }
 
     pub fn visit_children(
         &self,
-        mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        options: &crate::writer::WriteOptions,
     ) -> xml::writer::Result<()> {
+        let mut visitor = self.additional_data.interleave_comments(visitor);
         for validity in &self.validity {
-            visit_children!(visitor, "validity" => validity);
+            visit_children!(visitor, options, "validity" => validity);
         }
 
-        self.additional_data.append_children(visitor)
+        self.additional_data.append_children(visitor, options)
     }
 }
 
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for SignalReference
 where
-    I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+    I: Iterator<Item = crate::parser::Result<crate::backend::Event<'static>>>,
 {
     type Error = Box<crate::parser::Error>;
 
@@ -74,7 +96,7 @@ where
             orientation: read.attribute("orientation")?,
             s: Length::new::<meter>(read.attribute("s")?),
             t: Length::new::<meter>(read.attribute("t")?),
-            additional_data,
+            additional_data: additional_data.complete_from(&read),
         })
     }
 }
@@ -0,0 +1,278 @@
+//! [`proptest`](https://crates.io/crates/proptest) strategies for the model, so that properties
+//! like "evaluating a plan view is continuous" can be tested against many generated values.
+//!
+//! The generated values are valid: lengths and widths are positive, geometries of a plan view are
+//! consecutive and start at the end of their predecessor, lane sections are ordered by `@s` and
+//! lane ids are numbered from the center lane outwards.
+
+use crate::core::additional_data::AdditionalData;
+use crate::junction::connection::Connection;
+use crate::junction::contact_point::ContactPoint;
+use crate::junction::lane_link::LaneLink;
+use crate::junction::Junction;
+use crate::lane::center::Center;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::lane_section::LaneSection;
+use crate::lane::lane_type::LaneType;
+use crate::lane::lanes::Lanes;
+use crate::lane::left::Left;
+use crate::lane::left_lane::LeftLane;
+use crate::lane::right::Right;
+use crate::lane::right_lane::RightLane;
+use crate::lane::width::Width;
+use crate::lane::Lane;
+use crate::road::geometry::arc::Arc;
+use crate::road::geometry::geometry_type::GeometryType;
+use crate::road::geometry::line::Line;
+use crate::road::geometry::param_poly_3::ParamPoly3;
+use crate::road::geometry::param_poly_3_p_range::ParamPoly3pRange;
+use crate::road::geometry::plan_view::PlanView;
+use crate::road::geometry::poly_3::Poly3;
+use crate::road::geometry::spiral::Spiral;
+use crate::road::geometry::Geometry;
+use crate::road::Road;
+use crate::units::prelude::*;
+use proptest::prelude::*;
+use vec1::Vec1;
+
+/// Geometry types of moderate curvature, so that geometries of a few hundred meters do not
+/// turn onto themselves
+pub fn geometry_type() -> impl Strategy<Value = GeometryType> {
+    let curvature = || -0.05..0.05f64;
+    prop_oneof![
+        Just(GeometryType::Line(Line::default())),
+        curvature().prop_map(|curvature| GeometryType::Arc(Arc {
+            curvature: Curvature::new::<radian_per_meter>(curvature),
+        })),
+        (curvature(), curvature()).prop_map(|(start, end)| GeometryType::Spiral(Spiral {
+            curvature_start: Curvature::new::<radian_per_meter>(start),
+            curvature_end: Curvature::new::<radian_per_meter>(end),
+        })),
+        (-0.01..0.01f64, -1e-4..1e-4f64).prop_map(|(c, d)| GeometryType::Poly3(Poly3 {
+            a: 0.0,
+            b: 0.0,
+            c,
+            d,
+        })),
+        (10.0..500.0f64, -10.0..10.0f64, -10.0..10.0f64).prop_map(|(b_u, c_v, d_v)| {
+            GeometryType::ParamPoly3(ParamPoly3 {
+                a_u: 0.0,
+                a_v: 0.0,
+                b_u,
+                b_v: 0.0,
+                c_u: 0.0,
+                c_v,
+                d_u: 0.0,
+                d_v,
+                p_range: ParamPoly3pRange::Normalized,
+            })
+        }),
+    ]
+}
+
+/// A geometry at `s = 0` with a length between 0.1m and 500m
+pub fn geometry() -> impl Strategy<Value = Geometry> {
+    (
+        -1e4..1e4f64,
+        -1e4..1e4f64,
+        -std::f64::consts::PI..std::f64::consts::PI,
+        0.1..500.0f64,
+        geometry_type(),
+    )
+        .prop_map(|(x, y, hdg, length, r#type)| Geometry {
+            hdg: Angle::new::<radian>(hdg),
+            length: Length::new::<meter>(length),
+            s: Length::new::<meter>(0.0),
+            x: Length::new::<meter>(x),
+            y: Length::new::<meter>(y),
+            r#type,
+            additional_data: AdditionalData::default(),
+        })
+}
+
+/// A plan view of 1 to `max_geometries` geometries, each starting with the pose, but not
+/// necessarily the curvature, at the end of its predecessor
+pub fn plan_view(max_geometries: usize) -> impl Strategy<Value = PlanView> {
+    prop::collection::vec(geometry(), 1..=max_geometries.max(1)).prop_map(|geometries| {
+        let mut geometries = geometries.into_iter();
+        let mut plan_view = PlanView::new(geometries.next().expect("at least one geometry"));
+        for mut geometry in geometries {
+            let previous = plan_view.geometry.last();
+            let end = previous.pose_at(previous.length);
+            geometry.s = previous.s + previous.length;
+            geometry.x = end.x;
+            geometry.y = end.y;
+            geometry.hdg = end.hdg;
+            plan_view.geometry.push(geometry);
+        }
+        plan_view
+    })
+}
+
+/// A driving lane with a width of 2m to 4.5m at the start of the lane section, which changes by
+/// at most 1cm per meter
+pub fn lane() -> impl Strategy<Value = Lane> {
+    (2.0..4.5f64, -0.01..0.01f64).prop_map(|(a, b)| Lane {
+        choice: vec![LaneChoice::Width(Width {
+            a,
+            b,
+            c: 0.0,
+            d: 0.0,
+            s_offset: Length::new::<meter>(0.0),
+        })],
+        r#type: LaneType::Driving,
+        ..Lane::default()
+    })
+}
+
+/// A lane section at `s = 0` with 0 to `max_lanes` lanes on each side, but at least one lane
+pub fn lane_section(max_lanes: usize) -> impl Strategy<Value = LaneSection> {
+    let max_lanes = max_lanes.max(1);
+    (
+        prop::collection::vec(lane(), 0..=max_lanes),
+        prop::collection::vec(lane(), 0..=max_lanes),
+    )
+        .prop_filter(
+            "a lane section needs a left or right lane",
+            |(left, right)| !left.is_empty() || !right.is_empty(),
+        )
+        .prop_map(|(left, right)| {
+            let count = left.len() as i64;
+            let left = left.into_iter().enumerate().map(|(index, base)| LeftLane {
+                id: count - index as i64,
+                base,
+            });
+            let right = right
+                .into_iter()
+                .enumerate()
+                .map(|(index, base)| RightLane {
+                    id: -(index as i64 + 1),
+                    base,
+                });
+            LaneSection {
+                s: 0.0,
+                single_side: None,
+                left: Vec1::try_from_vec(left.collect()).ok().map(|lane| Left {
+                    lane,
+                    additional_data: AdditionalData::default(),
+                }),
+                center: Center::default(),
+                right: Vec1::try_from_vec(right.collect()).ok().map(|lane| Right {
+                    lane,
+                    additional_data: AdditionalData::default(),
+                }),
+                additional_data: AdditionalData::default(),
+            }
+        })
+}
+
+/// Lanes of 1 to `max_sections` lane sections with 0 to `max_lanes` lanes on each side, the
+/// lane sections start at ascending s-coordinates within `[0, length)`
+pub fn lanes(
+    length: Length,
+    max_sections: usize,
+    max_lanes: usize,
+) -> impl Strategy<Value = Lanes> {
+    let length = length.get::<meter>().max(0.0);
+    (
+        prop::collection::vec(lane_section(max_lanes), 1..=max_sections.max(1)),
+        prop::collection::vec(0.0..1.0f64, max_sections),
+    )
+        .prop_map(move |(sections, mut starts)| {
+            starts.sort_by(f64::total_cmp);
+            let mut sections = sections.into_iter();
+            let mut lanes = Lanes {
+                lane_section: Vec1::new(sections.next().expect("at least one lane section")),
+                ..Lanes::default()
+            };
+            for (mut section, start) in sections.zip(starts) {
+                section.s = start * length;
+                lanes.lane_section.push(section);
+            }
+            lanes
+        })
+}
+
+/// A road with the given id that belongs to no junction, see [`plan_view`] and [`lanes`]
+pub fn road(id: impl Into<String>) -> impl Strategy<Value = Road> {
+    let id = id.into();
+    plan_view(8).prop_flat_map(move |plan_view| {
+        let road = Road::new(id.clone(), plan_view);
+        lanes(road.length, 4, 3).prop_map(move |lanes| Road {
+            lanes,
+            ..road.clone()
+        })
+    })
+}
+
+/// A junction with the given id and 1 to `max_connections` connections between the given
+/// roads, each with lane links between lanes with ids of `-3..=3`. At least two roads are
+/// required.
+pub fn junction(
+    id: impl Into<String>,
+    roads: Vec<String>,
+    max_connections: usize,
+) -> impl Strategy<Value = Junction> {
+    assert!(roads.len() >= 2, "a junction connects at least two roads");
+    let id = id.into();
+    let lane_id = || prop_oneof![-3..=-1i64, 1..=3i64];
+    let connection = (
+        prop::sample::subsequence(roads, 2),
+        prop::bool::ANY,
+        prop::collection::vec((lane_id(), lane_id()), 0..=3),
+    )
+        .prop_map(|(roads, start, links)| Connection {
+            incoming_road: Some(roads[0].clone()),
+            connecting_road: Some(roads[1].clone()),
+            contact_point: Some(if start {
+                ContactPoint::Start
+            } else {
+                ContactPoint::End
+            }),
+            lane_link: links
+                .into_iter()
+                .map(|(from, to)| LaneLink { from, to })
+                .collect(),
+            ..Connection::default()
+        });
+    prop::collection::vec(connection, 1..=max_connections.max(1)).prop_map(move |connections| {
+        let mut connections = connections.into_iter().enumerate().map(|(index, mut c)| {
+            c.id = index.to_string();
+            c
+        });
+        let mut junction = Junction::new(
+            id.clone(),
+            connections.next().expect("at least one connection"),
+        );
+        junction.connection.extend(connections);
+        junction
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::strategies::{plan_view, road};
+    use crate::units::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_plan_view_is_continuous(plan_view in plan_view(8)) {
+            for pair in plan_view.geometry.windows(2) {
+                let end = pair[0].pose_at(pair[0].length);
+                prop_assert!((end.x - pair[1].x).get::<meter>().abs() < 1e-6);
+                prop_assert!((end.y - pair[1].y).get::<meter>().abs() < 1e-6);
+                prop_assert_eq!(pair[0].s + pair[0].length, pair[1].s);
+            }
+        }
+
+        #[test]
+        fn test_road_is_valid(road in road("1")) {
+            let sections = &road.lanes.lane_section;
+            prop_assert!(sections.windows(2).all(|w| w[0].s <= w[1].s));
+            prop_assert!(sections.iter().all(|s| s.s < road.length.get::<meter>()));
+            prop_assert!(sections.iter().all(|s| s.lanes().count() > 1));
+        }
+
+    }
+}
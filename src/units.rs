@@ -0,0 +1,103 @@
+//! The physical quantities of the model.
+//!
+//! With the `uom` feature, which is enabled by default, quantities are the
+//! [`uom`](https://crates.io/crates/uom) types of `uom::si::f64` and units are the units of
+//! `uom::si`. Without the feature, all quantities are plain [`f64`] values in SI base units
+//! (meter, radian, meter per second) and `Quantity` provides the `new::<unit>(...)` and
+//! `get::<unit>()` functions for them, so that code written against this module compiles with
+//! and without the feature.
+
+#[cfg(feature = "uom")]
+pub use uom::si::f64::{Angle, Curvature, Length, Velocity};
+#[cfg(feature = "uom")]
+pub use uom::si::{angle, curvature, length, velocity};
+
+/// The quantities and units of this module together with `Quantity`, if required
+pub mod prelude {
+    pub use super::angle::{degree, radian};
+    pub use super::curvature::radian_per_meter;
+    pub use super::length::meter;
+    pub use super::velocity::{kilometer_per_hour, meter_per_second, mile_per_hour};
+    pub use super::{Angle, Curvature, Length, Velocity};
+
+    #[cfg(not(feature = "uom"))]
+    pub use super::Quantity;
+}
+
+/// Length in meter
+#[cfg(not(feature = "uom"))]
+pub type Length = f64;
+/// Angle in radian
+#[cfg(not(feature = "uom"))]
+pub type Angle = f64;
+/// Curvature in radian per meter
+#[cfg(not(feature = "uom"))]
+pub type Curvature = f64;
+/// Velocity in meter per second
+#[cfg(not(feature = "uom"))]
+pub type Velocity = f64;
+
+/// A unit of measurement, given by the factor that converts a value in this unit to the SI base
+/// unit of the quantity.
+#[cfg(not(feature = "uom"))]
+pub trait Unit {
+    const FACTOR: f64;
+}
+
+/// Mirrors the constructor and accessor of the `uom` quantities for plain [`f64`] values
+#[cfg(not(feature = "uom"))]
+pub trait Quantity {
+    fn new<U: Unit>(value: f64) -> Self;
+    fn get<U: Unit>(&self) -> f64;
+}
+
+#[cfg(not(feature = "uom"))]
+impl Quantity for f64 {
+    #[inline]
+    fn new<U: Unit>(value: f64) -> Self {
+        value * U::FACTOR
+    }
+
+    #[inline]
+    fn get<U: Unit>(&self) -> f64 {
+        self / U::FACTOR
+    }
+}
+
+#[cfg(not(feature = "uom"))]
+macro_rules! units {
+    ($($module:ident { $($unit:ident = $factor:expr),* $(,)? })*) => {
+        $(
+            pub mod $module {
+                $(
+                    #[allow(non_camel_case_types)]
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                    pub struct $unit;
+
+                    impl super::Unit for $unit {
+                        const FACTOR: f64 = $factor;
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "uom"))]
+units! {
+    angle {
+        radian = 1.0,
+        degree = std::f64::consts::PI / 180.0,
+    }
+    curvature {
+        radian_per_meter = 1.0,
+    }
+    length {
+        meter = 1.0,
+    }
+    velocity {
+        meter_per_second = 1.0,
+        kilometer_per_hour = 1.0 / 3.6,
+        mile_per_hour = 0.44704,
+    }
+}
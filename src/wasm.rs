@@ -0,0 +1,70 @@
+//! A [`wasm-bindgen`](https://crates.io/crates/wasm-bindgen) interface to parse, validate and
+//! export documents in the browser. Build the module with
+//! `cargo rustc --lib --release --target wasm32-unknown-unknown --no-default-features --features
+//! wasm,uom --crate-type cdylib` and generate its bindings with `wasm-bindgen`.
+
+use crate::core::validation::Validator;
+use crate::core::OpenDrive;
+use crate::parser::ParseOptions;
+use wasm_bindgen::prelude::*;
+
+/// A parsed document
+#[wasm_bindgen]
+pub struct Document {
+    drive: OpenDrive,
+}
+
+#[wasm_bindgen]
+impl Document {
+    /// Parses the XML of an ASAM OpenDRIVE document
+    #[wasm_bindgen(constructor)]
+    pub fn parse(xml: &str) -> Result<Document, JsError> {
+        OpenDrive::from_xml_str(xml)
+            .map(|drive| Document { drive })
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen(getter, js_name = roadCount)]
+    pub fn road_count(&self) -> usize {
+        self.drive.road.len()
+    }
+
+    #[wasm_bindgen(getter, js_name = junctionCount)]
+    pub fn junction_count(&self) -> usize {
+        self.drive.junction.len()
+    }
+
+    /// The document as XML
+    #[wasm_bindgen(js_name = toXml)]
+    pub fn to_xml(&self) -> Result<String, JsError> {
+        self.drive
+            .to_xml_string()
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// The roads and objects as GeoJSON, see [`OpenDrive::to_geojson`]
+    #[wasm_bindgen(js_name = toGeoJson)]
+    pub fn to_geojson(&self) -> String {
+        self.drive.to_geojson()
+    }
+}
+
+/// Parses the XML of an ASAM OpenDRIVE document and returns the violations of the rules of
+/// [`Validator::new`], fails if the document cannot be parsed
+#[wasm_bindgen]
+pub fn validate(xml: &str) -> Result<Vec<String>, JsError> {
+    let mut validator = Validator::new();
+    OpenDrive::from_str_with(
+        xml,
+        ParseOptions {
+            validator: Some(&mut validator),
+            ..ParseOptions::default()
+        },
+    )
+    .map_err(|e| JsError::new(&e.to_string()))?;
+    Ok(validator
+        .violations()
+        .iter()
+        .map(ToString::to_string)
+        .collect())
+}
@@ -0,0 +1,321 @@
+use std::io::Write;
+
+/// Line endings of the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Newline {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+impl Newline {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Layout of pretty-printed output, see [`crate::writer::WriteOptions::layout`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Layout {
+    /// The character to indent with, usually a space or a tab
+    pub indent_char: char,
+    /// The number of indent characters per level
+    pub indent_width: usize,
+    pub newline: Newline,
+    /// Start tags longer than this are written with one attribute per line, never if `None`
+    pub max_line_width: Option<usize>,
+    /// Whether containers without attributes and children, such as an empty `<objects>`, are
+    /// written
+    pub emit_empty_containers: bool,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            indent_char: ' ',
+            indent_width: 2,
+            newline: Newline::Lf,
+            max_line_width: None,
+            emit_empty_containers: true,
+        }
+    }
+}
+
+impl Layout {
+    /// Elements that only group their children and that may be omitted if empty
+    pub const CONTAINERS: &'static [&'static str] = &[
+        "link",
+        "elevationProfile",
+        "lateralProfile",
+        "objects",
+        "signals",
+        "surface",
+        "railroad",
+        "outlines",
+        "markings",
+        "borders",
+    ];
+
+    fn indent(&self, depth: usize) -> String {
+        std::iter::repeat_n(self.indent_char, depth * self.indent_width).collect()
+    }
+}
+
+/// Writes the events of the elements according to a [`Layout`]
+pub struct LayoutWriter<'a, W: Write> {
+    out: W,
+    layout: &'a Layout,
+    /// Names of the open elements and whether they have child elements or comments
+    open: Vec<(String, bool)>,
+    /// Whether the start tag of the innermost open element still lacks its closing `>`
+    start_tag_open: bool,
+    /// A container start tag that is only written once its first child arrives
+    pending: Option<(String, String)>,
+}
+
+impl<'a, W: Write> LayoutWriter<'a, W> {
+    pub fn new(out: W, layout: &'a Layout) -> Self {
+        Self {
+            out,
+            layout,
+            open: Vec::new(),
+            start_tag_open: false,
+            pending: None,
+        }
+    }
+
+    pub fn write(&mut self, event: xml::writer::XmlEvent) -> xml::writer::Result<()> {
+        match event {
+            xml::writer::XmlEvent::StartDocument {
+                version,
+                encoding,
+                standalone,
+            } => {
+                write!(
+                    self.out,
+                    r#"<?xml version="{version}" encoding="{}""#,
+                    encoding.unwrap_or("utf-8")
+                )?;
+                if let Some(standalone) = standalone {
+                    let standalone = if standalone { "yes" } else { "no" };
+                    write!(self.out, r#" standalone="{standalone}""#)?;
+                }
+                write!(self.out, "?>")?;
+            }
+            xml::writer::XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } => {
+                self.flush_pending()?;
+                let tag = self.start_tag(name.local_name, &attributes, &namespace);
+                if !self.layout.emit_empty_containers
+                    && attributes.is_empty()
+                    && Layout::CONTAINERS.contains(&name.local_name)
+                {
+                    self.pending = Some((name.local_name.to_string(), tag));
+                } else {
+                    self.open_element(name.local_name, &tag)?;
+                }
+            }
+            xml::writer::XmlEvent::EndElement { .. } => {
+                if self.pending.take().is_some() {
+                    return Ok(());
+                }
+                let Some((name, has_children)) = self.open.pop() else {
+                    return Ok(());
+                };
+                if self.start_tag_open {
+                    write!(self.out, "/>")?;
+                    self.start_tag_open = false;
+                } else {
+                    if has_children {
+                        self.new_line(self.open.len())?;
+                    }
+                    write!(self.out, "</{name}>")?;
+                }
+                if self.open.is_empty() {
+                    write!(self.out, "{}", self.layout.newline.as_str())?;
+                }
+            }
+            xml::writer::XmlEvent::CData(data) => {
+                self.flush_pending()?;
+                self.close_start_tag()?;
+                write!(
+                    self.out,
+                    "<![CDATA[{}]]>",
+                    data.replace("]]>", "]]]]><![CDATA[>")
+                )?;
+            }
+            xml::writer::XmlEvent::Characters(data) => {
+                self.flush_pending()?;
+                self.close_start_tag()?;
+                write!(self.out, "{}", escape(data, false))?;
+            }
+            xml::writer::XmlEvent::Comment(text) => {
+                self.flush_pending()?;
+                self.close_start_tag()?;
+                self.mark_child();
+                self.new_line(self.open.len())?;
+                write!(self.out, "<!--{text}-->")?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn start_tag(
+        &self,
+        name: &str,
+        attributes: &[xml::attribute::Attribute],
+        namespace: &xml::namespace::Namespace,
+    ) -> String {
+        let depth = self.open.len();
+        let declarations = namespace
+            .into_iter()
+            .filter(|(prefix, _)| !matches!(*prefix, "xml" | "xmlns" | ""))
+            .map(|(prefix, uri)| format!(r#"xmlns:{prefix}="{}""#, escape(uri, true)));
+        let attributes = declarations
+            .chain(attributes.iter().map(|a| {
+                let prefix = a.name.prefix.map(|p| format!("{p}:")).unwrap_or_default();
+                format!(
+                    r#"{prefix}{}="{}""#,
+                    a.name.local_name,
+                    escape(a.value, true)
+                )
+            }))
+            .collect::<Vec<_>>();
+        let line = std::iter::once(format!("<{name}"))
+            .chain(attributes.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let width = self.layout.indent(depth).chars().count() + line.chars().count() + 1;
+        match self.layout.max_line_width {
+            Some(max) if width > max && attributes.len() > 1 => {
+                let separator = format!(
+                    "{}{}",
+                    self.layout.newline.as_str(),
+                    self.layout.indent(depth + 1)
+                );
+                format!("<{name}{separator}{}", attributes.join(&separator))
+            }
+            _ => line,
+        }
+    }
+
+    fn open_element(&mut self, name: &str, tag: &str) -> xml::writer::Result<()> {
+        self.close_start_tag()?;
+        let is_root = self.open.is_empty();
+        self.mark_child();
+        if is_root {
+            write!(self.out, "{}", self.layout.newline.as_str())?;
+        } else {
+            self.new_line(self.open.len())?;
+        }
+        write!(self.out, "{tag}")?;
+        self.open.push((name.to_string(), false));
+        self.start_tag_open = true;
+        Ok(())
+    }
+
+    fn flush_pending(&mut self) -> xml::writer::Result<()> {
+        match self.pending.take() {
+            Some((name, tag)) => self.open_element(&name, &tag),
+            None => Ok(()),
+        }
+    }
+
+    fn close_start_tag(&mut self) -> xml::writer::Result<()> {
+        if self.start_tag_open {
+            write!(self.out, ">")?;
+            self.start_tag_open = false;
+        }
+        Ok(())
+    }
+
+    fn mark_child(&mut self) {
+        if let Some((_, has_children)) = self.open.last_mut() {
+            *has_children = true;
+        }
+    }
+
+    fn new_line(&mut self, depth: usize) -> xml::writer::Result<()> {
+        write!(
+            self.out,
+            "{}{}",
+            self.layout.newline.as_str(),
+            self.layout.indent(depth)
+        )?;
+        Ok(())
+    }
+}
+
+fn escape(value: &str, attribute: bool) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if attribute => escaped.push_str("&quot;"),
+            '\n' if attribute => escaped.push_str("&#xA;"),
+            '\r' if attribute => escaped.push_str("&#xD;"),
+            '\t' if attribute => escaped.push_str("&#x9;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::TestRoad;
+    use crate::writer::layout::{Layout, Newline};
+    use crate::writer::WriteOptions;
+
+    #[test]
+    pub fn test_layout() {
+        let road = TestRoad::new("1")
+            .sides(&[], &[])
+            .child("<link/><objects/>");
+        let drive = OpenDrive::from_xml_str(&format!(
+            r#"<OpenDRIVE><header revMajor="1" revMinor="7" name="A &amp; B"/>{road}</OpenDRIVE>"#
+        ))
+        .unwrap();
+
+        let layout = Layout {
+            indent_char: '\t',
+            indent_width: 1,
+            newline: Newline::CrLf,
+            max_line_width: Some(50),
+            emit_empty_containers: false,
+        };
+        let written = drive
+            .to_xml_string_with(&WriteOptions {
+                layout: Some(layout),
+                ..WriteOptions::shortest()
+            })
+            .unwrap();
+
+        assert!(written.starts_with(
+            "<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"yes\"?>\r\n<OpenDRIVE>\r\n\t<header"
+        ));
+        assert!(written.contains("name=\"A &amp; B\""));
+        assert!(
+            written.contains("\t<road id=\"1\" junction=\"-1\" length=\"100\">\r\n\t\t<planView>")
+        );
+        assert!(written.contains("\t\t\t<geometry\r\n\t\t\t\thdg=\"0\"\r\n"));
+        assert!(!written.contains("<link"));
+        assert!(!written.contains("<objects"));
+        assert!(written.ends_with("</OpenDRIVE>\r\n"));
+
+        let mut expected = drive.clone();
+        expected.road[0].link = None;
+        expected.road[0].objects = None;
+        assert_eq!(expected, OpenDrive::from_xml_str(&written).unwrap());
+    }
+}
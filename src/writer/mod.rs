@@ -0,0 +1,231 @@
+use layout::Layout;
+
+pub mod layout;
+
+pub type Result<T> = std::result::Result<T, Box<Error>>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The written output is no valid UTF8-String: {0}")]
+    FromUtf8Error(#[from] std::string::FromUtf8Error),
+    #[error("The xml write process failed because of an internal error: {0}")]
+    XmlError(#[from] xml::writer::Error),
+    #[error("The xml write process failed because of an io-error: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+/// How numbers are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Notation {
+    /// Like `1.5e2`
+    #[default]
+    Scientific,
+    /// Like `150.0`
+    Fixed,
+}
+
+/// Controls the formatting of numeric attributes. The default writes every number in scientific
+/// notation with 17 digits after the decimal point, which is exact for all values and what
+/// [`crate::core::OpenDrive::to_xml_string`] writes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteOptions<'a> {
+    pub notation: Notation,
+    /// Digits after the decimal point of coordinates and offsets, such as `@s`, `@x` or
+    /// `@sOffset`, the shortest exact representation if `None`
+    pub coordinate_precision: Option<usize>,
+    /// Digits after the decimal point of polynomial coefficients, such as `@a` to `@d` or `@aU`
+    /// to `@dV`, the shortest exact representation if `None`
+    pub coefficient_precision: Option<usize>,
+    /// Digits after the decimal point of all other numbers, the shortest exact representation if
+    /// `None`
+    pub precision: Option<usize>,
+    /// Removes trailing zeros after the decimal point, and the decimal point if no digit remains
+    pub trim_trailing_zeros: bool,
+    /// Keeps the lexical form of unchanged numbers as read, see
+    /// [`crate::core::lexical_forms::LexicalForms`]
+    pub lexical_forms: Option<&'a crate::core::lexical_forms::LexicalForms>,
+    /// Pretty-prints the output, which is written without any whitespace between elements if
+    /// `None`
+    pub layout: Option<Layout>,
+    /// Declares the `xsi` namespace on the `<OpenDRIVE>` element and the given schema as its
+    /// `xsi:noNamespaceSchemaLocation`, like `opendrive_17_core.xsd`
+    pub schema_location: Option<&'a str>,
+}
+
+impl Default for WriteOptions<'_> {
+    fn default() -> Self {
+        Self {
+            notation: Notation::Scientific,
+            coordinate_precision: Some(Self::DEFAULT_PRECISION),
+            coefficient_precision: Some(Self::DEFAULT_PRECISION),
+            precision: Some(Self::DEFAULT_PRECISION),
+            trim_trailing_zeros: false,
+            lexical_forms: None,
+            layout: None,
+            schema_location: None,
+        }
+    }
+}
+
+impl WriteOptions<'_> {
+    /// The default precision of the writer
+    pub const DEFAULT_PRECISION: usize = 17;
+
+    /// Options that write every number in its shortest exact representation in fixed notation
+    /// without trailing zeros, such as `3.5` or `100`
+    pub fn shortest() -> Self {
+        Self {
+            notation: Notation::Fixed,
+            coordinate_precision: None,
+            coefficient_precision: None,
+            precision: None,
+            trim_trailing_zeros: true,
+            lexical_forms: None,
+            layout: None,
+            schema_location: None,
+        }
+    }
+
+    /// The precision for the attribute of the given name
+    fn precision_of(&self, attribute: &str) -> Option<usize> {
+        match attribute {
+            "a" | "b" | "c" | "d" | "aU" | "bU" | "cU" | "dU" | "aV" | "bV" | "cV" | "dV" => {
+                self.coefficient_precision
+            }
+            "s" | "t" | "u" | "v" | "x" | "y" | "z" | "ds" | "sOffset" | "tOffset" | "zOffset"
+            | "sStart" | "sEnd" | "tStart" | "tEnd" | "zStart" | "zEnd" | "sRefLine"
+            | "tRefLine" => self.coordinate_precision,
+            _ => self.precision,
+        }
+    }
+
+    /// Formats a number of the attribute of the given name. The elements format only the numbers
+    /// of the schema with it, never the kept values of unknown attributes or the numeric ids of
+    /// roads.
+    pub fn format(&self, attribute: &str, value: f64) -> String {
+        let precision = self.precision_of(attribute);
+        let formatted = match (self.notation, precision) {
+            (Notation::Scientific, Some(precision)) => format!("{value:.precision$e}"),
+            (Notation::Scientific, None) => format!("{value:e}"),
+            (Notation::Fixed, Some(precision)) => format!("{value:.precision$}"),
+            (Notation::Fixed, None) => format!("{value}"),
+        };
+        if self.trim_trailing_zeros {
+            trim_trailing_zeros(&formatted)
+        } else {
+            formatted
+        }
+    }
+
+    /// Wraps an event visitor, so that it keeps the lexical forms and declares the schema of these
+    /// options. The numbers are formatted by the elements, which get these options passed.
+    pub fn apply<'s>(
+        &'s self,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()> + 's,
+    ) -> Box<dyn FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()> + 's> {
+        let visitor: Box<dyn FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()> + 's> =
+            match self.lexical_forms {
+                Some(forms) => Box::new(forms.preserve(visitor)),
+                None => Box::new(visitor),
+            };
+        match self.schema_location {
+            Some(location) => Box::new(declare_schema(location, visitor)),
+            None => visitor,
+        }
+    }
+}
+
+/// The namespace of XML schema instances, which declares `xsi:noNamespaceSchemaLocation`
+pub const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Wraps an event visitor, so that the first element declares the given schema location
+fn declare_schema<'s>(
+    location: &'s str,
+    mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()> + 's,
+) -> impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()> + 's {
+    let mut declared = false;
+    move |event: xml::writer::XmlEvent<'_>| match event {
+        xml::writer::XmlEvent::StartElement {
+            name,
+            attributes,
+            namespace,
+        } if !declared => {
+            declared = true;
+            let mut namespace = namespace.into_owned();
+            namespace.put("xsi", XSI_NAMESPACE);
+            let mut attributes = attributes.iter().copied().collect::<Vec<_>>();
+            attributes.push(xml::attribute::Attribute::new(
+                xml::name::Name::prefixed("noNamespaceSchemaLocation", "xsi"),
+                location,
+            ));
+            visitor(xml::writer::XmlEvent::StartElement {
+                name,
+                attributes: std::borrow::Cow::Owned(attributes),
+                namespace: std::borrow::Cow::Owned(namespace),
+            })
+        }
+        event => visitor(event),
+    }
+}
+
+fn trim_trailing_zeros(formatted: &str) -> String {
+    let (mantissa, exponent) = match formatted.find('e') {
+        Some(index) => formatted.split_at(index),
+        None => (formatted, ""),
+    };
+    let mantissa = if mantissa.contains('.') {
+        mantissa.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        mantissa
+    };
+    format!("{mantissa}{exponent}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::OpenDrive;
+    use crate::fixtures::{parse, TestRoad};
+    use crate::writer::{Notation, WriteOptions};
+
+    #[test]
+    pub fn test_write_options() {
+        let drive = parse(TestRoad::new("10").at(12.345678, 0.0, 0.5).lanes(
+            r#"<laneSection s="0">
+                <center><lane id="0" type="none"/></center>
+                <right><lane id="-1" type="driving"><width sOffset="0" a="3.25" b="0.001" c="0" d="0"/></lane></right>
+            </laneSection>"#,
+        ));
+
+        let default = drive.to_xml_string_with(&WriteOptions::default()).unwrap();
+        assert_eq!(drive.to_xml_string().unwrap(), default);
+
+        let shortest = drive.to_xml_string_with(&WriteOptions::shortest()).unwrap();
+        assert!(shortest.contains(r#"<road id="10" junction="-1" length="100""#));
+        assert!(shortest.contains(r#"x="12.345678""#));
+        assert!(shortest.contains(r#"a="3.25" b="0.001""#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&shortest).unwrap());
+
+        let fixed = drive
+            .to_xml_string_with(&WriteOptions {
+                notation: Notation::Fixed,
+                coordinate_precision: Some(2),
+                coefficient_precision: Some(4),
+                precision: Some(1),
+                trim_trailing_zeros: false,
+                lexical_forms: None,
+                layout: None,
+                schema_location: None,
+            })
+            .unwrap();
+        assert!(fixed.contains(r#"x="12.35""#));
+        assert!(fixed.contains(r#"hdg="0.5" length="100.0""#));
+        assert!(fixed.contains(r#"a="3.2500" b="0.0010""#));
+
+        let vendor = parse(TestRoad::new("11").attribute("vendorX", "1.00000000000000000e0"));
+        let shortest = vendor
+            .to_xml_string_with(&WriteOptions::shortest())
+            .unwrap();
+        assert!(shortest.contains(r#"vendorX="1.00000000000000000e0""#));
+        assert!(shortest.contains(r#"length="100""#));
+    }
+}